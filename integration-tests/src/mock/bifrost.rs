@@ -232,6 +232,7 @@ impl pallet_xcm::Config for Runtime {
 
 impl bifrost_currencies::Config for Runtime {
 	type GetNativeCurrencyId = NativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency =
 		bifrost_currencies::BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
@@ -35,21 +35,33 @@ use std::sync::Arc;
 
 use bb_bnc_rpc::{BbBNCRpc, BbBNCRpcApiServer};
 use bb_bnc_rpc_runtime_api::BbBNCRuntimeApi;
+use bifrost_channel_commission_rpc::{ChannelCommissionRpc, ChannelCommissionRpcApiServer};
+use bifrost_channel_commission_rpc_runtime_api::ChannelCommissionRuntimeApi;
+use bifrost_cross_in_out_rpc::{CrossInOutRpc, CrossInOutRpcApiServer};
+use bifrost_cross_in_out_rpc_runtime_api::CrossInOutRuntimeApi;
 use bifrost_farming_rpc::{FarmingRpc, FarmingRpcApiServer};
 use bifrost_farming_rpc_runtime_api::FarmingRuntimeApi;
 use bifrost_flexible_fee_rpc::{FeeRpcApiServer, FlexibleFeeRpc};
 use bifrost_flexible_fee_rpc_runtime_api::FlexibleFeeRuntimeApi as FeeRuntimeApi;
 use bifrost_polkadot_runtime::Hash;
-use bifrost_primitives::{AccountId, Balance, Block, CurrencyId, Nonce, ParaId, PoolId};
+use bifrost_primitives::{
+	AccountId, Balance, Block, BlockNumber, CurrencyId, Nonce, ParaId, PoolId,
+};
 use bifrost_salp_rpc::{SalpRpc, SalpRpcApiServer};
 use bifrost_salp_rpc_runtime_api::SalpRuntimeApi;
 use bifrost_stable_pool_rpc::{StablePoolRpc, StablePoolRpcApiServer};
 use bifrost_stable_pool_rpc_runtime_api::StablePoolRuntimeApi;
+use bifrost_token_issuer_rpc::{TokenIssuerRpc, TokenIssuerRpcApiServer};
+use bifrost_token_issuer_rpc_runtime_api::TokenIssuerRuntimeApi;
+use bifrost_vesting_rpc::{VestingRpc, VestingRpcApiServer};
+use bifrost_vesting_rpc_runtime_api::VestingRuntimeApi;
 use bifrost_vtoken_minting_rpc::{VtokenMintingRpc, VtokenMintingRpcApiServer};
 use bifrost_vtoken_minting_rpc_runtime_api::VtokenMintingRuntimeApi;
 use futures::channel::mpsc;
 use lend_market_rpc::{LendMarket, LendMarketApiServer};
 use lend_market_rpc_runtime_api::LendMarketApi;
+use pallet_prices_rpc::{Prices, PricesApiServer};
+use pallet_prices_rpc_runtime_api::PricesApi as PricesRuntimeApi;
 use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 use sc_consensus_manual_seal::rpc::{EngineCommand, ManualSeal, ManualSealApiServer};
 use sc_rpc_api::DenyUnsafe;
@@ -106,10 +118,15 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: FarmingRuntimeApi<Block, AccountId, PoolId, CurrencyId>,
+	C::Api: ChannelCommissionRuntimeApi<Block>,
+	C::Api: CrossInOutRuntimeApi<Block, AccountId>,
 	C::Api: FeeRuntimeApi<Block, AccountId>,
 	C::Api: SalpRuntimeApi<Block, ParaId, AccountId>,
 	C::Api: StablePoolRuntimeApi<Block>,
 	C::Api: LendMarketApi<Block, AccountId, Balance>,
+	C::Api: PricesRuntimeApi<Block, BlockNumber>,
+	C::Api: TokenIssuerRuntimeApi<Block, AccountId>,
+	C::Api: VestingRuntimeApi<Block, AccountId>,
 	C::Api: VtokenMintingRuntimeApi<Block, CurrencyId, Balance>,
 	C::Api: ZenlinkProtocolRuntimeApi<Block, AccountId, AssetId>,
 	C::Api:
@@ -130,6 +147,11 @@ where
 	module.merge(StableAmm::new(client.clone()).into_rpc())?;
 	module.merge(StablePoolRpc::new(client.clone()).into_rpc())?;
 	module.merge(LendMarket::new(client.clone()).into_rpc())?;
+	module.merge(Prices::new(client.clone()).into_rpc())?;
+	module.merge(ChannelCommissionRpc::new(client.clone()).into_rpc())?;
+	module.merge(CrossInOutRpc::new(client.clone()).into_rpc())?;
+	module.merge(TokenIssuerRpc::new(client.clone()).into_rpc())?;
+	module.merge(VestingRpc::new(client.clone()).into_rpc())?;
 	module.merge(VtokenMintingRpc::new(client).into_rpc())?;
 
 	Ok(module)
@@ -150,10 +172,14 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: FarmingRuntimeApi<Block, AccountId, PoolId, CurrencyId>,
+	C::Api: ChannelCommissionRuntimeApi<Block>,
+	C::Api: CrossInOutRuntimeApi<Block, AccountId>,
 	C::Api: FeeRuntimeApi<Block, AccountId>,
 	C::Api: SalpRuntimeApi<Block, ParaId, AccountId>,
 	C::Api: BbBNCRuntimeApi<Block, AccountId>,
 	C::Api: LendMarketApi<Block, AccountId, Balance>,
+	C::Api: PricesRuntimeApi<Block, BlockNumber>,
+	C::Api: VestingRuntimeApi<Block, AccountId>,
 	C::Api: VtokenMintingRuntimeApi<Block, CurrencyId, Balance>,
 	C::Api: ZenlinkProtocolRuntimeApi<Block, AccountId, AssetId>,
 	C::Api: StablePoolRuntimeApi<Block>,
@@ -173,6 +199,10 @@ where
 	module.merge(ZenlinkProtocol::new(client.clone()).into_rpc())?;
 	module.merge(StablePoolRpc::new(client.clone()).into_rpc())?;
 	module.merge(LendMarket::new(client.clone()).into_rpc())?;
+	module.merge(Prices::new(client.clone()).into_rpc())?;
+	module.merge(ChannelCommissionRpc::new(client.clone()).into_rpc())?;
+	module.merge(CrossInOutRpc::new(client.clone()).into_rpc())?;
+	module.merge(VestingRpc::new(client.clone()).into_rpc())?;
 	module.merge(VtokenMintingRpc::new(client).into_rpc())?;
 
 	if let Some(command_sink) = command_sink {
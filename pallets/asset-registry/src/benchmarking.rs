@@ -21,7 +21,7 @@
 use super::*;
 use crate::Pallet as AssetRegistry;
 use bifrost_primitives::CurrencyId;
-use frame_benchmarking::{benchmarks, v1::BenchmarkError};
+use frame_benchmarking::{benchmarks, v1::BenchmarkError, whitelisted_caller};
 use frame_support::{assert_ok, traits::UnfilteredDispatchable};
 use sp_runtime::traits::UniqueSaturatedFrom;
 
@@ -166,6 +166,89 @@ benchmarks! {
 		);
 	}
 
+	set_asset_status {
+		let origin = T::RegisterOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		assert_ok!(AssetRegistry::<T>::register_token_metadata(
+			origin.clone(),
+			Box::new(AssetMetadata {
+				name: b"Token Name".to_vec(),
+				symbol: b"TN".to_vec(),
+				decimals: 12,
+				minimal_balance: BalanceOf::<T>::unique_saturated_from(1u128),
+			})
+		));
+
+		let call = Call::<T>::set_asset_status {
+			currency_id: CurrencyId::Token2(0),
+			status: AssetStatus::Deprecated,
+		};
+	}: {call.dispatch_bypass_filter(origin)?}
+	verify {
+		assert_eq!(AssetStatuses::<T>::get(CurrencyId::Token2(0)), AssetStatus::Deprecated);
+	}
+
+	set_extended_metadata {
+		let origin = T::RegisterOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		assert_ok!(AssetRegistry::<T>::register_token_metadata(
+			origin.clone(),
+			Box::new(AssetMetadata {
+				name: b"Token Name".to_vec(),
+				symbol: b"TN".to_vec(),
+				decimals: 12,
+				minimal_balance: BalanceOf::<T>::unique_saturated_from(1u128),
+			})
+		));
+
+		let metadata = ExtendedAssetMetadata {
+			logo_uri_hash: Some(Default::default()),
+			issuer: Some(whitelisted_caller()),
+			category: AssetCategory::Foreign,
+			is_sufficient: true,
+		};
+		let call = Call::<T>::set_extended_metadata {
+			currency_id: CurrencyId::Token2(0),
+			metadata: Some(metadata.clone()),
+		};
+	}: {call.dispatch_bypass_filter(origin)?}
+	verify {
+		assert_eq!(ExtendedMetadatas::<T>::get(CurrencyId::Token2(0)), Some(metadata));
+	}
+
+	set_xcm_transfer_rule {
+		let origin = T::RegisterOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		assert_ok!(AssetRegistry::<T>::register_token_metadata(
+			origin.clone(),
+			Box::new(AssetMetadata {
+				name: b"Token Name".to_vec(),
+				symbol: b"TN".to_vec(),
+				decimals: 12,
+				minimal_balance: BalanceOf::<T>::unique_saturated_from(1u128),
+			})
+		));
+		assert_ok!(AssetRegistry::<T>::register_location(
+			origin.clone(),
+			CurrencyId::Token2(0),
+			Box::new(VersionedLocation::V4(Location::new(1, [Parachain(2001)]))),
+			Weight::from_parts(2000_000_000, u64::MAX),
+		));
+
+		let reserve = Location::new(1, [Parachain(2001)]);
+		let call = Call::<T>::set_xcm_transfer_rule {
+			currency_id: CurrencyId::Token2(0),
+			location: Box::new(VersionedLocation::V4(reserve.clone())),
+			rule: Some(XcmTransferRule::Reserve),
+		};
+	}: {call.dispatch_bypass_filter(origin)?}
+	verify {
+		assert_eq!(
+			XcmTransferRules::<T>::get(CurrencyId::Token2(0), reserve),
+			Some(XcmTransferRule::Reserve)
+		);
+	}
+
 	impl_benchmark_test_suite!(
 	AssetRegistry,
 	crate::mock::ExtBuilder::default().build(),
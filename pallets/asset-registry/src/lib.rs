@@ -23,16 +23,16 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use bifrost_primitives::{
-	AssetIds, CurrencyId,
+	AssetCategory, AssetIds, AssetInfo, AssetStatus, CurrencyId,
 	CurrencyId::{Native, Token, Token2},
-	CurrencyIdConversion, CurrencyIdMapping, CurrencyIdRegister, ForeignAssetId, LeasePeriod,
-	ParaId, PoolId, TokenId, TokenInfo, TokenSymbol,
+	CurrencyIdConversion, CurrencyIdMapping, CurrencyIdRegister, ExtendedAssetMetadata,
+	ForeignAssetId, LeasePeriod, ParaId, PoolId, TokenId, TokenInfo, TokenSymbol, XcmTransferRule,
 };
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	pallet_prelude::*,
-	traits::{Currency, EnsureOrigin},
+	traits::{Contains, ContainsPair, Currency, EnsureOrigin},
 	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
 };
 use frame_system::pallet_prelude::*;
@@ -108,6 +108,10 @@ pub mod pallet {
 		CurrencyIdNotExists,
 		/// CurrencyId exists
 		CurrencyIdExisted,
+		/// The asset is destroyed, its status can't be changed
+		AssetDestroyed,
+		/// The asset has no location, so it can't be received over XCM
+		LocationNotExists,
 	}
 
 	#[pallet::event]
@@ -123,6 +127,19 @@ pub mod pallet {
 		LocationSet { currency_id: CurrencyId, location: Location, weight: Weight },
 		/// The CurrencyId updated.
 		CurrencyIdUpdated { currency_id: CurrencyId, metadata: AssetMetadata<BalanceOf<T>> },
+		/// The status of the CurrencyId updated.
+		AssetStatusUpdated { currency_id: CurrencyId, status: AssetStatus },
+		/// The extended metadata of the CurrencyId set.
+		ExtendedMetadataSet {
+			currency_id: CurrencyId,
+			metadata: Option<ExtendedAssetMetadata<T::AccountId>>,
+		},
+		/// The XCM transfer rule of the CurrencyId for a location set.
+		XcmTransferRuleSet {
+			currency_id: CurrencyId,
+			location: Location,
+			rule: Option<XcmTransferRule>,
+		},
 	}
 
 	/// Next available Foreign AssetId ID.
@@ -169,6 +186,34 @@ pub mod pallet {
 	pub type CurrencyMetadatas<T: Config> =
 		StorageMap<_, Twox64Concat, CurrencyId, AssetMetadata<BalanceOf<T>>, OptionQuery>;
 
+	/// The storages for AssetStatuses. Assets without a status are active.
+	///
+	/// AssetStatuses: map CurrencyId => AssetStatus
+	#[pallet::storage]
+	pub type AssetStatuses<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, AssetStatus, ValueQuery>;
+
+	/// The storages for ExtendedMetadatas.
+	///
+	/// ExtendedMetadatas: map CurrencyId => Option<ExtendedAssetMetadata>
+	#[pallet::storage]
+	pub type ExtendedMetadatas<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, ExtendedAssetMetadata<T::AccountId>, OptionQuery>;
+
+	/// The locations trusted as reserve or teleporter of an asset received over XCM.
+	///
+	/// XcmTransferRules: double_map CurrencyId, Location => Option<XcmTransferRule>
+	#[pallet::storage]
+	pub type XcmTransferRules<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CurrencyId,
+		Twox64Concat,
+		Location,
+		XcmTransferRule,
+		OptionQuery,
+	>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -352,6 +397,82 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set the lifecycle status of a currency. A destroyed currency keeps its metadata, and
+		/// its status can't be changed anymore.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_asset_status())]
+		pub fn set_asset_status(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			status: AssetStatus,
+		) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				CurrencyMetadatas::<T>::contains_key(currency_id),
+				Error::<T>::CurrencyIdNotExists
+			);
+			ensure!(
+				AssetStatuses::<T>::get(currency_id) != AssetStatus::Destroyed,
+				Error::<T>::AssetDestroyed
+			);
+
+			if status.is_active() {
+				AssetStatuses::<T>::remove(currency_id);
+			} else {
+				AssetStatuses::<T>::insert(currency_id, status);
+			}
+			Self::deposit_event(Event::<T>::AssetStatusUpdated { currency_id, status });
+
+			Ok(())
+		}
+
+		/// Set or remove the extended metadata of a currency.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_extended_metadata())]
+		pub fn set_extended_metadata(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			metadata: Option<ExtendedAssetMetadata<T::AccountId>>,
+		) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				CurrencyMetadatas::<T>::contains_key(currency_id),
+				Error::<T>::CurrencyIdNotExists
+			);
+
+			ExtendedMetadatas::<T>::set(currency_id, metadata.clone());
+			Self::deposit_event(Event::<T>::ExtendedMetadataSet { currency_id, metadata });
+
+			Ok(())
+		}
+
+		/// Trust a location as reserve or teleporter of a currency received over XCM, or remove
+		/// the trust with `None`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_xcm_transfer_rule())]
+		pub fn set_xcm_transfer_rule(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			location: Box<VersionedLocation>,
+			rule: Option<XcmTransferRule>,
+		) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			let location: Location =
+				(*location).try_into().map_err(|()| Error::<T>::BadLocation)?;
+			ensure!(
+				CurrencyIdToLocations::<T>::contains_key(currency_id),
+				Error::<T>::LocationNotExists
+			);
+
+			XcmTransferRules::<T>::set(currency_id, location.clone(), rule);
+			Self::deposit_event(Event::<T>::XcmTransferRuleSet { currency_id, location, rule });
+
+			Ok(())
+		}
 	}
 }
 
@@ -423,6 +544,92 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
+
+	/// Get all registered currencies with their full metadata.
+	pub fn get_assets() -> Vec<AssetInfo<T::AccountId, BalanceOf<T>>> {
+		CurrencyMetadatas::<T>::iter()
+			.map(|(currency_id, metadata)| AssetInfo {
+				currency_id,
+				name: metadata.name,
+				symbol: metadata.symbol,
+				decimals: metadata.decimals,
+				minimal_balance: metadata.minimal_balance,
+				status: AssetStatuses::<T>::get(currency_id),
+				location: CurrencyIdToLocations::<T>::get(currency_id).map(VersionedLocation::V4),
+				xcm_weight: CurrencyIdToWeights::<T>::get(currency_id),
+				extended: ExtendedMetadatas::<T>::get(currency_id),
+				xcm_rules: XcmTransferRules::<T>::iter_prefix(currency_id)
+					.map(|(location, rule)| (VersionedLocation::V4(location), rule))
+					.collect(),
+			})
+			.collect()
+	}
+
+	/// Whether `asset` can be received over XCM: it must be a registered and transferable
+	/// currency, sufficient to create the beneficiary account, and at least its minimal balance.
+	/// Currencies without extended metadata are sufficient.
+	pub fn is_receivable(asset: &Asset) -> bool {
+		let Some(currency_id) = LocationToCurrencyIds::<T>::get(&asset.id.0) else {
+			return false;
+		};
+		if !AssetStatuses::<T>::get(currency_id).is_transferable() {
+			return false;
+		}
+
+		let extended = ExtendedMetadatas::<T>::get(currency_id);
+		if extended.as_ref().is_some_and(|extended| !extended.is_sufficient) {
+			return false;
+		}
+
+		let Some(metadata) = CurrencyMetadatas::<T>::get(currency_id) else {
+			return false;
+		};
+		match asset.fun {
+			Fungible(amount) =>
+				BalanceOf::<T>::unique_saturated_from(amount) >= metadata.minimal_balance,
+			NonFungible(_) => false,
+		}
+	}
+
+	/// Whether `origin` is trusted with `rule` for the receivable currency of `asset`.
+	fn is_trusted_location(asset: &Asset, origin: &Location, rule: XcmTransferRule) -> bool {
+		Self::is_receivable(asset) &&
+			LocationToCurrencyIds::<T>::get(&asset.id.0).is_some_and(|currency_id| {
+				XcmTransferRules::<T>::get(currency_id, origin) == Some(rule)
+			})
+	}
+}
+
+/// The assets whose reserve is the origin according to the registry, used as `IsReserve` of the
+/// XCM executor.
+pub struct RegisteredReserves<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> ContainsPair<Asset, Location> for RegisteredReserves<T> {
+	fn contains(asset: &Asset, origin: &Location) -> bool {
+		Pallet::<T>::is_trusted_location(asset, origin, XcmTransferRule::Reserve)
+	}
+}
+
+/// Narrows reserve or teleport matchers that don't consult the registry, e.g. the fixed reserves
+/// of the runtime, to the assets the registry allows to be received over XCM.
+pub struct ReceivableAssets<T, Matcher>(sp_std::marker::PhantomData<(T, Matcher)>);
+
+impl<T: Config, Matcher: ContainsPair<Asset, Location>> ContainsPair<Asset, Location>
+	for ReceivableAssets<T, Matcher>
+{
+	fn contains(asset: &Asset, origin: &Location) -> bool {
+		Matcher::contains(asset, origin) && Pallet::<T>::is_receivable(asset)
+	}
+}
+
+/// The assets the origin can teleport according to the registry, used as `IsTeleporter` of the
+/// XCM executor.
+pub struct RegisteredTeleporters<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> ContainsPair<Asset, Location> for RegisteredTeleporters<T> {
+	fn contains(asset: &Asset, origin: &Location) -> bool {
+		Pallet::<T>::is_trusted_location(asset, origin, XcmTransferRule::Teleport)
+	}
 }
 
 pub struct AssetIdMaps<T>(sp_std::marker::PhantomData<T>);
@@ -450,6 +657,20 @@ impl<T: Config> CurrencyIdMapping<CurrencyId, MultiLocation, AssetMetadata<Balan
 		let v4_location = Location::try_from(multi_location).ok()?;
 		LocationToCurrencyIds::<T>::get(v4_location)
 	}
+
+	fn get_asset_status(currency_id: CurrencyId) -> AssetStatus {
+		AssetStatuses::<T>::get(currency_id)
+	}
+}
+
+/// The currencies whose status allows transfers, used to filter the transfers of frozen and
+/// destroyed assets.
+pub struct TransferableAssets<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> Contains<CurrencyId> for TransferableAssets<T> {
+	fn contains(currency_id: &CurrencyId) -> bool {
+		AssetStatuses::<T>::get(currency_id).is_transferable()
+	}
 }
 
 impl<T: Config> CurrencyIdConversion<CurrencyId> for AssetIdMaps<T> {
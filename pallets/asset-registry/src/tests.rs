@@ -21,7 +21,7 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Everything};
 use mock::{
 	AssetRegistry, CouncilAccount, ExtBuilder, Runtime, RuntimeEvent, RuntimeOrigin, System,
 };
@@ -317,3 +317,258 @@ fn update_currency_metadata_nonexistent_currency_id() {
 		);
 	});
 }
+
+#[test]
+fn set_asset_status_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let caller = CouncilAccount::get();
+		let currency_id = CurrencyId::Token2(0);
+		let metadata = AssetMetadata {
+			name: b"Bifrost Native Coin".to_vec(),
+			symbol: b"BNC".to_vec(),
+			decimals: 12,
+			minimal_balance: 0,
+		};
+
+		assert_noop!(
+			AssetRegistry::set_asset_status(
+				RuntimeOrigin::signed(caller.clone()),
+				currency_id,
+				AssetStatus::Frozen
+			),
+			Error::<Runtime>::CurrencyIdNotExists
+		);
+		assert_ok!(AssetRegistry::register_token_metadata(
+			RuntimeOrigin::signed(caller.clone()),
+			Box::new(metadata.clone())
+		));
+		assert_eq!(AssetIdMaps::<Runtime>::get_asset_status(currency_id), AssetStatus::Active);
+
+		assert_ok!(AssetRegistry::set_asset_status(
+			RuntimeOrigin::signed(caller.clone()),
+			currency_id,
+			AssetStatus::Frozen
+		));
+		System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::AssetStatusUpdated {
+			currency_id,
+			status: AssetStatus::Frozen,
+		}));
+		assert_eq!(AssetIdMaps::<Runtime>::get_asset_status(currency_id), AssetStatus::Frozen);
+		assert!(!TransferableAssets::<Runtime>::contains(&currency_id));
+
+		// back to active
+		assert_ok!(AssetRegistry::set_asset_status(
+			RuntimeOrigin::signed(caller.clone()),
+			currency_id,
+			AssetStatus::Active
+		));
+		assert!(!AssetStatuses::<Runtime>::contains_key(currency_id));
+		assert!(TransferableAssets::<Runtime>::contains(&currency_id));
+
+		assert_ok!(AssetRegistry::set_asset_status(
+			RuntimeOrigin::signed(caller.clone()),
+			currency_id,
+			AssetStatus::Destroyed
+		));
+		// the metadata is kept and the status is final
+		assert_eq!(CurrencyMetadatas::<Runtime>::get(currency_id), Some(metadata));
+		assert_noop!(
+			AssetRegistry::set_asset_status(
+				RuntimeOrigin::signed(caller),
+				currency_id,
+				AssetStatus::Active
+			),
+			Error::<Runtime>::AssetDestroyed
+		);
+	});
+}
+
+#[test]
+fn set_extended_metadata_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let caller = CouncilAccount::get();
+		let currency_id = CurrencyId::Token2(0);
+		let metadata = AssetMetadata {
+			name: b"Bifrost Native Coin".to_vec(),
+			symbol: b"BNC".to_vec(),
+			decimals: 12,
+			minimal_balance: 0,
+		};
+		let extended = ExtendedAssetMetadata {
+			logo_uri_hash: None,
+			issuer: Some(caller.clone()),
+			category: AssetCategory::Foreign,
+			is_sufficient: true,
+		};
+
+		assert_noop!(
+			AssetRegistry::set_extended_metadata(
+				RuntimeOrigin::signed(caller.clone()),
+				currency_id,
+				Some(extended.clone())
+			),
+			Error::<Runtime>::CurrencyIdNotExists
+		);
+		assert_ok!(AssetRegistry::register_token_metadata(
+			RuntimeOrigin::signed(caller.clone()),
+			Box::new(metadata.clone())
+		));
+		assert_ok!(AssetRegistry::set_extended_metadata(
+			RuntimeOrigin::signed(caller.clone()),
+			currency_id,
+			Some(extended.clone())
+		));
+		assert_eq!(ExtendedMetadatas::<Runtime>::get(currency_id), Some(extended.clone()));
+
+		let assets = AssetRegistry::get_assets();
+		assert_eq!(assets.len(), 1);
+		assert_eq!(assets[0].currency_id, currency_id);
+		assert_eq!(assets[0].symbol, metadata.symbol);
+		assert_eq!(assets[0].extended, Some(extended));
+
+		assert_ok!(AssetRegistry::set_extended_metadata(
+			RuntimeOrigin::signed(caller),
+			currency_id,
+			None
+		));
+		assert_eq!(ExtendedMetadatas::<Runtime>::get(currency_id), None);
+	});
+}
+
+#[test]
+fn xcm_transfer_rules_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let caller = CouncilAccount::get();
+		let currency_id = CurrencyId::Token2(0);
+		let metadata = AssetMetadata {
+			name: b"Bifrost Native Coin".to_vec(),
+			symbol: b"BNC".to_vec(),
+			decimals: 12,
+			minimal_balance: 0,
+		};
+		let asset_location = Location::new(1, [Parachain(2001), GeneralIndex(1)]);
+		let reserve = Location::new(1, [Parachain(2001)]);
+		let asset: Asset = (asset_location.clone(), 100u128).into();
+
+		assert_ok!(AssetRegistry::register_token_metadata(
+			RuntimeOrigin::signed(caller.clone()),
+			Box::new(metadata)
+		));
+		assert_noop!(
+			AssetRegistry::set_xcm_transfer_rule(
+				RuntimeOrigin::signed(caller.clone()),
+				currency_id,
+				Box::new(VersionedLocation::V4(reserve.clone())),
+				Some(XcmTransferRule::Reserve)
+			),
+			Error::<Runtime>::LocationNotExists
+		);
+		assert_ok!(AssetRegistry::register_location(
+			RuntimeOrigin::signed(caller.clone()),
+			currency_id,
+			Box::new(VersionedLocation::V4(asset_location)),
+			Weight::zero()
+		));
+		assert!(!RegisteredReserves::<Runtime>::contains(&asset, &reserve));
+
+		assert_ok!(AssetRegistry::set_xcm_transfer_rule(
+			RuntimeOrigin::signed(caller.clone()),
+			currency_id,
+			Box::new(VersionedLocation::V4(reserve.clone())),
+			Some(XcmTransferRule::Reserve)
+		));
+		System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::XcmTransferRuleSet {
+			currency_id,
+			location: reserve.clone(),
+			rule: Some(XcmTransferRule::Reserve),
+		}));
+		assert!(RegisteredReserves::<Runtime>::contains(&asset, &reserve));
+		assert!(!RegisteredTeleporters::<Runtime>::contains(&asset, &reserve));
+		assert!(!RegisteredReserves::<Runtime>::contains(&asset, &Location::parent()));
+		assert_eq!(
+			AssetRegistry::get_assets()[0].xcm_rules,
+			vec![(VersionedLocation::V4(reserve.clone()), XcmTransferRule::Reserve)]
+		);
+
+		// nor an asset that is not sufficient to create the beneficiary account
+		let mut extended = ExtendedAssetMetadata {
+			logo_uri_hash: None,
+			issuer: None,
+			category: AssetCategory::Foreign,
+			is_sufficient: false,
+		};
+		assert_ok!(AssetRegistry::set_extended_metadata(
+			RuntimeOrigin::signed(caller.clone()),
+			currency_id,
+			Some(extended.clone())
+		));
+		assert!(!RegisteredReserves::<Runtime>::contains(&asset, &reserve));
+		extended.is_sufficient = true;
+		assert_ok!(AssetRegistry::set_extended_metadata(
+			RuntimeOrigin::signed(caller.clone()),
+			currency_id,
+			Some(extended.clone())
+		));
+		assert!(RegisteredReserves::<Runtime>::contains(&asset, &reserve));
+
+		// the fixed reserves of the runtime are narrowed to the receivable assets as well
+		type FixedReserves = ReceivableAssets<Runtime, Everything>;
+		assert!(FixedReserves::contains(&asset, &Location::parent()));
+		let unregistered: Asset = (Location::new(1, [Parachain(2002)]), 100u128).into();
+		assert!(!FixedReserves::contains(&unregistered, &Location::parent()));
+
+		// a frozen asset can't be received
+		assert_ok!(AssetRegistry::set_asset_status(
+			RuntimeOrigin::signed(caller.clone()),
+			currency_id,
+			AssetStatus::Frozen
+		));
+		assert!(!RegisteredReserves::<Runtime>::contains(&asset, &reserve));
+
+		assert_ok!(AssetRegistry::set_xcm_transfer_rule(
+			RuntimeOrigin::signed(caller),
+			currency_id,
+			Box::new(VersionedLocation::V4(reserve.clone())),
+			None
+		));
+		assert_eq!(XcmTransferRules::<Runtime>::get(currency_id, reserve), None);
+	});
+}
+
+#[test]
+fn receive_below_minimal_balance_should_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let caller = CouncilAccount::get();
+		let currency_id = CurrencyId::Token2(0);
+		let metadata = AssetMetadata {
+			name: b"Bifrost Native Coin".to_vec(),
+			symbol: b"BNC".to_vec(),
+			decimals: 12,
+			minimal_balance: 1000,
+		};
+		let asset_location = Location::new(1, [Parachain(2001), GeneralIndex(1)]);
+		let reserve = Location::new(1, [Parachain(2001)]);
+		let dust: Asset = (asset_location.clone(), 999u128).into();
+		let asset: Asset = (asset_location.clone(), 1000u128).into();
+
+		assert_ok!(AssetRegistry::register_token_metadata(
+			RuntimeOrigin::signed(caller.clone()),
+			Box::new(metadata)
+		));
+		assert_ok!(AssetRegistry::register_location(
+			RuntimeOrigin::signed(caller.clone()),
+			currency_id,
+			Box::new(VersionedLocation::V4(asset_location)),
+			Weight::zero()
+		));
+		assert_ok!(AssetRegistry::set_xcm_transfer_rule(
+			RuntimeOrigin::signed(caller.clone()),
+			currency_id,
+			Box::new(VersionedLocation::V4(reserve.clone())),
+			Some(XcmTransferRule::Reserve)
+		));
+
+		assert!(!RegisteredReserves::<Runtime>::contains(&dust, &reserve));
+		assert!(RegisteredReserves::<Runtime>::contains(&asset, &reserve));
+	});
+}
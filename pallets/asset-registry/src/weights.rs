@@ -58,6 +58,9 @@ pub trait WeightInfo {
 	fn register_location() -> Weight;
 	fn force_set_location() -> Weight;
 	fn update_currency_metadata() -> Weight;
+	fn set_asset_status() -> Weight;
+	fn set_extended_metadata() -> Weight;
+	fn set_xcm_transfer_rule() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -131,4 +134,43 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AssetRegistry::CurrencyMetadatas` (r:1 w:0)
+	/// Proof: `AssetRegistry::CurrencyMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetRegistry::AssetStatuses` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetStatuses` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_asset_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `409`
+		//  Estimated: `3874`
+		// Minimum execution time: 13_580_000 picoseconds.
+		Weight::from_parts(14_000_000, 3874)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetRegistry::CurrencyMetadatas` (r:1 w:0)
+	/// Proof: `AssetRegistry::CurrencyMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetRegistry::ExtendedMetadatas` (r:0 w:1)
+	/// Proof: `AssetRegistry::ExtendedMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_extended_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `409`
+		//  Estimated: `3874`
+		// Minimum execution time: 14_550_000 picoseconds.
+		Weight::from_parts(15_000_000, 3874)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetRegistry::CurrencyIdToLocations` (r:1 w:0)
+	/// Proof: `AssetRegistry::CurrencyIdToLocations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetRegistry::XcmTransferRules` (r:0 w:1)
+	/// Proof: `AssetRegistry::XcmTransferRules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_xcm_transfer_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `515`
+		//  Estimated: `3980`
+		// Minimum execution time: 15_520_000 picoseconds.
+		Weight::from_parts(16_000_000, 3980)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -99,6 +99,7 @@ pub type AdaptedBasicCurrency =
 
 impl bifrost_currencies::Config for Runtime {
 	type GetNativeCurrencyId = NativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type WeightInfo = ();
@@ -113,6 +113,7 @@ pub type AdaptedBasicCurrency =
 
 impl bifrost_currencies::Config for Runtime {
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type WeightInfo = ();
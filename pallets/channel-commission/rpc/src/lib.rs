@@ -0,0 +1,137 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{marker::PhantomData, sync::Arc};
+
+pub use bifrost_channel_commission_rpc_runtime_api::{
+	self as runtime_api, ChannelCommissionRuntimeApi,
+};
+use bifrost_primitives::{Balance, ChannelRanking, ChannelSettlement, CurrencyId};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{ErrorCode, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+#[rpc(client, server)]
+pub trait ChannelCommissionRpcApi<BlockHash> {
+	/// rpc method for getting the settlement history of a channel
+	#[method(name = "channel_commission_getChannelSettlementHistory")]
+	fn get_channel_settlement_history(
+		&self,
+		channel_id: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ChannelSettlement<NumberOrHex>>>;
+
+	/// rpc method for getting the channel leaderboard of the ongoing period
+	#[method(name = "channel_commission_getPeriodLeaderboard")]
+	fn get_period_leaderboard(
+		&self,
+		vtoken: CurrencyId,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ChannelRanking<NumberOrHex>>>;
+}
+
+#[derive(Clone, Debug)]
+pub struct ChannelCommissionRpc<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> ChannelCommissionRpc<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: PhantomData }
+	}
+}
+
+#[async_trait]
+impl<C, Block> ChannelCommissionRpcApiServer<<Block as BlockT>::Hash>
+	for ChannelCommissionRpc<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: ChannelCommissionRuntimeApi<Block>,
+{
+	fn get_channel_settlement_history(
+		&self,
+		channel_id: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ChannelSettlement<NumberOrHex>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let rs: Result<Vec<ChannelSettlement<Balance>>, _> =
+			api.get_channel_settlement_history(at, channel_id);
+
+		match rs {
+			Ok(history) => Ok(history
+				.into_iter()
+				.map(|settlement| ChannelSettlement {
+					period: settlement.period,
+					vtoken: settlement.vtoken,
+					commission_token: settlement.commission_token,
+					channel_mint: NumberOrHex::Hex(settlement.channel_mint.into()),
+					total_mint: NumberOrHex::Hex(settlement.total_mint.into()),
+					total_redeem: NumberOrHex::Hex(settlement.total_redeem.into()),
+					share: settlement.share,
+					rate: settlement.rate,
+					commission: NumberOrHex::Hex(settlement.commission.into()),
+				})
+				.collect()),
+			Err(e) => Err(ErrorObject::owned(
+				ErrorCode::InternalError.code(),
+				"Failed to get channel settlement history.",
+				Some(format!("{:?}", e)),
+			)),
+		}
+	}
+
+	fn get_period_leaderboard(
+		&self,
+		vtoken: CurrencyId,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ChannelRanking<NumberOrHex>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let rs: Result<Vec<ChannelRanking<Balance>>, _> =
+			api.get_period_leaderboard(at, vtoken, limit);
+
+		match rs {
+			Ok(rankings) => Ok(rankings
+				.into_iter()
+				.map(|ranking| ChannelRanking {
+					channel_id: ranking.channel_id,
+					mint: NumberOrHex::Hex(ranking.mint.into()),
+					share: ranking.share,
+				})
+				.collect()),
+			Err(e) => Err(ErrorObject::owned(
+				ErrorCode::InternalError.code(),
+				"Failed to get period leaderboard.",
+				Some(format!("{:?}", e)),
+			)),
+		}
+	}
+}
@@ -24,7 +24,7 @@ use bifrost_primitives::{CurrencyId, KSM, VKSM};
 use frame_benchmarking::v1::{account, benchmarks, whitelisted_caller, BenchmarkError};
 use frame_support::assert_ok;
 use frame_system::RawOrigin;
-use sp_runtime::traits::UniqueSaturatedFrom;
+use sp_runtime::traits::{UniqueSaturatedFrom, UniqueSaturatedInto};
 
 use super::*;
 #[allow(unused_imports)]
@@ -135,6 +135,52 @@ benchmarks! {
 	}: _(RawOrigin::Signed(test_account), channel_id)
 
 	on_initialize {
+		let block_num = BlockNumberFor::<T>::from(0u32);
+	}: {ChannelCommission::<T>::on_initialize(block_num);}
+	verify {
+		assert_eq!(ClearingCursor::<T>::get(), Some(ClearingStep::SetEnvironment));
+	}
+
+	set_clearing_environment {
+		// assume we have 60 channels and 30 vtoken at most
+		let x in 1 .. 60;
+		let y in 1 .. 30;
+
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let channel_name =  b"Bifrost".to_vec();
+		let receiver: T::AccountId = whitelisted_caller();
+
+		for i in 0 .. y {
+			let i: u8 = i.try_into().unwrap();
+			let vtoken = CurrencyId::VToken2(i);
+			let commission_token = CurrencyId::Token2(i);
+
+			assert_ok!(ChannelCommission::<T>::set_commission_tokens(
+				origin.clone(),
+				vtoken, Some(commission_token)
+			));
+
+			let amount: BalanceOf<T> = 1000u128.unique_saturated_into();
+			PeriodVtokenTotalMint::<T>::insert(vtoken, (amount, amount));
+			PeriodVtokenTotalRedeem::<T>::insert(vtoken, (amount, amount));
+			PeriodTotalCommissions::<T>::insert(commission_token, (amount, amount));
+		}
+
+		for channel_id in 0 .. x {
+			assert_ok!(ChannelCommission::<T>::register_channel(
+				origin.clone(),
+				channel_name.clone(), receiver.clone()
+			));
+
+			for i in 0 .. y {
+				let i: u8 = i.try_into().unwrap();
+				let amount: BalanceOf<T> = 100u128.unique_saturated_into();
+				PeriodChannelVtokenMint::<T>::insert(channel_id, CurrencyId::VToken2(i), (amount, amount));
+			}
+		}
+	}: {ChannelCommission::<T>::set_clearing_environment();}
+
+	clear_channel_commissions {
 		// assume we have 30 vtoken at most
 		let x in 1 .. 30;
 
@@ -142,7 +188,6 @@ benchmarks! {
 		let channel_name =  b"Bifrost".to_vec();
 		let receiver: T::AccountId = whitelisted_caller();
 		let share = Permill::from_percent(20);
-		let commission_account: T::AccountId = T::CommissionPalletId::get().into_account_truncating();
 
 		// token_id
 		for i in 0 .. x {
@@ -168,15 +213,20 @@ benchmarks! {
 			let new_amount: BalanceOf<T> = 1000u128.unique_saturated_into();
 			PeriodVtokenTotalRedeem::<T>::insert(vtoken, (old_amount, new_amount));
 
-			let old_amount: BalanceOf<T> = 0u128.unique_saturated_into();
-			let new_amount: BalanceOf<T> = 100u128.unique_saturated_into();
-			PeriodTotalCommissions::<T>::insert(vtoken, (old_amount, new_amount));
+			let old_amount: BalanceOf<T> = 100u128.unique_saturated_into();
+			let new_amount: BalanceOf<T> = 0u128.unique_saturated_into();
+			PeriodTotalCommissions::<T>::insert(commission_token, (old_amount, new_amount));
+		}
 
-			// register_channel
-			assert_ok!(ChannelCommission::<T>::register_channel(
-				origin.clone(),
-				channel_name.clone(), receiver.clone()
-			));
+		// register_channel
+		assert_ok!(ChannelCommission::<T>::register_channel(
+			origin.clone(),
+			channel_name.clone(), receiver.clone()
+		));
+
+		for i in 0 .. x {
+			let i: u8 = i.try_into().unwrap();
+			let vtoken = CurrencyId::VToken2(i);
 
 			// set channel share
 			let old_amount: BalanceOf<T> = 2000u128.unique_saturated_into();
@@ -185,8 +235,37 @@ benchmarks! {
 			PeriodChannelVtokenMint::<T>::insert(0, vtoken, (old_amount, new_amount));
 		}
 
-		let block_num =BlockNumberFor::<T>::from(101u32);
-	}: {ChannelCommission::<T>::on_initialize(block_num);}
+		// a full history window to prune
+		ClearingPeriod::<T>::put(T::MaxHistoryPeriods::get().saturating_add(1));
+	}: {
+		ChannelCommission::<T>::clear_channel_commissions(0);
+		ChannelCommission::<T>::update_channel_vtoken_shares(0);
+	}
+
+	clear_bifrost_commissions {
+		// assume we have 30 vtoken at most
+		let x in 1 .. 30;
+
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let commission_account: T::AccountId = T::CommissionPalletId::get().into_account_truncating();
+
+		for i in 0 .. x {
+			let i: u8 = i.try_into().unwrap();
+			let vtoken = CurrencyId::VToken2(i);
+			let commission_token = CurrencyId::Token2(i);
+
+			assert_ok!(ChannelCommission::<T>::set_commission_tokens(
+				origin.clone(),
+				vtoken, Some(commission_token)
+			));
+
+			let old_amount: BalanceOf<T> = 4000000000u128.unique_saturated_into();
+			let new_amount: BalanceOf<T> = 0u128.unique_saturated_into();
+			PeriodTotalCommissions::<T>::insert(commission_token, (old_amount, new_amount));
+			PeriodClearedCommissions::<T>::insert(commission_token, BalanceOf::<T>::unique_saturated_from(1000u32));
+			T::MultiCurrency::deposit(commission_token, &commission_account, 4000000000u32.into())?;
+		}
+	}: {ChannelCommission::<T>::clear_bifrost_commissions();}
 
 	set_channel_vtoken_shares {
 		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
@@ -212,5 +291,39 @@ benchmarks! {
 
 	}: _<T::RuntimeOrigin>(origin.clone(), channel_id, vtoken_set, shares)
 
+	set_channel_commission_tiers {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let channel_name = b"Bifrost".to_vec();
+		let receiver: T::AccountId = whitelisted_caller();
+		let channel_id = 0;
+		let vtoken = CurrencyId::VToken2(0);
+
+		assert_ok!(ChannelCommission::<T>::set_commission_tokens(
+			origin.clone(),
+			vtoken, Some(CurrencyId::Token2(0))
+		));
+
+		assert_ok!(ChannelCommission::<T>::register_channel(
+			origin.clone(),
+			channel_name, receiver
+		));
+
+		let tiers: Vec<(BalanceOf<T>, Percent)> = (0 .. T::MaxCommissionTiers::get())
+			.map(|i| (BalanceOf::<T>::unique_saturated_from(i as u128 * 1_000_000u128), Percent::from_percent(5 + i as u8)))
+			.collect();
+	}: _<T::RuntimeOrigin>(origin.clone(), channel_id, vtoken, tiers)
+
+	set_channel_auto_compound {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let channel_name = b"Bifrost".to_vec();
+		let receiver: T::AccountId = whitelisted_caller();
+		let channel_id = 0;
+
+		assert_ok!(ChannelCommission::<T>::register_channel(
+			origin.clone(),
+			channel_name, receiver.clone()
+		));
+	}: _(RawOrigin::Signed(receiver), channel_id, true)
+
 	impl_benchmark_test_suite!(ChannelCommission,crate::mock::ExtBuilder::default().build(),crate::mock::Runtime);
 }
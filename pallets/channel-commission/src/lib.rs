@@ -23,21 +23,25 @@ extern crate alloc;
 
 use alloc::{vec, vec::Vec};
 use bifrost_primitives::{
-	CurrencyId, CurrencyIdExt, SlpHostingFeeProvider, VTokenMintRedeemProvider,
+	ChannelRanking, ChannelSettlement, CurrencyId, CurrencyIdExt, SlpHostingFeeProvider,
+	VTokenMintRedeemProvider, VtokenMintingInterface,
+};
+use frame_support::{
+	pallet_prelude::*, storage::with_storage_layer, weights::WeightMeter, PalletId,
 };
-use frame_support::{pallet_prelude::*, PalletId};
 use frame_system::pallet_prelude::*;
 use orml_traits::MultiCurrency;
 use sp_io::MultiRemovalResults;
 use sp_runtime::{
 	helpers_128bit::multiply_by_rational_with_rounding,
-	traits::{AccountIdConversion, CheckedAdd, UniqueSaturatedFrom, UniqueSaturatedInto, Zero},
+	traits::{AccountIdConversion, CheckedAdd, UniqueSaturatedFrom, Zero},
 	PerThing, Percent, Permill, Rounding, SaturatedConversion, Saturating,
 };
 pub use weights::WeightInfo;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migration;
 mod mock;
 mod tests;
 pub mod weights;
@@ -52,11 +56,28 @@ type ChannelId = u32;
 
 const REMOVE_TOKEN_LIMIT: u32 = 100;
 const DEFAULT_COMMISSION_RATE: Percent = Percent::from_percent(20);
+/// The number of blocks the clearing may go without a step in `on_idle` before `on_initialize`
+/// forces its next step.
+const MAX_CLEARING_STALL_BLOCKS: u32 = 10;
+
+/// The next step of the ongoing clearing, run in `on_idle` as the block weight allows, or forced
+/// in `on_initialize` once the clearing stalls.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ClearingStep {
+	/// Move the amounts of the ongoing period to the previous period.
+	SetEnvironment,
+	/// Clear the commissions and shares of the channels, starting from the channel id.
+	ClearChannels(ChannelId),
+	/// Transfer the commissions not cleared to channels to Bifrost.
+	ClearBifrost,
+}
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -72,6 +93,13 @@ pub mod pallet {
 		/// The receiving account of Bifrost commission
 		type BifrostCommissionReceiver: Get<AccountIdOf<Self>>;
 
+		/// Mint commissions into vtokens for the auto-compounding channels
+		type VtokenMintingInterface: VtokenMintingInterface<
+			AccountIdOf<Self>,
+			CurrencyId,
+			BalanceOf<Self>,
+		>;
+
 		/// Weight information for extrinsic in this module.
 		type WeightInfo: WeightInfo;
 
@@ -82,6 +110,14 @@ pub mod pallet {
 		// The maximum bytes length of channel name
 		#[pallet::constant]
 		type NameLengthLimit: Get<u32>;
+
+		// The number of clearing periods whose channel settlements are kept
+		#[pallet::constant]
+		type MaxHistoryPeriods: Get<u32>;
+
+		// The maximum number of commission rate tiers of a channel vtoken
+		#[pallet::constant]
+		type MaxCommissionTiers: Get<u32>;
 	}
 
 	#[pallet::error]
@@ -102,6 +138,10 @@ pub mod pallet {
 		DivisionByZero,
 		/// Error indicating that the removal operation was not completed successfully.
 		RemovalNotComplete,
+		/// The thresholds of commission rate tiers must be strictly increasing.
+		InvalidCommissionTiers,
+		/// The number of commission rate tiers exceeds `MaxCommissionTiers`.
+		TooManyCommissionTiers,
 	}
 
 	#[pallet::event]
@@ -189,6 +229,38 @@ pub mod pallet {
 			limit: u32,
 			executed_num: u32,
 		},
+		ChannelCommissionTiersSet {
+			channel_id: ChannelId,
+			vtoken: CurrencyId,
+			tiers: Vec<(BalanceOf<T>, Percent)>,
+		},
+		ChannelAutoCompoundSet {
+			channel_id: ChannelId,
+			enabled: bool,
+		},
+		/// The channel commission was minted into vtokens and sent to the receive account.
+		CommissionCompounded {
+			channel_id: ChannelId,
+			commission_token: CurrencyId,
+			amount: BalanceOf<T>,
+			vtoken: CurrencyId,
+			vtoken_amount: BalanceOf<T>,
+		},
+		/// Minting the channel commission failed, the commission is kept claimable instead.
+		CommissionCompoundFailed {
+			channel_id: ChannelId,
+			commission_token: CurrencyId,
+			amount: BalanceOf<T>,
+		},
+		/// A new period started before the last clearing finished. The new clearing starts
+		/// right after the last one.
+		ClearingDelayed {
+			period: u32,
+		},
+		/// All channels and Bifrost have been settled for the period.
+		ClearingPeriodFinished {
+			period: u32,
+		},
 	}
 
 	/// Auto increment channel id
@@ -289,41 +361,99 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Commission rate tiers of a channel + vtoken, ascending by the channel vtoken amount,
+	/// 【(channel_id, vtoken) => [(vtoken amount threshold, commission rate)]】
+	#[pallet::storage]
+	pub type ChannelCommissionTiers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ChannelId,
+		Blake2_128Concat,
+		CurrencyId,
+		BoundedVec<(BalanceOf<T>, Percent), T::MaxCommissionTiers>,
+		ValueQuery,
+	>;
+
+	/// Channels whose commissions are minted into vtokens at clearing, 【channel_id => enabled】
+	#[pallet::storage]
+	pub type ChannelAutoCompound<T> = StorageMap<_, Blake2_128Concat, ChannelId, bool, ValueQuery>;
+
+	/// The next step of the ongoing clearing, None if no clearing is in progress
+	#[pallet::storage]
+	pub type ClearingCursor<T> = StorageValue<_, ClearingStep, OptionQuery>;
+
+	/// The number of new clearings to start one after the other once the ongoing one finishes
+	#[pallet::storage]
+	pub type ClearingDelayed<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// The block the ongoing clearing started or last ran a step in
+	#[pallet::storage]
+	pub type ClearingProgressAt<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// Index of the latest clearing period, increased when a new clearing starts
+	#[pallet::storage]
+	pub type ClearingPeriod<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// Settlement results of a channel in the recent clearing periods, 【(channel_id, period) =>
+	/// settlements】
+	#[pallet::storage]
+	pub type ChannelSettlementHistory<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ChannelId,
+		Twox64Concat,
+		u32,
+		BoundedVec<ChannelSettlement<BalanceOf<T>>, ConstU32<REMOVE_TOKEN_LIMIT>>,
+		ValueQuery,
+	>;
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
-			let channel_count: u32 = ChannelNextId::<T>::get().into();
+			// Force the next step of a clearing that on_idle has not advanced for too long, so
+			// that it finishes even if the blocks keep no idle weight for it.
+			let weight = Self::force_stalled_clearing(n);
+
+			// If the current block number is the first block of a new clearing period, start the
+			// clearing, which is run step by step in on_idle.
+			if !(n % T::ClearingDuration::get()).is_zero() {
+				return weight;
+			}
 
-			// get the commission token count
-			let commission_token_count = CommissionTokens::<T>::iter().count() as u32;
+			if ClearingCursor::<T>::exists() {
+				ClearingDelayed::<T>::mutate(|delayed| *delayed = delayed.saturating_add(1));
+				Self::deposit_event(Event::ClearingDelayed { period: ClearingPeriod::<T>::get() });
+			} else {
+				ClearingCursor::<T>::put(ClearingStep::SetEnvironment);
+				ClearingProgressAt::<T>::put(n);
+			}
 
-			// If the current block number is the first block of a new clearing period, we need to
-			// prepare data for clearing.
-			if (n % T::ClearingDuration::get()).is_zero() {
-				Self::set_clearing_environment();
-			} else if (n % T::ClearingDuration::get()) < (channel_count + 1).into() {
-				let channel_index = n % T::ClearingDuration::get() - 1u32.into();
-				let channel_id: ChannelId =
-					BlockNumberFor::<T>::unique_saturated_into(channel_index);
-				Self::clear_channel_commissions(channel_id);
-				Self::update_channel_vtoken_shares(channel_id);
-			} else if (n % T::ClearingDuration::get()) == (channel_count + 1).into() {
-				Self::clear_bifrost_commissions();
+			weight.saturating_add(T::WeightInfo::on_initialize())
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, limit: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(limit);
+
+			if meter.try_consume(T::DbWeight::get().reads(1)).is_err() ||
+				!ClearingCursor::<T>::exists()
+			{
+				return meter.consumed();
 			}
 
-			// Weight under the assumption that we have 30 vtoken
-			T::WeightInfo::on_initialize(commission_token_count)
+			Self::process_clearing(&mut meter);
+			meter.consumed()
 		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		// Weight under the assumption that we have 30 vtoken
+		// Weight scales with the number of commission tokens the channel gets a rate for
 		#[pallet::weight(T::WeightInfo::register_channel(CommissionTokens::<T>::iter().count() as u32))]
 		pub fn register_channel(
 			origin: OriginFor<T>,
@@ -376,25 +506,40 @@ pub mod pallet {
 			Channels::<T>::remove(channel_id);
 
 			// remove the channel from ChannelCommissionTokenRates storage
-			Self::check_removed_all(ChannelCommissionTokenRates::<T>::clear_prefix(
-				channel_id,
-				REMOVE_TOKEN_LIMIT,
-				None,
-			))?;
+			Self::clear_by_cursor(|cursor| {
+				ChannelCommissionTokenRates::<T>::clear_prefix(
+					channel_id,
+					REMOVE_TOKEN_LIMIT,
+					cursor,
+				)
+			});
 
 			// remove the channel from ChannelVtokenShares storage
-			Self::check_removed_all(ChannelVtokenShares::<T>::clear_prefix(
-				channel_id,
-				REMOVE_TOKEN_LIMIT,
-				None,
-			))?;
+			Self::clear_by_cursor(|cursor| {
+				ChannelVtokenShares::<T>::clear_prefix(channel_id, REMOVE_TOKEN_LIMIT, cursor)
+			});
 
 			// remove the channel from PeriodChannelVtokenMint storage
-			Self::check_removed_all(PeriodChannelVtokenMint::<T>::clear_prefix(
-				channel_id,
-				REMOVE_TOKEN_LIMIT,
-				None,
-			))?;
+			Self::clear_by_cursor(|cursor| {
+				PeriodChannelVtokenMint::<T>::clear_prefix(channel_id, REMOVE_TOKEN_LIMIT, cursor)
+			});
+
+			// remove the channel from ChannelCommissionTiers storage
+			Self::clear_by_cursor(|cursor| {
+				ChannelCommissionTiers::<T>::clear_prefix(channel_id, REMOVE_TOKEN_LIMIT, cursor)
+			});
+
+			// remove the channel from ChannelAutoCompound storage
+			ChannelAutoCompound::<T>::remove(channel_id);
+
+			// remove the channel from ChannelSettlementHistory storage
+			Self::clear_by_cursor(|cursor| {
+				ChannelSettlementHistory::<T>::clear_prefix(
+					channel_id,
+					T::MaxHistoryPeriods::get().max(1),
+					cursor,
+				)
+			});
 
 			Self::deposit_event(Event::ChannelRemoved { channel_id });
 
@@ -514,6 +659,8 @@ pub mod pallet {
 					ChannelCommissionTokenRates::<T>::remove(channel_id, vtoken);
 					// remove the vtoken from ChannelVtokenShares storage
 					ChannelVtokenShares::<T>::remove(channel_id, vtoken);
+					// remove the vtoken from ChannelCommissionTiers storage
+					ChannelCommissionTiers::<T>::remove(channel_id, vtoken);
 					// remove the vtoken from PeriodChannelVtokenMint storage
 					PeriodChannelVtokenMint::<T>::remove(channel_id, vtoken);
 				});
@@ -593,11 +740,191 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::set_channel_commission_tiers())]
+		pub fn set_channel_commission_tiers(
+			origin: OriginFor<T>,
+			channel_id: ChannelId,
+			vtoken: CurrencyId,
+			tiers: Vec<(BalanceOf<T>, Percent)>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			// check if the channel exists
+			ensure!(Channels::<T>::contains_key(channel_id), Error::<T>::ChannelNotExist);
+			// check if the vtoken exists
+			ensure!(
+				CommissionTokens::<T>::contains_key(vtoken),
+				Error::<T>::VtokenNotConfiguredForCommission
+			);
+
+			// the thresholds must be strictly increasing
+			ensure!(
+				tiers.windows(2).all(|pair| pair[0].0 < pair[1].0),
+				Error::<T>::InvalidCommissionTiers
+			);
+
+			// if tiers is empty, fall back to the flat channel commission rate
+			if tiers.is_empty() {
+				ChannelCommissionTiers::<T>::remove(channel_id, vtoken);
+			} else {
+				let bounded_tiers = BoundedVec::try_from(tiers.clone())
+					.map_err(|_| Error::<T>::TooManyCommissionTiers)?;
+				ChannelCommissionTiers::<T>::insert(channel_id, vtoken, bounded_tiers);
+			}
+
+			Self::deposit_event(Event::ChannelCommissionTiersSet { channel_id, vtoken, tiers });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_channel_auto_compound())]
+		pub fn set_channel_auto_compound(
+			origin: OriginFor<T>,
+			channel_id: ChannelId,
+			enabled: bool,
+		) -> DispatchResult {
+			let receive_account = Channels::<T>::get(channel_id)
+				.map(|channel_info| channel_info.0)
+				.ok_or(Error::<T>::ChannelNotExist)?;
+
+			// either the control origin or the channel receive account can switch it
+			if let Err(origin) = T::ControlOrigin::try_origin(origin) {
+				let who = ensure_signed(origin)?;
+				ensure!(who == receive_account, DispatchError::BadOrigin);
+			}
+
+			if enabled {
+				ChannelAutoCompound::<T>::insert(channel_id, true);
+			} else {
+				ChannelAutoCompound::<T>::remove(channel_id);
+			}
+
+			Self::deposit_event(Event::ChannelAutoCompoundSet { channel_id, enabled });
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Run the clearing steps from the cursor until the clearing finishes or the next step does
+	/// not fit into the remaining weight.
+	pub(crate) fn process_clearing(meter: &mut WeightMeter) {
+		let (channel_count, token_count) = Self::clearing_counts();
+		if meter.try_consume(Self::clearing_overhead(token_count)).is_err() {
+			return;
+		}
+
+		while let Some(step) = ClearingCursor::<T>::get() {
+			if meter
+				.try_consume(Self::clearing_step_weight(step, channel_count, token_count))
+				.is_err()
+			{
+				break;
+			}
+
+			Self::run_clearing_step(step, channel_count);
+		}
+	}
+
+	/// Run the next clearing step if the clearing made no progress for
+	/// `MAX_CLEARING_STALL_BLOCKS`, regardless of the remaining block weight.
+	pub(crate) fn force_stalled_clearing(n: BlockNumberFor<T>) -> Weight {
+		let mut weight = T::DbWeight::get().reads(1);
+		let Some(step) = ClearingCursor::<T>::get() else {
+			return weight;
+		};
+
+		weight.saturating_accrue(T::DbWeight::get().reads(1));
+		if n.saturating_sub(ClearingProgressAt::<T>::get()) < MAX_CLEARING_STALL_BLOCKS.into() {
+			return weight;
+		}
+
+		let (channel_count, token_count) = Self::clearing_counts();
+		weight.saturating_accrue(Self::clearing_overhead(token_count));
+		weight.saturating_accrue(Self::clearing_step_weight(step, channel_count, token_count));
+		Self::run_clearing_step(step, channel_count);
+
+		weight
+	}
+
+	fn run_clearing_step(step: ClearingStep, channel_count: u32) {
+		ClearingProgressAt::<T>::put(frame_system::Pallet::<T>::block_number());
+
+		match step {
+			ClearingStep::SetEnvironment => {
+				Self::set_clearing_environment();
+				ClearingCursor::<T>::put(Self::next_channel_step(0, channel_count));
+			},
+			ClearingStep::ClearChannels(channel_id) if channel_id < channel_count => {
+				Self::clear_channel_commissions(channel_id);
+				Self::update_channel_vtoken_shares(channel_id);
+				ClearingCursor::<T>::put(Self::next_channel_step(
+					channel_id.saturating_add(1),
+					channel_count,
+				));
+			},
+			ClearingStep::ClearChannels(_) => {
+				ClearingCursor::<T>::put(ClearingStep::ClearBifrost);
+			},
+			ClearingStep::ClearBifrost => {
+				Self::clear_bifrost_commissions();
+				ClearingCursor::<T>::kill();
+				Self::deposit_event(Event::ClearingPeriodFinished {
+					period: ClearingPeriod::<T>::get(),
+				});
+
+				// start the next delayed clearing right away
+				let delayed = ClearingDelayed::<T>::get();
+				if !delayed.is_zero() {
+					ClearingDelayed::<T>::put(delayed - 1);
+					ClearingCursor::<T>::put(ClearingStep::SetEnvironment);
+				}
+			},
+		}
+	}
+
+	fn next_channel_step(channel_id: ChannelId, channel_count: u32) -> ClearingStep {
+		if channel_id < channel_count {
+			ClearingStep::ClearChannels(channel_id)
+		} else {
+			ClearingStep::ClearBifrost
+		}
+	}
+
+	/// The channel count and the commission token count the clearing weights depend on.
+	pub(crate) fn clearing_counts() -> (u32, u32) {
+		(ChannelNextId::<T>::get(), CommissionTokens::<T>::iter_keys().count() as u32)
+	}
+
+	/// The weight of reading the clearing counts and updating the cursor.
+	pub(crate) fn clearing_overhead(token_count: u32) -> Weight {
+		T::DbWeight::get().reads_writes(2u64.saturating_add(token_count.into()), 2)
+	}
+
+	pub(crate) fn clearing_step_weight(
+		step: ClearingStep,
+		channel_count: u32,
+		token_count: u32,
+	) -> Weight {
+		let step_weight = match step {
+			ClearingStep::SetEnvironment =>
+				T::WeightInfo::set_clearing_environment(channel_count, token_count),
+			ClearingStep::ClearChannels(channel_id) if channel_id < channel_count =>
+				T::WeightInfo::clear_channel_commissions(token_count),
+			ClearingStep::ClearChannels(_) => Weight::zero(),
+			ClearingStep::ClearBifrost => T::WeightInfo::clear_bifrost_commissions(token_count),
+		};
+
+		step_weight.saturating_add(T::DbWeight::get().reads_writes(1, 2))
+	}
+
 	pub(crate) fn set_clearing_environment() {
+		ClearingPeriod::<T>::mutate(|period| *period = period.saturating_add(1));
+
 		//  Move the vtoken issuance amount from ongoing period to the previous period and clear the
 		// ongoing period issuance amount
 		let snapshots: Vec<CurrencyId> = VtokenIssuanceSnapshots::<T>::iter_keys().collect();
@@ -699,6 +1026,10 @@ impl<T: Config> Pallet<T> {
 			return;
 		}
 
+		let period = ClearingPeriod::<T>::get();
+		let auto_compound = ChannelAutoCompound::<T>::get(channel_id);
+		let mut settlements = Vec::new();
+
 		// calculate the commission amount for each commission token
 		ChannelVtokenShares::<T>::iter_prefix(channel_id).for_each(
 			|(vtoken, channel_vtoken_share)| {
@@ -719,40 +1050,209 @@ impl<T: Config> Pallet<T> {
 					let raw_channel_commission = channel_vtoken_share.mul_floor(total_commission);
 
 					// get the channel vtoken commission rate
-					let mut channel_commission_rate =
-						ChannelCommissionTokenRates::<T>::get(channel_id, vtoken);
-					if channel_commission_rate.is_zero() {
-						channel_commission_rate = DEFAULT_COMMISSION_RATE;
-					}
+					let channel_commission_rate = Self::channel_commission_rate(
+						channel_id,
+						vtoken,
+						channel_vtoken_share.mul_floor(vtoken_issuance),
+					);
 
 					// calculate the channel commission amount
 					let channel_commission =
 						channel_commission_rate.mul_floor(raw_channel_commission);
 
-					// update channel_commission to ChannelClaimableCommissions storage
-					ChannelClaimableCommissions::<T>::mutate(
-						channel_id,
-						commission_token,
-						|amount| {
-							let sum_up = amount.saturating_add(channel_commission);
-							*amount = sum_up;
-
-							Self::deposit_event(Event::ChannelClaimableCommissionUpdated {
-								channel_id,
-								commission_token,
-								amount: *amount,
-							});
-						},
-					);
+					// mint the commission into vtokens for the auto-compounding channel, or keep it
+					// claimable if minting fails
+					let compounded = auto_compound &&
+						!channel_commission.is_zero() &&
+						Self::compound_channel_commission(
+							channel_id,
+							commission_token,
+							channel_commission,
+						)
+						.is_ok();
+
+					if !compounded {
+						// update channel_commission to ChannelClaimableCommissions storage
+						ChannelClaimableCommissions::<T>::mutate(
+							channel_id,
+							commission_token,
+							|amount| {
+								let sum_up = amount.saturating_add(channel_commission);
+								*amount = sum_up;
+
+								Self::deposit_event(Event::ChannelClaimableCommissionUpdated {
+									channel_id,
+									commission_token,
+									amount: *amount,
+								});
+							},
+						);
+					}
 
 					// add the amount to the PeriodClearedCommissions storage
 					PeriodClearedCommissions::<T>::mutate(commission_token, |amount| {
 						let sum_up = amount.saturating_add(channel_commission);
 						*amount = sum_up;
 					});
+
+					settlements.push(ChannelSettlement {
+						period,
+						vtoken,
+						commission_token,
+						channel_mint: PeriodChannelVtokenMint::<T>::get(channel_id, vtoken).0,
+						total_mint: PeriodVtokenTotalMint::<T>::get(vtoken).0,
+						total_redeem: PeriodVtokenTotalRedeem::<T>::get(vtoken).0,
+						share: channel_vtoken_share,
+						rate: channel_commission_rate,
+						commission: channel_commission,
+					});
 				}
 			},
 		);
+
+		Self::record_channel_settlements(channel_id, period, settlements);
+	}
+
+	/// The commission rate of the highest tier reached by the channel vtoken amount, or the flat
+	/// channel commission rate if no tier is reached.
+	pub(crate) fn channel_commission_rate(
+		channel_id: ChannelId,
+		vtoken: CurrencyId,
+		channel_vtoken_amount: BalanceOf<T>,
+	) -> Percent {
+		let tier_rate = ChannelCommissionTiers::<T>::get(channel_id, vtoken)
+			.into_iter()
+			.rev()
+			.find(|(threshold, _)| channel_vtoken_amount >= *threshold)
+			.map(|(_, rate)| rate);
+
+		match tier_rate {
+			Some(rate) => rate,
+			None => {
+				let rate = ChannelCommissionTokenRates::<T>::get(channel_id, vtoken);
+				if rate.is_zero() {
+					DEFAULT_COMMISSION_RATE
+				} else {
+					rate
+				}
+			},
+		}
+	}
+
+	/// Mint the channel commission into vtokens and send them to the channel receive account.
+	pub(crate) fn compound_channel_commission(
+		channel_id: ChannelId,
+		commission_token: CurrencyId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let result = with_storage_layer(|| -> Result<_, DispatchError> {
+			let receive_account = Channels::<T>::get(channel_id)
+				.map(|channel_info| channel_info.0)
+				.ok_or(Error::<T>::ChannelNotExist)?;
+			let vtoken = commission_token.to_vtoken().map_err(|_| Error::<T>::ConversionError)?;
+
+			let vtoken_amount = T::VtokenMintingInterface::mint(
+				Self::account_id(),
+				commission_token,
+				amount,
+				BoundedVec::default(),
+				None,
+			)?;
+			T::MultiCurrency::transfer(
+				vtoken,
+				&Self::account_id(),
+				&receive_account,
+				vtoken_amount,
+			)?;
+
+			Ok((vtoken, vtoken_amount))
+		});
+
+		match result {
+			Ok((vtoken, vtoken_amount)) => {
+				Self::deposit_event(Event::CommissionCompounded {
+					channel_id,
+					commission_token,
+					amount,
+					vtoken,
+					vtoken_amount,
+				});
+				Ok(())
+			},
+			Err(e) => {
+				log::error!(
+					"Failed to compound commission for channel: {:?}, error: {:?}",
+					channel_id,
+					e
+				);
+				Self::deposit_event(Event::CommissionCompoundFailed {
+					channel_id,
+					commission_token,
+					amount,
+				});
+				Err(e)
+			},
+		}
+	}
+
+	/// Keep the settlements of the channel for the clearing period, and prune the period falling
+	/// out of the history window.
+	pub(crate) fn record_channel_settlements(
+		channel_id: ChannelId,
+		period: u32,
+		settlements: Vec<ChannelSettlement<BalanceOf<T>>>,
+	) {
+		let max_periods = T::MaxHistoryPeriods::get();
+
+		// prune every period out of the window, not only the one that just fell out of it
+		let expired: Vec<u32> = ChannelSettlementHistory::<T>::iter_key_prefix(channel_id)
+			.filter(|kept| kept.saturating_add(max_periods) <= period)
+			.collect();
+		for expired_period in expired {
+			ChannelSettlementHistory::<T>::remove(channel_id, expired_period);
+		}
+
+		if settlements.is_empty() || max_periods.is_zero() {
+			return;
+		}
+
+		ChannelSettlementHistory::<T>::insert(
+			channel_id,
+			period,
+			BoundedVec::truncate_from(settlements),
+		);
+	}
+
+	/// Settlements of a channel in the kept clearing periods, latest period first.
+	pub fn get_channel_settlement_history(
+		channel_id: ChannelId,
+	) -> Vec<ChannelSettlement<BalanceOf<T>>> {
+		let mut history: Vec<(u32, BoundedVec<_, _>)> =
+			ChannelSettlementHistory::<T>::iter_prefix(channel_id).collect();
+		history.sort_by(|a, b| b.0.cmp(&a.0));
+
+		history
+			.into_iter()
+			.flat_map(|(_, settlements)| settlements.into_inner())
+			.collect()
+	}
+
+	/// Channels ranked by the vtoken amount minted through them in the ongoing period.
+	pub fn get_period_leaderboard(
+		vtoken: CurrencyId,
+		limit: u32,
+	) -> Vec<ChannelRanking<BalanceOf<T>>> {
+		let mut rankings: Vec<ChannelRanking<BalanceOf<T>>> = Channels::<T>::iter_keys()
+			.map(|channel_id| ChannelRanking {
+				channel_id,
+				mint: PeriodChannelVtokenMint::<T>::get(channel_id, vtoken).1,
+				share: ChannelVtokenShares::<T>::get(channel_id, vtoken),
+			})
+			.collect();
+		rankings.sort_by(|a, b| b.mint.cmp(&a.mint).then(a.channel_id.cmp(&b.channel_id)));
+		rankings.truncate(limit as usize);
+
+		rankings
 	}
 
 	pub(crate) fn update_channel_vtoken_shares(channel_id: ChannelId) {
@@ -922,6 +1422,14 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Repeat a limited removal from the cursor it returns until nothing is left.
+	fn clear_by_cursor(mut clear: impl FnMut(Option<&[u8]>) -> MultiRemovalResults) {
+		let mut res = clear(None);
+		while let Some(cursor) = res.maybe_cursor {
+			res = clear(Some(&cursor));
+		}
+	}
+
 	fn check_removed_all(res: MultiRemovalResults) -> Result<(), Error<T>> {
 		ensure!(res.maybe_cursor.is_none(), Error::<T>::RemovalNotComplete);
 		Ok(())
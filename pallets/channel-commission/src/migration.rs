@@ -0,0 +1,71 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::traits::OnRuntimeUpgrade;
+use sp_runtime::traits::UniqueSaturatedInto;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+pub mod v1 {
+	use super::*;
+
+	/// Clearing used to run one step per block in `on_initialize`: the environment at the first
+	/// block of a period, then one channel per block, then Bifrost. Resume an in-flight clearing
+	/// from the step the current block would have run, starting its stall window at the upgrade
+	/// block.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+			let in_code_storage_version = Pallet::<T>::in_code_storage_version();
+			if on_chain_storage_version == 0 && in_code_storage_version == 1 {
+				log::info!("Migrating channel-commission storage to v1");
+
+				let n = frame_system::Pallet::<T>::block_number();
+				let index: u32 = (n % T::ClearingDuration::get()).unique_saturated_into();
+				let channel_count = ChannelNextId::<T>::get();
+
+				// index 0 is a new period, which on_initialize of this block starts
+				if index > 0 && index <= channel_count {
+					ClearingCursor::<T>::put(ClearingStep::ClearChannels(index - 1));
+				} else if index == channel_count.saturating_add(1) {
+					ClearingCursor::<T>::put(ClearingStep::ClearBifrost);
+				}
+				if ClearingCursor::<T>::exists() {
+					ClearingProgressAt::<T>::put(n);
+				}
+
+				in_code_storage_version.put::<Pallet<T>>();
+				T::DbWeight::get().reads_writes(3, 3)
+			} else {
+				log::warn!("channel-commission migration should be removed.");
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"on_chain_storage_version should be 1"
+			);
+			Ok(())
+		}
+	}
+}
@@ -22,12 +22,20 @@
 use crate::mock::sp_api_hidden_includes_construct_runtime::hidden_include::traits::OnInitialize;
 use bifrost_primitives::{
 	currency::{ASG, BNC, KSM},
-	CommissionPalletId, CurrencyId,
+	CommissionPalletId, CurrencyId, CurrencyIdExt, RedeemType, VtokenMintingInterface,
+};
+use frame_support::{
+	derive_impl,
+	dispatch::DispatchResultWithPostInfo,
+	ord_parameter_types, parameter_types,
+	traits::{Everything, Get, Nothing, OnIdle},
+	weights::Weight,
+	BoundedVec, PalletId,
 };
-use frame_support::{derive_impl, ord_parameter_types, parameter_types, traits::Nothing, PalletId};
 use frame_system::EnsureSignedBy;
+use orml_traits::MultiCurrency;
 use sp_core::ConstU32;
-use sp_runtime::{traits::AccountIdConversion, AccountId32, BuildStorage};
+use sp_runtime::{traits::AccountIdConversion, AccountId32, BuildStorage, DispatchError};
 
 use crate as bifrost_channel_commission;
 
@@ -74,6 +82,7 @@ pub type AdaptedBasicCurrency =
 
 impl bifrost_currencies::Config for Runtime {
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type WeightInfo = ();
@@ -129,6 +138,8 @@ ord_parameter_types! {
 parameter_types! {
 	pub const ClearingDuration: u32 = 100;
 	pub const NameLengthLimit: u32 = 20;
+	pub const MaxHistoryPeriods: u32 = 3;
+	pub const MaxCommissionTiers: u32 = 3;
 	pub BifrostCommissionReceiver: AccountId = AccountId32::new([7u8; 32]);
 }
 
@@ -138,9 +149,84 @@ impl bifrost_channel_commission::Config for Runtime {
 	type ControlOrigin = EnsureSignedBy<One, AccountId>;
 	type CommissionPalletId = CommissionPalletId;
 	type BifrostCommissionReceiver = BifrostCommissionReceiver;
+	type VtokenMintingInterface = MockVtokenMinting;
 	type WeightInfo = ();
 	type ClearingDuration = ClearingDuration;
 	type NameLengthLimit = NameLengthLimit;
+	type MaxHistoryPeriods = MaxHistoryPeriods;
+	type MaxCommissionTiers = MaxCommissionTiers;
+}
+
+/// Mints vtokens 1:1 for the tokens of the exchanger.
+pub struct MockVtokenMinting;
+
+impl VtokenMintingInterface<AccountId, CurrencyId, Balance> for MockVtokenMinting {
+	fn mint(
+		exchanger: AccountId,
+		token_id: CurrencyId,
+		token_amount: Balance,
+		_remark: BoundedVec<u8, ConstU32<32>>,
+		_channel_id: Option<u32>,
+	) -> Result<Balance, DispatchError> {
+		let vtoken_id = token_id.to_vtoken().map_err(|_| DispatchError::Other("NotToken"))?;
+		Currencies::withdraw(token_id, &exchanger, token_amount)?;
+		Currencies::deposit(vtoken_id, &exchanger, token_amount)?;
+		Ok(token_amount)
+	}
+
+	fn redeem(
+		exchanger: AccountId,
+		vtoken_id: CurrencyId,
+		vtoken_amount: Balance,
+	) -> DispatchResultWithPostInfo {
+		<() as VtokenMintingInterface<AccountId, CurrencyId, Balance>>::redeem(
+			exchanger,
+			vtoken_id,
+			vtoken_amount,
+		)
+	}
+
+	fn slpx_redeem(
+		exchanger: AccountId,
+		vtoken_id: CurrencyId,
+		vtoken_amount: Balance,
+		redeem: RedeemType<AccountId>,
+	) -> DispatchResultWithPostInfo {
+		<() as VtokenMintingInterface<AccountId, CurrencyId, Balance>>::slpx_redeem(
+			exchanger,
+			vtoken_id,
+			vtoken_amount,
+			redeem,
+		)
+	}
+
+	fn get_v_currency_amount_by_currency_amount(
+		_token_id: CurrencyId,
+		_vtoken_id: CurrencyId,
+		token_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		Ok(token_amount)
+	}
+
+	fn get_currency_amount_by_v_currency_amount(
+		_token_id: CurrencyId,
+		_vtoken_id: CurrencyId,
+		vtoken_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		Ok(vtoken_amount)
+	}
+
+	fn get_token_pool(_currency_id: CurrencyId) -> Balance {
+		0
+	}
+
+	fn get_minimums_redeem(_vtoken_id: CurrencyId) -> Balance {
+		0
+	}
+
+	fn get_moonbeam_parachain_id() -> u32 {
+		0
+	}
 }
 
 pub struct ExtBuilder {
@@ -203,10 +289,24 @@ impl ExtBuilder {
 	}
 }
 
-// simulate block production
+// simulate block production, with room for one clearing step per block
 pub(crate) fn run_to_block(n: u64) {
 	while System::block_number() < n {
 		System::set_block_number(System::block_number() + 1);
 		ChannelCommission::on_initialize(System::block_number());
+		ChannelCommission::on_idle(System::block_number(), clearing_step_limit());
 	}
 }
+
+// the on_idle weight needed to run the next clearing step
+pub(crate) fn clearing_step_limit() -> Weight {
+	let (channel_count, token_count) = ChannelCommission::clearing_counts();
+	let step_weight = crate::ClearingCursor::<Runtime>::get()
+		.map(|step| ChannelCommission::clearing_step_weight(step, channel_count, token_count))
+		.unwrap_or_default();
+
+	<Runtime as frame_system::Config>::DbWeight::get()
+		.reads(1)
+		.saturating_add(ChannelCommission::clearing_overhead(token_count))
+		.saturating_add(step_weight)
+}
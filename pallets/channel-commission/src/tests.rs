@@ -814,7 +814,7 @@ fn register_a_new_channel_and_mint_should_update_shares_and_get_claimable_tokens
 }
 
 #[test]
-fn on_initialize_hook_should_work() {
+fn on_idle_hook_should_work() {
 	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
 		let commission_account: AccountId =
 			<Runtime as crate::Config>::CommissionPalletId::get().into_account_truncating();
@@ -871,64 +871,397 @@ fn on_initialize_hook_should_work() {
 		// check balance of commission account
 		assert_eq!(Currencies::free_balance(VKSM, &commission_account), 11000);
 
-		// set block number to 100
+		// the clearing only starts in on_initialize
+		System::set_block_number(100);
 		ChannelCommission::on_initialize(100);
-		// set_clearing_environment already been called in block 100
-		// check whether the clearing environment is set correctly for block 100
+		assert_eq!(ClearingCursor::<Runtime>::get(), Some(ClearingStep::SetEnvironment));
+		assert_eq!(PeriodTotalCommissions::<Runtime>::get(KSM), (0, 100));
+
+		// not enough weight for the environment step
+		ChannelCommission::on_idle(100, Weight::from_parts(1, 0));
+		assert_eq!(ClearingCursor::<Runtime>::get(), Some(ClearingStep::SetEnvironment));
+
+		// with enough weight the whole period is cleared in one block
+		ChannelCommission::on_idle(100, Weight::MAX);
+		assert_eq!(ClearingCursor::<Runtime>::get(), None);
+		System::assert_last_event(RuntimeEvent::ChannelCommission(Event::ClearingPeriodFinished {
+			period: 1,
+		}));
+
 		assert_eq!(VtokenIssuanceSnapshots::<Runtime>::get(VKSM), (10000, 11000));
 		assert_eq!(PeriodVtokenTotalMint::<Runtime>::get(VKSM), (2000, 0));
 		assert_eq!(PeriodVtokenTotalRedeem::<Runtime>::get(VKSM), (1000, 0));
-		assert_eq!(PeriodChannelVtokenMint::<Runtime>::get(0, VKSM), (500, 0));
-		assert_eq!(PeriodChannelVtokenMint::<Runtime>::get(1, VKSM), (100, 0));
 		assert_eq!(PeriodTotalCommissions::<Runtime>::get(KSM), (100, 0));
 
-		// get channel B's vtoken share before being cleared
-		let channel_b_vtoken_share_before = ChannelVtokenShares::<Runtime>::get(1, VKSM);
+		// check channel A and channel B claimable KSM amount after being cleared
+		assert_eq!(ChannelClaimableCommissions::<Runtime>::get(0, KSM), 4);
+		assert_eq!(ChannelClaimableCommissions::<Runtime>::get(1, KSM), 2);
+		assert_eq!(
+			ChannelVtokenShares::<Runtime>::get(0, VKSM),
+			Permill::from_rational_with_rounding(2250u32, 11000u32, Rounding::Down).unwrap()
+		);
+		assert_eq!(
+			ChannelVtokenShares::<Runtime>::get(1, VKSM),
+			Permill::from_rational_with_rounding(1050u32, 11000u32, Rounding::Down).unwrap()
+		);
 
-		ChannelCommission::on_initialize(101);
+		// check Bifrost commission balance after being cleared
+		let bifrost_commission_receiver: AccountId32 =
+			<Runtime as crate::Config>::BifrostCommissionReceiver::get();
+		assert_eq!(PeriodClearedCommissions::<Runtime>::get(KSM), 0);
+		assert_eq!(Currencies::free_balance(KSM, &bifrost_commission_receiver), 100 - 6);
+	});
+}
 
-		let channel_a_commission = 4;
-		// check channel A claimable KSM amount after being cleared
-		assert_eq!(ChannelClaimableCommissions::<Runtime>::get(0, KSM), channel_a_commission);
+#[test]
+fn clearing_should_be_delayed_until_the_last_one_finishes() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		System::set_block_number(35);
 
-		let channel_a_new_percentage =
-			Permill::from_rational_with_rounding(2250u32, 11000u32, Rounding::Down).unwrap();
-		// check channel A vtoken share after being cleared
-		assert_eq!(ChannelVtokenShares::<Runtime>::get(0, VKSM), channel_a_new_percentage);
+		setup();
 
-		// check channel B has not been cleared yet
-		assert_eq!(ChannelClaimableCommissions::<Runtime>::get(1, KSM), 0);
-		assert_eq!(ChannelVtokenShares::<Runtime>::get(1, VKSM), channel_b_vtoken_share_before);
+		// no idle weight left in the first period, the clearing stays at the first step
+		System::set_block_number(100);
+		ChannelCommission::on_initialize(100);
+		ChannelCommission::on_idle(100, Weight::zero());
+		assert_eq!(ClearingCursor::<Runtime>::get(), Some(ClearingStep::SetEnvironment));
+
+		// the next two periods start before the last clearing finishes
+		System::set_block_number(200);
+		ChannelCommission::on_initialize(200);
+		assert_eq!(ClearingDelayed::<Runtime>::get(), 1);
+		System::assert_last_event(RuntimeEvent::ChannelCommission(Event::ClearingDelayed {
+			period: 1,
+		}));
+		System::set_block_number(300);
+		ChannelCommission::on_initialize(300);
+		assert_eq!(ClearingDelayed::<Runtime>::get(), 2);
+
+		// all clearings run one after the other
+		ChannelCommission::on_idle(300, Weight::MAX);
+		System::assert_has_event(RuntimeEvent::ChannelCommission(Event::ClearingPeriodFinished {
+			period: 1,
+		}));
+		System::assert_has_event(RuntimeEvent::ChannelCommission(Event::ClearingPeriodFinished {
+			period: 2,
+		}));
+		System::assert_last_event(RuntimeEvent::ChannelCommission(Event::ClearingPeriodFinished {
+			period: 3,
+		}));
+		assert_eq!(ClearingCursor::<Runtime>::get(), None);
+		assert_eq!(ClearingDelayed::<Runtime>::get(), 0);
+	});
+}
 
-		ChannelCommission::on_initialize(102);
+#[test]
+fn stalled_clearing_should_be_forced_in_on_initialize() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		System::set_block_number(35);
 
-		let channel_b_commission = 2;
-		// check channel B claimable KSM amount after being cleared
-		assert_eq!(ChannelClaimableCommissions::<Runtime>::get(1, KSM), channel_b_commission);
+		setup();
 
-		let channel_b_new_percentage =
-			Permill::from_rational_with_rounding(1050u32, 11000u32, Rounding::Down).unwrap();
-		// check channel B vtoken share after being cleared
-		assert_eq!(ChannelVtokenShares::<Runtime>::get(1, VKSM), channel_b_new_percentage);
+		// the blocks keep no idle weight for the clearing
+		for n in 100..110 {
+			System::set_block_number(n);
+			ChannelCommission::on_initialize(n);
+			ChannelCommission::on_idle(n, Weight::zero());
+		}
+		assert_eq!(ClearingCursor::<Runtime>::get(), Some(ClearingStep::SetEnvironment));
+		assert_eq!(ClearingPeriod::<Runtime>::get(), 0);
+
+		// on_initialize runs the environment step once the clearing stalls
+		System::set_block_number(110);
+		ChannelCommission::on_initialize(110);
+		assert_eq!(ClearingCursor::<Runtime>::get(), Some(ClearingStep::ClearChannels(0)));
+		assert_eq!(ClearingPeriod::<Runtime>::get(), 1);
+		assert_eq!(ClearingProgressAt::<Runtime>::get(), 110);
+
+		// and waits for another stall before forcing the next step
+		System::set_block_number(111);
+		ChannelCommission::on_initialize(111);
+		assert_eq!(ClearingCursor::<Runtime>::get(), Some(ClearingStep::ClearChannels(0)));
+	});
+}
 
-		// check PeriodClearedCommissions, should be channel a commission + channel b commission
-		assert_eq!(PeriodClearedCommissions::<Runtime>::get(KSM), 6);
+#[test]
+fn channel_settlement_history_should_work() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		let commission_account: AccountId =
+			<Runtime as crate::Config>::CommissionPalletId::get().into_account_truncating();
 
-		let bifrost_commission_receiver: AccountId32 =
-			<Runtime as crate::Config>::BifrostCommissionReceiver::get();
-		// check Bifrost commission balance before being cleared
-		let bifrost_account_balance_before =
-			Currencies::free_balance(KSM, &bifrost_commission_receiver);
-		assert_eq!(bifrost_account_balance_before, 0);
+		System::set_block_number(35);
 
-		ChannelCommission::on_initialize(103);
+		setup();
 
-		// cleared commissions should be none
-		assert_eq!(PeriodClearedCommissions::<Runtime>::get(KSM), 0);
+		ChannelVtokenShares::<Runtime>::insert(0, VKSM, Permill::from_percent(20));
+		VtokenIssuanceSnapshots::<Runtime>::insert(VKSM, (9000, 10000));
+		PeriodVtokenTotalMint::<Runtime>::insert(VKSM, (10000, 2000));
+		PeriodVtokenTotalRedeem::<Runtime>::insert(VKSM, (0, 1000));
+		PeriodChannelVtokenMint::<Runtime>::insert(0, VKSM, (2000, 500));
+		PeriodTotalCommissions::<Runtime>::insert(KSM, (0, 100));
 
-		// check Bifrost commission balance after being cleared
-		let bifrost_commission_balance_after =
-			Currencies::free_balance(KSM, &bifrost_commission_receiver);
-		assert_eq!(bifrost_commission_balance_after, 100 - 6);
+		let _ = Currencies::update_balance(
+			RuntimeOrigin::root(),
+			commission_account.clone(),
+			VKSM,
+			11000,
+		);
+		let _ = Currencies::update_balance(
+			RuntimeOrigin::root(),
+			commission_account.clone(),
+			KSM,
+			11000,
+		);
+
+		run_to_block(101);
+
+		assert_eq!(ClearingPeriod::<Runtime>::get(), 1);
+		// channel A is settled with the share and rate of the period, channel B has no share
+		assert_eq!(
+			ChannelCommission::get_channel_settlement_history(0),
+			vec![ChannelSettlement {
+				period: 1,
+				vtoken: VKSM,
+				commission_token: KSM,
+				channel_mint: 500,
+				total_mint: 2000,
+				total_redeem: 1000,
+				share: Permill::from_percent(20),
+				rate: DEFAULT_COMMISSION_RATE,
+				commission: 4,
+			}]
+		);
+
+		// a period left behind the window, e.g. after MaxHistoryPeriods was lowered
+		let stale = ChannelSettlementHistory::<Runtime>::get(0, 1);
+		ChannelSettlementHistory::<Runtime>::insert(0, 0, stale.clone());
+
+		// only the latest MaxHistoryPeriods periods are kept, latest first
+		run_to_block(401);
+		assert_eq!(ClearingPeriod::<Runtime>::get(), 4);
+		let periods: Vec<u32> = ChannelCommission::get_channel_settlement_history(0)
+			.into_iter()
+			.map(|settlement| settlement.period)
+			.collect();
+		assert_eq!(periods, vec![4, 3, 2]);
+		assert!(!ChannelSettlementHistory::<Runtime>::contains_key(0, 1));
+		assert!(!ChannelSettlementHistory::<Runtime>::contains_key(0, 0));
+
+		// remove channel A, its history should be removed as well
+		assert_ok!(ChannelCommission::remove_channel(RuntimeOrigin::signed(ALICE), 0));
+		assert!(ChannelCommission::get_channel_settlement_history(0).is_empty());
+
+		// more periods than a single removal clears are removed as well
+		for period in 0..10 {
+			ChannelSettlementHistory::<Runtime>::insert(1, period, stale.clone());
+		}
+		assert_ok!(ChannelCommission::remove_channel(RuntimeOrigin::signed(ALICE), 1));
+		assert!(ChannelCommission::get_channel_settlement_history(1).is_empty());
+	});
+}
+
+#[test]
+fn get_period_leaderboard_should_work() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		setup();
+
+		assert_ok!(ChannelCommission::register_channel(
+			RuntimeOrigin::signed(ALICE),
+			b"channel_c".to_vec(),
+			CHANNEL_A_BACKUP_RECEIVER.clone(),
+		));
+		ChannelVtokenShares::<Runtime>::insert(2, VKSM, Permill::from_percent(10));
+
+		assert_ok!(ChannelCommission::record_mint_amount(Some(0), VKSM, 100));
+		assert_ok!(ChannelCommission::record_mint_amount(Some(1), VKSM, 300));
+		assert_ok!(ChannelCommission::record_mint_amount(Some(2), VKSM, 100));
+
+		assert_eq!(
+			ChannelCommission::get_period_leaderboard(VKSM, 2),
+			vec![
+				ChannelRanking { channel_id: 1, mint: 300, share: Permill::zero() },
+				ChannelRanking { channel_id: 0, mint: 100, share: Permill::zero() },
+			]
+		);
+		assert_eq!(ChannelCommission::get_period_leaderboard(VKSM, 3)[2].channel_id, 2);
+		assert_eq!(
+			ChannelCommission::get_period_leaderboard(VKSM, 3)[2].share,
+			Permill::from_percent(10)
+		);
+	});
+}
+
+#[test]
+fn set_channel_commission_tiers_should_work() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		setup();
+
+		let tiers = vec![(0, Percent::from_percent(5)), (1_000_000, Percent::from_percent(8))];
+		assert_ok!(ChannelCommission::set_channel_commission_tiers(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			VKSM,
+			tiers.clone(),
+		));
+		assert_eq!(ChannelCommissionTiers::<Runtime>::get(0, VKSM).into_inner(), tiers);
+
+		// the highest reached tier is used, and below all tiers the flat rate is used
+		assert_eq!(
+			ChannelCommission::channel_commission_rate(0, VKSM, 999_999),
+			Percent::from_percent(5)
+		);
+		assert_eq!(
+			ChannelCommission::channel_commission_rate(0, VKSM, 1_000_000),
+			Percent::from_percent(8)
+		);
+		assert_ok!(ChannelCommission::set_channel_commission_tiers(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			VKSM,
+			vec![(100, Percent::from_percent(5))],
+		));
+		assert_eq!(
+			ChannelCommission::channel_commission_rate(0, VKSM, 99),
+			DEFAULT_COMMISSION_RATE
+		);
+
+		// thresholds must be strictly increasing
+		assert_noop!(
+			ChannelCommission::set_channel_commission_tiers(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				VKSM,
+				vec![(100, Percent::from_percent(5)), (100, Percent::from_percent(8))],
+			),
+			Error::<Runtime>::InvalidCommissionTiers
+		);
+
+		assert_noop!(
+			ChannelCommission::set_channel_commission_tiers(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				VKSM,
+				vec![
+					(0, Percent::from_percent(5)),
+					(1, Percent::from_percent(6)),
+					(2, Percent::from_percent(7)),
+					(3, Percent::from_percent(8)),
+				],
+			),
+			Error::<Runtime>::TooManyCommissionTiers
+		);
+
+		// empty tiers remove the tiers
+		assert_ok!(ChannelCommission::set_channel_commission_tiers(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			VKSM,
+			vec![],
+		));
+		assert!(!ChannelCommissionTiers::<Runtime>::contains_key(0, VKSM));
+	});
+}
+
+#[test]
+fn set_channel_auto_compound_should_work() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		setup();
+
+		assert_noop!(
+			ChannelCommission::set_channel_auto_compound(RuntimeOrigin::signed(BOB), 0, true),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			ChannelCommission::set_channel_auto_compound(RuntimeOrigin::signed(ALICE), 2, true),
+			Error::<Runtime>::ChannelNotExist
+		);
+
+		// the channel receive account can opt in
+		assert_ok!(ChannelCommission::set_channel_auto_compound(
+			RuntimeOrigin::signed(CHANNEL_A_RECEIVER),
+			0,
+			true
+		));
+		assert!(ChannelAutoCompound::<Runtime>::get(0));
+
+		// the control origin can opt out
+		assert_ok!(ChannelCommission::set_channel_auto_compound(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			false
+		));
+		assert!(!ChannelAutoCompound::<Runtime>::contains_key(0));
+	});
+}
+
+#[test]
+fn clearing_with_tiers_and_auto_compound_should_work() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		let commission_account: AccountId =
+			<Runtime as crate::Config>::CommissionPalletId::get().into_account_truncating();
+
+		System::set_block_number(35);
+
+		setup();
+
+		ChannelVtokenShares::<Runtime>::insert(0, VKSM, Permill::from_percent(20));
+		ChannelVtokenShares::<Runtime>::insert(1, VKSM, Permill::from_percent(10));
+		VtokenIssuanceSnapshots::<Runtime>::insert(VKSM, (9000, 10000));
+		PeriodVtokenTotalMint::<Runtime>::insert(VKSM, (10000, 2000));
+		PeriodVtokenTotalRedeem::<Runtime>::insert(VKSM, (0, 1000));
+		PeriodChannelVtokenMint::<Runtime>::insert(0, VKSM, (2000, 500));
+		PeriodChannelVtokenMint::<Runtime>::insert(1, VKSM, (2000, 100));
+		PeriodTotalCommissions::<Runtime>::insert(KSM, (0, 100));
+
+		let _ = Currencies::update_balance(
+			RuntimeOrigin::root(),
+			commission_account.clone(),
+			VKSM,
+			11000,
+		);
+		let _ = Currencies::update_balance(
+			RuntimeOrigin::root(),
+			commission_account.clone(),
+			KSM,
+			11000,
+		);
+
+		// channel A holds 20% of 10000 VKSM, which reaches the 50% tier
+		assert_ok!(ChannelCommission::set_channel_commission_tiers(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			VKSM,
+			vec![(0, Percent::from_percent(5)), (1000, Percent::from_percent(50))],
+		));
+
+		// channel B compounds its commission into VKSM
+		assert_ok!(ChannelCommission::set_channel_auto_compound(
+			RuntimeOrigin::signed(CHANNEL_B_RECEIVER),
+			1,
+			true
+		));
+
+		run_to_block(102);
+
+		// channel A: 20% * 100 * 50%
+		assert_eq!(ChannelClaimableCommissions::<Runtime>::get(0, KSM), 10);
+		assert_eq!(
+			ChannelCommission::get_channel_settlement_history(0)[0].rate,
+			Percent::from_percent(50)
+		);
+
+		// channel B: 10% * 100 * 20%, minted into VKSM and sent to the receive account
+		assert_eq!(ChannelClaimableCommissions::<Runtime>::get(1, KSM), 0);
+		assert_eq!(Currencies::free_balance(VKSM, &CHANNEL_B_RECEIVER), 2);
+		System::assert_has_event(RuntimeEvent::ChannelCommission(Event::CommissionCompounded {
+			channel_id: 1,
+			commission_token: KSM,
+			amount: 2,
+			vtoken: VKSM,
+			vtoken_amount: 2,
+		}));
+
+		// both commissions are counted as cleared
+		assert_eq!(PeriodClearedCommissions::<Runtime>::get(KSM), 12);
 	});
 }
@@ -103,6 +103,7 @@ pub type AdaptedBasicCurrency =
 
 impl bifrost_currencies::Config for Runtime {
 	type GetNativeCurrencyId = NativeCurrencyId;
+	type TransferFilter = frame_support::traits::Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type WeightInfo = ();
@@ -0,0 +1,101 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use std::{marker::PhantomData, sync::Arc};
+
+pub use bifrost_cross_in_out_rpc_runtime_api::{self as runtime_api, CrossInOutRuntimeApi};
+use bifrost_primitives::{Balance, BlockNumber, CrossOutRequest};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{ErrorCode, ErrorObject},
+};
+use parity_scale_codec::Codec;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+#[rpc(client, server)]
+pub trait CrossInOutRpcApi<BlockHash, AccountId> {
+	/// rpc method for getting the cross-out requests of an account
+	#[method(name = "cross_in_out_getCrossOutRequests")]
+	fn get_cross_out_requests(
+		&self,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(u64, CrossOutRequest<AccountId, NumberOrHex, BlockNumber>)>>;
+}
+
+#[derive(Clone, Debug)]
+pub struct CrossInOutRpc<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> CrossInOutRpc<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: PhantomData }
+	}
+}
+
+#[async_trait]
+impl<C, Block, AccountId> CrossInOutRpcApiServer<<Block as BlockT>::Hash, AccountId>
+	for CrossInOutRpc<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: CrossInOutRuntimeApi<Block, AccountId>,
+	AccountId: Codec,
+{
+	fn get_cross_out_requests(
+		&self,
+		who: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(u64, CrossOutRequest<AccountId, NumberOrHex, BlockNumber>)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let rs: Result<Vec<(u64, CrossOutRequest<AccountId, Balance, BlockNumber>)>, _> =
+			api.get_cross_out_requests(at, who);
+
+		match rs {
+			Ok(requests) => Ok(requests
+				.into_iter()
+				.map(|(id, request)| {
+					(
+						id,
+						CrossOutRequest {
+							crosser: request.crosser,
+							currency_id: request.currency_id,
+							amount: NumberOrHex::Hex(request.amount.into()),
+							status: request.status,
+							created_at: request.created_at,
+							updated_at: request.updated_at,
+							foreign_tx_hash: request.foreign_tx_hash,
+						},
+					)
+				})
+				.collect()),
+			Err(e) => Err(ErrorObject::owned(
+				ErrorCode::InternalError.code(),
+				"Failed to get cross-out requests.",
+				Some(format!("{:?}", e)),
+			)),
+		}
+	}
+}
@@ -25,6 +25,7 @@ use bifrost_primitives::{CurrencyId, TokenSymbol};
 use frame_benchmarking::v1::{account, benchmarks, whitelisted_caller, BenchmarkError};
 use frame_support::assert_ok;
 use frame_system::RawOrigin;
+use sp_core::H256;
 use sp_runtime::traits::{AccountIdConversion, UniqueSaturatedFrom};
 use xcm::v2::prelude::*;
 
@@ -32,6 +33,98 @@ use super::*;
 #[allow(unused_imports)]
 use crate::Pallet as CrossInOut;
 
+fn create_cross_out_request<T: Config>(crosser: &T::AccountId) -> Result<(), BenchmarkError> {
+	assert_ok!(CrossInOut::<T>::register_currency_for_cross_in_out(
+		T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+		CurrencyId::Token(TokenSymbol::DOT)
+	));
+
+	assert_ok!(CrossInOut::<T>::set_crossing_minimum_amount(
+		T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+		CurrencyId::Token(TokenSymbol::DOT),
+		100u32.into(),
+		100u32.into()
+	));
+
+	assert_ok!(CrossInOut::<T>::add_to_register_whitelist(
+		T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+		CurrencyId::Token(TokenSymbol::DOT),
+		crosser.clone()
+	));
+
+	let location = Box::new(MultiLocation {
+		parents: 0,
+		interior: X1(AccountId32 {
+			network: Any,
+			id: T::EntrancePalletId::get().into_account_truncating(),
+		}),
+	});
+
+	assert_ok!(CrossInOut::<T>::register_linked_account(
+		RawOrigin::Signed(crosser.clone()).into(),
+		CurrencyId::Token(TokenSymbol::DOT),
+		crosser.clone(),
+		location
+	));
+
+	T::MultiCurrency::deposit(
+		CurrencyId::Token(TokenSymbol::DOT),
+		crosser,
+		BalanceOf::<T>::unique_saturated_from(1000000000000u128),
+	)?;
+
+	assert_ok!(CrossInOut::<T>::cross_out(
+		RawOrigin::Signed(crosser.clone()).into(),
+		CurrencyId::Token(TokenSymbol::DOT),
+		BalanceOf::<T>::unique_saturated_from(100000000000u128)
+	));
+
+	Ok(())
+}
+
+/// Set up DOT for cross-in with `attestor` and `count - 1` other attestors, and a threshold of
+/// `count` attestations.
+fn set_up_cross_in<T: Config>(attestor: &T::AccountId, count: u32) -> Result<(), BenchmarkError> {
+	assert_ok!(CrossInOut::<T>::register_currency_for_cross_in_out(
+		T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+		CurrencyId::Token(TokenSymbol::DOT)
+	));
+
+	assert_ok!(CrossInOut::<T>::set_crossing_minimum_amount(
+		T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+		CurrencyId::Token(TokenSymbol::DOT),
+		100u32.into(),
+		100u32.into()
+	));
+
+	assert_ok!(CrossInOut::<T>::add_cross_in_attestor(
+		T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+		CurrencyId::Token(TokenSymbol::DOT),
+		attestor.clone()
+	));
+	for i in 1..count {
+		assert_ok!(CrossInOut::<T>::add_cross_in_attestor(
+			T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+			CurrencyId::Token(TokenSymbol::DOT),
+			account("attestor", i, 1)
+		));
+	}
+
+	assert_ok!(CrossInOut::<T>::set_cross_in_threshold(
+		T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+		CurrencyId::Token(TokenSymbol::DOT),
+		count
+	));
+
+	assert_ok!(CrossInOut::<T>::set_cross_in_rate_limit(
+		T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+		CurrencyId::Token(TokenSymbol::DOT),
+		Some((BalanceOf::<T>::unique_saturated_from(1000000000000u128), 100u32.into()))
+	));
+
+	Ok(())
+}
+
 benchmarks! {
 	deregister_currency_for_cross_in_out {
 		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
@@ -158,5 +251,140 @@ benchmarks! {
 
 	}: _<T::RuntimeOrigin>(origin,CurrencyId::Token(TokenSymbol::DOT),location2,test_account)
 
+	cross_in {
+		let n in 1 .. T::MaxLengthLimit::get();
+		let test_account: T::AccountId = account("seed",1,1);
+		let attestor: T::AccountId = whitelisted_caller();
+		set_up_cross_in::<T>(&attestor, n)?;
+		// the other attestors attest the request first, the last attestation mints it
+		for i in 1..n {
+			assert_ok!(CrossInOut::<T>::cross_in(
+				RawOrigin::Signed(account::<T::AccountId>("attestor",i,1)).into(),
+				CurrencyId::Token(TokenSymbol::DOT),
+				H256::repeat_byte(1),
+				test_account.clone(),
+				BalanceOf::<T>::unique_saturated_from(100000000000u128)
+			));
+		}
+	}: _(RawOrigin::Signed(attestor),CurrencyId::Token(TokenSymbol::DOT),H256::repeat_byte(1),test_account,BalanceOf::<T>::unique_saturated_from(100000000000u128))
+
+	add_cross_in_attestor {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let attestor = whitelisted_caller();
+	}: _<T::RuntimeOrigin>(origin,CurrencyId::Token(TokenSymbol::DOT),attestor)
+
+	remove_cross_in_attestor {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let attestor: T::AccountId = account("seed",1,1);
+		for i in 1..3 {
+			assert_ok!(CrossInOut::<T>::add_cross_in_attestor(
+				T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+				CurrencyId::Token(TokenSymbol::DOT),
+				account("seed",i,1)
+			));
+		}
+		assert_ok!(CrossInOut::<T>::set_cross_in_threshold(
+			T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+			CurrencyId::Token(TokenSymbol::DOT),
+			1
+		));
+	}: _<T::RuntimeOrigin>(origin,CurrencyId::Token(TokenSymbol::DOT),attestor)
+
+	set_cross_in_threshold {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		for i in 0..2 {
+			assert_ok!(CrossInOut::<T>::add_cross_in_attestor(
+				T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+				CurrencyId::Token(TokenSymbol::DOT),
+				account("seed",i,1)
+			));
+		}
+	}: _<T::RuntimeOrigin>(origin,CurrencyId::Token(TokenSymbol::DOT),2)
+
+	set_cross_in_rate_limit {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin,CurrencyId::Token(TokenSymbol::DOT),Some((100u32.into(),100u32.into())))
+
+	set_cross_in_paused {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin,CurrencyId::Token(TokenSymbol::DOT),true)
+
+	add_cross_out_operator {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let operator = whitelisted_caller();
+	}: _<T::RuntimeOrigin>(origin,CurrencyId::Token(TokenSymbol::DOT),operator)
+
+	remove_cross_out_operator {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let operator: T::AccountId = account("seed",1,1);
+		assert_ok!(CrossInOut::<T>::add_cross_out_operator(
+			T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+			CurrencyId::Token(TokenSymbol::DOT),
+			operator.clone()
+		));
+	}: _<T::RuntimeOrigin>(origin,CurrencyId::Token(TokenSymbol::DOT),operator)
+
+	process_cross_out {
+		let test_account: T::AccountId = account("seed",1,1);
+		let operator: T::AccountId = whitelisted_caller();
+		create_cross_out_request::<T>(&test_account)?;
+		assert_ok!(CrossInOut::<T>::add_cross_out_operator(
+			T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+			CurrencyId::Token(TokenSymbol::DOT),
+			operator.clone()
+		));
+	}: _(RawOrigin::Signed(operator),0)
+
+	complete_cross_out {
+		let test_account: T::AccountId = account("seed",1,1);
+		let operator: T::AccountId = whitelisted_caller();
+		create_cross_out_request::<T>(&test_account)?;
+		assert_ok!(CrossInOut::<T>::add_cross_out_operator(
+			T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+			CurrencyId::Token(TokenSymbol::DOT),
+			operator.clone()
+		));
+	}: _(RawOrigin::Signed(operator),0,H256::repeat_byte(1))
+
+	cancel_cross_out {
+		let test_account: T::AccountId = account("seed",1,1);
+		create_cross_out_request::<T>(&test_account)?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::CrossOutTimeout::get(),
+		);
+	}: _(RawOrigin::Signed(test_account),0)
+
+	remove_pending_cross_in {
+		let n in 1 .. T::MaxLengthLimit::get() - 1;
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let attestor: T::AccountId = whitelisted_caller();
+		set_up_cross_in::<T>(&attestor, n + 1)?;
+		// every attestor attests a different request, so none reaches the threshold
+		for i in 1..=n {
+			assert_ok!(CrossInOut::<T>::cross_in(
+				RawOrigin::Signed(account::<T::AccountId>("attestor",i,1)).into(),
+				CurrencyId::Token(TokenSymbol::DOT),
+				H256::repeat_byte(1),
+				account("seed",i,1),
+				BalanceOf::<T>::unique_saturated_from(100000000000u128)
+			));
+		}
+	}: _<T::RuntimeOrigin>(origin,CurrencyId::Token(TokenSymbol::DOT),H256::repeat_byte(1))
+
+	refund_cross_out {
+		let test_account: T::AccountId = account("seed",1,1);
+		let operator: T::AccountId = whitelisted_caller();
+		create_cross_out_request::<T>(&test_account)?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::CrossOutTimeout::get(),
+		);
+		assert_ok!(CrossInOut::<T>::cancel_cross_out(RawOrigin::Signed(test_account).into(), 0));
+		assert_ok!(CrossInOut::<T>::add_cross_out_operator(
+			T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+			CurrencyId::Token(TokenSymbol::DOT),
+			operator.clone()
+		));
+	}: _(RawOrigin::Signed(operator),0)
+
 	impl_benchmark_test_suite!(CrossInOut,crate::mock::ExtBuilder::default().build(),crate::mock::Runtime);
 }
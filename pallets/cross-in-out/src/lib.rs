@@ -21,11 +21,16 @@
 
 extern crate alloc;
 
-use alloc::vec;
-use bifrost_primitives::CurrencyId;
+use alloc::{vec, vec::Vec};
+use bifrost_primitives::{CrossOutRequest, CrossOutStatus, CurrencyId};
 use frame_support::{ensure, pallet_prelude::*, PalletId};
 use frame_system::pallet_prelude::*;
 use orml_traits::MultiCurrency;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash, Zero},
+	Perbill,
+};
 use sp_std::boxed::Box;
 pub use weights::WeightInfo;
 #[allow(deprecated)]
@@ -44,6 +49,26 @@ type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<
 	<T as frame_system::Config>::AccountId,
 >>::Balance;
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+type CrossOutRequestOf<T> = CrossOutRequest<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+
+/// A cross-in request of a foreign transaction, as attested by the attestors of the currency.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct CrossInRequest<AccountId, Balance> {
+	pub currency_id: CurrencyId,
+	pub recipient: AccountId,
+	pub amount: Balance,
+}
+
+/// The rolling cross-in rate limit of a currency. The used amount is replenished linearly,
+/// so that at most `limit` is crossed in within any `period` blocks.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct CrossInRateLimit<Balance, BlockNumber> {
+	pub limit: Balance,
+	pub period: BlockNumber,
+	/// The amount counted against the limit at `updated_at`.
+	pub used: Balance,
+	pub updated_at: BlockNumber,
+}
 
 #[allow(deprecated)]
 #[frame_support::pallet]
@@ -67,6 +92,10 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type MaxLengthLimit: Get<u32>;
+
+		/// The number of blocks after which a pending cross-out request can be cancelled
+		#[pallet::constant]
+		type CrossOutTimeout: Get<BlockNumberFor<Self>>;
 	}
 
 	#[pallet::error]
@@ -89,6 +118,25 @@ pub mod pallet {
 		AmountLowerThanMinimum,
 		/// Indicates that the list has reached its maximum capacity.
 		ListOverflow,
+		/// Indicates that there is no attestation threshold set for the currency.
+		NoAttestationThresholdSet,
+		/// Indicates that the attestation threshold is zero or would exceed the number of
+		/// attestors.
+		InvalidThreshold,
+		/// Indicates that the foreign transaction has already been crossed in.
+		AlreadyCrossedIn,
+		/// Indicates that the attestor has already attested the foreign transaction.
+		AlreadyAttested,
+		/// Indicates that crossing in of the currency is paused.
+		CrossInPaused,
+		/// Indicates that the rate limit period is zero.
+		InvalidRateLimit,
+		/// Indicates that the cross-in amount exceeds the rate limit of the currency.
+		RateLimitExceeded,
+		/// Indicates that the cross-out request is not in a status allowing the operation.
+		InvalidCrossOutStatus,
+		/// Indicates that the cross-out request can't be cancelled before the timeout.
+		CrossOutNotTimedOut,
 	}
 
 	#[pallet::event]
@@ -100,6 +148,7 @@ pub mod pallet {
 			crosser: AccountIdOf<T>,
 			location: MultiLocation,
 			amount: BalanceOf<T>,
+			request_id: u64,
 		},
 		/// Event emitted when a currency is deregistered.
 		CurrencyDeregistered { currency_id: CurrencyId },
@@ -119,6 +168,52 @@ pub mod pallet {
 			cross_in_minimum: BalanceOf<T>,
 			cross_out_minimum: BalanceOf<T>,
 		},
+		/// Event emitted when an attestor attests a cross-in request.
+		CrossInAttested {
+			tx_hash: H256,
+			request_hash: H256,
+			attestor: AccountIdOf<T>,
+			currency_id: CurrencyId,
+		},
+		/// Event emitted when a currency is minted for an attested foreign transaction.
+		CrossedIn {
+			tx_hash: H256,
+			currency_id: CurrencyId,
+			recipient: AccountIdOf<T>,
+			amount: BalanceOf<T>,
+		},
+		/// Event emitted when an attestor is added for a currency.
+		AttestorAdded { currency_id: CurrencyId, attestor: AccountIdOf<T> },
+		/// Event emitted when an attestor is removed for a currency.
+		AttestorRemoved { currency_id: CurrencyId, attestor: AccountIdOf<T> },
+		/// Event emitted when the attestation threshold is set for a currency.
+		AttestationThresholdSet { currency_id: CurrencyId, threshold: u32 },
+		/// Event emitted when the pending cross-in requests of a foreign transaction are removed.
+		PendingCrossInRemoved { currency_id: CurrencyId, tx_hash: H256 },
+		/// Event emitted when the cross-in rate limit of a currency is set or removed.
+		CrossInRateLimitSet {
+			currency_id: CurrencyId,
+			limit: Option<(BalanceOf<T>, BlockNumberFor<T>)>,
+		},
+		/// Event emitted when crossing in of a currency is paused or resumed.
+		CrossInPausedSet { currency_id: CurrencyId, paused: bool },
+		/// Event emitted when a bridge operator is added for a currency.
+		CrossOutOperatorAdded { currency_id: CurrencyId, operator: AccountIdOf<T> },
+		/// Event emitted when a bridge operator is removed for a currency.
+		CrossOutOperatorRemoved { currency_id: CurrencyId, operator: AccountIdOf<T> },
+		/// Event emitted when a bridge operator starts processing a cross-out request.
+		CrossOutProcessing { request_id: u64, operator: AccountIdOf<T> },
+		/// Event emitted when a bridge operator reports a cross-out request as completed.
+		CrossOutCompleted { request_id: u64, operator: AccountIdOf<T>, foreign_tx_hash: H256 },
+		/// Event emitted when the crosser cancels a cross-out request that timed out.
+		CrossOutCancelled { request_id: u64, crosser: AccountIdOf<T> },
+		/// Event emitted when a bridge operator refunds a cancelled cross-out request.
+		CrossOutRefunded {
+			request_id: u64,
+			crosser: AccountIdOf<T>,
+			currency_id: CurrencyId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	/// The current storage version, we set to 2 our new version(after migrate stroage from vec t
@@ -168,6 +263,87 @@ pub mod pallet {
 	pub type CrossingMinimumAmount<T> =
 		StorageMap<_, Blake2_128Concat, CurrencyId, (BalanceOf<T>, BalanceOf<T>)>;
 
+	/// Accounts that attest cross-in requests of the corresponding Currency.
+	#[pallet::storage]
+	pub type CrossInAttestors<T: Config> =
+		StorageMap<_, Blake2_128Concat, CurrencyId, BoundedVec<AccountIdOf<T>, T::MaxLengthLimit>>;
+
+	/// The number of attestations needed to mint a cross-in request of a currency.
+	#[pallet::storage]
+	pub type CrossInThreshold<T> = StorageMap<_, Blake2_128Concat, CurrencyId, u32>;
+
+	/// Cross-in requests that haven't reached the attestation threshold, by currency, foreign tx
+	/// hash and request hash. Foreign tx hashes are only unique within the chain of a currency,
+	/// so they are scoped by currency. Attestors may disagree on the request of a foreign
+	/// transaction, so every attested variant is kept until one of them reaches the threshold.
+	#[pallet::storage]
+	pub type PendingCrossIns<T: Config> = StorageNMap<
+		_,
+		(NMapKey<Blake2_128Concat, CurrencyId>, NMapKey<Identity, H256>, NMapKey<Identity, H256>),
+		CrossInRequest<AccountIdOf<T>, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The request hash attested by each attestor of a pending foreign transaction, by currency,
+	/// foreign tx hash and attestor. An attestor attests one request per foreign transaction.
+	#[pallet::storage]
+	pub type CrossInAttestations<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, CurrencyId>,
+			NMapKey<Identity, H256>,
+			NMapKey<Blake2_128Concat, AccountIdOf<T>>,
+		),
+		H256,
+		OptionQuery,
+	>;
+
+	/// Foreign transactions that have been crossed in, by currency and foreign tx hash, and the
+	/// block they were minted at.
+	#[pallet::storage]
+	pub type CrossedInTransactions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		Identity,
+		H256,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// The rolling cross-in rate limit of a currency.
+	#[pallet::storage]
+	pub type CrossInRateLimits<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		CrossInRateLimit<BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Currencies whose crossing in is paused.
+	#[pallet::storage]
+	pub type PausedCrossIns<T> = StorageMap<_, Blake2_128Concat, CurrencyId, ()>;
+
+	/// Accounts that process cross-out requests of the corresponding Currency.
+	#[pallet::storage]
+	pub type CrossOutOperators<T: Config> =
+		StorageMap<_, Blake2_128Concat, CurrencyId, BoundedVec<AccountIdOf<T>, T::MaxLengthLimit>>;
+
+	/// The id of the next cross-out request.
+	#[pallet::storage]
+	pub type NextCrossOutRequestId<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// Cross-out requests by id.
+	#[pallet::storage]
+	pub type CrossOutRequests<T: Config> =
+		StorageMap<_, Twox64Concat, u64, CrossOutRequestOf<T>, OptionQuery>;
+
+	/// The ids of the cross-out requests of an account.
+	#[pallet::storage]
+	pub type AccountCrossOutRequests<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Twox64Concat, u64, (), OptionQuery>;
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -205,7 +381,33 @@ pub mod pallet {
 
 			T::MultiCurrency::withdraw(currency_id, &crosser, amount)?;
 
-			Self::deposit_event(Event::CrossedOut { currency_id, crosser, location, amount });
+			let request_id = NextCrossOutRequestId::<T>::mutate(|id| {
+				let request_id = *id;
+				*id = id.saturating_add(1);
+				request_id
+			});
+			let now = frame_system::Pallet::<T>::block_number();
+			CrossOutRequests::<T>::insert(
+				request_id,
+				CrossOutRequest {
+					crosser: crosser.clone(),
+					currency_id,
+					amount,
+					status: CrossOutStatus::Pending,
+					created_at: now,
+					updated_at: now,
+					foreign_tx_hash: None,
+				},
+			);
+			AccountCrossOutRequests::<T>::insert(&crosser, request_id, ());
+
+			Self::deposit_event(Event::CrossedOut {
+				currency_id,
+				crosser,
+				location,
+				amount,
+				request_id,
+			});
 			Ok(())
 		}
 
@@ -394,6 +596,414 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Attest a cross-in request of a foreign transaction. Each attestor attests one request
+		/// per foreign transaction, and the currency is minted to the recipient of the first
+		/// request reaching the attestation threshold of the currency. If minting would exceed
+		/// the rate limit, the attestation fails and can be retried later.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::cross_in(T::MaxLengthLimit::get()))]
+		pub fn cross_in(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			tx_hash: H256,
+			recipient: AccountIdOf<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let attestor = ensure_signed(origin)?;
+
+			ensure!(
+				CrossCurrencyRegistry::<T>::contains_key(currency_id),
+				Error::<T>::CurrencyNotSupportCrossInAndOut
+			);
+			ensure!(!PausedCrossIns::<T>::contains_key(currency_id), Error::<T>::CrossInPaused);
+			ensure!(
+				!CrossedInTransactions::<T>::contains_key(currency_id, tx_hash),
+				Error::<T>::AlreadyCrossedIn
+			);
+
+			let attestors =
+				CrossInAttestors::<T>::get(currency_id).ok_or(Error::<T>::NotAllowed)?;
+			ensure!(attestors.contains(&attestor), Error::<T>::NotAllowed);
+			let threshold = CrossInThreshold::<T>::get(currency_id)
+				.ok_or(Error::<T>::NoAttestationThresholdSet)?;
+
+			let crossing_minimum_amount = CrossingMinimumAmount::<T>::get(currency_id)
+				.ok_or(Error::<T>::NoCrossingMinimumSet)?;
+			ensure!(amount >= crossing_minimum_amount.0, Error::<T>::AmountLowerThanMinimum);
+
+			let request = CrossInRequest { currency_id, recipient: recipient.clone(), amount };
+			let request_hash = BlakeTwo256::hash_of(&request);
+
+			// attestations of removed attestors are kept until the transaction is settled, but
+			// aren't counted
+			let mut attestations = 0u32;
+			let mut request_attestations = 1u32;
+			for (account, attested_hash) in
+				CrossInAttestations::<T>::iter_prefix((currency_id, tx_hash))
+			{
+				ensure!(account != attestor, Error::<T>::AlreadyAttested);
+				attestations = attestations.saturating_add(1);
+				if attested_hash == request_hash && attestors.contains(&account) {
+					request_attestations = request_attestations.saturating_add(1);
+				}
+			}
+			ensure!(attestations < T::MaxLengthLimit::get(), Error::<T>::ListOverflow);
+
+			if !PendingCrossIns::<T>::contains_key((currency_id, tx_hash, request_hash)) {
+				PendingCrossIns::<T>::insert((currency_id, tx_hash, request_hash), request);
+			}
+			CrossInAttestations::<T>::insert((currency_id, tx_hash, &attestor), request_hash);
+
+			Self::deposit_event(Event::CrossInAttested {
+				tx_hash,
+				request_hash,
+				attestor,
+				currency_id,
+			});
+
+			if request_attestations < threshold {
+				return Ok(Some(T::WeightInfo::cross_in(0)).into());
+			}
+
+			Self::update_cross_in_rate_limit(currency_id, amount)?;
+			T::MultiCurrency::deposit(currency_id, &recipient, amount)?;
+
+			let removed = Self::remove_pending_cross_ins(currency_id, tx_hash);
+			CrossedInTransactions::<T>::insert(
+				currency_id,
+				tx_hash,
+				frame_system::Pallet::<T>::block_number(),
+			);
+
+			Self::deposit_event(Event::CrossedIn { tx_hash, currency_id, recipient, amount });
+
+			Ok(Some(T::WeightInfo::cross_in(removed)).into())
+		}
+
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::add_cross_in_attestor())]
+		pub fn add_cross_in_attestor(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			attestor: AccountIdOf<T>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			CrossInAttestors::<T>::try_mutate(currency_id, |attestors| -> DispatchResult {
+				let attestors = attestors.get_or_insert_with(BoundedVec::default);
+				ensure!(!attestors.contains(&attestor), Error::<T>::AlreadyExist);
+				attestors.try_push(attestor.clone()).map_err(|_| Error::<T>::ListOverflow)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::AttestorAdded { currency_id, attestor });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::remove_cross_in_attestor())]
+		pub fn remove_cross_in_attestor(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			attestor: AccountIdOf<T>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			CrossInAttestors::<T>::try_mutate(currency_id, |attestors| -> DispatchResult {
+				match attestors {
+					Some(attestors) if attestors.contains(&attestor) => {
+						attestors.retain(|x| *x != attestor);
+						// the threshold has to stay reachable, it needs lowering first
+						if let Some(threshold) = CrossInThreshold::<T>::get(currency_id) {
+							ensure!(
+								threshold <= attestors.len() as u32,
+								Error::<T>::InvalidThreshold
+							);
+						}
+						Ok(())
+					},
+					_ => Err(Error::<T>::NotExist.into()),
+				}
+			})?;
+
+			Self::deposit_event(Event::AttestorRemoved { currency_id, attestor });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::set_cross_in_threshold())]
+		pub fn set_cross_in_threshold(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			threshold: u32,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			let attestors = CrossInAttestors::<T>::get(currency_id).unwrap_or_default();
+			ensure!(
+				!threshold.is_zero() && threshold <= attestors.len() as u32,
+				Error::<T>::InvalidThreshold
+			);
+			CrossInThreshold::<T>::insert(currency_id, threshold);
+
+			Self::deposit_event(Event::AttestationThresholdSet { currency_id, threshold });
+
+			Ok(())
+		}
+
+		/// Set the max amount of a currency crossed in within a period of blocks, or remove
+		/// the rate limit with `None`.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::set_cross_in_rate_limit())]
+		pub fn set_cross_in_rate_limit(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			limit: Option<(BalanceOf<T>, BlockNumberFor<T>)>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			match limit {
+				Some((limit, period)) => {
+					ensure!(!period.is_zero(), Error::<T>::InvalidRateLimit);
+					CrossInRateLimits::<T>::mutate(currency_id, |rate_limit| {
+						// the amount already crossed in keeps counting against the new limit
+						let now = frame_system::Pallet::<T>::block_number();
+						let used = rate_limit
+							.as_ref()
+							.map(|rate_limit| Self::rate_limit_used(rate_limit, now))
+							.unwrap_or_default();
+						*rate_limit =
+							Some(CrossInRateLimit { limit, period, used, updated_at: now });
+					});
+				},
+				None => CrossInRateLimits::<T>::remove(currency_id),
+			}
+
+			Self::deposit_event(Event::CrossInRateLimitSet { currency_id, limit });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::set_cross_in_paused())]
+		pub fn set_cross_in_paused(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			paused: bool,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			if paused {
+				PausedCrossIns::<T>::insert(currency_id, ());
+			} else {
+				PausedCrossIns::<T>::remove(currency_id);
+			}
+
+			Self::deposit_event(Event::CrossInPausedSet { currency_id, paused });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::add_cross_out_operator())]
+		pub fn add_cross_out_operator(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			operator: AccountIdOf<T>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			CrossOutOperators::<T>::try_mutate(currency_id, |operators| -> DispatchResult {
+				let operators = operators.get_or_insert_with(BoundedVec::default);
+				ensure!(!operators.contains(&operator), Error::<T>::AlreadyExist);
+				operators.try_push(operator.clone()).map_err(|_| Error::<T>::ListOverflow)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::CrossOutOperatorAdded { currency_id, operator });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::remove_cross_out_operator())]
+		pub fn remove_cross_out_operator(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			operator: AccountIdOf<T>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			CrossOutOperators::<T>::try_mutate(currency_id, |operators| -> DispatchResult {
+				match operators {
+					Some(operators) if operators.contains(&operator) => {
+						operators.retain(|x| *x != operator);
+						Ok(())
+					},
+					_ => Err(Error::<T>::NotExist.into()),
+				}
+			})?;
+
+			Self::deposit_event(Event::CrossOutOperatorRemoved { currency_id, operator });
+
+			Ok(())
+		}
+
+		/// Mark a pending cross-out request as being processed, so that it can no longer be
+		/// cancelled by the crosser.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::process_cross_out())]
+		pub fn process_cross_out(origin: OriginFor<T>, request_id: u64) -> DispatchResult {
+			let operator = ensure_signed(origin)?;
+
+			CrossOutRequests::<T>::try_mutate(request_id, |request| -> DispatchResult {
+				let request = request.as_mut().ok_or(Error::<T>::NotExist)?;
+				Self::ensure_cross_out_operator(request.currency_id, &operator)?;
+				ensure!(
+					request.status == CrossOutStatus::Pending,
+					Error::<T>::InvalidCrossOutStatus
+				);
+
+				request.status = CrossOutStatus::Processing;
+				request.updated_at = frame_system::Pallet::<T>::block_number();
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::CrossOutProcessing { request_id, operator });
+
+			Ok(())
+		}
+
+		/// Report a cross-out request as transferred on the foreign chain.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::complete_cross_out())]
+		pub fn complete_cross_out(
+			origin: OriginFor<T>,
+			request_id: u64,
+			foreign_tx_hash: H256,
+		) -> DispatchResult {
+			let operator = ensure_signed(origin)?;
+
+			CrossOutRequests::<T>::try_mutate(request_id, |request| -> DispatchResult {
+				let request = request.as_mut().ok_or(Error::<T>::NotExist)?;
+				Self::ensure_cross_out_operator(request.currency_id, &operator)?;
+				ensure!(
+					matches!(
+						request.status,
+						CrossOutStatus::Pending |
+							CrossOutStatus::Processing |
+							CrossOutStatus::Cancelling
+					),
+					Error::<T>::InvalidCrossOutStatus
+				);
+
+				request.status = CrossOutStatus::Completed;
+				request.updated_at = frame_system::Pallet::<T>::block_number();
+				request.foreign_tx_hash = Some(foreign_tx_hash);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::CrossOutCompleted { request_id, operator, foreign_tx_hash });
+
+			Ok(())
+		}
+
+		/// Cancel a cross-out request that is still pending after `CrossOutTimeout` blocks. The
+		/// amount is refunded once a bridge operator or the control origin confirms it was not
+		/// transferred. An operator may still complete the request instead.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::cancel_cross_out())]
+		pub fn cancel_cross_out(origin: OriginFor<T>, request_id: u64) -> DispatchResult {
+			let crosser = ensure_signed(origin)?;
+
+			CrossOutRequests::<T>::try_mutate(request_id, |request| -> DispatchResult {
+				let request = request.as_mut().ok_or(Error::<T>::NotExist)?;
+				ensure!(request.crosser == crosser, Error::<T>::NotAllowed);
+				ensure!(
+					request.status == CrossOutStatus::Pending,
+					Error::<T>::InvalidCrossOutStatus
+				);
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(
+					now >= request.created_at.saturating_add(T::CrossOutTimeout::get()),
+					Error::<T>::CrossOutNotTimedOut
+				);
+
+				request.status = CrossOutStatus::Cancelling;
+				request.updated_at = now;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::CrossOutCancelled { request_id, crosser });
+
+			Ok(())
+		}
+
+		/// Remove every pending request of a foreign transaction of a currency together with its
+		/// attestations, e.g. when attestors attested a wrong request.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::remove_pending_cross_in(T::MaxLengthLimit::get()))]
+		pub fn remove_pending_cross_in(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			tx_hash: H256,
+		) -> DispatchResultWithPostInfo {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				PendingCrossIns::<T>::iter_key_prefix((currency_id, tx_hash)).next().is_some(),
+				Error::<T>::NotExist
+			);
+			let removed = Self::remove_pending_cross_ins(currency_id, tx_hash);
+
+			Self::deposit_event(Event::PendingCrossInRemoved { currency_id, tx_hash });
+
+			Ok(Some(T::WeightInfo::remove_pending_cross_in(removed)).into())
+		}
+
+		/// Refund a cancelled cross-out request to the crosser. A bridge operator of the currency
+		/// or the control origin confirms that it was not transferred on the foreign chain.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::refund_cross_out())]
+		pub fn refund_cross_out(origin: OriginFor<T>, request_id: u64) -> DispatchResult {
+			// either the control origin or an operator of the currency can refund it
+			let operator = match T::ControlOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+
+			let (crosser, currency_id, amount) = CrossOutRequests::<T>::try_mutate(
+				request_id,
+				|request| -> Result<_, DispatchError> {
+					let request = request.as_mut().ok_or(Error::<T>::NotExist)?;
+					if let Some(operator) = &operator {
+						Self::ensure_cross_out_operator(request.currency_id, operator)?;
+					}
+					ensure!(
+						request.status == CrossOutStatus::Cancelling,
+						Error::<T>::InvalidCrossOutStatus
+					);
+
+					request.status = CrossOutStatus::Refunded;
+					request.updated_at = frame_system::Pallet::<T>::block_number();
+					Ok((request.crosser.clone(), request.currency_id, request.amount))
+				},
+			)?;
+
+			T::MultiCurrency::deposit(currency_id, &crosser, amount)?;
+
+			Self::deposit_event(Event::CrossOutRefunded {
+				request_id,
+				crosser,
+				currency_id,
+				amount,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -411,5 +1021,59 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// The cross-out requests of an account, ordered by id.
+		pub fn get_cross_out_requests(who: &AccountIdOf<T>) -> Vec<(u64, CrossOutRequestOf<T>)> {
+			let mut requests: Vec<_> = AccountCrossOutRequests::<T>::iter_key_prefix(who)
+				.filter_map(|id| CrossOutRequests::<T>::get(id).map(|request| (id, request)))
+				.collect();
+			requests.sort_by_key(|(id, _)| *id);
+			requests
+		}
+
+		fn ensure_cross_out_operator(
+			currency_id: CurrencyId,
+			operator: &AccountIdOf<T>,
+		) -> DispatchResult {
+			let operators =
+				CrossOutOperators::<T>::get(currency_id).ok_or(Error::<T>::NotAllowed)?;
+			ensure!(operators.contains(operator), Error::<T>::NotAllowed);
+			Ok(())
+		}
+
+		/// The amount counted against the rate limit at `now`, after replenishment.
+		fn rate_limit_used(
+			rate_limit: &CrossInRateLimit<BalanceOf<T>, BlockNumberFor<T>>,
+			now: BlockNumberFor<T>,
+		) -> BalanceOf<T> {
+			let elapsed = now.saturating_sub(rate_limit.updated_at).min(rate_limit.period);
+			let replenished = Perbill::from_rational(elapsed, rate_limit.period) * rate_limit.limit;
+			rate_limit.used.saturating_sub(replenished)
+		}
+
+		fn update_cross_in_rate_limit(
+			currency_id: CurrencyId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			CrossInRateLimits::<T>::try_mutate(currency_id, |rate_limit| -> DispatchResult {
+				if let Some(rate_limit) = rate_limit {
+					let now = frame_system::Pallet::<T>::block_number();
+					let used = Self::rate_limit_used(rate_limit, now).saturating_add(amount);
+					ensure!(used <= rate_limit.limit, Error::<T>::RateLimitExceeded);
+					rate_limit.used = used;
+					rate_limit.updated_at = now;
+				}
+				Ok(())
+			})
+		}
+
+		/// Remove the pending requests of a foreign transaction with their attestations, and
+		/// return the number of removed attestations. Both are bounded by `MaxLengthLimit`, as
+		/// `cross_in` takes at most that many attestations per foreign transaction.
+		fn remove_pending_cross_ins(currency_id: CurrencyId, tx_hash: H256) -> u32 {
+			let limit = T::MaxLengthLimit::get();
+			let _ = PendingCrossIns::<T>::clear_prefix((currency_id, tx_hash), limit, None);
+			CrossInAttestations::<T>::clear_prefix((currency_id, tx_hash), limit, None).unique
+		}
 	}
 }
@@ -23,7 +23,11 @@ use bifrost_primitives::{
 	currency::{ASG, BNC, DOT, KSM, VDOT},
 	CurrencyId, SlpEntrancePalletId,
 };
-use frame_support::{derive_impl, ord_parameter_types, parameter_types, traits::Nothing, PalletId};
+use frame_support::{
+	derive_impl, ord_parameter_types, parameter_types,
+	traits::{Everything, Nothing},
+	PalletId,
+};
 use frame_system::EnsureSignedBy;
 use sp_core::ConstU32;
 use sp_runtime::{traits::AccountIdConversion, AccountId32, BuildStorage};
@@ -73,6 +77,7 @@ pub type AdaptedBasicCurrency =
 
 impl bifrost_currencies::Config for Runtime {
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type WeightInfo = ();
@@ -127,6 +132,7 @@ ord_parameter_types! {
 
 parameter_types! {
 	pub const MaxLengthLimit: u32 = 100;
+	pub const CrossOutTimeout: BlockNumber = 10;
 }
 
 impl bifrost_cross_in_out::Config for Runtime {
@@ -136,6 +142,7 @@ impl bifrost_cross_in_out::Config for Runtime {
 	type EntrancePalletId = SlpEntrancePalletId;
 	type WeightInfo = ();
 	type MaxLengthLimit = MaxLengthLimit;
+	type CrossOutTimeout = CrossOutTimeout;
 }
 
 pub struct ExtBuilder {
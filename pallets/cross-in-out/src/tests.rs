@@ -19,7 +19,7 @@
 #![cfg(test)]
 
 use crate::{mock::*, *};
-use bifrost_primitives::currency::KSM;
+use bifrost_primitives::currency::{DOT, KSM};
 use frame_support::{assert_noop, assert_ok, WeakBoundedVec};
 use sp_runtime::DispatchError::BadOrigin;
 #[allow(deprecated)]
@@ -216,3 +216,485 @@ fn set_crossing_minimum_amount_should_work() {
 		assert_eq!(CrossingMinimumAmount::<Runtime>::get(KSM), Some((100, 100)));
 	});
 }
+
+fn set_up_cross_in(threshold: u32) {
+	CrossCurrencyRegistry::<Runtime>::insert(KSM, ());
+	CrossingMinimumAmount::<Runtime>::insert(KSM, (1, 1));
+	for attestor in [ALICE, BOB, CHARLIE] {
+		assert_ok!(CrossInOut::add_cross_in_attestor(RuntimeOrigin::signed(ALICE), KSM, attestor));
+	}
+	assert_ok!(CrossInOut::set_cross_in_threshold(RuntimeOrigin::signed(ALICE), KSM, threshold));
+}
+
+#[test]
+fn cross_in_should_mint_after_threshold() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		System::set_block_number(1);
+		set_up_cross_in(2);
+		let tx_hash = H256::repeat_byte(1);
+
+		assert_noop!(
+			CrossInOut::cross_in(RuntimeOrigin::signed(ALICE), DOT, tx_hash, CHARLIE, 50),
+			Error::<Runtime>::CurrencyNotSupportCrossInAndOut
+		);
+		assert_noop!(
+			CrossInOut::cross_in(RuntimeOrigin::signed(ALICE), KSM, tx_hash, CHARLIE, 0),
+			Error::<Runtime>::AmountLowerThanMinimum
+		);
+
+		assert_ok!(CrossInOut::cross_in(RuntimeOrigin::signed(ALICE), KSM, tx_hash, CHARLIE, 50));
+		assert_eq!(Tokens::free_balance(KSM, &CHARLIE), 0);
+		assert_noop!(
+			CrossInOut::cross_in(RuntimeOrigin::signed(ALICE), KSM, tx_hash, CHARLIE, 50),
+			Error::<Runtime>::AlreadyAttested
+		);
+
+		assert_ok!(CrossInOut::cross_in(RuntimeOrigin::signed(BOB), KSM, tx_hash, CHARLIE, 50));
+		assert_eq!(Tokens::free_balance(KSM, &CHARLIE), 50);
+		assert_eq!(PendingCrossIns::<Runtime>::iter_prefix((KSM, tx_hash)).count(), 0);
+		assert_eq!(CrossInAttestations::<Runtime>::iter_prefix((KSM, tx_hash)).count(), 0);
+		assert_eq!(CrossedInTransactions::<Runtime>::get(KSM, tx_hash), Some(1));
+		System::assert_last_event(RuntimeEvent::CrossInOut(Event::CrossedIn {
+			tx_hash,
+			currency_id: KSM,
+			recipient: CHARLIE,
+			amount: 50,
+		}));
+
+		// a foreign transaction can't be minted twice
+		assert_noop!(
+			CrossInOut::cross_in(RuntimeOrigin::signed(CHARLIE), KSM, tx_hash, CHARLIE, 50),
+			Error::<Runtime>::AlreadyCrossedIn
+		);
+	});
+}
+
+#[test]
+fn cross_in_should_mint_the_request_reaching_the_threshold() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		set_up_cross_in(2);
+		let tx_hash = H256::repeat_byte(1);
+
+		// a wrong first attestation doesn't block the attestations of the right request
+		assert_ok!(CrossInOut::cross_in(RuntimeOrigin::signed(ALICE), KSM, tx_hash, ALICE, 90));
+		assert_ok!(CrossInOut::cross_in(RuntimeOrigin::signed(BOB), KSM, tx_hash, CHARLIE, 50));
+		assert_eq!(PendingCrossIns::<Runtime>::iter_prefix((KSM, tx_hash)).count(), 2);
+		assert_eq!(Tokens::free_balance(KSM, &CHARLIE), 0);
+
+		// an attestor attests one request per foreign transaction
+		assert_noop!(
+			CrossInOut::cross_in(RuntimeOrigin::signed(ALICE), KSM, tx_hash, CHARLIE, 50),
+			Error::<Runtime>::AlreadyAttested
+		);
+		assert_noop!(
+			CrossInOut::cross_in(RuntimeOrigin::signed(ALICE), KSM, tx_hash, BOB, 90),
+			Error::<Runtime>::AlreadyAttested
+		);
+		assert_eq!(
+			CrossInAttestations::<Runtime>::get((KSM, tx_hash, ALICE)),
+			Some(BlakeTwo256::hash_of(&CrossInRequest {
+				currency_id: KSM,
+				recipient: ALICE,
+				amount: 90
+			}))
+		);
+
+		assert_ok!(CrossInOut::cross_in(RuntimeOrigin::signed(CHARLIE), KSM, tx_hash, CHARLIE, 50));
+		assert_eq!(Tokens::free_balance(KSM, &CHARLIE), 50);
+		assert_eq!(Tokens::free_balance(KSM, &ALICE), 100);
+		assert_eq!(PendingCrossIns::<Runtime>::iter_prefix((KSM, tx_hash)).count(), 0);
+		assert_noop!(
+			CrossInOut::cross_in(RuntimeOrigin::signed(BOB), KSM, tx_hash, ALICE, 90),
+			Error::<Runtime>::AlreadyCrossedIn
+		);
+	});
+}
+
+#[test]
+fn remove_pending_cross_in_should_work() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		set_up_cross_in(2);
+		let tx_hash = H256::repeat_byte(1);
+
+		assert_noop!(
+			CrossInOut::remove_pending_cross_in(RuntimeOrigin::signed(ALICE), KSM, tx_hash),
+			Error::<Runtime>::NotExist
+		);
+		assert_ok!(CrossInOut::cross_in(RuntimeOrigin::signed(ALICE), KSM, tx_hash, ALICE, 90));
+		assert_noop!(
+			CrossInOut::remove_pending_cross_in(RuntimeOrigin::signed(BOB), KSM, tx_hash),
+			BadOrigin
+		);
+		assert_ok!(CrossInOut::remove_pending_cross_in(RuntimeOrigin::signed(ALICE), KSM, tx_hash));
+		assert_eq!(PendingCrossIns::<Runtime>::iter_prefix((KSM, tx_hash)).count(), 0);
+		assert_eq!(CrossInAttestations::<Runtime>::iter_prefix((KSM, tx_hash)).count(), 0);
+		System::assert_last_event(RuntimeEvent::CrossInOut(Event::PendingCrossInRemoved {
+			currency_id: KSM,
+			tx_hash,
+		}));
+
+		// ALICE can attest again after the removal
+		assert_ok!(CrossInOut::cross_in(RuntimeOrigin::signed(ALICE), KSM, tx_hash, ALICE, 90));
+	});
+}
+
+#[test]
+fn set_cross_in_threshold_should_not_exceed_attestors() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		set_up_cross_in(3);
+		assert_noop!(
+			CrossInOut::set_cross_in_threshold(RuntimeOrigin::signed(ALICE), KSM, 4),
+			Error::<Runtime>::InvalidThreshold
+		);
+		assert_noop!(
+			CrossInOut::set_cross_in_threshold(RuntimeOrigin::signed(ALICE), DOT, 1),
+			Error::<Runtime>::InvalidThreshold
+		);
+
+		// removing an attestor can't leave the threshold unreachable
+		assert_noop!(
+			CrossInOut::remove_cross_in_attestor(RuntimeOrigin::signed(ALICE), KSM, CHARLIE),
+			Error::<Runtime>::InvalidThreshold
+		);
+		assert_ok!(CrossInOut::set_cross_in_threshold(RuntimeOrigin::signed(ALICE), KSM, 2));
+		assert_ok!(CrossInOut::remove_cross_in_attestor(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			CHARLIE
+		));
+		assert_eq!(
+			CrossInAttestors::<Runtime>::get(KSM).map(|attestors| attestors.into_inner()),
+			Some(vec![ALICE, BOB])
+		);
+	});
+}
+
+#[test]
+fn cross_in_should_scope_tx_hash_by_currency() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		set_up_cross_in(1);
+		CrossCurrencyRegistry::<Runtime>::insert(DOT, ());
+		CrossingMinimumAmount::<Runtime>::insert(DOT, (1, 1));
+		assert_ok!(CrossInOut::add_cross_in_attestor(RuntimeOrigin::signed(ALICE), DOT, ALICE));
+		assert_ok!(CrossInOut::set_cross_in_threshold(RuntimeOrigin::signed(ALICE), DOT, 1));
+		let tx_hash = H256::repeat_byte(1);
+
+		// the same hash on the chains of two currencies belongs to different transactions
+		assert_ok!(CrossInOut::cross_in(RuntimeOrigin::signed(ALICE), KSM, tx_hash, CHARLIE, 50));
+		assert_ok!(CrossInOut::cross_in(RuntimeOrigin::signed(ALICE), DOT, tx_hash, CHARLIE, 30));
+		assert_eq!(Tokens::free_balance(KSM, &CHARLIE), 50);
+		assert_eq!(Tokens::free_balance(DOT, &CHARLIE), 30);
+		assert!(CrossedInTransactions::<Runtime>::contains_key(KSM, tx_hash));
+		assert!(CrossedInTransactions::<Runtime>::contains_key(DOT, tx_hash));
+	});
+}
+
+#[test]
+fn cross_in_should_not_count_removed_attestors() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		set_up_cross_in(2);
+		let tx_hash = H256::repeat_byte(1);
+
+		assert_ok!(CrossInOut::cross_in(RuntimeOrigin::signed(ALICE), KSM, tx_hash, CHARLIE, 50));
+		assert_ok!(CrossInOut::remove_cross_in_attestor(RuntimeOrigin::signed(ALICE), KSM, ALICE));
+		assert_noop!(
+			CrossInOut::cross_in(RuntimeOrigin::signed(ALICE), KSM, tx_hash, CHARLIE, 50),
+			Error::<Runtime>::NotAllowed
+		);
+
+		assert_ok!(CrossInOut::cross_in(RuntimeOrigin::signed(BOB), KSM, tx_hash, CHARLIE, 50));
+		assert_eq!(Tokens::free_balance(KSM, &CHARLIE), 0);
+		assert_ok!(CrossInOut::cross_in(RuntimeOrigin::signed(CHARLIE), KSM, tx_hash, CHARLIE, 50));
+		assert_eq!(Tokens::free_balance(KSM, &CHARLIE), 50);
+	});
+}
+
+#[test]
+fn cross_in_should_respect_rate_limit_and_pause() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		System::set_block_number(1);
+		set_up_cross_in(1);
+		assert_noop!(
+			CrossInOut::set_cross_in_rate_limit(RuntimeOrigin::signed(ALICE), KSM, Some((100, 0))),
+			Error::<Runtime>::InvalidRateLimit
+		);
+		assert_ok!(CrossInOut::set_cross_in_rate_limit(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Some((100, 10))
+		));
+
+		assert_ok!(CrossInOut::cross_in(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			H256::repeat_byte(1),
+			CHARLIE,
+			80
+		));
+		assert_noop!(
+			CrossInOut::cross_in(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				H256::repeat_byte(2),
+				CHARLIE,
+				30
+			),
+			Error::<Runtime>::RateLimitExceeded
+		);
+
+		// half of the limit is replenished after half of the period
+		System::set_block_number(6);
+		assert_ok!(CrossInOut::cross_in(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			H256::repeat_byte(2),
+			CHARLIE,
+			30
+		));
+		assert_eq!(Tokens::free_balance(KSM, &CHARLIE), 110);
+		assert_eq!(CrossInRateLimits::<Runtime>::get(KSM).map(|limit| limit.used), Some(60));
+
+		assert_ok!(CrossInOut::set_cross_in_paused(RuntimeOrigin::signed(ALICE), KSM, true));
+		assert_noop!(
+			CrossInOut::cross_in(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				H256::repeat_byte(3),
+				CHARLIE,
+				1
+			),
+			Error::<Runtime>::CrossInPaused
+		);
+		assert_ok!(CrossInOut::set_cross_in_paused(RuntimeOrigin::signed(ALICE), KSM, false));
+		assert_ok!(CrossInOut::cross_in(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			H256::repeat_byte(3),
+			CHARLIE,
+			1
+		));
+
+		assert_noop!(
+			CrossInOut::set_cross_in_paused(RuntimeOrigin::signed(BOB), KSM, true),
+			BadOrigin
+		);
+	});
+}
+
+#[allow(deprecated)]
+fn set_up_cross_out() {
+	let location = MultiLocation {
+		parents: 100,
+		interior: X1(Junction::GeneralKey(WeakBoundedVec::default())),
+	};
+	CrossCurrencyRegistry::<Runtime>::insert(KSM, ());
+	CrossingMinimumAmount::<Runtime>::insert(KSM, (1, 1));
+	AccountToOuterMultilocation::<Runtime>::insert(KSM, ALICE, location.clone());
+	OuterMultilocationToAccount::<Runtime>::insert(KSM, location, ALICE);
+	assert_ok!(CrossInOut::add_cross_out_operator(RuntimeOrigin::signed(ALICE), KSM, BOB));
+}
+
+#[test]
+fn cross_out_request_should_be_completed_by_operator() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		System::set_block_number(1);
+		set_up_cross_out();
+
+		assert_ok!(CrossInOut::cross_out(RuntimeOrigin::signed(ALICE), KSM, 50));
+		assert_ok!(CrossInOut::cross_out(RuntimeOrigin::signed(ALICE), KSM, 20));
+		assert_eq!(
+			CrossOutRequests::<Runtime>::get(0),
+			Some(CrossOutRequest {
+				crosser: ALICE,
+				currency_id: KSM,
+				amount: 50,
+				status: CrossOutStatus::Pending,
+				created_at: 1,
+				updated_at: 1,
+				foreign_tx_hash: None,
+			})
+		);
+
+		System::set_block_number(2);
+		assert_noop!(
+			CrossInOut::process_cross_out(RuntimeOrigin::signed(CHARLIE), 0),
+			Error::<Runtime>::NotAllowed
+		);
+		assert_ok!(CrossInOut::process_cross_out(RuntimeOrigin::signed(BOB), 0));
+		assert_noop!(
+			CrossInOut::process_cross_out(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::InvalidCrossOutStatus
+		);
+
+		System::set_block_number(3);
+		let foreign_tx_hash = H256::repeat_byte(1);
+		assert_ok!(CrossInOut::complete_cross_out(RuntimeOrigin::signed(BOB), 0, foreign_tx_hash));
+		System::assert_last_event(RuntimeEvent::CrossInOut(Event::CrossOutCompleted {
+			request_id: 0,
+			operator: BOB,
+			foreign_tx_hash,
+		}));
+		assert_noop!(
+			CrossInOut::complete_cross_out(RuntimeOrigin::signed(BOB), 0, foreign_tx_hash),
+			Error::<Runtime>::InvalidCrossOutStatus
+		);
+
+		let requests = CrossInOut::get_cross_out_requests(&ALICE);
+		assert_eq!(
+			requests.iter().map(|(id, request)| (*id, request.status)).collect::<Vec<_>>(),
+			vec![(0, CrossOutStatus::Completed), (1, CrossOutStatus::Pending)]
+		);
+		assert_eq!(
+			(requests[0].1.updated_at, requests[0].1.foreign_tx_hash),
+			(3, Some(foreign_tx_hash))
+		);
+		assert!(CrossInOut::get_cross_out_requests(&BOB).is_empty());
+	});
+}
+
+#[test]
+fn cancel_cross_out_should_be_refunded_by_operator() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		System::set_block_number(1);
+		set_up_cross_out();
+
+		assert_ok!(CrossInOut::cross_out(RuntimeOrigin::signed(ALICE), KSM, 50));
+		assert_ok!(CrossInOut::cross_out(RuntimeOrigin::signed(ALICE), KSM, 20));
+		assert_eq!(Tokens::free_balance(KSM, &ALICE), 30);
+
+		assert_noop!(
+			CrossInOut::cancel_cross_out(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Runtime>::CrossOutNotTimedOut
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			CrossInOut::cancel_cross_out(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::NotAllowed
+		);
+		assert_noop!(
+			CrossInOut::refund_cross_out(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::InvalidCrossOutStatus
+		);
+
+		// cancelling alone refunds nothing, the operator may have transferred it already
+		assert_ok!(CrossInOut::cancel_cross_out(RuntimeOrigin::signed(ALICE), 0));
+		System::assert_last_event(RuntimeEvent::CrossInOut(Event::CrossOutCancelled {
+			request_id: 0,
+			crosser: ALICE,
+		}));
+		assert_eq!(Tokens::free_balance(KSM, &ALICE), 30);
+		assert_eq!(
+			CrossOutRequests::<Runtime>::get(0).map(|request| (request.status, request.updated_at)),
+			Some((CrossOutStatus::Cancelling, 11))
+		);
+		assert_noop!(
+			CrossInOut::cancel_cross_out(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Runtime>::InvalidCrossOutStatus
+		);
+
+		// the operator confirms it was not transferred and refunds it
+		System::set_block_number(12);
+		assert_noop!(
+			CrossInOut::refund_cross_out(RuntimeOrigin::signed(CHARLIE), 0),
+			Error::<Runtime>::NotAllowed
+		);
+		assert_ok!(CrossInOut::refund_cross_out(RuntimeOrigin::signed(BOB), 0));
+		assert_eq!(Tokens::free_balance(KSM, &ALICE), 80);
+		assert_eq!(
+			CrossOutRequests::<Runtime>::get(0).map(|request| (request.status, request.updated_at)),
+			Some((CrossOutStatus::Refunded, 12))
+		);
+		assert_noop!(
+			CrossInOut::refund_cross_out(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::InvalidCrossOutStatus
+		);
+		assert_noop!(
+			CrossInOut::complete_cross_out(RuntimeOrigin::signed(BOB), 0, H256::repeat_byte(1)),
+			Error::<Runtime>::InvalidCrossOutStatus
+		);
+
+		// a request being processed can't be cancelled
+		assert_ok!(CrossInOut::process_cross_out(RuntimeOrigin::signed(BOB), 1));
+		assert_noop!(
+			CrossInOut::cancel_cross_out(RuntimeOrigin::signed(ALICE), 1),
+			Error::<Runtime>::InvalidCrossOutStatus
+		);
+		assert_noop!(
+			CrossInOut::cancel_cross_out(RuntimeOrigin::signed(ALICE), 2),
+			Error::<Runtime>::NotExist
+		);
+	});
+}
+
+#[test]
+fn cancelled_cross_out_should_be_completed_if_transferred() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		System::set_block_number(1);
+		set_up_cross_out();
+
+		assert_ok!(CrossInOut::cross_out(RuntimeOrigin::signed(ALICE), KSM, 50));
+		System::set_block_number(11);
+		assert_ok!(CrossInOut::cancel_cross_out(RuntimeOrigin::signed(ALICE), 0));
+
+		// the operator transferred it before the cancellation, so it is not refunded
+		let foreign_tx_hash = H256::repeat_byte(1);
+		assert_ok!(CrossInOut::complete_cross_out(RuntimeOrigin::signed(BOB), 0, foreign_tx_hash));
+		assert_noop!(
+			CrossInOut::refund_cross_out(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::InvalidCrossOutStatus
+		);
+		assert_eq!(Tokens::free_balance(KSM, &ALICE), 50);
+		assert_eq!(
+			CrossOutRequests::<Runtime>::get(0).map(|request| request.status),
+			Some(CrossOutStatus::Completed)
+		);
+	});
+}
+
+#[test]
+fn cancelled_cross_out_should_not_be_refunded_by_crosser() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		System::set_block_number(1);
+		set_up_cross_out();
+		let location = MultiLocation {
+			parents: 101,
+			interior: X1(Junction::GeneralKey(WeakBoundedVec::default())),
+		};
+		AccountToOuterMultilocation::<Runtime>::insert(KSM, CHARLIE, location.clone());
+		OuterMultilocationToAccount::<Runtime>::insert(KSM, location, CHARLIE);
+		assert_ok!(Tokens::deposit(KSM, &CHARLIE, 100));
+
+		assert_ok!(CrossInOut::cross_out(RuntimeOrigin::signed(CHARLIE), KSM, 50));
+		System::set_block_number(11);
+		assert_noop!(
+			CrossInOut::refund_cross_out(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Runtime>::InvalidCrossOutStatus
+		);
+		assert_ok!(CrossInOut::cancel_cross_out(RuntimeOrigin::signed(CHARLIE), 0));
+
+		// only an operator or the control origin knows it wasn't transferred
+		System::set_block_number(1_000);
+		assert_noop!(
+			CrossInOut::refund_cross_out(RuntimeOrigin::signed(CHARLIE), 0),
+			Error::<Runtime>::NotAllowed
+		);
+
+		assert_ok!(CrossInOut::refund_cross_out(RuntimeOrigin::signed(ALICE), 0));
+		System::assert_last_event(RuntimeEvent::CrossInOut(Event::CrossOutRefunded {
+			request_id: 0,
+			crosser: CHARLIE,
+			currency_id: KSM,
+			amount: 50,
+		}));
+		assert_eq!(Tokens::free_balance(KSM, &CHARLIE), 100);
+		assert_eq!(
+			CrossOutRequests::<Runtime>::get(0).map(|request| (request.status, request.updated_at)),
+			Some((CrossOutStatus::Refunded, 1_000))
+		);
+		assert_noop!(
+			CrossInOut::refund_cross_out(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Runtime>::InvalidCrossOutStatus
+		);
+	});
+}
@@ -45,7 +45,7 @@ use frame_support::{
 			fungible, fungibles, DepositConsequence, Fortitude, Precision, Preservation,
 			Provenance, WithdrawConsequence,
 		},
-		Currency as PalletCurrency, ExistenceRequirement, Get, Imbalance,
+		Contains, Currency as PalletCurrency, ExistenceRequirement, Get, Imbalance,
 		LockableCurrency as PalletLockableCurrency,
 		NamedReservableCurrency as PalletNamedReservableCurrency,
 		ReservableCurrency as PalletReservableCurrency, WithdrawReasons,
@@ -54,7 +54,7 @@ use frame_support::{
 use frame_system::{ensure_root, ensure_signed, pallet_prelude::*};
 use orml_traits::{
 	arithmetic::{Signed, SimpleArithmetic},
-	currency::TransferAll,
+	currency::{OnTransfer, TransferAll},
 	BalanceStatus, BasicCurrency, BasicCurrencyExtended, BasicLockableCurrency,
 	BasicReservableCurrency, LockIdentifier, MultiCurrency, MultiCurrencyExtended,
 	MultiLockableCurrency, MultiReservableCurrency, NamedBasicReservableCurrency,
@@ -126,6 +126,9 @@ pub mod module {
 		#[pallet::constant]
 		type GetNativeCurrencyId: Get<CurrencyIdOf<Self>>;
 
+		/// The currencies that can be transferred, e.g. excluding frozen assets.
+		type TransferFilter: Contains<CurrencyIdOf<Self>>;
+
 		/// Weight information for extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -138,6 +141,8 @@ pub mod module {
 		BalanceTooLow,
 		/// Deposit result is not expected
 		DepositFailed,
+		/// The currency can't be transferred
+		CurrencyNotTransferable,
 	}
 
 	#[pallet::pallet]
@@ -260,6 +265,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 		if amount.is_zero() || from == to {
 			return Ok(());
 		}
+		ensure!(T::TransferFilter::contains(&currency_id), Error::<T>::CurrencyNotTransferable);
 		if currency_id == T::GetNativeCurrencyId::get() {
 			T::NativeCurrency::transfer(from, to, amount)
 		} else {
@@ -704,6 +710,7 @@ impl<T: Config> fungibles::Mutate<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 		preservation: Preservation,
 	) -> Result<Self::Balance, DispatchError> {
+		ensure!(T::TransferFilter::contains(&asset_id), Error::<T>::CurrencyNotTransferable);
 		match asset_id {
 			id if id == T::GetNativeCurrencyId::get() => <T::NativeCurrency as fungible::Mutate<
 				_,
@@ -1284,6 +1291,22 @@ where
 	}
 }
 
+/// `OnTransfer` hook of the underlying `MultiCurrency` that rejects the currencies not in
+/// `TransferFilter`, so that transfers made directly with it are filtered too.
+pub struct TransferFilterHook<T>(marker::PhantomData<T>);
+
+impl<T: Config> OnTransfer<T::AccountId, CurrencyIdOf<T>, BalanceOf<T>> for TransferFilterHook<T> {
+	fn on_transfer(
+		currency_id: CurrencyIdOf<T>,
+		_from: &T::AccountId,
+		_to: &T::AccountId,
+		_amount: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(T::TransferFilter::contains(&currency_id), Error::<T>::CurrencyNotTransferable);
+		Ok(())
+	}
+}
+
 impl<T: Config> TransferAll<T::AccountId> for Pallet<T> {
 	fn transfer_all(source: &T::AccountId, dest: &T::AccountId) -> DispatchResult {
 		with_transaction_result(|| {
@@ -58,16 +58,12 @@ parameter_types! {
 }
 
 pub struct CurrencyHooks<T>(marker::PhantomData<T>);
-impl<T: orml_tokens::Config> MutationHooks<T::AccountId, T::CurrencyId, T::Balance>
-	for CurrencyHooks<T>
-where
-	T::AccountId: From<AccountId32>,
-{
-	type OnDust = orml_tokens::TransferDust<T, DustAccount>;
+impl MutationHooks<AccountId, CurrencyId, Balance> for CurrencyHooks<Runtime> {
+	type OnDust = orml_tokens::TransferDust<Runtime, DustAccount>;
 	type OnSlash = ();
 	type PreDeposit = ();
 	type PostDeposit = ();
-	type PreTransfer = ();
+	type PreTransfer = TransferFilterHook<Runtime>;
 	type PostTransfer = ();
 	type OnNewTokenAccount = ();
 	type OnKilledTokenAccount = ();
@@ -89,15 +85,24 @@ impl orml_tokens::Config for Runtime {
 
 pub const NATIVE_CURRENCY_ID: CurrencyId = 1;
 pub const X_TOKEN_ID: CurrencyId = 2;
+pub const FROZEN_TOKEN_ID: CurrencyId = 3;
 
 parameter_types! {
 	pub const GetNativeCurrencyId: CurrencyId = NATIVE_CURRENCY_ID;
 }
 
+pub struct TransferableCurrencies;
+impl Contains<CurrencyId> for TransferableCurrencies {
+	fn contains(currency_id: &CurrencyId) -> bool {
+		*currency_id != FROZEN_TOKEN_ID
+	}
+}
+
 impl Config for Runtime {
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TransferFilter = TransferableCurrencies;
 	type WeightInfo = ();
 }
 pub type NativeCurrency = NativeCurrencyOf<Runtime>;
@@ -149,6 +149,44 @@ fn multi_currency_should_work() {
 	});
 }
 
+#[test]
+fn transfer_filter_should_work() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, FROZEN_TOKEN_ID, 100)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Currencies::transfer(Some(ALICE).into(), BOB, FROZEN_TOKEN_ID, 50),
+				Error::<Runtime>::CurrencyNotTransferable
+			);
+			assert_noop!(
+				<Currencies as fungibles::Mutate<_>>::transfer(
+					FROZEN_TOKEN_ID,
+					&ALICE,
+					&BOB,
+					50,
+					Preservation::Expendable
+				),
+				Error::<Runtime>::CurrencyNotTransferable
+			);
+			assert_noop!(
+				Tokens::transfer(Some(ALICE).into(), BOB, FROZEN_TOKEN_ID, 50),
+				Error::<Runtime>::CurrencyNotTransferable
+			);
+			assert_noop!(
+				Tokens::transfer_all(Some(ALICE).into(), BOB, FROZEN_TOKEN_ID, false),
+				Error::<Runtime>::CurrencyNotTransferable
+			);
+			// the balance can still be withdrawn by pallets
+			assert_ok!(<Currencies as MultiCurrency<AccountId>>::withdraw(
+				FROZEN_TOKEN_ID,
+				&ALICE,
+				50
+			));
+			assert_eq!(Currencies::free_balance(FROZEN_TOKEN_ID, &ALICE), 50);
+		});
+}
+
 #[test]
 fn multi_currency_extended_should_work() {
 	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
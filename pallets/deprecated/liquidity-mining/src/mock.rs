@@ -16,6 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use bifrost_primitives::{traits::CheckSubAccount, Amount, Balance, CurrencyId, TokenSymbol};
 use frame_support::{
 	construct_runtime, parameter_types,
 	sp_io::TestExternalities,
@@ -27,7 +28,6 @@ use frame_support::{
 	traits::Contains,
 	PalletId,
 };
-use bifrost_primitives::{traits::CheckSubAccount, Amount, Balance, CurrencyId, TokenSymbol};
 use sp_core::H256;
 
 use crate as lm;
@@ -109,10 +109,12 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Test>;
 }
 
-pub type BifrostToken = bifrost_currencies::BasicCurrencyAdapter<Test, Balances, Amount, BlockNumber>;
+pub type BifrostToken =
+	bifrost_currencies::BasicCurrencyAdapter<Test, Balances, Amount, BlockNumber>;
 
 impl bifrost_currencies::Config for Test {
 	type GetNativeCurrencyId = NativeCurrencyId;
+	type TransferFilter = frame_support::traits::Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = BifrostToken;
 	type WeightInfo = ();
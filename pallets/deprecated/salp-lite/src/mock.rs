@@ -17,6 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 // Ensure we're `no_std` when compiling for Wasm.
+use bifrost_primitives::{Amount, Balance, CurrencyId, MessageId, TokenSymbol};
 use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{EnsureOrigin, GenesisBuild, Nothing},
@@ -24,7 +25,6 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::RawOrigin;
-use bifrost_primitives::{Amount, Balance, CurrencyId, MessageId, TokenSymbol};
 use smallvec::smallvec;
 use sp_arithmetic::Percent;
 use sp_core::H256;
@@ -150,10 +150,12 @@ impl orml_tokens::Config for Test {
 	type CurrencyHooks = ();
 }
 
-pub type BifrostToken = bifrost_currencies::BasicCurrencyAdapter<Test, Balances, Amount, BlockNumber>;
+pub type BifrostToken =
+	bifrost_currencies::BasicCurrencyAdapter<Test, Balances, Amount, BlockNumber>;
 
 impl bifrost_currencies::Config for Test {
 	type GetNativeCurrencyId = NativeCurrencyId;
+	type TransferFilter = frame_support::traits::Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = BifrostToken;
 	type WeightInfo = ();
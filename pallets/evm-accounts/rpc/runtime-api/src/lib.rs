@@ -21,6 +21,7 @@ use codec::Codec;
 
 sp_api::decl_runtime_apis! {
 	/// The API to query EVM account conversions.
+	#[api_version(2)]
 	pub trait EvmAccountsApi<AccountId, EvmAddress> where
 		AccountId: Codec,
 		EvmAddress: Codec,
@@ -34,5 +35,9 @@ sp_api::decl_runtime_apis! {
 		/// Get the Substrate address from the EVM address.
 		/// Returns the truncated version of the address if the address wasn't bind.
 		fn account_id(evm_address: EvmAddress) -> AccountId;
+
+		/// Return the EVM address bound or claimed by the Substrate account. If not bound, returns `None`.
+		#[api_version(2)]
+		fn bound_evm_address(account_id: AccountId) -> Option<EvmAddress>;
 	}
 }
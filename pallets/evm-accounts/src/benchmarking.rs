@@ -74,5 +74,41 @@ benchmarks! {
 		assert!(!ContractDeployer::<T>::contains_key(evm_address));
 	}
 
+	claim_evm_address {
+		let user: T::AccountId = account("user", 0, 1);
+		let (evm_address, signature) = Pallet::<T>::sign_claim(&user);
+		assert!(!ClaimedAccount::<T>::contains_key(evm_address));
+
+	}: _(RawOrigin::Signed(user.clone()), evm_address, signature)
+	verify {
+		assert!(ClaimedAccount::<T>::contains_key(evm_address));
+	}
+
+	unbind_evm_address {
+		let user: T::AccountId = account("user", 0, 1);
+		let (evm_address, signature) = Pallet::<T>::sign_claim(&user);
+
+		EVMAccounts::<T>::claim_evm_address(RawOrigin::Signed(user.clone()).into(), evm_address, signature)?;
+
+	}: _(RawOrigin::Signed(user.clone()))
+	verify {
+		assert!(PendingUnbind::<T>::contains_key(&user));
+	}
+
+	confirm_unbind {
+		let user: T::AccountId = account("user", 0, 1);
+		let (evm_address, signature) = Pallet::<T>::sign_claim(&user);
+
+		EVMAccounts::<T>::claim_evm_address(RawOrigin::Signed(user.clone()).into(), evm_address, signature)?;
+		EVMAccounts::<T>::unbind_evm_address(RawOrigin::Signed(user.clone()).into())?;
+
+		let unbind_at = PendingUnbind::<T>::get(&user).unwrap();
+		frame_system::Pallet::<T>::set_block_number(unbind_at);
+
+	}: _(RawOrigin::Signed(user))
+	verify {
+		assert!(!ClaimedAccount::<T>::contains_key(evm_address));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::ExtBuilder::default().build(), crate::mock::Test);
 }
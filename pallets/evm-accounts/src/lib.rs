@@ -38,6 +38,18 @@
 //! With binding, we store the last 12 bytes of the Substrate address. Then we can get the original
 //! Substrate address by concatenating these 12 bytes stored in the storage to the EVM address.
 //!
+//! ### Claiming
+//! A user who already has an EVM key can bind that EVM address to their Substrate account instead
+//! of the truncated one. The EVM key signs an EIP-712 message containing the Substrate address,
+//! which proves the ownership of the EVM address. Only EVM addresses with a zero nonce whose
+//! truncated account doesn't exist can be claimed, so no funds are left behind in the truncated
+//! account of the EVM address.
+//!
+//! ### Unbinding
+//! A bound or claimed address can be unbound by its Substrate account. The binding is kept until
+//! `UnbindCooldown` blocks have passed after the request, so that transactions already submitted
+//! from the EVM address are still executed on behalf of the Substrate account.
+//!
 //! ### Smart contract deployment
 //! This pallet also allows granting a permission to deploy smart contracts.
 //! `ControllerOrigin` can add this permission to EVM addresses.
@@ -46,6 +58,9 @@
 //! ### Dispatchable Functions
 //!
 //! * `bind_evm_address` - Binds a Substrate address to EVM address.
+//! * `claim_evm_address` - Binds a Substrate address to an EVM address proven by a signature.
+//! * `unbind_evm_address` - Requests to unbind the EVM address of a Substrate address.
+//! * `confirm_unbind` - Unbinds the EVM address after the cooldown has passed.
 //! * `add_contract_deployer` - Adds a permission to deploy smart contracts.
 //! * `remove_contract_deployer` - Removes a permission of whitelisted address to deploy smart
 //!   contracts.
@@ -58,12 +73,15 @@ use frame_support::{
 	ensure,
 	pallet_prelude::{DispatchResult, Get},
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_traits::evm::InspectEvmAccounts;
 use sp_core::{
 	crypto::{AccountId32, ByteArray},
-	H160, U256,
+	H160, H256, U256,
 };
-use sp_runtime::traits::Hash;
+use sp_io::hashing::keccak_256;
+use sp_runtime::traits::{Hash, Saturating, Zero};
+use sp_std::vec::Vec;
 
 #[cfg(test)]
 mod mock;
@@ -79,6 +97,7 @@ pub use weights::WeightInfo;
 pub type Balance = u128;
 pub type EvmAddress = H160;
 pub type AccountIdLast12Bytes = [u8; 12];
+pub type EvmSignature = [u8; 65];
 pub type Hashing = sp_runtime::traits::BlakeTwo256;
 
 pub trait EvmNonceProvider {
@@ -109,6 +128,13 @@ pub mod pallet {
 		/// Origin that can whitelist addresses for smart contract deployment.
 		type ControllerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// EVM chain id, part of the EIP-712 domain of the claims.
+		type ChainId: Get<u64>;
+
+		/// Number of blocks between an unbind request and the removal of the binding.
+		#[pallet::constant]
+		type UnbindCooldown: Get<BlockNumberFor<Self>>;
+
 		/// Weight information for extrinsic in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -122,6 +148,21 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type ContractDeployer<T: Config> = StorageMap<_, Blake2_128Concat, EvmAddress, ()>;
 
+	/// Maps a claimed EVM address to the substrate account that claimed it.
+	#[pallet::storage]
+	pub(super) type ClaimedAccount<T: Config> =
+		StorageMap<_, Blake2_128Concat, EvmAddress, T::AccountId>;
+
+	/// Maps a substrate account to the EVM address it claimed.
+	#[pallet::storage]
+	pub(super) type ClaimedAddress<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, EvmAddress>;
+
+	/// Block at which the binding of a substrate account can be removed.
+	#[pallet::storage]
+	pub(super) type PendingUnbind<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -131,6 +172,12 @@ pub mod pallet {
 		DeployerAdded { who: EvmAddress },
 		/// Deployer was removed.
 		DeployerRemoved { who: EvmAddress },
+		/// EVM address was claimed.
+		Claimed { account: T::AccountId, address: EvmAddress },
+		/// Unbinding was requested.
+		UnbindRequested { account: T::AccountId, address: EvmAddress, unbind_at: BlockNumberFor<T> },
+		/// Binding was removed.
+		Unbound { account: T::AccountId, address: EvmAddress },
 	}
 
 	#[pallet::error]
@@ -144,6 +191,20 @@ pub mod pallet {
 		BoundAddressCannotBeUsed,
 		/// Address not whitelisted
 		AddressNotWhitelisted,
+		/// Account is already bound to an address
+		AccountAlreadyBound,
+		/// Signature doesn't match the claimed address
+		InvalidSignature,
+		/// Account is not bound to an address
+		AddressNotBound,
+		/// Unbinding is already requested
+		UnbindAlreadyRequested,
+		/// Unbinding is not requested
+		UnbindNotRequested,
+		/// Unbind cooldown hasn't passed yet
+		UnbindCooldownNotPassed,
+		/// Truncated account of the EVM address still exists
+		TruncatedAccountNotEmpty,
 	}
 
 	#[pallet::hooks]
@@ -180,16 +241,14 @@ pub mod pallet {
 		pub fn bind_evm_address(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let evm_address = Self::evm_address(&who);
+			let evm_address = Self::truncated_evm_address(&who);
 
 			// This check is not necessary. It prevents binding the same address multiple times.
 			// Without this check binding the address second time can have pass or fail, depending
 			// on the nonce. So it's better to prevent any confusion and throw an error when address
 			// is already bound.
-			ensure!(
-				!AccountExtension::<T>::contains_key(evm_address),
-				Error::<T>::AddressAlreadyBound
-			);
+			ensure!(Self::bound_account_id(evm_address).is_none(), Error::<T>::AddressAlreadyBound);
+			ensure!(!ClaimedAddress::<T>::contains_key(&who), Error::<T>::AccountAlreadyBound);
 
 			let nonce = T::EvmNonceProvider::get_nonce(evm_address);
 			ensure!(nonce.is_zero(), Error::<T>::TruncatedAccountAlreadyUsed);
@@ -267,6 +326,178 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Binds a Substrate address to an arbitrary EVM address.
+		/// The ownership of the EVM address is proven by an EIP-712 signature of the Substrate
+		/// address, made with the key of the EVM address. After binding, the EVM converts the EVM
+		/// address to the Substrate address.
+		///
+		/// Parameters:
+		/// - `origin`: Substrate account binding an address
+		/// - `address`: EVM address that is bound
+		/// - `signature`: signature of `claim_signable_message` made with the key of `address`
+		///
+		/// Emits `Claimed` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_evm_address().saturating_mul(<T as Config>::FeeMultiplier::get() as u64))]
+		pub fn claim_evm_address(
+			origin: OriginFor<T>,
+			address: EvmAddress,
+			signature: EvmSignature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::bound_evm_address(&who).is_none(), Error::<T>::AccountAlreadyBound);
+			ensure!(Self::bound_account_id(address).is_none(), Error::<T>::AddressAlreadyBound);
+
+			let nonce = T::EvmNonceProvider::get_nonce(address);
+			ensure!(nonce.is_zero(), Error::<T>::TruncatedAccountAlreadyUsed);
+			// A zero nonce doesn't mean the truncated account has no funds, e.g. it could have
+			// received transfers. They would be lost once the address converts to `who`.
+			ensure!(
+				!frame_system::Pallet::<T>::account_exists(&Self::convert_account_id(address)),
+				Error::<T>::TruncatedAccountNotEmpty
+			);
+
+			ensure!(
+				Self::recover_claim_signer(&who, &signature) == Some(address),
+				Error::<T>::InvalidSignature
+			);
+
+			<ClaimedAccount<T>>::insert(address, &who);
+			<ClaimedAddress<T>>::insert(&who, address);
+
+			Self::deposit_event(Event::Claimed { account: who, address });
+
+			Ok(())
+		}
+
+		/// Requests to unbind the EVM address bound to the caller.
+		/// The binding is kept until `UnbindCooldown` blocks have passed and `confirm_unbind` is
+		/// called.
+		///
+		/// Parameters:
+		/// - `origin`: Substrate account unbinding its address
+		///
+		/// Emits `UnbindRequested` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::unbind_evm_address())]
+		pub fn unbind_evm_address(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let address = Self::bound_evm_address(&who).ok_or(Error::<T>::AddressNotBound)?;
+			ensure!(!PendingUnbind::<T>::contains_key(&who), Error::<T>::UnbindAlreadyRequested);
+
+			let unbind_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::UnbindCooldown::get());
+			<PendingUnbind<T>>::insert(&who, unbind_at);
+
+			Self::deposit_event(Event::UnbindRequested { account: who, address, unbind_at });
+
+			Ok(())
+		}
+
+		/// Removes the binding of the caller once the unbind cooldown has passed.
+		///
+		/// Parameters:
+		/// - `origin`: Substrate account that requested the unbinding
+		///
+		/// Emits `Unbound` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::confirm_unbind())]
+		pub fn confirm_unbind(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let unbind_at = PendingUnbind::<T>::get(&who).ok_or(Error::<T>::UnbindNotRequested)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= unbind_at,
+				Error::<T>::UnbindCooldownNotPassed
+			);
+
+			<PendingUnbind<T>>::remove(&who);
+			let address = match <ClaimedAddress<T>>::take(&who) {
+				Some(address) => {
+					<ClaimedAccount<T>>::remove(address);
+					address
+				},
+				None => {
+					let address = Self::truncated_evm_address(&who);
+					<AccountExtension<T>>::remove(address);
+					address
+				},
+			};
+
+			Self::deposit_event(Event::Unbound { account: who, address });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Get the EVM address made of the first 20 bytes of the substrate address, ignoring claims.
+	pub fn truncated_evm_address(account_id: &impl AsRef<[u8; 32]>) -> EvmAddress {
+		let acc = account_id.as_ref();
+		EvmAddress::from_slice(&acc[..20])
+	}
+
+	/// EIP-712 domain separator of the claims, bound to the chain id and the genesis hash.
+	fn claim_domain_separator() -> [u8; 32] {
+		let mut chain_id = [0u8; 32];
+		U256::from(T::ChainId::get()).to_big_endian(&mut chain_id);
+
+		let mut msg =
+			keccak_256(b"EIP712Domain(string name,string version,uint256 chainId,bytes32 salt)")
+				.to_vec();
+		msg.extend_from_slice(&keccak_256(b"Bifrost EVM claim"));
+		msg.extend_from_slice(&keccak_256(b"1"));
+		msg.extend_from_slice(&chain_id);
+		msg.extend_from_slice(
+			frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero()).as_ref(),
+		);
+		keccak_256(&msg)
+	}
+
+	/// EIP-712 message that the key of an EVM address signs to bind the address to `who`.
+	pub fn claim_signable_message(who: &T::AccountId) -> Vec<u8> {
+		let mut payload = keccak_256(b"Claim(bytes substrateAddress)").to_vec();
+		payload.extend_from_slice(&keccak_256(&who.encode()));
+
+		let mut msg = b"\x19\x01".to_vec();
+		msg.extend_from_slice(&Self::claim_domain_separator());
+		msg.extend_from_slice(&keccak_256(&payload));
+		msg
+	}
+
+	/// Return the Substrate address that claimed the EVM address. If not claimed, returns `None`.
+	pub fn claimed_account_id(evm_address: EvmAddress) -> Option<T::AccountId> {
+		ClaimedAccount::<T>::get(evm_address)
+	}
+
+	/// Recover the EVM address that signed the claim of `who`.
+	pub fn recover_claim_signer(
+		who: &T::AccountId,
+		signature: &EvmSignature,
+	) -> Option<EvmAddress> {
+		let msg_hash = keccak_256(&Self::claim_signable_message(who));
+		sp_io::crypto::secp256k1_ecdsa_recover(signature, &msg_hash)
+			.map(|pubkey| EvmAddress::from(H256::from_slice(&keccak_256(&pubkey))))
+			.ok()
+	}
+
+	/// Sign the claim of `who` with a new key of the keystore.
+	/// Returns the EVM address of the key and the signature.
+	#[cfg(any(feature = "runtime-benchmarks", test))]
+	pub fn sign_claim(who: &T::AccountId) -> (EvmAddress, EvmSignature) {
+		let public = sp_io::crypto::ecdsa_generate(sp_core::testing::ECDSA, None);
+		let msg_hash = keccak_256(&Self::claim_signable_message(who));
+		let sig = sp_io::crypto::ecdsa_sign_prehashed(sp_core::testing::ECDSA, &public, &msg_hash)
+			.expect("key was just generated; qed");
+
+		let mut signature = [0u8; 65];
+		signature.copy_from_slice(sig.as_ref());
+		let address = Self::recover_claim_signer(who, &signature).expect("signature is valid; qed");
+		(address, signature)
 	}
 }
 
@@ -275,9 +506,10 @@ where
 	T::AccountId: AsRef<[u8; 32]> + frame_support::traits::IsType<AccountId32>,
 {
 	/// Get the EVM address from the substrate address.
+	/// Returns the claimed address if the account claimed one.
 	fn evm_address(account_id: &impl AsRef<[u8; 32]>) -> EvmAddress {
-		let acc = account_id.as_ref();
-		EvmAddress::from_slice(&acc[..20])
+		let who: T::AccountId = AccountId32::new(*account_id.as_ref()).into();
+		ClaimedAddress::<T>::get(&who).unwrap_or_else(|| Self::truncated_evm_address(account_id))
 	}
 
 	/// Get the AccountId from the EVM address.
@@ -289,6 +521,9 @@ where
 
 	/// Return the Substrate address bound to the EVM account. If not bound, returns `None`.
 	fn bound_account_id(evm_address: EvmAddress) -> Option<T::AccountId> {
+		if let Some(account_id) = ClaimedAccount::<T>::get(evm_address) {
+			return Some(account_id);
+		}
 		let Some(last_12_bytes) = AccountExtension::<T>::get(evm_address) else {
 			return None;
 		};
@@ -298,6 +533,15 @@ where
 		Some(AccountId32::from(data).into())
 	}
 
+	/// Return the EVM address bound to the Substrate account. If not bound, returns `None`.
+	fn bound_evm_address(account_id: &T::AccountId) -> Option<EvmAddress> {
+		if let Some(address) = ClaimedAddress::<T>::get(account_id) {
+			return Some(address);
+		}
+		let evm_address = Self::truncated_evm_address(account_id);
+		(Self::bound_account_id(evm_address).as_ref() == Some(account_id)).then_some(evm_address)
+	}
+
 	/// Get the Substrate address from the EVM address.
 	/// Returns the converted address if the address wasn't bind.
 	fn account_id(evm_address: EvmAddress) -> T::AccountId {
@@ -14,6 +14,7 @@ use frame_support::{
 use frame_system::EnsureRoot;
 use orml_traits::parameter_type_with_key;
 pub use sp_core::H160;
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use std::{cell::RefCell, collections::HashMap};
 
 pub type AssetId = u32;
@@ -25,6 +26,7 @@ pub const ONE: Balance = 1_000_000_000_000;
 pub const INITIAL_BALANCE: Balance = 1_000_000_000_000 * ONE;
 
 pub const ALICE: AccountId = AccountId::new([1; 32]);
+pub const BOB: AccountId = AccountId::new([2; 32]);
 
 pub const HDX: AssetId = 0;
 
@@ -60,6 +62,8 @@ impl Config for Test {
 	type FeeMultiplier = sp_core::ConstU32<10>;
 	type EvmNonceProvider = EvmNonceProviderMock;
 	type ControllerOrigin = EnsureRoot<AccountId>;
+	type ChainId = sp_core::ConstU64<2030>;
+	type UnbindCooldown = sp_core::ConstU64<10>;
 	type WeightInfo = ();
 }
 
@@ -113,6 +117,7 @@ impl ExtBuilder {
 			.unwrap();
 
 		let mut r: sp_io::TestExternalities = t.into();
+		r.register_extension(KeystoreExt::new(MemoryKeystore::new()));
 		r.execute_with(|| System::set_block_number(1));
 		r
 	}
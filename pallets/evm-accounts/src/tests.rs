@@ -129,3 +129,202 @@ fn renounce_contract_deployer_should_remove_address_from_the_storage() {
 		assert_ok!(EVMAccounts::renounce_contract_deployer(RuntimeOrigin::signed(ALICE)));
 	});
 }
+
+#[test]
+fn claim_evm_address_should_bind_the_signing_address() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let (evm_address, signature) = EVMAccounts::sign_claim(&ALICE);
+		assert_ne!(evm_address, EVMAccounts::evm_address(&ALICE));
+
+		// Act
+		assert_ok!(EVMAccounts::claim_evm_address(
+			RuntimeOrigin::signed(ALICE),
+			evm_address,
+			signature
+		));
+
+		// Assert
+		assert_eq!(EVMAccounts::bound_account_id(evm_address), Some(ALICE));
+		assert_eq!(EVMAccounts::account_id(evm_address), ALICE);
+		assert_eq!(EVMAccounts::bound_evm_address(&ALICE), Some(evm_address));
+		assert_eq!(EVMAccounts::evm_address(&ALICE), evm_address);
+		assert_ne!(EVMAccounts::truncated_evm_address(&ALICE), evm_address);
+		System::assert_last_event(
+			Event::<Test>::Claimed { account: ALICE, address: evm_address }.into(),
+		);
+	});
+}
+
+#[test]
+fn claim_evm_address_should_fail_when_signature_is_for_other_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (evm_address, signature) = EVMAccounts::sign_claim(&ALICE);
+
+		assert_noop!(
+			EVMAccounts::claim_evm_address(RuntimeOrigin::signed(BOB), evm_address, signature),
+			Error::<Test>::InvalidSignature
+		);
+		assert_noop!(
+			EVMAccounts::claim_evm_address(RuntimeOrigin::signed(ALICE), evm_address, [0u8; 65]),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn claim_evm_address_should_fail_when_nonce_is_not_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (evm_address, signature) = EVMAccounts::sign_claim(&ALICE);
+		NONCE.with(|v| v.borrow_mut().insert(evm_address, U256::one()));
+
+		assert_noop!(
+			EVMAccounts::claim_evm_address(RuntimeOrigin::signed(ALICE), evm_address, signature),
+			Error::<Test>::TruncatedAccountAlreadyUsed
+		);
+	});
+}
+
+#[test]
+fn claim_evm_address_should_fail_when_truncated_account_exists() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let (evm_address, signature) = EVMAccounts::sign_claim(&ALICE);
+		let truncated_account = EVMAccounts::convert_account_id(evm_address);
+		assert_ok!(<Tokens as orml_traits::MultiCurrency<AccountId>>::transfer(
+			HDX,
+			&ALICE,
+			&truncated_account,
+			INITIAL_BALANCE / 2
+		));
+
+		// Act & Assert
+		assert_noop!(
+			EVMAccounts::claim_evm_address(RuntimeOrigin::signed(ALICE), evm_address, signature),
+			Error::<Test>::TruncatedAccountNotEmpty
+		);
+	});
+}
+
+#[test]
+fn claim_evm_address_should_fail_when_already_bound() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(EVMAccounts::bind_evm_address(RuntimeOrigin::signed(ALICE)));
+		let (evm_address, signature) = EVMAccounts::sign_claim(&ALICE);
+
+		// Act & Assert
+		assert_noop!(
+			EVMAccounts::claim_evm_address(RuntimeOrigin::signed(ALICE), evm_address, signature),
+			Error::<Test>::AccountAlreadyBound
+		);
+		assert_noop!(
+			EVMAccounts::claim_evm_address(
+				RuntimeOrigin::signed(BOB),
+				EVMAccounts::evm_address(&ALICE),
+				[0u8; 65]
+			),
+			Error::<Test>::AddressAlreadyBound
+		);
+	});
+}
+
+#[test]
+fn bind_address_should_fail_when_address_is_claimed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (evm_address, signature) = EVMAccounts::sign_claim(&ALICE);
+		assert_ok!(EVMAccounts::claim_evm_address(
+			RuntimeOrigin::signed(ALICE),
+			evm_address,
+			signature
+		));
+
+		assert_noop!(
+			EVMAccounts::bind_evm_address(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::AccountAlreadyBound
+		);
+	});
+}
+
+#[test]
+fn unbind_claimed_address_should_work_after_cooldown() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let (evm_address, signature) = EVMAccounts::sign_claim(&ALICE);
+		assert_ok!(EVMAccounts::claim_evm_address(
+			RuntimeOrigin::signed(ALICE),
+			evm_address,
+			signature
+		));
+
+		// Act
+		assert_ok!(EVMAccounts::unbind_evm_address(RuntimeOrigin::signed(ALICE)));
+
+		// Assert
+		System::assert_last_event(
+			Event::<Test>::UnbindRequested { account: ALICE, address: evm_address, unbind_at: 11 }
+				.into(),
+		);
+		assert_noop!(
+			EVMAccounts::unbind_evm_address(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::UnbindAlreadyRequested
+		);
+
+		// the binding is kept during the cooldown
+		System::set_block_number(10);
+		assert_noop!(
+			EVMAccounts::confirm_unbind(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::UnbindCooldownNotPassed
+		);
+		assert_eq!(EVMAccounts::bound_account_id(evm_address), Some(ALICE));
+
+		System::set_block_number(11);
+		assert_ok!(EVMAccounts::confirm_unbind(RuntimeOrigin::signed(ALICE)));
+
+		System::assert_last_event(
+			Event::<Test>::Unbound { account: ALICE, address: evm_address }.into(),
+		);
+		assert_eq!(EVMAccounts::bound_account_id(evm_address), None);
+		assert_eq!(EVMAccounts::bound_evm_address(&ALICE), None);
+		assert_eq!(
+			EVMAccounts::account_id(evm_address),
+			EVMAccounts::convert_account_id(evm_address)
+		);
+	});
+}
+
+#[test]
+fn unbind_bound_address_should_work_after_cooldown() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(EVMAccounts::bind_evm_address(RuntimeOrigin::signed(ALICE)));
+		let evm_address = EVMAccounts::evm_address(&ALICE);
+		assert_eq!(EVMAccounts::bound_evm_address(&ALICE), Some(evm_address));
+
+		// Act
+		assert_ok!(EVMAccounts::unbind_evm_address(RuntimeOrigin::signed(ALICE)));
+		System::set_block_number(11);
+		assert_ok!(EVMAccounts::confirm_unbind(RuntimeOrigin::signed(ALICE)));
+
+		// Assert
+		assert_eq!(EVMAccounts::bound_account_id(evm_address), None);
+		assert_eq!(EVMAccounts::bound_evm_address(&ALICE), None);
+
+		// the address can be bound again
+		assert_ok!(EVMAccounts::bind_evm_address(RuntimeOrigin::signed(ALICE)));
+	});
+}
+
+#[test]
+fn unbind_should_fail_when_not_bound_or_not_requested() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EVMAccounts::unbind_evm_address(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::AddressNotBound
+		);
+		assert_noop!(
+			EVMAccounts::confirm_unbind(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::UnbindNotRequested
+		);
+	});
+}
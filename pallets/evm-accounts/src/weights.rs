@@ -52,6 +52,9 @@ pub trait WeightInfo {
 	fn add_contract_deployer() -> Weight;
 	fn remove_contract_deployer() -> Weight;
 	fn renounce_contract_deployer() -> Weight;
+	fn claim_evm_address() -> Weight;
+	fn unbind_evm_address() -> Weight;
+	fn confirm_unbind() -> Weight;
 }
 
 pub struct HydraWeight<T>(PhantomData<T>);
@@ -107,6 +110,56 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 		// Minimum execution time: 13_153_000 picoseconds.
 		Weight::from_parts(13_380_000, 0).saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `EVMAccounts::ClaimedAddress` (r:1 w:1)
+	/// Proof: `EVMAccounts::ClaimedAddress` (`max_values`: None, `max_size`: Some(68), added:
+	/// 2543, mode: `MaxEncodedLen`) Storage: `EVMAccounts::ClaimedAccount` (r:2 w:1)
+	/// Proof: `EVMAccounts::ClaimedAccount` (`max_values`: None, `max_size`: Some(68), added:
+	/// 2543, mode: `MaxEncodedLen`) Storage: `EVMAccounts::AccountExtension` (r:2 w:0)
+	/// Proof: `EVMAccounts::AccountExtension` (`max_values`: None, `max_size`: Some(48), added:
+	/// 2523, mode: `MaxEncodedLen`) Storage: `System::Account` (r:2 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`) Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode:
+	/// `MaxEncodedLen`)
+	fn claim_evm_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `479`
+		//  Estimated: `6196`
+		// Minimum execution time: 71_987_000 picoseconds.
+		Weight::from_parts(72_904_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EVMAccounts::ClaimedAddress` (r:1 w:0)
+	/// Proof: `EVMAccounts::ClaimedAddress` (`max_values`: None, `max_size`: Some(68), added:
+	/// 2543, mode: `MaxEncodedLen`) Storage: `EVMAccounts::PendingUnbind` (r:1 w:1)
+	/// Proof: `EVMAccounts::PendingUnbind` (`max_values`: None, `max_size`: Some(52), added:
+	/// 2527, mode: `MaxEncodedLen`)
+	fn unbind_evm_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `235`
+		//  Estimated: `3533`
+		// Minimum execution time: 17_834_000 picoseconds.
+		Weight::from_parts(18_290_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EVMAccounts::PendingUnbind` (r:1 w:1)
+	/// Proof: `EVMAccounts::PendingUnbind` (`max_values`: None, `max_size`: Some(52), added:
+	/// 2527, mode: `MaxEncodedLen`) Storage: `EVMAccounts::ClaimedAddress` (r:1 w:1)
+	/// Proof: `EVMAccounts::ClaimedAddress` (`max_values`: None, `max_size`: Some(68), added:
+	/// 2543, mode: `MaxEncodedLen`) Storage: `EVMAccounts::ClaimedAccount` (r:0 w:1)
+	/// Proof: `EVMAccounts::ClaimedAccount` (`max_values`: None, `max_size`: Some(68), added:
+	/// 2543, mode: `MaxEncodedLen`)
+	fn confirm_unbind() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `291`
+		//  Estimated: `3533`
+		// Minimum execution time: 19_605_000 picoseconds.
+		Weight::from_parts(20_113_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
 
 // For backwards compatibility and tests
@@ -161,4 +214,54 @@ impl WeightInfo for () {
 		// Minimum execution time: 13_153_000 picoseconds.
 		Weight::from_parts(13_380_000, 0).saturating_add(RocksDbWeight::get().writes(1))
 	}
+	/// Storage: `EVMAccounts::ClaimedAddress` (r:1 w:1)
+	/// Proof: `EVMAccounts::ClaimedAddress` (`max_values`: None, `max_size`: Some(68), added:
+	/// 2543, mode: `MaxEncodedLen`) Storage: `EVMAccounts::ClaimedAccount` (r:2 w:1)
+	/// Proof: `EVMAccounts::ClaimedAccount` (`max_values`: None, `max_size`: Some(68), added:
+	/// 2543, mode: `MaxEncodedLen`) Storage: `EVMAccounts::AccountExtension` (r:2 w:0)
+	/// Proof: `EVMAccounts::AccountExtension` (`max_values`: None, `max_size`: Some(48), added:
+	/// 2523, mode: `MaxEncodedLen`) Storage: `System::Account` (r:2 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+	/// `MaxEncodedLen`) Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode:
+	/// `MaxEncodedLen`)
+	fn claim_evm_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `479`
+		//  Estimated: `6196`
+		// Minimum execution time: 71_987_000 picoseconds.
+		Weight::from_parts(72_904_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `EVMAccounts::ClaimedAddress` (r:1 w:0)
+	/// Proof: `EVMAccounts::ClaimedAddress` (`max_values`: None, `max_size`: Some(68), added:
+	/// 2543, mode: `MaxEncodedLen`) Storage: `EVMAccounts::PendingUnbind` (r:1 w:1)
+	/// Proof: `EVMAccounts::PendingUnbind` (`max_values`: None, `max_size`: Some(52), added:
+	/// 2527, mode: `MaxEncodedLen`)
+	fn unbind_evm_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `235`
+		//  Estimated: `3533`
+		// Minimum execution time: 17_834_000 picoseconds.
+		Weight::from_parts(18_290_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `EVMAccounts::PendingUnbind` (r:1 w:1)
+	/// Proof: `EVMAccounts::PendingUnbind` (`max_values`: None, `max_size`: Some(52), added:
+	/// 2527, mode: `MaxEncodedLen`) Storage: `EVMAccounts::ClaimedAddress` (r:1 w:1)
+	/// Proof: `EVMAccounts::ClaimedAddress` (`max_values`: None, `max_size`: Some(68), added:
+	/// 2543, mode: `MaxEncodedLen`) Storage: `EVMAccounts::ClaimedAccount` (r:0 w:1)
+	/// Proof: `EVMAccounts::ClaimedAccount` (`max_values`: None, `max_size`: Some(68), added:
+	/// 2543, mode: `MaxEncodedLen`)
+	fn confirm_unbind() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `291`
+		//  Estimated: `3533`
+		// Minimum execution time: 19_605_000 picoseconds.
+		Weight::from_parts(20_113_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
 }
@@ -19,7 +19,7 @@
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg(feature = "runtime-benchmarks")]
 
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_support::{assert_ok, sp_runtime::traits::UniqueSaturatedFrom};
 use frame_system::{Pallet as System, RawOrigin};
 use sp_std::vec;
@@ -369,4 +369,51 @@ benchmarks! {
 		assert_ok!(Farming::<T>::add_boost_pool_whitelist(RawOrigin::Root.into(), vec![0]));
 		assert_ok!(Farming::<T>::start_boost_round(RawOrigin::Root.into(), BlockNumberFor::<T>::from(100000u32)));
 	}: _(RawOrigin::Signed(caller.clone()), charge_list)
+
+	set_share_token {
+		let token_amount = BalanceOf::<T>::unique_saturated_from(1000u128);
+		let default_currency_id = CurrencyIdOf::<T>::default();
+		let tokens_proportion = vec![(default_currency_id, Perbill::from_percent(100))];
+		let basic_rewards = vec![(default_currency_id, token_amount)];
+		assert_ok!(Farming::<T>::create_farming_pool(
+			RawOrigin::Root.into(),
+			tokens_proportion.clone(),
+			basic_rewards.clone(),
+			None,
+			BalanceOf::<T>::unique_saturated_from(0u128),
+			BlockNumberFor::<T>::from(0u32),
+			BlockNumberFor::<T>::from(7u32),
+			BlockNumberFor::<T>::from(6u32),
+			5,
+		));
+	}: _(RawOrigin::Root, 0, None)
+
+	transfer_share_token {
+		let caller: T::AccountId = whitelisted_caller();
+		let receiver: T::AccountId = account("receiver", 0, 0);
+		let token_amount = BalanceOf::<T>::unique_saturated_from(1000u128);
+		let default_currency_id = CurrencyIdOf::<T>::default();
+		let tokens_proportion = vec![(default_currency_id, Perbill::from_percent(100))];
+		let basic_rewards = vec![(default_currency_id, token_amount)];
+		let gauge_basic_rewards = vec![(default_currency_id, token_amount)];
+		assert_ok!(Farming::<T>::create_farming_pool(
+			RawOrigin::Root.into(),
+			tokens_proportion.clone(),
+			basic_rewards.clone(),
+			Some((BlockNumberFor::<T>::from(1000u32), gauge_basic_rewards)),
+			BalanceOf::<T>::unique_saturated_from(0u128),
+			BlockNumberFor::<T>::from(0u32),
+			BlockNumberFor::<T>::from(7u32),
+			BlockNumberFor::<T>::from(6u32),
+			5,
+		));
+		let charge_rewards = vec![(default_currency_id,BalanceOf::<T>::unique_saturated_from(300000u128))];
+		assert_ok!(Farming::<T>::charge(RawOrigin::Signed(caller.clone()).into(), 0, charge_rewards, false));
+		assert_ok!(Farming::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), 0, token_amount));
+		// The caller holds the staking token, so it can stand in for the share token.
+		ShareTokens::<T>::insert(0, default_currency_id);
+		ShareTokenPools::<T>::insert(default_currency_id, 0);
+		System::<T>::set_block_number(System::<T>::block_number() + BlockNumberFor::<T>::from(10u32));
+		Farming::<T>::on_initialize(BlockNumberFor::<T>::from(0u32));
+	}: _(RawOrigin::Signed(caller.clone()), receiver, 0, token_amount)
 }
@@ -34,7 +34,8 @@ pub mod weights;
 pub use weights::WeightInfo;
 
 use crate::boost::*;
-use bifrost_primitives::{FarmingInfo, PoolId};
+use bifrost_asset_registry::AssetMetadata;
+use bifrost_primitives::{CurrencyIdMapping, FarmingInfo, FarmingOperator, PoolId};
 use frame_support::{
 	pallet_prelude::*,
 	sp_runtime::{
@@ -48,11 +49,12 @@ use frame_support::{
 };
 use frame_system::pallet_prelude::*;
 pub use gauge::*;
-use orml_traits::MultiCurrency;
+use orml_traits::{currency::OnTransfer, MultiCurrency};
 pub use pallet::*;
 pub use rewards::*;
 use sp_runtime::SaturatedConversion;
 use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+use xcm::v3::MultiLocation;
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
@@ -123,6 +125,13 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type GaugeRewardIssuer: Get<PalletId>;
+
+		/// Asset registry to check the metadata of share tokens.
+		type AssetIdMaps: CurrencyIdMapping<
+			CurrencyIdOf<Self>,
+			MultiLocation,
+			AssetMetadata<BalanceOf<Self>>,
+		>;
 	}
 
 	#[pallet::event]
@@ -261,6 +270,24 @@ pub mod pallet {
 			/// Charged boost pool id.
 			rewards: Vec<(CurrencyIdOf<T>, BalanceOf<T>)>,
 		},
+		/// The share token of the pool is set.
+		ShareTokenSet {
+			/// The pool id.
+			pid: PoolId,
+			/// The share token, `None` if the share token is removed.
+			share_token: Option<CurrencyIdOf<T>>,
+		},
+		/// The shares are transferred along with the share token.
+		ShareTransferred {
+			/// The sender of the shares.
+			from: AccountIdOf<T>,
+			/// The receiver of the shares.
+			to: AccountIdOf<T>,
+			/// The pool id.
+			pid: PoolId,
+			/// Transferred shares.
+			amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -305,6 +332,18 @@ pub mod pallet {
 		PoolNotCleared,
 		/// Invalid remove amount
 		InvalidRemoveAmount,
+		/// The share token is not registered in the asset registry.
+		ShareTokenNotRegistered,
+		/// The share token is already bound to a pool or still has issuance.
+		ShareTokenInUse,
+		/// The share token can only be set while the pool has no shares.
+		PoolNotEmpty,
+		/// Invalid transfer amount
+		InvalidTransferAmount,
+		/// The share token can only be transferred with `transfer_share_token`.
+		ShareTokenNotTransferable,
+		/// The pool has no share token.
+		NoShareToken,
 	}
 
 	/// Record the id of the new pool.
@@ -366,6 +405,18 @@ pub mod pallet {
 		ShareInfo<BalanceOf<T>, CurrencyIdOf<T>, BlockNumberFor<T>, AccountIdOf<T>>,
 	>;
 
+	/// Record the share token of the pool, minted 1:1 with the shares.
+	///
+	/// map PoolId => CurrencyId
+	#[pallet::storage]
+	pub type ShareTokens<T: Config> = StorageMap<_, Twox64Concat, PoolId, CurrencyIdOf<T>>;
+
+	/// Record the pool of the share token.
+	///
+	/// map CurrencyId => PoolId
+	#[pallet::storage]
+	pub type ShareTokenPools<T: Config> = StorageMap<_, Twox64Concat, CurrencyIdOf<T>, PoolId>;
+
 	/// Record all voting pool information.
 	#[pallet::storage]
 	pub type BoostPoolInfos<T: Config> =
@@ -599,37 +650,7 @@ pub mod pallet {
 			// Check origin
 			let exchanger = ensure_signed(origin)?;
 
-			let mut pool_info = PoolInfos::<T>::get(&pid).ok_or(Error::<T>::PoolDoesNotExist)?;
-			ensure!(
-				pool_info.state == PoolState::Ongoing || pool_info.state == PoolState::Charged,
-				Error::<T>::InvalidPoolState
-			);
-
-			if let PoolState::Charged = pool_info.state {
-				let current_block_number: BlockNumberFor<T> =
-					frame_system::Pallet::<T>::block_number();
-				ensure!(
-					current_block_number >= pool_info.after_block_to_start,
-					Error::<T>::CanNotDeposit
-				);
-			}
-
-			let native_amount = pool_info.basic_token.1.saturating_reciprocal_mul(add_value);
-			pool_info.tokens_proportion.iter().try_for_each(
-				|(token, proportion)| -> DispatchResult {
-					T::MultiCurrency::transfer(
-						*token,
-						&exchanger,
-						&pool_info.keeper,
-						*proportion * native_amount,
-					)
-				},
-			)?;
-			Self::add_share(&exchanger, pid, &mut pool_info, add_value);
-			Self::update_reward(&exchanger, pid)?;
-
-			Self::deposit_event(Event::Deposited { who: exchanger, pid, add_value });
-			Ok(())
+			Self::deposit_inner(&exchanger, pid, add_value)
 		}
 
 		/// Withdraw from the pool.
@@ -651,25 +672,7 @@ pub mod pallet {
 			// Check origin
 			let exchanger = ensure_signed(origin)?;
 
-			let pool_info = PoolInfos::<T>::get(&pid).ok_or(Error::<T>::PoolDoesNotExist)?;
-			ensure!(
-				pool_info.state == PoolState::Ongoing ||
-					pool_info.state == PoolState::Charged ||
-					pool_info.state == PoolState::Dead,
-				Error::<T>::InvalidPoolState
-			);
-			let share_info = SharesAndWithdrawnRewards::<T>::get(&pid, &exchanger)
-				.ok_or(Error::<T>::ShareInfoNotExists)?;
-			ensure!(
-				share_info.withdraw_list.len() < pool_info.withdraw_limit_count.into(),
-				Error::<T>::WithdrawLimitCountExceeded
-			);
-
-			Self::remove_share(&exchanger, pid, remove_value, pool_info.withdraw_limit_time)?;
-			Self::update_reward(&exchanger, pid)?;
-
-			Self::deposit_event(Event::Withdrawn { who: exchanger, pid, remove_value });
-			Ok(())
+			Self::withdraw_inner(&exchanger, pid, remove_value)
 		}
 
 		/// Claim the rewards from the pool.
@@ -748,6 +751,12 @@ pub mod pallet {
 					break;
 				}
 				let who = share_info.who;
+				if let Some(share_token) = ShareTokens::<T>::get(pid) {
+					// The share token may have been dusted, burn what is left.
+					let burn_amount =
+						T::MultiCurrency::free_balance(share_token, &who).min(share_info.share);
+					T::MultiCurrency::withdraw(share_token, &who, burn_amount)?;
+				}
 				Self::remove_share(&who, pid, None, withdraw_limit_time)?;
 				Self::process_withdraw_list(&who, pid, &pool_info, true)?;
 			}
@@ -883,6 +892,9 @@ pub mod pallet {
 			let res = SharesAndWithdrawnRewards::<T>::clear_prefix(pid, u32::max_value(), None);
 			ensure!(res.maybe_cursor.is_none(), Error::<T>::PoolNotCleared);
 			PoolInfos::<T>::remove(pid);
+			if let Some(share_token) = ShareTokens::<T>::take(pid) {
+				ShareTokenPools::<T>::remove(share_token);
+			}
 
 			Self::deposit_event(Event::FarmingPoolKilled { pid });
 			Ok(())
@@ -1093,6 +1105,149 @@ pub mod pallet {
 			Self::deposit_event(Event::BoostCharged { who: exchanger, rewards });
 			Ok(())
 		}
+
+		/// Set the share token of the pool.
+		///
+		/// The share token is minted 1:1 with the shares on deposit and burned on withdraw, and
+		/// can only be transferred with `transfer_share_token`. The share token must be registered
+		/// in the asset registry, and can only be set or removed while the pool has no shares.
+		///
+		/// - `pid`: The pool id.
+		/// - `share_token`: The share token, `None` to remove the share token.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::set_share_token())]
+		pub fn set_share_token(
+			origin: OriginFor<T>,
+			pid: PoolId,
+			share_token: Option<CurrencyIdOf<T>>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			let pool_info = PoolInfos::<T>::get(&pid).ok_or(Error::<T>::PoolDoesNotExist)?;
+			ensure!(pool_info.total_shares.is_zero(), Error::<T>::PoolNotEmpty);
+
+			if let Some(old_share_token) = ShareTokens::<T>::take(pid) {
+				ensure!(
+					T::MultiCurrency::total_issuance(old_share_token).is_zero(),
+					Error::<T>::ShareTokenInUse
+				);
+				ShareTokenPools::<T>::remove(old_share_token);
+			}
+
+			if let Some(share_token) = share_token {
+				ensure!(
+					T::AssetIdMaps::get_currency_metadata(share_token).is_some(),
+					Error::<T>::ShareTokenNotRegistered
+				);
+				ensure!(
+					!ShareTokenPools::<T>::contains_key(share_token) &&
+						T::MultiCurrency::total_issuance(share_token).is_zero(),
+					Error::<T>::ShareTokenInUse
+				);
+				ShareTokens::<T>::insert(pid, share_token);
+				ShareTokenPools::<T>::insert(share_token, pid);
+			}
+
+			Self::deposit_event(Event::ShareTokenSet { pid, share_token });
+			Ok(())
+		}
+
+		/// Transfer the share token of the pool, together with the shares and their BbBNC gauge
+		/// rewards. The rewards of the sender are settled before.
+		///
+		/// - `dest`: The receiver of the share token.
+		/// - `pid`: The pool id.
+		/// - `amount`: The amount of the share token to transfer.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::transfer_share_token())]
+		pub fn transfer_share_token(
+			origin: OriginFor<T>,
+			dest: AccountIdOf<T>,
+			pid: PoolId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let exchanger = ensure_signed(origin)?;
+			let share_token = ShareTokens::<T>::get(pid).ok_or(Error::<T>::NoShareToken)?;
+
+			Self::transfer_share(&exchanger, &dest, pid, amount)?;
+			// Move the balance without the transfer hooks, which reject share tokens.
+			T::MultiCurrency::withdraw(share_token, &exchanger, amount)?;
+			T::MultiCurrency::deposit(share_token, &dest, amount)?;
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn deposit_inner(
+		exchanger: &AccountIdOf<T>,
+		pid: PoolId,
+		add_value: BalanceOf<T>,
+	) -> DispatchResult {
+		let mut pool_info = PoolInfos::<T>::get(&pid).ok_or(Error::<T>::PoolDoesNotExist)?;
+		ensure!(
+			pool_info.state == PoolState::Ongoing || pool_info.state == PoolState::Charged,
+			Error::<T>::InvalidPoolState
+		);
+
+		if let PoolState::Charged = pool_info.state {
+			let current_block_number: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				current_block_number >= pool_info.after_block_to_start,
+				Error::<T>::CanNotDeposit
+			);
+		}
+
+		let native_amount = pool_info.basic_token.1.saturating_reciprocal_mul(add_value);
+		pool_info.tokens_proportion.iter().try_for_each(
+			|(token, proportion)| -> DispatchResult {
+				T::MultiCurrency::transfer(
+					*token,
+					exchanger,
+					&pool_info.keeper,
+					*proportion * native_amount,
+				)
+			},
+		)?;
+		Self::add_share(exchanger, pid, &mut pool_info, add_value);
+		Self::update_reward(exchanger, pid)?;
+		if let Some(share_token) = ShareTokens::<T>::get(pid) {
+			T::MultiCurrency::deposit(share_token, exchanger, add_value)?;
+		}
+
+		Self::deposit_event(Event::Deposited { who: exchanger.clone(), pid, add_value });
+		Ok(())
+	}
+
+	pub fn withdraw_inner(
+		exchanger: &AccountIdOf<T>,
+		pid: PoolId,
+		remove_value: Option<BalanceOf<T>>,
+	) -> DispatchResult {
+		let pool_info = PoolInfos::<T>::get(&pid).ok_or(Error::<T>::PoolDoesNotExist)?;
+		ensure!(
+			pool_info.state == PoolState::Ongoing ||
+				pool_info.state == PoolState::Charged ||
+				pool_info.state == PoolState::Dead,
+			Error::<T>::InvalidPoolState
+		);
+		let share_info = SharesAndWithdrawnRewards::<T>::get(&pid, exchanger)
+			.ok_or(Error::<T>::ShareInfoNotExists)?;
+		ensure!(
+			share_info.withdraw_list.len() < pool_info.withdraw_limit_count.into(),
+			Error::<T>::WithdrawLimitCountExceeded
+		);
+
+		if let Some(share_token) = ShareTokens::<T>::get(pid) {
+			let remove_amount = remove_value.unwrap_or(share_info.share);
+			ensure!(remove_amount <= share_info.share, Error::<T>::InvalidRemoveAmount);
+			T::MultiCurrency::withdraw(share_token, exchanger, remove_amount)?;
+		}
+		Self::remove_share(exchanger, pid, remove_value, pool_info.withdraw_limit_time)?;
+		Self::update_reward(exchanger, pid)?;
+
+		Self::deposit_event(Event::Withdrawn { who: exchanger.clone(), pid, remove_value });
+		Ok(())
 	}
 }
 
@@ -1108,3 +1263,44 @@ impl<T: Config> FarmingInfo<BalanceOf<T>, CurrencyIdOf<T>> for Pallet<T> {
 		Zero::zero()
 	}
 }
+
+impl<T: Config> OnTransfer<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>> for Pallet<T> {
+	/// Settling the shares on every token transfer would not be covered by the transfer weight,
+	/// so share tokens can only be moved with `transfer_share_token`.
+	fn on_transfer(
+		currency_id: CurrencyIdOf<T>,
+		_from: &AccountIdOf<T>,
+		_to: &AccountIdOf<T>,
+		_amount: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(
+			!ShareTokenPools::<T>::contains_key(currency_id),
+			Error::<T>::ShareTokenNotTransferable
+		);
+		Ok(())
+	}
+}
+
+impl<T: Config> FarmingOperator<AccountIdOf<T>, BalanceOf<T>, CurrencyIdOf<T>> for Pallet<T> {
+	fn get_staking_token(pool_id: PoolId) -> Option<CurrencyIdOf<T>> {
+		let pool_info = PoolInfos::<T>::get(&pool_id)?;
+		let (token, proportion) = pool_info.tokens_proportion.first_key_value()?;
+		if pool_info.tokens_proportion.len() == 1 &&
+			*proportion == Perbill::from_percent(100) &&
+			pool_info.basic_token == (*token, Perbill::from_percent(100))
+		{
+			return Some(*token);
+		}
+		None
+	}
+
+	fn deposit(who: &AccountIdOf<T>, pool_id: PoolId, amount: BalanceOf<T>) -> DispatchResult {
+		Self::deposit_inner(who, pool_id, amount)
+	}
+
+	fn withdraw(who: &AccountIdOf<T>, pool_id: PoolId, amount: BalanceOf<T>) -> DispatchResult {
+		Self::withdraw_inner(who, pool_id, Some(amount))?;
+		let pool_info = PoolInfos::<T>::get(&pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
+		Self::process_withdraw_list(who, pool_id, &pool_info, false)
+	}
+}
@@ -21,13 +21,16 @@
 #![cfg(test)]
 #![allow(non_upper_case_globals)]
 
-pub use bifrost_primitives::{currency::*, CurrencyId};
+pub use bifrost_asset_registry::AssetIdMaps;
 use bifrost_primitives::{
-	currency::{ASG, VBNC},
-	BuyBackAccount, FarmingBoostPalletId, FarmingGaugeRewardIssuerPalletId, FarmingKeeperPalletId,
-	FarmingRewardIssuerPalletId, IncentivePalletId,
+	currency::{ASG, VBNC, *},
+	BuyBackAccount, CurrencyId, FarmingBoostPalletId, FarmingGaugeRewardIssuerPalletId,
+	FarmingKeeperPalletId, FarmingRewardIssuerPalletId, IncentivePalletId,
+};
+use frame_support::{
+	derive_impl, ord_parameter_types, parameter_types,
+	traits::{Everything, Nothing},
 };
-use frame_support::{derive_impl, ord_parameter_types, parameter_types, traits::Nothing};
 use frame_system::EnsureSignedBy;
 use sp_core::ConstU32;
 use sp_runtime::{
@@ -82,6 +85,7 @@ pub type AdaptedBasicCurrency =
 
 impl bifrost_currencies::Config for Runtime {
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type WeightInfo = ();
@@ -113,6 +117,18 @@ orml_traits::parameter_type_with_key! {
 		0
 	};
 }
+
+pub struct CurrencyHooks;
+impl orml_traits::currency::MutationHooks<AccountId, CurrencyId, Balance> for CurrencyHooks {
+	type OnDust = ();
+	type OnSlash = ();
+	type PreDeposit = ();
+	type PostDeposit = ();
+	type PreTransfer = ();
+	type PostTransfer = Farming;
+	type OnNewTokenAccount = ();
+	type OnKilledTokenAccount = ();
+}
 impl orml_tokens::Config for Runtime {
 	type Amount = i128;
 	type Balance = Balance;
@@ -124,7 +140,7 @@ impl orml_tokens::Config for Runtime {
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
 	type WeightInfo = ();
-	type CurrencyHooks = ();
+	type CurrencyHooks = CurrencyHooks;
 }
 
 parameter_types! {
@@ -150,6 +166,7 @@ impl bifrost_farming::Config for Runtime {
 	type BlockNumberToBalance = ConvertInto;
 	type WhitelistMaximumLimit = WhitelistMaximumLimit;
 	type GaugeRewardIssuer = FarmingGaugeRewardIssuerPalletId;
+	type AssetIdMaps = AssetIdMaps<Runtime>;
 }
 
 parameter_types! {
@@ -294,6 +294,78 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Transfer shares from `from` to `to`, the rewards of `from` are settled before and the
+	/// withdrawn rewards are moved in proportion to the shares, so that `to` takes over the
+	/// reward entitlement of the transferred shares. The BbBNC gauge rewards of both accounts
+	/// are updated with the shares as well.
+	pub fn transfer_share(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		pool: PoolId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if amount.is_zero() || from == to {
+			return Ok(());
+		}
+
+		// claim rewards firstly
+		Self::claim_rewards(from, pool)?;
+
+		let mut from_share_info = SharesAndWithdrawnRewards::<T>::get(pool, from)
+			.ok_or(Error::<T>::ShareInfoNotExists)?;
+		ensure!(amount <= from_share_info.share, Error::<T>::InvalidTransferAmount);
+
+		// the gauge rewards of the receiver are accrued with the shares it held before
+		if SharesAndWithdrawnRewards::<T>::contains_key(pool, to) {
+			Self::update_reward(to, pool)?;
+		}
+
+		let current_block_number: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
+		let mut to_share_info = SharesAndWithdrawnRewards::<T>::get(pool, to)
+			.unwrap_or_else(|| ShareInfo::new(to.clone(), current_block_number));
+
+		let moving_share = U256::from(amount.saturated_into::<u128>());
+		let from_share = U256::from(from_share_info.share.saturated_into::<u128>());
+		from_share_info.withdrawn_rewards.iter_mut().try_for_each(
+			|(reward_currency, withdrawn_reward)| -> DispatchResult {
+				let withdrawn_reward_to_move: BalanceOf<T> = u128::try_from(
+					moving_share
+						.saturating_mul(withdrawn_reward.to_owned().saturated_into::<u128>().into())
+						.checked_div(from_share)
+						.unwrap_or_default(),
+				)
+				.map_err(|_| ArithmeticError::Overflow)?
+				.unique_saturated_into();
+
+				*withdrawn_reward = withdrawn_reward.saturating_sub(withdrawn_reward_to_move);
+				to_share_info
+					.withdrawn_rewards
+					.entry(*reward_currency)
+					.and_modify(|to_withdrawn_reward| {
+						*to_withdrawn_reward =
+							to_withdrawn_reward.saturating_add(withdrawn_reward_to_move);
+					})
+					.or_insert(withdrawn_reward_to_move);
+				Ok(())
+			},
+		)?;
+		from_share_info.share = from_share_info.share.saturating_sub(amount);
+		to_share_info.share = to_share_info.share.saturating_add(amount);
+
+		SharesAndWithdrawnRewards::<T>::insert(pool, from, from_share_info);
+		SharesAndWithdrawnRewards::<T>::insert(pool, to, to_share_info);
+		Self::update_reward(from, pool)?;
+		Self::update_reward(to, pool)?;
+
+		Self::deposit_event(Event::ShareTransferred {
+			from: from.clone(),
+			to: to.clone(),
+			pid: pool,
+			amount,
+		});
+		Ok(())
+	}
+
 	pub fn claim_rewards(who: &T::AccountId, pool: PoolId) -> DispatchResult {
 		if let Some(_) = GaugePoolInfos::<T>::get(pool) {
 			let pool_info = PoolInfos::<T>::get(pool).ok_or(Error::<T>::PoolDoesNotExist)?;
@@ -593,3 +593,143 @@ fn charge_boost() {
 		assert_eq!(BoostNextRoundWhitelist::<Runtime>::iter().count(), 0);
 	})
 }
+
+fn init_share_token() -> (PoolId, CurrencyId) {
+	let tokens_proportion = vec![(KSM, Perbill::from_percent(100))];
+	let basic_rewards = vec![(KSM, 1000)];
+	assert_ok!(Farming::create_farming_pool(
+		RuntimeOrigin::signed(ALICE),
+		tokens_proportion,
+		basic_rewards,
+		None,
+		0,
+		0,
+		10,
+		0,
+		5
+	));
+	assert_ok!(AssetRegistry::register_token_metadata(
+		RuntimeOrigin::signed(CouncilAccount::get()),
+		Box::new(bifrost_asset_registry::AssetMetadata {
+			name: b"Farming Share 0".to_vec(),
+			symbol: b"FS0".to_vec(),
+			decimals: 12,
+			minimal_balance: 0,
+		})
+	));
+
+	let pid = 0;
+	let share_token = CurrencyId::Token2(0);
+	assert_ok!(Farming::set_share_token(RuntimeOrigin::signed(ALICE), pid, Some(share_token)));
+	assert_eq!(ShareTokens::<Runtime>::get(pid), Some(share_token));
+	assert_eq!(ShareTokenPools::<Runtime>::get(share_token), Some(pid));
+	let charge_rewards = vec![(KSM, 100000)];
+	assert_ok!(Farming::charge(RuntimeOrigin::signed(BOB), pid, charge_rewards, false));
+	(pid, share_token)
+}
+
+#[test]
+fn share_token() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		let (pid, share_token) = init_share_token();
+		assert_ok!(Farming::deposit(RuntimeOrigin::signed(ALICE), pid, 1000));
+		assert_eq!(Tokens::free_balance(share_token, &ALICE), 1000);
+		assert_eq!(Tokens::free_balance(KSM, &ALICE), 2000);
+		Farming::on_initialize(0);
+		Farming::on_initialize(0);
+
+		// The share token can not be moved by a plain token transfer.
+		assert_err!(
+			Tokens::transfer(RuntimeOrigin::signed(ALICE), CHARLIE, share_token, 500),
+			Error::<Runtime>::ShareTokenNotTransferable
+		);
+		assert_err!(
+			Farming::transfer_share_token(RuntimeOrigin::signed(ALICE), CHARLIE, pid, 1001),
+			Error::<Runtime>::InvalidTransferAmount
+		);
+
+		// The rewards of the sender are settled, and the shares move with the share token.
+		assert_ok!(Farming::transfer_share_token(RuntimeOrigin::signed(ALICE), CHARLIE, pid, 500));
+		assert_eq!(Tokens::free_balance(share_token, &ALICE), 500);
+		assert_eq!(Tokens::free_balance(share_token, &CHARLIE), 500);
+		assert_eq!(Tokens::total_issuance(share_token), 1000);
+		assert_eq!(Tokens::free_balance(KSM, &ALICE), 3000);
+		assert_eq!(SharesAndWithdrawnRewards::<Runtime>::get(pid, &ALICE).unwrap().share, 500);
+		assert_eq!(SharesAndWithdrawnRewards::<Runtime>::get(pid, &CHARLIE).unwrap().share, 500);
+		assert_eq!(PoolInfos::<Runtime>::get(pid).unwrap().total_shares, 1000);
+
+		// The receiver only earns the rewards accrued after the transfer.
+		System::set_block_number(System::block_number() + 1);
+		Farming::on_initialize(0);
+		assert_ok!(Farming::claim(RuntimeOrigin::signed(ALICE), pid));
+		assert_ok!(Farming::claim(RuntimeOrigin::signed(CHARLIE), pid));
+		assert_eq!(Tokens::free_balance(KSM, &ALICE), 3500);
+		assert_eq!(Tokens::free_balance(KSM, &CHARLIE), 1_000_000_000_500);
+
+		assert_err!(
+			Farming::withdraw(RuntimeOrigin::signed(ALICE), pid, Some(600)),
+			Error::<Runtime>::InvalidRemoveAmount
+		);
+		assert_ok!(Farming::withdraw(RuntimeOrigin::signed(CHARLIE), pid, None));
+		assert_eq!(Tokens::free_balance(share_token, &CHARLIE), 0);
+		assert_eq!(Tokens::total_issuance(share_token), 500);
+	})
+}
+
+#[test]
+fn share_token_should_move_gauge_rewards() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		let (pid, _) = init_share_token();
+		assert_ok!(BbBNC::set_config(RuntimeOrigin::signed(ALICE), Some(0), Some(7 * 86400 / 12)));
+		assert_ok!(BbBNC::notify_reward_amount(pid, &Some(CHARLIE), vec![(KSM, 990_000)]));
+		let unlock_time = System::block_number() + (4 * 365 * 86400 - 7 * 86400) / 12;
+		assert_ok!(BbBNC::create_lock_inner(&ALICE, 100_000_000_000, unlock_time));
+		assert_ok!(BbBNC::create_lock_inner(&CHARLIE, 100_000_000_000, unlock_time));
+		assert_ok!(Farming::deposit(RuntimeOrigin::signed(ALICE), pid, 1000));
+		assert_eq!(bb_bnc::UserFarmingPool::<Runtime>::get(ALICE).into_inner(), vec![pid]);
+		assert!(bb_bnc::UserFarmingPool::<Runtime>::get(CHARLIE).is_empty());
+
+		// The gauge rewards move with the shares.
+		assert_ok!(Farming::transfer_share_token(RuntimeOrigin::signed(ALICE), CHARLIE, pid, 1000));
+		assert!(bb_bnc::UserFarmingPool::<Runtime>::get(ALICE).is_empty());
+		assert_eq!(bb_bnc::UserFarmingPool::<Runtime>::get(CHARLIE).into_inner(), vec![pid]);
+	})
+}
+
+#[test]
+fn set_share_token_should_not_work() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		let (pid, share_token) = init_share_token();
+		assert_err!(
+			Farming::set_share_token(
+				RuntimeOrigin::signed(ALICE),
+				pid,
+				Some(CurrencyId::Token2(1))
+			),
+			Error::<Runtime>::ShareTokenNotRegistered
+		);
+
+		let tokens_proportion = vec![(KSM, Perbill::from_percent(100))];
+		assert_ok!(Farming::create_farming_pool(
+			RuntimeOrigin::signed(ALICE),
+			tokens_proportion,
+			vec![(KSM, 1000)],
+			None,
+			0,
+			0,
+			10,
+			0,
+			5
+		));
+		assert_err!(
+			Farming::set_share_token(RuntimeOrigin::signed(ALICE), 1, Some(share_token)),
+			Error::<Runtime>::ShareTokenInUse
+		);
+
+		assert_ok!(Farming::deposit(RuntimeOrigin::signed(ALICE), pid, 1000));
+		assert_err!(
+			Farming::set_share_token(RuntimeOrigin::signed(ALICE), pid, None),
+			Error::<Runtime>::PoolNotEmpty
+		);
+	})
+}
@@ -74,6 +74,8 @@ pub trait WeightInfo {
 	fn start_boost_round() -> Weight;
 	fn end_boost_round() -> Weight;
 	fn charge_boost() -> Weight;
+	fn set_share_token() -> Weight;
+	fn transfer_share_token() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -380,4 +382,66 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: Farming PoolInfos (r:1 w:0)
+	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming ShareTokens (r:1 w:1)
+	/// Proof Skipped: Farming ShareTokens (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
+	/// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming ShareTokenPools (r:1 w:1)
+	/// Proof Skipped: Farming ShareTokenPools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	fn set_share_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1188`
+		//  Estimated: `4653`
+		// Minimum execution time: 38_231_000 picoseconds.
+		Weight::from_parts(39_412_000, 4653)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Farming ShareTokens (r:1 w:0)
+	/// Proof Skipped: Farming ShareTokens (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming SharesAndWithdrawnRewards (r:2 w:2)
+	/// Proof Skipped: Farming SharesAndWithdrawnRewards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming PoolInfos (r:1 w:1)
+	/// Proof Skipped: Farming PoolInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Farming GaugePoolInfos (r:1 w:0)
+	/// Proof Skipped: Farming GaugePoolInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: BbBNC IncentiveConfigs (r:1 w:1)
+	/// Proof Skipped: BbBNC IncentiveConfigs (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Number (r:1 w:0)
+	/// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: BbBNC Epoch (r:1 w:0)
+	/// Proof Skipped: BbBNC Epoch (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: BbBNC PointHistory (r:1 w:0)
+	/// Proof Skipped: BbBNC PointHistory (max_values: None, max_size: None, mode: Measured)
+	/// Storage: BbBNC SlopeChanges (r:104 w:0)
+	/// Proof Skipped: BbBNC SlopeChanges (max_values: None, max_size: None, mode: Measured)
+	/// Storage: BbBNC UserPointEpoch (r:2 w:0)
+	/// Proof Skipped: BbBNC UserPointEpoch (max_values: None, max_size: None, mode: Measured)
+	/// Storage: BbBNC UserPointHistory (r:2 w:0)
+	/// Proof Skipped: BbBNC UserPointHistory (max_values: None, max_size: None, mode: Measured)
+	/// Storage: BbBNC Rewards (r:2 w:2)
+	/// Proof Skipped: BbBNC Rewards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: BbBNC UserRewardPerTokenPaid (r:2 w:2)
+	/// Proof Skipped: BbBNC UserRewardPerTokenPaid (max_values: None, max_size: None, mode: Measured)
+	/// Storage: BbBNC UserFarmingPool (r:2 w:2)
+	/// Proof Skipped: BbBNC UserFarmingPool (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn transfer_share_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2236`
+		//  Estimated: `264312`
+		// Minimum execution time: 1_612_000_000 picoseconds.
+		Weight::from_parts(1_650_000_000, 264312)
+			.saturating_add(RocksDbWeight::get().reads(129_u64))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
+	}
 }
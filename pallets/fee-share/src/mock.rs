@@ -45,7 +45,7 @@ use orml_traits::{
 use sp_core::ConstU32;
 use sp_runtime::{
 	traits::{AccountIdConversion, IdentityLookup, UniqueSaturatedInto},
-	AccountId32, BuildStorage, SaturatedConversion,
+	AccountId32, BuildStorage, Permill, SaturatedConversion,
 };
 use sp_std::marker::PhantomData;
 use std::{
@@ -122,6 +122,7 @@ pub type AdaptedBasicCurrency =
 
 impl bifrost_currencies::Config for Runtime {
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type WeightInfo = ();
@@ -184,6 +185,25 @@ impl bifrost_fee_share::Config for Runtime {
 	type OraclePriceProvider = MockOraclePriceProvider;
 }
 
+parameter_types! {
+	pub const MaxVtokenPriceDeviation: Permill = Permill::from_percent(10);
+	pub const TwapPeriod: u64 = 60_000;
+	pub const MaxPriceConfigs: u32 = 10;
+	pub const MaxSnapshotAssets: u32 = 10;
+	pub const MaxPriceSnapshots: u32 = 10;
+	pub const PriceSnapshotMaxAge: BlockNumber = 100;
+}
+
+pub struct MockTime;
+
+impl frame_support::traits::Time for MockTime {
+	type Moment = u64;
+
+	fn now() -> u64 {
+		0
+	}
+}
+
 impl pallet_prices::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Source = MockDataProvider;
@@ -192,6 +212,16 @@ impl pallet_prices::Config for Runtime {
 	type RelayCurrency = RelayCurrencyId;
 	type Assets = Currencies;
 	type CurrencyIdConvert = AssetIdMaps<Runtime>;
+	type VtokenMinting = ();
+	type MaxVtokenPriceDeviation = MaxVtokenPriceDeviation;
+	type Time = MockTime;
+	type StablePoolHandler = ();
+	type XcmPriceOrigin = frame_support::traits::NeverEnsureOrigin<xcm::v4::Location>;
+	type TwapPeriod = TwapPeriod;
+	type MaxPriceConfigs = MaxPriceConfigs;
+	type MaxSnapshotAssets = MaxSnapshotAssets;
+	type MaxPriceSnapshots = MaxPriceSnapshots;
+	type PriceSnapshotMaxAge = PriceSnapshotMaxAge;
 	type WeightInfo = ();
 }
 
@@ -26,7 +26,7 @@ decl_runtime_apis! {
 	pub trait FlexibleFeeRuntimeApi<AccountId> where
 		AccountId: Codec,
 	{
-		/// get flexible fee token and amount to be deducted
+		/// get flexible fee token and amount to be deducted, following the fee preference of `utx`
 		fn get_fee_token_and_amount(who: AccountId, fee: Balance, utx: Block::Extrinsic) -> (CurrencyId, Balance);
 	}
 }
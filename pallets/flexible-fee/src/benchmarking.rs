@@ -18,12 +18,13 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
-use frame_benchmarking::v1::{benchmarks, whitelisted_caller};
-use frame_support::BoundedVec;
+use frame_benchmarking::v1::{account, benchmarks, whitelisted_caller};
+use frame_support::{traits::Get, BoundedVec};
 
-use bifrost_primitives::{CurrencyId, TokenSymbol};
+use bifrost_primitives::{CurrencyId, Price, TokenSymbol};
 use frame_system::RawOrigin;
-use sp_std::vec;
+use sp_runtime::traits::One;
+use sp_std::{vec, vec::Vec};
 
 use crate::{Call, Config, Pallet};
 
@@ -36,6 +37,39 @@ benchmarks! {
 		let default_list = BoundedVec::try_from(vec![CurrencyId::Token(TokenSymbol::DOT)]).unwrap();
 	}: _(RawOrigin::Root,default_list)
 
+	register_sponsor_policy {
+		let x in 0 .. T::MaxSponsorWhitelist::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let allowed_calls = BoundedVec::try_from(vec![BoundedVec::try_from(vec![10u8]).unwrap()]).unwrap();
+		let whitelist: Vec<T::AccountId> = (0..x).map(|i| account("user", i, 0)).collect();
+		let whitelist = BoundedVec::try_from(whitelist).unwrap();
+		// the existing policy and its whitelist are replaced
+		Pallet::<T>::register_sponsor_policy(
+			RawOrigin::Signed(caller.clone()).into(),
+			allowed_calls.clone(),
+			1_000_000_000_000u128,
+			100u32.into(),
+			Some(whitelist.clone()),
+		)?;
+	}: _(RawOrigin::Signed(caller), allowed_calls, 1_000_000_000_000u128, 100u32.into(), Some(whitelist))
+
+	remove_sponsor_policy {
+		let x in 0 .. T::MaxSponsorWhitelist::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let allowed_calls = BoundedVec::try_from(vec![BoundedVec::try_from(vec![10u8]).unwrap()]).unwrap();
+		let whitelist: Vec<T::AccountId> = (0..x).map(|i| account("user", i, 0)).collect();
+		Pallet::<T>::register_sponsor_policy(
+			RawOrigin::Signed(caller.clone()).into(),
+			allowed_calls,
+			1_000_000_000_000u128,
+			100u32.into(),
+			Some(BoundedVec::try_from(whitelist).unwrap()),
+		)?;
+	}: _(RawOrigin::Signed(caller))
+
+	set_min_pool_fee_rate {
+	}: _(RawOrigin::Root, CurrencyId::Token(TokenSymbol::DOT), Some(Price::one()))
+
 	impl_benchmark_test_suite!(
 	Pallet,
 	crate::mock::new_test_ext(),
@@ -17,7 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{Config, Error, Pallet, UniversalFeeCurrencyOrderList, UserDefaultFeeCurrency};
-use bifrost_primitives::{AccountFeeCurrency, BalanceCmp, CurrencyId, WETH};
+use bifrost_primitives::{AccountFeeCurrency, BalanceCmp, CurrencyId, CurrencyIdMapping, WETH};
 use frame_support::traits::{
 	fungibles::Inspect,
 	tokens::{Fortitude, Preservation},
@@ -39,7 +39,7 @@ impl<T: Config> AccountFeeCurrency<T::AccountId> for Pallet<T> {
 	/// The method first checks if the balance of the highest-priority currency is sufficient to
 	/// cover the fee.If the balance is insufficient, it iterates through the list of currencies in
 	/// priority order.If no currency has a sufficient balance, it returns the currency with the
-	/// highest balance.
+	/// highest balance. Currencies that aren't active in the asset registry are skipped.
 	fn get_fee_currency(account: &T::AccountId, fee: U256) -> Result<CurrencyId, Error<T>> {
 		let fee: u128 = fee.unique_saturated_into();
 		let priority_currency = UserDefaultFeeCurrency::<T>::get(account);
@@ -53,7 +53,9 @@ impl<T: Config> AccountFeeCurrency<T::AccountId> for Pallet<T> {
 		// When all currency balances are insufficient, return the one with the highest balance
 		let mut hopeless_currency = WETH;
 
-		if let Some(currency) = priority_currency {
+		if let Some(currency) = priority_currency
+			.filter(|currency| T::AssetIdMaps::get_asset_status(*currency).is_active())
+		{
 			currency_list
 				.try_insert(first_item_index, currency)
 				.map_err(|_| Error::<T>::MaxCurrenciesReached)?;
@@ -61,6 +63,9 @@ impl<T: Config> AccountFeeCurrency<T::AccountId> for Pallet<T> {
 		}
 
 		for maybe_currency in currency_list.iter() {
+			if !T::AssetIdMaps::get_asset_status(*maybe_currency).is_active() {
+				continue;
+			}
 			let comp_res = Self::cmp_with_precision(account, maybe_currency, fee, 18)?;
 
 			match comp_res {
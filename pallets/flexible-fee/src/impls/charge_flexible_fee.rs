@@ -0,0 +1,236 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	impls::on_charge_transaction::PaymentInfo, Config, Event, FeePreference, Pallet, SponsoredFees,
+};
+use bifrost_primitives::Balance;
+use frame_support::{
+	dispatch::{DispatchInfo, PostDispatchInfo},
+	ensure,
+	pallet_prelude::DispatchResult,
+};
+use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+};
+use sp_std::any::Any;
+
+/// Charges the fee of a transaction like `ChargeTransactionPayment`, which it replaces in the
+/// signed extensions. The fee is paid following the fee preference of the transaction, so the
+/// transaction is invalid if the fee can't be paid in the preferred currency or exceeds the
+/// maximum.
+///
+/// The signer may name a sponsor paying the fee instead. The policy of the sponsor must cover the
+/// signer and the call, and the fee must fit in the budget left for the signer, otherwise the
+/// transaction is invalid. The sponsored signer must already have an account, the sponsor
+/// doesn't provide one, so the signer can't be left with a nonce nothing keeps alive.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeFlexibleFee<T: Config> {
+	/// The tip for the block author.
+	#[codec(compact)]
+	pub tip: Balance,
+	/// The fee currency, and the maximum fee amount, of the transaction.
+	pub preference: FeePreference,
+	/// The account paying the fee, the signer pays it if `None`.
+	pub sponsor: Option<T::AccountId>,
+}
+
+impl<T: Config> ChargeFlexibleFee<T>
+where
+	T::OnChargeTransaction: OnChargeTransaction<T, Balance = Balance>,
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	pub fn new(tip: Balance, preference: FeePreference, sponsor: Option<T::AccountId>) -> Self {
+		Self { tip, preference, sponsor }
+	}
+
+	/// Get the account paying the fee of `call` signed by `who`, and the fee preference applying
+	/// to it.
+	fn payer(
+		&self,
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		fee: Balance,
+	) -> Result<(T::AccountId, FeePreference), TransactionValidityError> {
+		let Some(sponsor) = &self.sponsor else {
+			return Ok((who.clone(), self.preference));
+		};
+		ensure!(
+			frame_system::Pallet::<T>::account_exists(who) &&
+				Pallet::<T>::can_sponsor_fee(sponsor, who, call, fee),
+			InvalidTransaction::Payment
+		);
+		// the fee currency of the signer doesn't apply to a sponsor, the maximum fee does
+		Ok((sponsor.clone(), FeePreference { currency_id: None, ..self.preference }))
+	}
+
+	/// Withdraw the fee of `call` signed by `who` from the payer. Returns the fee, the payer and
+	/// the payment.
+	fn withdraw_fee(
+		&self,
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		len: usize,
+	) -> Result<(Balance, T::AccountId, Option<PaymentInfo>), TransactionValidityError> {
+		let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, self.tip);
+		let (payer, preference) = self.payer(who, call, fee)?;
+		let payment = Pallet::<T>::withdraw_fee_by_preference(&payer, call, fee, preference)?;
+		Ok((fee, payer, payment))
+	}
+}
+
+impl<T: Config> sp_std::fmt::Debug for ChargeFlexibleFee<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "ChargeFlexibleFee({}, {:?}, {:?})", self.tip, self.preference, self.sponsor)
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config> SignedExtension for ChargeFlexibleFee<T>
+where
+	T::OnChargeTransaction: OnChargeTransaction<T, Balance = Balance>,
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	const IDENTIFIER: &'static str = "ChargeFlexibleFee";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	/// The tip, the payer and the payment, and the signer of a sponsored transaction.
+	type Pre = (Balance, T::AccountId, Option<PaymentInfo>, Option<T::AccountId>);
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		let (fee, _, _) = self.withdraw_fee(who, call, info, len)?;
+		Ok(ValidTransaction {
+			priority: ChargeTransactionPayment::<T>::get_priority(info, len, self.tip, fee),
+			..Default::default()
+		})
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let (_, payer, payment) = self.withdraw_fee(who, call, info, len)?;
+		Ok((self.tip, payer, payment, self.sponsor.map(|_| who.clone())))
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		_result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		let Some((tip, payer, payment, sponsored)) = pre else {
+			return Ok(());
+		};
+		let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+			len as u32, info, post_info, tip,
+		);
+		<Pallet<T> as OnChargeTransaction<T>>::correct_and_deposit_fee(
+			&payer, info, post_info, actual_fee, tip, payment,
+		)?;
+		frame_system::Pallet::<T>::deposit_event(
+			<T as pallet_transaction_payment::Config>::RuntimeEvent::from(
+				pallet_transaction_payment::Event::<T>::TransactionFeePaid {
+					who: payer.clone(),
+					actual_fee,
+					tip,
+				},
+			),
+		);
+
+		if let Some(who) = sponsored {
+			// only the corrected fee is counted against the sponsor budget
+			SponsoredFees::<T>::mutate(&payer, &who, |spent| {
+				*spent = spent.saturating_add(actual_fee)
+			});
+			Pallet::<T>::deposit_event(Event::<T>::FeeSponsored {
+				sponsor: payer,
+				who,
+				fee: actual_fee,
+			});
+		}
+		Ok(())
+	}
+}
+
+/// Looks up the fee preference carried by the `ChargeFlexibleFee` extension in a tuple of signed
+/// extensions by its type, so callers don't depend on the position of the extension.
+pub trait FeePreferenceOf<T: Config> {
+	fn fee_preference(&self) -> Option<FeePreference>;
+}
+
+macro_rules! impl_fee_preference_of_for_tuples {
+	($($extension:ident $value:ident),+) => {
+		impl<T: Config, $($extension: 'static),+> FeePreferenceOf<T> for ($($extension,)+) {
+			fn fee_preference(&self) -> Option<FeePreference> {
+				let ($($value,)+) = self;
+				$(
+					if let Some(charge) =
+						($value as &dyn Any).downcast_ref::<ChargeFlexibleFee<T>>()
+					{
+						return Some(charge.preference);
+					}
+				)+
+				None
+			}
+		}
+	};
+}
+
+impl_fee_preference_of_for_tuples!(A a);
+impl_fee_preference_of_for_tuples!(A a, B b);
+impl_fee_preference_of_for_tuples!(A a, B b, C c);
+impl_fee_preference_of_for_tuples!(A a, B b, C c, D d);
+impl_fee_preference_of_for_tuples!(A a, B b, C c, D d, E e);
+impl_fee_preference_of_for_tuples!(A a, B b, C c, D d, E e, F f);
+impl_fee_preference_of_for_tuples!(A a, B b, C c, D d, E e, F f, G g);
+impl_fee_preference_of_for_tuples!(A a, B b, C c, D d, E e, F f, G g, H h);
+impl_fee_preference_of_for_tuples!(A a, B b, C c, D d, E e, F f, G g, H h, I i);
+impl_fee_preference_of_for_tuples!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j);
+impl_fee_preference_of_for_tuples!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k);
+impl_fee_preference_of_for_tuples!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k, L l);
@@ -16,48 +16,43 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Config, ExtraFeeByCall, Pallet};
+use crate::{Config, ExtraFeeByCall, FeePreference, FeePriceSource, Pallet};
 use bifrost_primitives::{Balance, CurrencyId, OraclePriceProvider, Price, BNC};
 use orml_traits::MultiCurrency;
 use pallet_transaction_payment::OnChargeTransaction;
 use parity_scale_codec::Encode;
 use sp_core::Get;
 use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
 	traits::{DispatchInfoOf, PostDispatchInfoOf, Zero},
 	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	Rounding,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PaymentInfo {
 	Native(Balance),
 	NonNative(Balance, CurrencyId, Price, Price),
+	/// Paid in a currency priced by a pool quote: paid fee, fee currency, quoted BNC fee.
+	Quoted(Balance, CurrencyId, Balance),
 }
 
-/// Default implementation for a Currency and an OnUnbalanced handler.
-impl<T> OnChargeTransaction<T> for Pallet<T>
-where
-	T: Config,
-	T::MultiCurrency: MultiCurrency<T::AccountId, CurrencyId = CurrencyId>,
-{
-	type Balance = Balance;
-	type LiquidityInfo = Option<PaymentInfo>;
-
-	/// Withdraw the predicted fee from the transaction origin.
+impl<T: Config> Pallet<T> {
+	/// Withdraw the predicted fee from `who` following the fee preference of the transaction.
 	///
 	/// Note: The `fee` already includes the `tip`.
-	fn withdraw_fee(
+	pub(crate) fn withdraw_fee_by_preference(
 		who: &T::AccountId,
 		call: &T::RuntimeCall,
-		_info: &DispatchInfoOf<T::RuntimeCall>,
-		fee: Self::Balance,
-		_tip: Self::Balance,
-	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		fee: Balance,
+		preference: FeePreference,
+	) -> Result<Option<PaymentInfo>, TransactionValidityError> {
 		if fee.is_zero() {
 			return Ok(None);
 		}
 
-		let (fee_currency, fee_amount, bnc_price, fee_currency_price) =
-			Self::get_fee_currency_and_fee_amount(who, fee)
+		let (fee_currency, fee_amount, price_source) =
+			Self::get_fee_currency_and_fee_amount_by_preference(who, fee, preference)
 				.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
 
 		// withdraw normal extrinsic fee
@@ -91,14 +86,38 @@ where
 		if fee_currency == BNC {
 			Ok(Some(PaymentInfo::Native(fee_amount)))
 		} else {
-			Ok(Some(PaymentInfo::NonNative(
-				fee_amount,
-				fee_currency,
-				bnc_price,
-				fee_currency_price,
-			)))
+			match price_source {
+				FeePriceSource::Oracle(bnc_price, fee_currency_price) => Ok(Some(
+					PaymentInfo::NonNative(fee_amount, fee_currency, bnc_price, fee_currency_price),
+				)),
+				FeePriceSource::Pool(quoted_fee) =>
+					Ok(Some(PaymentInfo::Quoted(fee_amount, fee_currency, quoted_fee))),
+			}
 		}
 	}
+}
+
+/// Default implementation for a Currency and an OnUnbalanced handler.
+impl<T> OnChargeTransaction<T> for Pallet<T>
+where
+	T: Config,
+	T::MultiCurrency: MultiCurrency<T::AccountId, CurrencyId = CurrencyId>,
+{
+	type Balance = Balance;
+	type LiquidityInfo = Option<PaymentInfo>;
+
+	/// Withdraw the predicted fee from the transaction origin.
+	///
+	/// Note: The `fee` already includes the `tip`.
+	fn withdraw_fee(
+		who: &T::AccountId,
+		call: &T::RuntimeCall,
+		_info: &DispatchInfoOf<T::RuntimeCall>,
+		fee: Self::Balance,
+		_tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		Self::withdraw_fee_by_preference(who, call, fee, FeePreference::default())
+	}
 
 	/// Hand the fee and the tip over to the `[OnUnbalanced]` implementation.
 	/// Since the predicted fee might have been too high, parts of the fee may
@@ -148,6 +167,30 @@ where
 						converted_tip,
 					)
 				},
+				PaymentInfo::Quoted(paid_fee, fee_currency, quoted_fee) => {
+					// convert corrected_fee and tip at the rate the fee was quoted
+					let converted_corrected_fee = multiply_by_rational_with_rounding(
+						corrected_fee,
+						paid_fee,
+						quoted_fee,
+						Rounding::Up,
+					)
+					.ok_or(TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
+					let refund = paid_fee.saturating_sub(converted_corrected_fee);
+					let converted_tip = multiply_by_rational_with_rounding(
+						tip,
+						paid_fee,
+						quoted_fee,
+						Rounding::Down,
+					)
+					.ok_or(TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
+					(
+						fee_currency,
+						refund,
+						converted_corrected_fee.saturating_sub(converted_tip),
+						converted_tip,
+					)
+				},
 			};
 			// refund to the account that paid the fees
 			T::MultiCurrency::deposit(currency, who, refund)
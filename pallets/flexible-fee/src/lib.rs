@@ -19,12 +19,15 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use crate::pallet::*;
+use bifrost_asset_registry::AssetMetadata;
 use bifrost_primitives::{
 	currency::{VGLMR, VMANTA, WETH},
 	traits::XcmDestWeightAndFeeHandler,
-	AssetHubChainId, Balance, BalanceCmp, CurrencyId, DerivativeIndex, OraclePriceProvider, Price,
-	TryConvertFrom, XcmOperationType, BNC, DOT, GLMR, MANTA, VBNC, VDOT,
+	AssetHubChainId, Balance, BalanceCmp, CurrencyId, CurrencyIdMapping, DerivativeIndex,
+	OraclePriceProvider, Price, TryConvertFrom, XcmOperationType, BNC, DOT, GLMR, MANTA, VBNC,
+	VDOT,
 };
+use bifrost_stable_pool::traits::StablePoolHandler;
 use bifrost_xcm_interface::calls::{PolkadotXcmCall, RelaychainCall};
 use core::convert::Into;
 use cumulus_primitives_core::ParaId;
@@ -40,16 +43,17 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::pallet_prelude::*;
+pub use impls::charge_flexible_fee::{ChargeFlexibleFee, FeePreferenceOf};
 use orml_traits::MultiCurrency;
 use polkadot_parachain_primitives::primitives::Sibling;
 use sp_arithmetic::traits::UniqueSaturatedInto;
 use sp_runtime::{
-	traits::{AccountIdConversion, One},
-	BoundedVec,
+	traits::{AccountIdConversion, One, Zero},
+	BoundedVec, FixedPointNumber,
 };
 use sp_std::{boxed::Box, cmp::Ordering, vec, vec::Vec};
 pub use weights::WeightInfo;
-use xcm::{prelude::Unlimited, v4::prelude::*};
+use xcm::{prelude::Unlimited, v3::MultiLocation, v4::prelude::*};
 use zenlink_protocol::{AssetId, ExportZenlink};
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -64,6 +68,12 @@ pub mod weights;
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<AccountIdOf<T>>>::Balance;
 pub type RawCallName = BoundedVec<u8, ConstU32<32>>;
+pub type SponsorPolicyOf<T> = SponsorPolicy<
+	AccountIdOf<T>,
+	BlockNumberFor<T>,
+	<T as Config>::MaxSponsoredCalls,
+	<T as Config>::MaxSponsorWhitelist,
+>;
 
 #[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum TargetChain {
@@ -71,6 +81,51 @@ pub enum TargetChain {
 	RelayChain,
 }
 
+/// The fee sponsorship policy registered by a sponsor.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxCalls, MaxWhitelist))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+pub struct SponsorPolicy<AccountId, BlockNumber, MaxCalls, MaxWhitelist>
+where
+	MaxCalls: Get<u32>,
+	MaxWhitelist: Get<u32>,
+{
+	/// The sponsored calls, as raw call name prefixes. `[pallet_index]` covers all calls of a
+	/// pallet and `[pallet_index, call_index]` covers a single call.
+	pub allowed_calls: BoundedVec<RawCallName, MaxCalls>,
+	/// The maximum fee, in BNC, the sponsor pays for each user.
+	pub budget_per_user: Balance,
+	/// The last block the policy applies to.
+	pub expiry: BlockNumber,
+	/// If set, only these users are covered. A user is only sponsored when naming the sponsor in
+	/// the `ChargeFlexibleFee` extension.
+	pub whitelist: Option<BoundedVec<AccountId, MaxWhitelist>>,
+}
+
+/// The fee payment preference of a single transaction, carried by the `ChargeFlexibleFee`
+/// extension.
+#[derive(
+	Encode, Decode, Clone, Copy, Default, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct FeePreference {
+	/// The currency to pay the fee in, BNC or a currency of the universal fee currency order
+	/// list. If `None`, the user fee currency order list is used.
+	pub currency_id: Option<CurrencyId>,
+	/// The maximum fee amount, in the currency the fee is paid in. It also applies when the fee
+	/// is paid by a sponsor.
+	pub max_fee: Option<Balance>,
+}
+
+/// How a non-native fee amount was priced against BNC.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub enum FeePriceSource {
+	/// Priced by the oracle, carrying the prices of BNC and the fee currency.
+	Oracle(Price, Price),
+	/// Priced by a pool quote bounded by `MinPoolFeeRates`, carrying the BNC fee amount that was
+	/// quoted.
+	Pool(Balance),
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -92,6 +147,8 @@ pub mod pallet {
 		type DexOperator: ExportZenlink<Self::AccountId, AssetId>;
 		/// The oracle price feeder
 		type OraclePriceProvider: OraclePriceProvider;
+		/// Stable pool interface, used to quote fee currencies
+		type StablePoolHandler: StablePoolHandler<Balance = Balance, CurrencyId = CurrencyId>;
 		/// The only origin that can set universal fee currency order list
 		type ControlOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// Get the weight and fee for executing Xcm.
@@ -115,6 +172,19 @@ pub mod pallet {
 		type ParachainId: Get<ParaId>;
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
+		/// Maximum number of calls in a sponsor policy.
+		#[pallet::constant]
+		type MaxSponsoredCalls: Get<u32>;
+		/// Maximum number of users in a sponsor policy whitelist.
+		#[pallet::constant]
+		type MaxSponsorWhitelist: Get<u32>;
+		/// Asset registry to check the status of fee currencies. Only active currencies can
+		/// pay fees.
+		type AssetIdMaps: CurrencyIdMapping<
+			CurrencyId,
+			MultiLocation,
+			AssetMetadata<BalanceOf<Self>>,
+		>;
 	}
 
 	#[pallet::hooks]
@@ -156,6 +226,14 @@ pub mod pallet {
 			/// currency_id, fee_amount, receiver
 			fee_info: Option<(CurrencyId, BalanceOf<T>, T::AccountId)>,
 		},
+		/// A sponsor policy is registered or replaced
+		SponsorPolicyRegistered { sponsor: T::AccountId, policy: SponsorPolicyOf<T> },
+		/// A sponsor policy is removed
+		SponsorPolicyRemoved { sponsor: T::AccountId },
+		/// The transaction fee of a user is paid by a sponsor
+		FeeSponsored { sponsor: T::AccountId, who: T::AccountId, fee: BalanceOf<T> },
+		/// Set the minimum pool fee rate of a currency
+		SetMinPoolFeeRate { currency_id: CurrencyId, rate: Option<Price> },
 	}
 
 	/// The current storage version, we set to 2 our new version.
@@ -182,6 +260,29 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Fee sponsorship policies, by sponsor
+	#[pallet::storage]
+	pub type SponsorPolicies<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, SponsorPolicyOf<T>, OptionQuery>;
+
+	/// The fees, in BNC, paid by a sponsor for a user
+	#[pallet::storage]
+	pub type SponsoredFees<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// The minimum amount of a currency charged per unit of BNC when the fee is priced by a pool
+	/// quote alone. Currencies without an oracle price or a minimum rate can't pay fees.
+	#[pallet::storage]
+	pub type MinPoolFeeRates<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, Price, OptionQuery>;
+
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
@@ -195,6 +296,11 @@ pub mod pallet {
 		XcmExecutionFailed,
 		CurrencyNotSupport,
 		MaxCurrenciesReached,
+		InvalidSponsorPolicy,
+		SponsorPolicyNotExist,
+		MaxFeeExceeded,
+		FeeCurrencyNotActive,
+		FeeCurrencyNotAllowed,
 	}
 
 	#[pallet::call]
@@ -255,6 +361,69 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::SetExtraFee { raw_call_name, fee_info });
 			Ok(())
 		}
+
+		/// Register or replace the fee sponsorship policy of the caller.
+		/// Parameters:
+		/// - `allowed_calls`: The raw call name prefixes of the sponsored calls.
+		/// - `budget_per_user`: The maximum fee, in BNC, paid for each user.
+		/// - `expiry`: The last block the policy applies to.
+		/// - `whitelist`: If set, only these users are covered.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_sponsor_policy(T::MaxSponsorWhitelist::get()))]
+		pub fn register_sponsor_policy(
+			origin: OriginFor<T>,
+			allowed_calls: BoundedVec<RawCallName, T::MaxSponsoredCalls>,
+			budget_per_user: BalanceOf<T>,
+			expiry: BlockNumberFor<T>,
+			whitelist: Option<BoundedVec<T::AccountId, T::MaxSponsorWhitelist>>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			ensure!(
+				!allowed_calls.is_empty() &&
+					allowed_calls.iter().all(|call| !call.is_empty()) &&
+					!budget_per_user.is_zero() &&
+					expiry >= frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidSponsorPolicy
+			);
+
+			let policy = SponsorPolicy { allowed_calls, budget_per_user, expiry, whitelist };
+			SponsorPolicies::<T>::insert(&sponsor, &policy);
+			Self::deposit_event(Event::<T>::SponsorPolicyRegistered { sponsor, policy });
+			Ok(())
+		}
+
+		/// Remove the fee sponsorship policy of the caller. The fees already paid for each user
+		/// are kept, so registering again doesn't reset the budgets.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_sponsor_policy(T::MaxSponsorWhitelist::get()))]
+		pub fn remove_sponsor_policy(origin: OriginFor<T>) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			SponsorPolicies::<T>::take(&sponsor).ok_or(Error::<T>::SponsorPolicyNotExist)?;
+			Self::deposit_event(Event::<T>::SponsorPolicyRemoved { sponsor });
+			Ok(())
+		}
+
+		/// Set or remove the minimum pool fee rate of a currency
+		/// Parameters:
+		/// - `currency_id`: The fee currency.
+		/// - `rate`: The minimum amount of the currency charged per unit of BNC when the fee is
+		///   priced by a pool quote alone. If `None`, the currency can only be priced by the
+		///   oracle.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_min_pool_fee_rate())]
+		pub fn set_min_pool_fee_rate(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			rate: Option<Price>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+			match rate {
+				Some(rate) => MinPoolFeeRates::<T>::insert(currency_id, rate),
+				None => MinPoolFeeRates::<T>::remove(currency_id),
+			};
+			Self::deposit_event(Event::<T>::SetMinPoolFeeRate { currency_id, rate });
+			Ok(())
+		}
 	}
 }
 
@@ -369,6 +538,8 @@ impl<T: Config> Pallet<T> {
 			let first_fee_currency_index = 0;
 			fee_currency_list.insert(first_fee_currency_index, default_fee_currency);
 		};
+		fee_currency_list
+			.retain(|currency_id| T::AssetIdMaps::get_asset_status(*currency_id).is_active());
 
 		fee_currency_list
 	}
@@ -376,33 +547,116 @@ impl<T: Config> Pallet<T> {
 	fn get_fee_currency_and_fee_amount(
 		who: &T::AccountId,
 		fee_amount: Balance,
-	) -> Result<(CurrencyId, Balance, Price, Price), Error<T>> {
+	) -> Result<(CurrencyId, Balance, FeePriceSource), Error<T>> {
 		let fee_currency_list = Self::get_fee_currency_list(who);
 		// charge the fee by the order of the above order list.
-		// first to check whether the user has the asset. If no, pass it. If yes, try to make
-		// transaction in the DEX in exchange for BNC
+		// first to check whether the user has the asset. If no, pass it. If yes, price the fee
+		// amount in the asset. Assets that can't be priced are passed as well.
 		for currency_id in fee_currency_list {
 			// If it is mainnet currency
 			if currency_id == BNC {
 				if T::MultiCurrency::ensure_can_withdraw(currency_id, who, fee_amount).is_ok() {
-					return Ok((currency_id, fee_amount, Price::one(), Price::one()));
-				}
-			} else {
-				let (fee_amount, price_in, price_out) =
-					T::OraclePriceProvider::get_oracle_amount_by_currency_and_amount_in(
-						&BNC,
+					return Ok((
+						currency_id,
 						fee_amount,
-						&currency_id,
-					)
-					.ok_or(Error::<T>::ConversionError)?;
+						FeePriceSource::Oracle(Price::one(), Price::one()),
+					));
+				}
+			} else if let Some((fee_amount, price_source)) =
+				Self::get_fee_amount_in_currency(currency_id, fee_amount)
+			{
 				if T::MultiCurrency::ensure_can_withdraw(currency_id, who, fee_amount).is_ok() {
-					return Ok((currency_id, fee_amount, price_in, price_out));
+					return Ok((currency_id, fee_amount, price_source));
 				}
 			}
 		}
 		Err(Error::<T>::NotEnoughBalance)
 	}
 
+	/// Get the fee currency and amount following the fee preference of a transaction. The
+	/// preferred currency is the only one tried, and the fee amount must not exceed the maximum.
+	fn get_fee_currency_and_fee_amount_by_preference(
+		who: &T::AccountId,
+		fee_amount: Balance,
+		preference: FeePreference,
+	) -> Result<(CurrencyId, Balance, FeePriceSource), Error<T>> {
+		let (fee_currency, fee_amount, price_source) = match preference.currency_id {
+			Some(BNC) => (BNC, fee_amount, FeePriceSource::Oracle(Price::one(), Price::one())),
+			Some(currency_id) => {
+				ensure!(
+					UniversalFeeCurrencyOrderList::<T>::get().contains(&currency_id),
+					Error::<T>::FeeCurrencyNotAllowed
+				);
+				ensure!(
+					T::AssetIdMaps::get_asset_status(currency_id).is_active(),
+					Error::<T>::FeeCurrencyNotActive
+				);
+				let (fee_amount, price_source) =
+					Self::get_fee_amount_in_currency(currency_id, fee_amount)
+						.ok_or(Error::<T>::ConversionError)?;
+				(currency_id, fee_amount, price_source)
+			},
+			None => Self::get_fee_currency_and_fee_amount(who, fee_amount)?,
+		};
+
+		T::MultiCurrency::ensure_can_withdraw(fee_currency, who, fee_amount)
+			.map_err(|_| Error::<T>::NotEnoughBalance)?;
+		if let Some(max_fee) = preference.max_fee {
+			ensure!(fee_amount <= max_fee, Error::<T>::MaxFeeExceeded);
+		}
+		Ok((fee_currency, fee_amount, price_source))
+	}
+
+	/// Price `fee_amount` of BNC in `currency_id`. The oracle price is used first, and the pools
+	/// are only quoted when the oracle can't price the currency: the stable pool quote first and
+	/// then the Zenlink quote. A pool quote is only used if the currency has a minimum pool fee
+	/// rate, and is raised to it.
+	///
+	/// Returns `None` if the currency can't be priced.
+	fn get_fee_amount_in_currency(
+		currency_id: CurrencyId,
+		fee_amount: Balance,
+	) -> Option<(Balance, FeePriceSource)> {
+		if let Some((oracle_amount, price_in, price_out)) =
+			T::OraclePriceProvider::get_oracle_amount_by_currency_and_amount_in(
+				&BNC,
+				fee_amount,
+				&currency_id,
+			) {
+			return Some((oracle_amount, FeePriceSource::Oracle(price_in, price_out)));
+		}
+
+		// a manipulated pool can't price the fee below the minimum rate
+		let min_amount = MinPoolFeeRates::<T>::get(currency_id)?.saturating_mul_int(fee_amount);
+		Self::get_stable_pool_amount_in(currency_id, fee_amount)
+			.or_else(|| Self::get_zenlink_amount_in(currency_id, fee_amount))
+			.map(|amount| (amount.max(min_amount), FeePriceSource::Pool(fee_amount)))
+	}
+
+	/// The amount of `currency_id` needed to get `fee_amount` of BNC from the stable pool.
+	fn get_stable_pool_amount_in(currency_id: CurrencyId, fee_amount: Balance) -> Option<Balance> {
+		let (pool_id, currency_index_in, currency_index_out) =
+			T::StablePoolHandler::get_pool_id(&currency_id, &BNC)?;
+		T::StablePoolHandler::get_swap_input(
+			pool_id,
+			currency_index_in,
+			currency_index_out,
+			fee_amount,
+		)
+		.ok()
+	}
+
+	/// The amount of `currency_id` needed to get `fee_amount` of BNC from Zenlink.
+	fn get_zenlink_amount_in(currency_id: CurrencyId, fee_amount: Balance) -> Option<Balance> {
+		let path = vec![
+			Self::get_currency_asset_id(currency_id).ok()?,
+			Self::get_currency_asset_id(BNC).ok()?,
+		];
+		T::DexOperator::get_amount_in_by_path(fee_amount, &path)
+			.ok()
+			.and_then(|amounts| amounts.first().copied())
+	}
+
 	fn charge_extra_fee(
 		who: &T::AccountId,
 		extra_fee_currency: CurrencyId,
@@ -460,14 +714,68 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Whether the policy of `sponsor` covers `call` signed by `who`, without checking the budget.
+	pub fn is_sponsor_policy_covered(
+		sponsor: &T::AccountId,
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+	) -> bool {
+		SponsorPolicies::<T>::get(sponsor)
+			.map_or(false, |policy| Self::policy_covers(&policy, who, call))
+	}
+
+	fn policy_covers(
+		policy: &SponsorPolicyOf<T>,
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+	) -> bool {
+		if frame_system::Pallet::<T>::block_number() > policy.expiry {
+			return false;
+		}
+		if let Some(whitelist) = &policy.whitelist {
+			if !whitelist.contains(who) {
+				return false;
+			}
+		}
+		let encoded_call = call.encode();
+		policy
+			.allowed_calls
+			.iter()
+			.any(|raw_call_name| encoded_call.starts_with(raw_call_name.as_slice()))
+	}
+
+	/// Whether `sponsor` pays `fee` for `call` signed by `who`. Calls charging an extra fee are
+	/// never sponsored, the extra fee is paid by the signer.
+	pub(crate) fn can_sponsor_fee(
+		sponsor: &T::AccountId,
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		fee: Balance,
+	) -> bool {
+		SponsorPolicies::<T>::get(sponsor).map_or(false, |policy| {
+			Self::policy_covers(&policy, who, call) &&
+				SponsoredFees::<T>::get(sponsor, who).saturating_add(fee) <=
+					policy.budget_per_user &&
+				!Self::has_extra_fee(call)
+		})
+	}
+
+	fn has_extra_fee(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+		let encoded_call = call.encode();
+		ExtraFeeByCall::<T>::iter_keys()
+			.any(|raw_call_name| encoded_call.starts_with(raw_call_name.as_slice()))
+	}
+
 	/// This function is for runtime-api to call
 	pub fn cal_fee_token_and_amount(
 		who: &T::AccountId,
 		fee: Balance,
 		_utx: &<T as frame_system::Config>::RuntimeCall,
+		preference: FeePreference,
 	) -> Result<(CurrencyId, Balance), Error<T>> {
-		let (fee_currency, fee_amount, _, _) = Self::get_fee_currency_and_fee_amount(who, fee)
-			.map_err(|_| Error::<T>::NotEnoughBalance)?;
+		let (fee_currency, fee_amount, _) =
+			Self::get_fee_currency_and_fee_amount_by_preference(who, fee, preference)
+				.map_err(|_| Error::<T>::NotEnoughBalance)?;
 		Ok((fee_currency, fee_amount))
 	}
 
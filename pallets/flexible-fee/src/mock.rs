@@ -20,13 +20,14 @@
 
 use super::*;
 use crate::{self as flexible_fee, mock_price::MockOraclePriceProvider};
+use bifrost_asset_registry::AssetIdMaps;
 use bifrost_currencies::BasicCurrencyAdapter;
 use bifrost_primitives::{Balance, CurrencyId, FlexibleFeePalletId, TokenSymbol, ZenlinkPalletId};
 use cumulus_primitives_core::ParaId as Pid;
 use frame_support::{
 	derive_impl, parameter_types,
 	sp_runtime::{DispatchError, DispatchResult},
-	traits::{ConstU128, Get, Nothing},
+	traits::{ConstU128, Everything, Get, Nothing},
 	weights::{ConstantMultiplier, IdentityFee},
 	PalletId,
 };
@@ -149,6 +150,8 @@ impl orml_tokens::Config for Test {
 parameter_types! {
 	pub const TreasuryAccount: AccountId32 = TREASURY_ACCOUNT;
 	pub const MaxFeeCurrencyOrderListLen: u32 = 50;
+	pub const MaxSponsoredCalls: u32 = 10;
+	pub const MaxSponsorWhitelist: u32 = 10;
 }
 
 impl crate::Config for Test {
@@ -167,6 +170,10 @@ impl crate::Config for Test {
 	type XcmRouter = ();
 	type PalletId = FlexibleFeePalletId;
 	type OraclePriceProvider = MockOraclePriceProvider;
+	type StablePoolHandler = ();
+	type MaxSponsoredCalls = MaxSponsoredCalls;
+	type MaxSponsorWhitelist = MaxSponsorWhitelist;
+	type AssetIdMaps = AssetIdMaps<Test>;
 }
 
 pub struct XcmDestWeightAndFee;
@@ -200,6 +207,7 @@ parameter_types! {
 
 impl bifrost_currencies::Config for Test {
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = BasicCurrencyAdapter<Test, Balances, Amount, BlockNumber>;
 	type WeightInfo = ();
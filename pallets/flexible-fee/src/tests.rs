@@ -20,11 +20,14 @@
 
 #![cfg(test)]
 use crate::{
-	impls::on_charge_transaction::PaymentInfo, mock::*, BlockNumberFor, BoundedVec, Config,
-	DispatchError::BadOrigin, UserDefaultFeeCurrency,
+	impls::on_charge_transaction::PaymentInfo, mock::*, mock_price::MockOraclePriceProvider,
+	BlockNumberFor, BoundedVec, ChargeFlexibleFee, Config, DispatchError::BadOrigin, Error, Event,
+	FeePreference, FeePreferenceOf, RawCallName, SponsorPolicies, SponsoredFees,
+	UserDefaultFeeCurrency,
 };
 use bifrost_primitives::{
-	AccountFeeCurrency, BalanceCmp, CurrencyId, TryConvertFrom, BNC, DOT, KSM, MANTA, VDOT, WETH,
+	AccountFeeCurrency, AssetStatus, BalanceCmp, CurrencyId, TryConvertFrom, BNC, DOT, KSM, MANTA,
+	VBNC, VDOT, WETH,
 };
 use frame_support::{
 	assert_noop, assert_ok,
@@ -35,15 +38,16 @@ use frame_support::{
 use orml_traits::MultiCurrency;
 use pallet_transaction_payment::OnChargeTransaction;
 use sp_arithmetic::FixedU128;
-use sp_runtime::AccountId32;
+use sp_runtime::{traits::SignedExtension, transaction_validity::InvalidTransaction, AccountId32};
 use std::cmp::Ordering::{Greater, Less};
-use zenlink_protocol::AssetId;
+use zenlink_protocol::{AssetId, ExportZenlink};
 
 // some common variables
 pub const CHARLIE: AccountId32 = AccountId32::new([0u8; 32]);
 
 pub const ALICE: AccountId32 = AccountId32::new([2u8; 32]);
 pub const DICK: AccountId32 = AccountId32::new([3u8; 32]);
+pub const EVE: AccountId32 = AccountId32::new([4u8; 32]);
 
 /// create a transaction info struct from weight. Handy to avoid building the whole struct.
 pub fn info() -> DispatchInfo {
@@ -259,6 +263,149 @@ fn withdraw_fee_with_universal_fee_currency() {
 	})
 }
 
+#[test]
+fn withdraw_fee_should_skip_unpriceable_currency() {
+	new_test_ext().execute_with(|| {
+		basic_setup();
+		let fee = 100 * 10u128.pow(12);
+		// VBNC has neither an oracle price nor a pool
+		assert_ok!(Currencies::deposit(VBNC, &ALICE, 1000 * 10u128.pow(12)));
+		assert_ok!(FlexibleFee::set_default_fee_currency_list(
+			RuntimeOrigin::root(),
+			BoundedVec::try_from(vec![VBNC, DOT]).unwrap()
+		));
+
+		assert_eq!(
+			FlexibleFee::withdraw_fee(&ALICE, &BALANCE_TRANSFER_CALL, &info(), fee, 0).unwrap(),
+			Some(PaymentInfo::NonNative(
+				4 * 10u128.pow(10),
+				DOT,
+				FixedU128::from_inner(200_000_000_000_000_000),
+				FixedU128::from(5)
+			))
+		);
+		assert_eq!(Currencies::free_balance(VBNC, &ALICE), 1000 * 10u128.pow(12));
+		assert_eq!(Currencies::free_balance(DOT, &ALICE), 996 * 10u128.pow(10));
+	})
+}
+
+#[test]
+fn withdraw_fee_should_fallback_to_zenlink_quote() {
+	new_test_ext().execute_with(|| {
+		basic_setup();
+		System::set_block_number(1);
+		let fee = 10u128.pow(12);
+		assert_ok!(Currencies::deposit(VBNC, &ALICE, 1000 * 10u128.pow(12)));
+		assert_ok!(Currencies::deposit(VBNC, &DICK, 1000 * 10u128.pow(12)));
+
+		let para_id: u32 = 2001;
+		let bnc_asset_id: AssetId = AssetId::try_convert_from(BNC, para_id).unwrap();
+		let vbnc_asset_id: AssetId = AssetId::try_convert_from(VBNC, para_id).unwrap();
+		assert_ok!(ZenlinkProtocol::create_pair(
+			RuntimeOrigin::root(),
+			bnc_asset_id,
+			vbnc_asset_id,
+			DICK
+		));
+		assert_ok!(ZenlinkProtocol::add_liquidity(
+			RuntimeOrigin::signed(DICK),
+			bnc_asset_id,
+			vbnc_asset_id,
+			100 * 10u128.pow(12),
+			100 * 10u128.pow(12),
+			1,
+			1,
+			<frame_system::Pallet<Test>>::block_number() + 100
+		));
+		assert_ok!(FlexibleFee::set_default_fee_currency_list(
+			RuntimeOrigin::root(),
+			BoundedVec::try_from(vec![VBNC]).unwrap()
+		));
+
+		// VBNC has no oracle price, a pool quote alone needs a minimum rate
+		assert_noop!(
+			FlexibleFee::set_min_pool_fee_rate(
+				RuntimeOrigin::signed(ALICE),
+				VBNC,
+				Some(FixedU128::from(1))
+			),
+			BadOrigin
+		);
+		assert!(FlexibleFee::withdraw_fee(&ALICE, &BALANCE_TRANSFER_CALL, &info(), fee, 0).is_err());
+		assert_ok!(FlexibleFee::set_min_pool_fee_rate(
+			RuntimeOrigin::root(),
+			VBNC,
+			Some(FixedU128::from(1))
+		));
+		System::assert_last_event(RuntimeEvent::FlexibleFee(Event::SetMinPoolFeeRate {
+			currency_id: VBNC,
+			rate: Some(FixedU128::from(1)),
+		}));
+
+		let quoted_amount =
+			<ZenlinkProtocol as ExportZenlink<AccountId32, AssetId>>::get_amount_in_by_path(
+				fee,
+				&vec![vbnc_asset_id, bnc_asset_id],
+			)
+			.unwrap()[0];
+		assert_eq!(
+			FlexibleFee::withdraw_fee(&ALICE, &BALANCE_TRANSFER_CALL, &info(), fee, 0).unwrap(),
+			Some(PaymentInfo::Quoted(quoted_amount, VBNC, fee))
+		);
+		assert_eq!(Currencies::free_balance(VBNC, &ALICE), 1000 * 10u128.pow(12) - quoted_amount);
+
+		// Half of the fee is refunded at the quoted rate
+		assert_ok!(FlexibleFee::correct_and_deposit_fee(
+			&ALICE,
+			&info(),
+			&post_info(),
+			fee / 2,
+			0,
+			Some(PaymentInfo::Quoted(quoted_amount, VBNC, fee))
+		));
+		assert_eq!(
+			Currencies::free_balance(VBNC, &ALICE),
+			1000 * 10u128.pow(12) - (quoted_amount + 1) / 2
+		);
+
+		// A pool quote below the minimum rate is raised to it
+		assert_ok!(FlexibleFee::set_min_pool_fee_rate(
+			RuntimeOrigin::root(),
+			VBNC,
+			Some(FixedU128::from(2))
+		));
+		assert_eq!(
+			FlexibleFee::withdraw_fee(&ALICE, &BALANCE_TRANSFER_CALL, &info(), fee, 0).unwrap(),
+			Some(PaymentInfo::Quoted(2 * fee, VBNC, fee))
+		);
+	})
+}
+
+#[test]
+fn withdraw_fee_should_prefer_oracle_price_to_pool_quote() {
+	new_test_ext().execute_with(|| {
+		basic_setup();
+		let fee = 10u128.pow(12);
+		assert_ok!(FlexibleFee::set_default_fee_currency_list(
+			RuntimeOrigin::root(),
+			BoundedVec::try_from(vec![KSM, DOT]).unwrap()
+		));
+
+		// The KSM oracle amount is 0.01 KSM, while the BNC/KSM pool quotes about 1 KSM
+		assert_eq!(
+			FlexibleFee::withdraw_fee(&ALICE, &BALANCE_TRANSFER_CALL, &info(), fee, 0).unwrap(),
+			Some(PaymentInfo::NonNative(
+				10u128.pow(10),
+				KSM,
+				FixedU128::from_inner(200_000_000_000_000_000),
+				FixedU128::from(20)
+			))
+		);
+		assert_eq!(Currencies::free_balance(KSM, &ALICE), 1000 * 10u128.pow(12) - 10u128.pow(10));
+		assert_eq!(Currencies::free_balance(DOT, &ALICE), 1000 * 10u128.pow(10));
+	})
+}
+
 #[test]
 fn withdraw_extra_fee() {
 	new_test_ext().execute_with(|| {
@@ -493,6 +640,34 @@ fn get_fee_currency_should_work_with_universal_fee_currency() {
 	});
 }
 
+#[test]
+fn get_fee_currency_should_skip_inactive_currency() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Currencies::deposit(BNC, &ALICE, 1u128.pow(12))); // BNC
+		assert_ok!(Currencies::deposit(DOT, &ALICE, 100u128.pow(10))); // DOT
+		assert_ok!(Currencies::deposit(VDOT, &ALICE, 100u128.pow(10))); // vDOT
+
+		let asset_order_list_vec: BoundedVec<
+			CurrencyId,
+			<Test as Config>::MaxFeeCurrencyOrderListLen,
+		> = BoundedVec::try_from(vec![VDOT, DOT, BNC]).unwrap();
+
+		assert_ok!(FlexibleFee::set_default_fee_currency_list(
+			RuntimeOrigin::root(),
+			asset_order_list_vec.clone()
+		));
+		assert_ok!(FlexibleFee::set_user_default_fee_currency(
+			RuntimeOrigin::signed(ALICE),
+			Some(VDOT)
+		));
+
+		// a deprecated currency can't pay fees, even as the default fee currency
+		bifrost_asset_registry::AssetStatuses::<Test>::insert(VDOT, AssetStatus::Deprecated);
+		let currency = FlexibleFee::get_fee_currency(&ALICE, 10u128.pow(18).into()).unwrap();
+		assert_eq!(currency, DOT);
+	});
+}
+
 #[test]
 fn get_fee_currency_should_work_with_universal_fee_currency_poor() {
 	new_test_ext().execute_with(|| {
@@ -579,3 +754,420 @@ fn cmp_with_precision_should_work_with_bnc() {
 		assert_eq!(ordering, Greater);
 	});
 }
+
+fn sponsored_calls(raw_call_names: Vec<Vec<u8>>) -> BoundedVec<RawCallName, MaxSponsoredCalls> {
+	BoundedVec::try_from(
+		raw_call_names
+			.into_iter()
+			.map(|raw_call_name| BoundedVec::try_from(raw_call_name).unwrap())
+			.collect::<Vec<_>>(),
+	)
+	.unwrap()
+}
+
+#[test]
+fn register_sponsor_policy_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+		assert_noop!(
+			FlexibleFee::register_sponsor_policy(
+				RuntimeOrigin::signed(DICK),
+				sponsored_calls(vec![]),
+				100,
+				100,
+				None
+			),
+			Error::<Test>::InvalidSponsorPolicy
+		);
+		assert_noop!(
+			FlexibleFee::register_sponsor_policy(
+				RuntimeOrigin::signed(DICK),
+				sponsored_calls(vec![vec![10]]),
+				100,
+				9,
+				None
+			),
+			Error::<Test>::InvalidSponsorPolicy
+		);
+
+		assert_ok!(FlexibleFee::register_sponsor_policy(
+			RuntimeOrigin::signed(DICK),
+			sponsored_calls(vec![vec![10]]),
+			100,
+			100,
+			Some(BoundedVec::try_from(vec![ALICE]).unwrap())
+		));
+		assert_eq!(SponsorPolicies::<Test>::get(DICK).unwrap().budget_per_user, 100);
+
+		// Replacing the policy replaces the whitelist
+		assert_ok!(FlexibleFee::register_sponsor_policy(
+			RuntimeOrigin::signed(DICK),
+			sponsored_calls(vec![vec![10, 0]]),
+			200,
+			100,
+			None
+		));
+		assert_eq!(SponsorPolicies::<Test>::get(DICK).unwrap().budget_per_user, 200);
+		assert_eq!(SponsorPolicies::<Test>::get(DICK).unwrap().whitelist, None);
+
+		assert_ok!(FlexibleFee::remove_sponsor_policy(RuntimeOrigin::signed(DICK)));
+		assert_eq!(SponsorPolicies::<Test>::get(DICK), None);
+		assert_noop!(
+			FlexibleFee::remove_sponsor_policy(RuntimeOrigin::signed(DICK)),
+			Error::<Test>::SponsorPolicyNotExist
+		);
+	});
+}
+
+#[test]
+fn charge_flexible_fee_with_sponsor_should_work() {
+	new_test_ext().execute_with(|| {
+		basic_setup();
+		System::set_block_number(1);
+		let len = 10;
+		let fee = TransactionPayment::compute_fee(len as u32, &info(), 0);
+		assert_ok!(FlexibleFee::set_default_fee_currency_list(
+			RuntimeOrigin::root(),
+			BoundedVec::try_from(vec![BNC, DOT]).unwrap()
+		));
+		assert_ok!(FlexibleFee::register_sponsor_policy(
+			RuntimeOrigin::signed(DICK),
+			sponsored_calls(vec![vec![10]]),
+			fee + fee / 2,
+			100,
+			Some(BoundedVec::try_from(vec![ALICE]).unwrap())
+		));
+		let alice_balance = Currencies::free_balance(BNC, &ALICE);
+		let dick_balance = Currencies::free_balance(BNC, &DICK);
+		let dick_dot_balance = Currencies::free_balance(DOT, &DICK);
+
+		// ALICE has no policy
+		assert_eq!(
+			ChargeFlexibleFee::<Test>::new(0, FeePreference::default(), Some(ALICE)).validate(
+				&DICK,
+				&BALANCE_TRANSFER_CALL,
+				&info(),
+				len
+			),
+			Err(InvalidTransaction::Payment.into())
+		);
+		// CHARLIE is not whitelisted
+		assert_eq!(
+			ChargeFlexibleFee::<Test>::new(0, FeePreference::default(), Some(DICK)).validate(
+				&CHARLIE,
+				&BALANCE_TRANSFER_CALL,
+				&info(),
+				len
+			),
+			Err(InvalidTransaction::Payment.into())
+		);
+
+		// The maximum fee of ALICE applies to the sponsor
+		let preference = FeePreference { currency_id: Some(DOT), max_fee: Some(fee - 1) };
+		assert_eq!(
+			ChargeFlexibleFee::<Test>::new(0, preference, Some(DICK)).pre_dispatch(
+				&ALICE,
+				&BALANCE_TRANSFER_CALL,
+				&info(),
+				len
+			),
+			Err(InvalidTransaction::Payment.into())
+		);
+
+		// The fee currency of ALICE doesn't apply to the sponsor
+		let preference = FeePreference { currency_id: Some(DOT), max_fee: None };
+		let pre = ChargeFlexibleFee::<Test>::new(0, preference, Some(DICK))
+			.pre_dispatch(&ALICE, &BALANCE_TRANSFER_CALL, &info(), len)
+			.unwrap();
+		assert_eq!(Currencies::free_balance(BNC, &ALICE), alice_balance);
+		assert_eq!(Currencies::free_balance(BNC, &DICK), dick_balance - fee);
+		assert_eq!(Currencies::free_balance(DOT, &DICK), dick_dot_balance);
+
+		assert_ok!(ChargeFlexibleFee::<Test>::post_dispatch(
+			Some(pre),
+			&info(),
+			&post_info(),
+			len,
+			&Ok(())
+		));
+		assert_eq!(SponsoredFees::<Test>::get(DICK, ALICE), fee);
+		System::assert_last_event(RuntimeEvent::FlexibleFee(Event::FeeSponsored {
+			sponsor: DICK,
+			who: ALICE,
+			fee,
+		}));
+
+		// Without naming the sponsor, ALICE pays even though being whitelisted
+		let pre = ChargeFlexibleFee::<Test>::new(0, FeePreference::default(), None)
+			.pre_dispatch(&ALICE, &BALANCE_TRANSFER_CALL, &info(), len)
+			.unwrap();
+		assert_ok!(ChargeFlexibleFee::<Test>::post_dispatch(
+			Some(pre),
+			&info(),
+			&post_info(),
+			len,
+			&Ok(())
+		));
+		assert_eq!(Currencies::free_balance(BNC, &ALICE), alice_balance - fee);
+		assert_eq!(SponsoredFees::<Test>::get(DICK, ALICE), fee);
+
+		// The budget of ALICE is used up
+		assert_eq!(
+			ChargeFlexibleFee::<Test>::new(0, FeePreference::default(), Some(DICK)).validate(
+				&ALICE,
+				&BALANCE_TRANSFER_CALL,
+				&info(),
+				len
+			),
+			Err(InvalidTransaction::Payment.into())
+		);
+
+		assert_ok!(FlexibleFee::register_sponsor_policy(
+			RuntimeOrigin::signed(DICK),
+			sponsored_calls(vec![vec![10]]),
+			1000 * fee,
+			100,
+			None
+		));
+		assert_ok!(ChargeFlexibleFee::<Test>::new(0, FeePreference::default(), Some(DICK))
+			.validate(&ALICE, &BALANCE_TRANSFER_CALL, &info(), len));
+
+		// Calls charging an extra fee are not sponsored
+		assert_ok!(FlexibleFee::set_extra_fee(
+			RuntimeOrigin::root(),
+			BoundedVec::try_from(vec![10]).unwrap(),
+			Some((BNC, 1, CHARLIE))
+		));
+		assert_eq!(
+			ChargeFlexibleFee::<Test>::new(0, FeePreference::default(), Some(DICK)).validate(
+				&ALICE,
+				&BALANCE_TRANSFER_CALL,
+				&info(),
+				len
+			),
+			Err(InvalidTransaction::Payment.into())
+		);
+		assert_ok!(FlexibleFee::set_extra_fee(
+			RuntimeOrigin::root(),
+			BoundedVec::try_from(vec![10]).unwrap(),
+			None
+		));
+
+		// The policy is expired
+		System::set_block_number(101);
+		assert_eq!(
+			ChargeFlexibleFee::<Test>::new(0, FeePreference::default(), Some(DICK)).validate(
+				&ALICE,
+				&BALANCE_TRANSFER_CALL,
+				&info(),
+				len
+			),
+			Err(InvalidTransaction::Payment.into())
+		);
+	});
+}
+
+#[test]
+fn charge_flexible_fee_should_not_sponsor_signer_without_account() {
+	new_test_ext().execute_with(|| {
+		basic_setup();
+		System::set_block_number(1);
+		let len = 10;
+		assert_ok!(FlexibleFee::register_sponsor_policy(
+			RuntimeOrigin::signed(DICK),
+			sponsored_calls(vec![vec![10]]),
+			1000 * TransactionPayment::compute_fee(len as u32, &info(), 0),
+			100,
+			None
+		));
+		assert!(!System::account_exists(&EVE));
+		let dick_balance = Currencies::free_balance(BNC, &DICK);
+
+		// The sponsor doesn't provide EVE an account, which would only be kept by the nonce
+		let extra = (
+			ChargeFlexibleFee::<Test>::new(0, FeePreference::default(), Some(DICK)),
+			frame_system::CheckNonce::<Test>::from(0),
+		);
+		assert_eq!(
+			extra.validate(&EVE, &BALANCE_TRANSFER_CALL, &info(), len),
+			Err(InvalidTransaction::Payment.into())
+		);
+		assert_eq!(
+			extra.pre_dispatch(&EVE, &BALANCE_TRANSFER_CALL, &info(), len),
+			Err(InvalidTransaction::Payment.into())
+		);
+		assert!(!System::account_exists(&EVE));
+		assert_eq!(Currencies::free_balance(BNC, &DICK), dick_balance);
+	});
+}
+
+#[test]
+fn withdraw_fee_with_fee_preference_should_work() {
+	new_test_ext().execute_with(|| {
+		basic_setup();
+		let fee = 100 * 10u128.pow(12);
+		assert_ok!(FlexibleFee::set_default_fee_currency_list(
+			RuntimeOrigin::root(),
+			BoundedVec::try_from(vec![BNC, DOT, VBNC]).unwrap()
+		));
+
+		// Pay this transaction in DOT instead of BNC
+		let preference = FeePreference { currency_id: Some(DOT), max_fee: None };
+		assert_eq!(
+			FlexibleFee::withdraw_fee_by_preference(
+				&ALICE,
+				&BALANCE_TRANSFER_CALL,
+				fee,
+				preference
+			)
+			.unwrap(),
+			Some(PaymentInfo::NonNative(
+				4 * 10u128.pow(10),
+				DOT,
+				FixedU128::from_inner(200_000_000_000_000_000),
+				FixedU128::from(5)
+			))
+		);
+		assert_eq!(Currencies::free_balance(BNC, &ALICE), 1000 * 10u128.pow(12));
+		assert_eq!(Currencies::free_balance(DOT, &ALICE), 996 * 10u128.pow(10));
+
+		// The fee exceeds the maximum
+		let preference =
+			FeePreference { currency_id: Some(DOT), max_fee: Some(3 * 10u128.pow(10)) };
+		assert!(FlexibleFee::withdraw_fee_by_preference(
+			&ALICE,
+			&BALANCE_TRANSFER_CALL,
+			fee,
+			preference
+		)
+		.is_err());
+
+		// The preferred currency can't be priced, other currencies are not tried
+		let preference = FeePreference { currency_id: Some(VBNC), max_fee: None };
+		assert!(FlexibleFee::withdraw_fee_by_preference(
+			&ALICE,
+			&BALANCE_TRANSFER_CALL,
+			fee,
+			preference
+		)
+		.is_err());
+
+		// The preferred currency is not in the universal fee currency order list
+		assert_eq!(
+			FlexibleFee::get_fee_currency_and_fee_amount_by_preference(
+				&ALICE,
+				fee,
+				FeePreference { currency_id: Some(KSM), max_fee: None }
+			),
+			Err(Error::<Test>::FeeCurrencyNotAllowed)
+		);
+
+		// Without a preference the order list is used
+		assert_eq!(
+			FlexibleFee::withdraw_fee(&ALICE, &BALANCE_TRANSFER_CALL, &info(), fee, 0).unwrap(),
+			Some(PaymentInfo::Native(fee))
+		);
+		assert_eq!(Currencies::free_balance(BNC, &ALICE), 900 * 10u128.pow(12));
+	});
+}
+
+#[test]
+fn charge_flexible_fee_with_fee_preference_should_work() {
+	new_test_ext().execute_with(|| {
+		basic_setup();
+		System::set_block_number(1);
+		let len = 10;
+		let fee = TransactionPayment::compute_fee(len as u32, &info(), 0);
+		assert_ok!(FlexibleFee::set_default_fee_currency_list(
+			RuntimeOrigin::root(),
+			BoundedVec::try_from(vec![BNC, DOT]).unwrap()
+		));
+		let alice_balance = Currencies::free_balance(BNC, &ALICE);
+		let alice_dot_balance = Currencies::free_balance(DOT, &ALICE);
+
+		// The maximum fee is checked during pool validation
+		let preference = FeePreference { currency_id: Some(DOT), max_fee: Some(0) };
+		assert_eq!(
+			ChargeFlexibleFee::<Test>::new(0, preference, None).validate(
+				&ALICE,
+				&BALANCE_TRANSFER_CALL,
+				&info(),
+				len
+			),
+			Err(InvalidTransaction::Payment.into())
+		);
+
+		let preference = FeePreference { currency_id: Some(DOT), max_fee: None };
+		let pre = ChargeFlexibleFee::<Test>::new(0, preference, None)
+			.pre_dispatch(&ALICE, &BALANCE_TRANSFER_CALL, &info(), len)
+			.unwrap();
+		assert_ok!(ChargeFlexibleFee::<Test>::post_dispatch(
+			Some(pre),
+			&info(),
+			&post_info(),
+			len,
+			&Ok(())
+		));
+		assert_eq!(Currencies::free_balance(BNC, &ALICE), alice_balance);
+		assert!(Currencies::free_balance(DOT, &ALICE) < alice_dot_balance);
+		System::assert_last_event(RuntimeEvent::TransactionPayment(
+			pallet_transaction_payment::Event::TransactionFeePaid {
+				who: ALICE,
+				actual_fee: fee,
+				tip: 0,
+			},
+		));
+	});
+}
+
+#[test]
+fn cal_fee_token_and_amount_with_fee_preference_should_work() {
+	new_test_ext().execute_with(|| {
+		basic_setup();
+		let fee = 100 * 10u128.pow(12);
+		assert_ok!(FlexibleFee::set_default_fee_currency_list(
+			RuntimeOrigin::root(),
+			BoundedVec::try_from(vec![BNC, DOT]).unwrap()
+		));
+
+		assert_eq!(
+			FlexibleFee::cal_fee_token_and_amount(
+				&ALICE,
+				fee,
+				&BALANCE_TRANSFER_CALL,
+				FeePreference::default()
+			),
+			Ok((BNC, fee))
+		);
+		assert_eq!(
+			FlexibleFee::cal_fee_token_and_amount(
+				&ALICE,
+				fee,
+				&BALANCE_TRANSFER_CALL,
+				FeePreference { currency_id: Some(DOT), max_fee: Some(4 * 10u128.pow(10)) }
+			),
+			Ok((DOT, 4 * 10u128.pow(10)))
+		);
+		assert!(FlexibleFee::cal_fee_token_and_amount(
+			&ALICE,
+			fee,
+			&BALANCE_TRANSFER_CALL,
+			FeePreference { currency_id: Some(DOT), max_fee: Some(10u128.pow(10)) }
+		)
+		.is_err());
+	});
+}
+
+#[test]
+fn fee_preference_of_should_find_charge_flexible_fee() {
+	let preference = FeePreference { currency_id: Some(DOT), max_fee: Some(10u128.pow(10)) };
+	let extra = (
+		frame_system::CheckWeight::<Test>::new(),
+		ChargeFlexibleFee::<Test>::new(0, preference, None),
+	);
+	assert_eq!(FeePreferenceOf::<Test>::fee_preference(&extra), Some(preference));
+
+	let extra =
+		(frame_system::CheckWeight::<Test>::new(), frame_system::CheckNonZeroSender::<Test>::new());
+	assert_eq!(FeePreferenceOf::<Test>::fee_preference(&extra), None);
+}
@@ -25,8 +25,10 @@
 use core::cmp::max;
 
 pub use crate::rate_model::*;
+use bifrost_asset_registry::AssetMetadata;
 use bifrost_primitives::{
-	Balance, CurrencyId, Liquidity, OraclePriceProvider, Price, Rate, Ratio, Shortfall, Timestamp,
+	Balance, CurrencyId, CurrencyIdMapping, Liquidity, OraclePriceProvider, Price, Rate, Ratio,
+	Shortfall, Timestamp,
 };
 use frame_support::{
 	pallet_prelude::*,
@@ -43,7 +45,7 @@ use num_traits::cast::ToPrimitive;
 pub use pallet::*;
 use pallet_traits::{
 	ConvertToBigUint, LendMarket as LendMarketTrait, LendMarketMarketDataProvider,
-	LendMarketPositionDataProvider, MarketInfo, MarketStatus,
+	LendMarketPositionDataProvider, MarketInfo, MarketStatus, PriceCircuitBreaker,
 };
 use sp_core::bounded::BoundedVec;
 use sp_runtime::{
@@ -59,6 +61,7 @@ use log;
 use sp_io::hashing::blake2_256;
 pub use types::{BorrowSnapshot, Deposits, EarnedSnapshot, Market, MarketState, RewardMarketState};
 pub use weights::WeightInfo;
+use xcm::v3::MultiLocation;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
@@ -98,6 +101,9 @@ pub mod pallet {
 		/// The oracle price feeder
 		type OraclePriceProvider: OraclePriceProvider;
 
+		/// Borrowing of an asset is paused while its price circuit breaker is tripped
+		type PriceCircuitBreaker: PriceCircuitBreaker<CurrencyId>;
+
 		/// The loan's module id, keep all collaterals of CDPs.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -128,6 +134,10 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type MaxLengthLimit: Get<u32>;
+
+		/// Asset registry to check the status of market assets. Deprecated assets can only be
+		/// redeemed and repaid.
+		type AssetIdMaps: CurrencyIdMapping<CurrencyId, MultiLocation, AssetMetadata<Balance>>;
 	}
 
 	#[pallet::error]
@@ -194,6 +204,12 @@ pub mod pallet {
 		MarketBondDoesNotExist,
 		/// Error converting Vec to BoundedVec.
 		ConversionError,
+		/// Borrowing is paused by the price circuit breaker
+		PriceCircuitBreakerTripped,
+		/// The asset isn't active, no new positions can be opened with it
+		AssetNotActive,
+		/// The asset can't be transferred
+		AssetNotTransferable,
 	}
 
 	#[pallet::event]
@@ -928,6 +944,9 @@ pub mod pallet {
 			if deposits.is_collateral == enable {
 				return Err(Error::<T>::DuplicateOperation.into());
 			}
+			if enable {
+				Self::ensure_asset_active(asset_id)?;
+			}
 
 			Self::do_collateral_asset(&who, asset_id, enable)?;
 
@@ -1818,6 +1837,21 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Ensures new positions can be opened with `asset_id`.
+	fn ensure_asset_active(asset_id: AssetIdOf<T>) -> DispatchResult {
+		ensure!(T::AssetIdMaps::get_asset_status(asset_id).is_active(), Error::<T>::AssetNotActive);
+		Ok(())
+	}
+
+	/// Ensures positions in `asset_id` can be closed, which deprecated assets still allow.
+	fn ensure_asset_transferable(asset_id: AssetIdOf<T>) -> DispatchResult {
+		ensure!(
+			T::AssetIdMaps::get_asset_status(asset_id).is_transferable(),
+			Error::<T>::AssetNotTransferable
+		);
+		Ok(())
+	}
+
 	// Ensures a given `asset_id` is an active market.
 	fn ensure_active_market(asset_id: AssetIdOf<T>) -> Result<Market<BalanceOf<T>>, DispatchError> {
 		Self::active_markets()
@@ -2051,6 +2085,7 @@ impl<T: Config> Pallet<T> {
 		asset_id: AssetIdOf<T>,
 	) -> Result<BalanceOf<T>, DispatchError> {
 		Self::ensure_active_market(asset_id)?;
+		Self::ensure_asset_transferable(asset_id)?;
 		Self::accrue_interest(asset_id)?;
 		let exchange_rate = Self::exchange_rate_stored(asset_id)?;
 		Self::update_earned_stored(&who, asset_id, exchange_rate)?;
@@ -2069,6 +2104,7 @@ impl<T: Config> LendMarketTrait<AssetIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for
 	) -> Result<(), DispatchError> {
 		ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
 		Self::ensure_active_market(asset_id)?;
+		Self::ensure_asset_active(asset_id)?;
 		Self::ensure_under_supply_cap(asset_id, amount)?;
 
 		Self::accrue_interest(asset_id)?;
@@ -2112,6 +2148,11 @@ impl<T: Config> LendMarketTrait<AssetIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for
 		amount: BalanceOf<T>,
 	) -> Result<(), DispatchError> {
 		Self::ensure_active_market(asset_id)?;
+		Self::ensure_asset_active(asset_id)?;
+		ensure!(
+			!T::PriceCircuitBreaker::is_circuit_breaker_tripped(&asset_id),
+			Error::<T>::PriceCircuitBreakerTripped
+		);
 
 		Self::accrue_interest(asset_id)?;
 		Self::borrow_allowed_for_market_bond(asset_id, borrower, amount)?;
@@ -2194,6 +2235,7 @@ impl<T: Config> LendMarketTrait<AssetIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for
 		amount: BalanceOf<T>,
 	) -> Result<(), DispatchError> {
 		Self::ensure_active_market(asset_id)?;
+		Self::ensure_asset_transferable(asset_id)?;
 		Self::accrue_interest(asset_id)?;
 		let account_borrows = Self::current_borrow_balance(borrower, asset_id)?;
 		Self::do_repay_borrow_with_amount(borrower, asset_id, account_borrows, amount)?;
@@ -2207,6 +2249,7 @@ impl<T: Config> LendMarketTrait<AssetIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for
 		amount: BalanceOf<T>,
 	) -> Result<(), DispatchError> {
 		Self::ensure_active_market(asset_id)?;
+		Self::ensure_asset_transferable(asset_id)?;
 		Self::accrue_interest(asset_id)?;
 		let exchange_rate = Self::exchange_rate_stored(asset_id)?;
 		Self::update_earned_stored(supplier, asset_id, exchange_rate)?;
@@ -21,11 +21,11 @@ use bifrost_asset_registry::AssetIdMaps;
 pub use bifrost_primitives::{currency::*, *};
 use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
-	traits::{AsEnsureOriginWithArg, Nothing, SortedMembers},
+	traits::{AsEnsureOriginWithArg, Everything, Nothing, SortedMembers},
 };
 use frame_system::{EnsureRoot, EnsureSigned, EnsureSignedBy};
 use orml_traits::{DataFeeder, DataProvider, DataProviderExtended};
-use sp_runtime::{traits::IdentityLookup, AccountId32};
+use sp_runtime::{traits::IdentityLookup, AccountId32, Permill};
 use sp_std::vec::Vec;
 use std::{
 	cell::RefCell,
@@ -150,6 +150,7 @@ pub type AdaptedBasicCurrency =
 
 impl bifrost_currencies::Config for Test {
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type WeightInfo = ();
@@ -294,6 +295,24 @@ impl pallet_assets::Config for Test {
 	type CallbackHandle = ();
 }
 
+parameter_types! {
+	pub const MaxVtokenPriceDeviation: Permill = Permill::from_percent(10);
+	pub const TwapPeriod: u64 = 60_000;
+	pub const MaxPriceConfigs: u32 = 10;
+	pub const MaxSnapshotAssets: u32 = 10;
+	pub const MaxPriceSnapshots: u32 = 10;
+	pub const PriceSnapshotMaxAge: BlockNumber = 100;
+	pub static TrippedPriceCircuitBreakers: Vec<CurrencyId> = vec![];
+}
+
+pub struct MockPriceCircuitBreaker;
+
+impl PriceCircuitBreaker<CurrencyId> for MockPriceCircuitBreaker {
+	fn is_circuit_breaker_tripped(asset_id: &CurrencyId) -> bool {
+		TrippedPriceCircuitBreakers::get().contains(asset_id)
+	}
+}
+
 impl pallet_prices::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Source = MockDataProvider;
@@ -302,6 +321,16 @@ impl pallet_prices::Config for Test {
 	type RelayCurrency = RelayCurrency;
 	type Assets = Currencies;
 	type CurrencyIdConvert = AssetIdMaps<Test>;
+	type VtokenMinting = ();
+	type MaxVtokenPriceDeviation = MaxVtokenPriceDeviation;
+	type Time = TimestampPallet;
+	type StablePoolHandler = ();
+	type XcmPriceOrigin = frame_support::traits::NeverEnsureOrigin<xcm::v4::Location>;
+	type TwapPeriod = TwapPeriod;
+	type MaxPriceConfigs = MaxPriceConfigs;
+	type MaxSnapshotAssets = MaxSnapshotAssets;
+	type MaxPriceSnapshots = MaxPriceSnapshots;
+	type PriceSnapshotMaxAge = PriceSnapshotMaxAge;
 	type WeightInfo = ();
 }
 
@@ -314,6 +343,7 @@ parameter_types! {
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type OraclePriceProvider = MockOraclePriceProvider;
+	type PriceCircuitBreaker = MockPriceCircuitBreaker;
 	type PalletId = LendMarketPalletId;
 	type ReserveOrigin = EnsureRoot<AccountId>;
 	type UpdateOrigin = EnsureRoot<AccountId>;
@@ -323,6 +353,7 @@ impl Config for Test {
 	type RewardAssetId = RewardAssetId;
 	type LiquidationFreeAssetId = LiquidationFreeAssetId;
 	type MaxLengthLimit = MaxLengthLimit;
+	type AssetIdMaps = AssetIdMaps<Test>;
 }
 
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
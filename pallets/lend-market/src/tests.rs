@@ -742,6 +742,63 @@ fn borrow_works() {
 	})
 }
 
+#[test]
+fn borrow_paused_by_price_circuit_breaker() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LendMarket::add_market_bond(
+			RuntimeOrigin::root(),
+			DOT,
+			vec![DOT, BNC, KSM, DOT_U, PHA]
+		));
+		// Deposit 200 DOT as collateral
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT, unit(200)));
+		assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), DOT, true));
+
+		TrippedPriceCircuitBreakers::set(vec![DOT]);
+		assert_noop!(
+			LendMarket::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(100)),
+			Error::<Test>::PriceCircuitBreakerTripped
+		);
+
+		// borrowing resumes once the circuit breaker is reset
+		TrippedPriceCircuitBreakers::set(vec![]);
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(100)));
+		assert_eq!(AccountBorrows::<Test>::get(DOT, ALICE).principal, unit(100));
+	})
+}
+
+#[test]
+fn deprecated_asset_can_only_be_redeemed_and_repaid() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LendMarket::add_market_bond(
+			RuntimeOrigin::root(),
+			DOT,
+			vec![DOT, BNC, KSM, DOT_U, PHA]
+		));
+		// Deposit 200 DOT as collateral and borrow 50 DOT
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT, unit(200)));
+		assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), DOT, true));
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(50)));
+
+		bifrost_asset_registry::AssetStatuses::<Test>::insert(DOT, AssetStatus::Deprecated);
+		assert_noop!(
+			LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT, unit(100)),
+			Error::<Test>::AssetNotActive
+		);
+		assert_noop!(
+			LendMarket::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(50)),
+			Error::<Test>::AssetNotActive
+		);
+		assert_ok!(LendMarket::repay_borrow(RuntimeOrigin::signed(ALICE), DOT, unit(50)));
+		assert_ok!(LendMarket::redeem(RuntimeOrigin::signed(ALICE), DOT, unit(100)));
+
+		bifrost_asset_registry::AssetStatuses::<Test>::insert(DOT, AssetStatus::Frozen);
+		assert_noop!(
+			LendMarket::redeem(RuntimeOrigin::signed(ALICE), DOT, unit(100)),
+			Error::<Test>::AssetNotTransferable
+		);
+	})
+}
 #[test]
 fn lf_borrow_works() {
 	new_test_ext().execute_with(|| {
@@ -25,19 +25,25 @@ pub use bifrost_primitives::{
 use bifrost_primitives::{
 	BifrostEntranceAccount, BifrostExitAccount, IncentivePoolAccount, LendMarketPalletId, Moment,
 	MoonbeamChainId, OraclePriceProvider, Price, PriceDetail, Ratio, StableAssetPalletId,
+	ZenlinkPalletId,
 };
 use bifrost_runtime_common::milli;
+use cumulus_primitives_core::ParaId;
 use frame_support::{
 	derive_impl, ord_parameter_types, parameter_types,
-	traits::{ConstU128, ConstU32, Everything, Nothing},
+	traits::{ConstU128, ConstU32, Everything, Get, Nothing},
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use lend_market::{InterestRateModel, JumpModel, Market, MarketState};
 use orml_traits::{
 	location::RelativeReserveProvider, parameter_type_with_key, DataFeeder, DataProvider,
-	DataProviderExtended,
+	DataProviderExtended, MultiCurrency,
 };
-use sp_runtime::{traits::IdentityLookup, BuildStorage, FixedPointNumber};
+use sp_runtime::{
+	traits::{IdentityLookup, UniqueSaturatedInto},
+	BuildStorage, DispatchError, DispatchResult, FixedPointNumber, Permill, SaturatedConversion,
+};
+use sp_std::marker::PhantomData;
 use std::{
 	cell::RefCell,
 	collections::HashMap,
@@ -46,6 +52,9 @@ use std::{
 use xcm::{prelude::*, v3::Weight};
 use xcm_builder::{FixedWeightBounds, FrameTransactionalProcessor};
 use xcm_executor::XcmExecutor;
+use zenlink_protocol::{
+	AssetBalance, AssetId as ZenlinkAssetId, LocalAssetHandler, PairLpGenerate, ZenlinkMultiAssets,
+};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -66,6 +75,7 @@ frame_support::construct_runtime!(
 		TimestampPallet: pallet_timestamp::{Pallet, Call, Storage, Inherent},
 		LeverageStaking: leverage_staking::{Pallet, Storage, Call, Event<T>},
 		Prices: pallet_prices::{Pallet, Storage, Call, Event<T>},
+		ZenlinkProtocol: zenlink_protocol,
 		// PolkadotXcm: pallet_xcm,
 	}
 );
@@ -124,6 +134,7 @@ pub type AdaptedBasicCurrency =
 
 impl bifrost_currencies::Config for Test {
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type WeightInfo = ();
@@ -263,6 +274,97 @@ impl bifrost_stable_pool::Config for Test {
 	type VtokenMinting = VtokenMinting;
 	type CurrencyIdConversion = AssetIdMaps<Test>;
 	type CurrencyIdRegister = AssetIdMaps<Test>;
+	type DexOperator = ZenlinkProtocol;
+	type ParachainId = ParachainId;
+	type MaxSwapHops = ConstU32<3>;
+	type MaxRouterPools = ConstU32<10>;
+	type OraclePriceProvider = MockOraclePriceProvider;
+	type LendMarket = LendMarket;
+	type Farming = ();
+	type AssetIdMaps = AssetIdMaps<Test>;
+}
+
+pub struct ParachainId;
+impl Get<ParaId> for ParachainId {
+	fn get() -> ParaId {
+		2001.into()
+	}
+}
+
+parameter_types! {
+	pub const SelfParaId: u32 = 2001;
+}
+
+impl zenlink_protocol::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type MultiAssetsHandler = MultiAssets;
+	type PalletId = ZenlinkPalletId;
+	type SelfParaId = SelfParaId;
+	type TargetChains = ();
+	type WeightInfo = ();
+	type AssetId = ZenlinkAssetId;
+	type LpGenerate = PairLpGenerate<Self>;
+}
+
+type MultiAssets = ZenlinkMultiAssets<ZenlinkProtocol, Balances, LocalAssetAdaptor<Currencies>>;
+
+// Below is the implementation of tokens manipulation functions other than native token.
+pub struct LocalAssetAdaptor<Local>(PhantomData<Local>);
+
+impl<Local, AccountId> LocalAssetHandler<AccountId> for LocalAssetAdaptor<Local>
+where
+	Local: MultiCurrency<AccountId, CurrencyId = CurrencyId>,
+{
+	fn local_balance_of(asset_id: ZenlinkAssetId, who: &AccountId) -> AssetBalance {
+		let currency_id: CurrencyId = asset_id.try_into().unwrap();
+		Local::free_balance(currency_id, &who).saturated_into()
+	}
+
+	fn local_total_supply(asset_id: ZenlinkAssetId) -> AssetBalance {
+		let currency_id: CurrencyId = asset_id.try_into().unwrap();
+		Local::total_issuance(currency_id).saturated_into()
+	}
+
+	fn local_is_exists(asset_id: ZenlinkAssetId) -> bool {
+		let rs: Result<CurrencyId, _> = asset_id.try_into();
+		match rs {
+			Ok(_) => true,
+			Err(_) => false,
+		}
+	}
+
+	fn local_transfer(
+		asset_id: ZenlinkAssetId,
+		origin: &AccountId,
+		target: &AccountId,
+		amount: AssetBalance,
+	) -> DispatchResult {
+		let currency_id: CurrencyId = asset_id.try_into().unwrap();
+		Local::transfer(currency_id, &origin, &target, amount.unique_saturated_into())?;
+
+		Ok(())
+	}
+
+	fn local_deposit(
+		asset_id: ZenlinkAssetId,
+		origin: &AccountId,
+		amount: AssetBalance,
+	) -> Result<AssetBalance, DispatchError> {
+		let currency_id: CurrencyId = asset_id.try_into().unwrap();
+		Local::deposit(currency_id, &origin, amount.unique_saturated_into())?;
+		return Ok(amount);
+	}
+
+	fn local_withdraw(
+		asset_id: ZenlinkAssetId,
+		origin: &AccountId,
+		amount: AssetBalance,
+	) -> Result<AssetBalance, DispatchError> {
+		let currency_id: CurrencyId = asset_id.try_into().unwrap();
+		Local::withdraw(currency_id, &origin, amount.unique_saturated_into())?;
+
+		Ok(amount)
+	}
 }
 
 impl leverage_staking::Config for Test {
@@ -439,6 +541,7 @@ parameter_types! {
 impl lend_market::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type OraclePriceProvider = MockOraclePriceProvider;
+	type PriceCircuitBreaker = ();
 	type PalletId = LendMarketPalletId;
 	type ReserveOrigin = EnsureRoot<u128>;
 	type UpdateOrigin = EnsureRoot<u128>;
@@ -448,6 +551,16 @@ impl lend_market::Config for Test {
 	type RewardAssetId = RewardAssetId;
 	type LiquidationFreeAssetId = LiquidationFreeAssetId;
 	type MaxLengthLimit = MaxLengthLimit;
+	type AssetIdMaps = AssetIdMaps<Test>;
+}
+
+parameter_types! {
+	pub const MaxVtokenPriceDeviation: Permill = Permill::from_percent(10);
+	pub const TwapPeriod: u64 = 60_000;
+	pub const MaxPriceConfigs: u32 = 10;
+	pub const MaxSnapshotAssets: u32 = 10;
+	pub const MaxPriceSnapshots: u32 = 10;
+	pub const PriceSnapshotMaxAge: BlockNumber = 100;
 }
 
 impl pallet_prices::Config for Test {
@@ -458,6 +571,16 @@ impl pallet_prices::Config for Test {
 	type RelayCurrency = RelayCurrencyId;
 	type Assets = Currencies;
 	type CurrencyIdConvert = AssetIdMaps<Test>;
+	type VtokenMinting = ();
+	type MaxVtokenPriceDeviation = MaxVtokenPriceDeviation;
+	type Time = TimestampPallet;
+	type StablePoolHandler = ();
+	type XcmPriceOrigin = frame_support::traits::NeverEnsureOrigin<xcm::v4::Location>;
+	type TwapPeriod = TwapPeriod;
+	type MaxPriceConfigs = MaxPriceConfigs;
+	type MaxSnapshotAssets = MaxSnapshotAssets;
+	type MaxPriceSnapshots = MaxPriceSnapshots;
+	type PriceSnapshotMaxAge = PriceSnapshotMaxAge;
 	type WeightInfo = ();
 }
 
@@ -0,0 +1,33 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use bifrost_primitives::{CurrencyId, PriceDetail, PriceSnapshot};
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait PricesApi<BlockNumber> where
+		BlockNumber: Codec {
+		fn get_price(asset_id: CurrencyId) -> Option<PriceDetail>;
+		fn get_emergency_price(asset_id: CurrencyId) -> Option<PriceDetail>;
+		fn get_derived_price(asset_id: CurrencyId) -> Option<PriceDetail>;
+		fn get_price_history(asset_id: CurrencyId, from: BlockNumber, to: BlockNumber) -> Vec<PriceSnapshot<BlockNumber>>;
+		fn get_price_at(asset_id: CurrencyId, block_number: BlockNumber) -> Option<PriceSnapshot<BlockNumber>>;
+	}
+}
@@ -0,0 +1,180 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use std::sync::Arc;
+
+pub use pallet_prices_rpc_runtime_api::PricesApi as PricesRuntimeApi;
+
+use bifrost_primitives::{CurrencyId, PriceDetail, PriceSnapshot};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::ErrorObject,
+};
+use parity_scale_codec::Codec;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+#[rpc(client, server)]
+pub trait PricesApi<BlockHash, BlockNumber> {
+	#[method(name = "prices_getPrice")]
+	fn get_price(
+		&self,
+		asset_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<PriceDetail>>;
+	#[method(name = "prices_getEmergencyPrice")]
+	fn get_emergency_price(
+		&self,
+		asset_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<PriceDetail>>;
+	#[method(name = "prices_getDerivedPrice")]
+	fn get_derived_price(
+		&self,
+		asset_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<PriceDetail>>;
+	#[method(name = "prices_getPriceHistory")]
+	fn get_price_history(
+		&self,
+		asset_id: CurrencyId,
+		from: BlockNumber,
+		to: BlockNumber,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<PriceSnapshot<BlockNumber>>>;
+	#[method(name = "prices_getPriceAt")]
+	fn get_price_at(
+		&self,
+		asset_id: CurrencyId,
+		block_number: BlockNumber,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<PriceSnapshot<BlockNumber>>>;
+}
+
+/// A struct that implements the [`PricesApi`].
+pub struct Prices<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Prices<C, B> {
+	/// Create new `Prices` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+pub enum Error {
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+#[async_trait]
+impl<C, Block, BlockNumber> PricesApiServer<<Block as BlockT>::Hash, BlockNumber>
+	for Prices<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static,
+	C: ProvideRuntimeApi<Block>,
+	C: HeaderBackend<Block>,
+	C::Api: PricesRuntimeApi<Block, BlockNumber>,
+	BlockNumber: Codec,
+{
+	fn get_price(
+		&self,
+		asset_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<PriceDetail>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		);
+		api.get_price(at, asset_id).map_err(runtime_error_into_rpc_error)
+	}
+
+	fn get_emergency_price(
+		&self,
+		asset_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<PriceDetail>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		);
+		api.get_emergency_price(at, asset_id).map_err(runtime_error_into_rpc_error)
+	}
+
+	fn get_derived_price(
+		&self,
+		asset_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<PriceDetail>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		);
+		api.get_derived_price(at, asset_id).map_err(runtime_error_into_rpc_error)
+	}
+
+	fn get_price_history(
+		&self,
+		asset_id: CurrencyId,
+		from: BlockNumber,
+		to: BlockNumber,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<PriceSnapshot<BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		);
+		api.get_price_history(at, asset_id, from, to)
+			.map_err(runtime_error_into_rpc_error)
+	}
+
+	fn get_price_at(
+		&self,
+		asset_id: CurrencyId,
+		block_number: BlockNumber,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<PriceSnapshot<BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		);
+		api.get_price_at(at, asset_id, block_number)
+			.map_err(runtime_error_into_rpc_error)
+	}
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_error(err: impl std::fmt::Debug) -> ErrorObject<'static> {
+	ErrorObject::owned(Error::RuntimeError.into(), "Runtime trapped", Some(format!("{:?}", err)))
+}
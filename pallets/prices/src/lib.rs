@@ -19,23 +19,41 @@
 //! This pallet provides the price from Oracle Module by implementing the
 //! `OraclePriceProvider` trait. In case of emergency, the price can be set directly
 //! by Oracle Collective.
+//!
+//! The price of a vToken is derived from the price of its token and the exchange rate of
+//! vtoken-minting. If the vToken also has a direct feed, the derived price is only used
+//! while the two stay within `MaxVtokenPriceDeviation`.
+//!
+//! For an asset with a `PriceConfig`, the price is the median of the oracle, the TWAP of a
+//! stable pool and a price delivered through XCM. The price is aggregated every block, and a
+//! circuit breaker trips if the sources are stale, too few or the price moves too much. The
+//! last accepted price is kept until the circuit breaker is reset by governance, and
+//! lend-market pauses borrowing of the asset in the meantime.
+//!
+//! Snapshots of the price of an asset can be taken at an interval set by governance. They
+//! are kept in a bounded buffer per asset, pruned by `PriceSnapshotMaxAge`, and are
+//! queried through the `PricesApi` runtime API.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use bifrost_asset_registry::AssetMetadata;
 use bifrost_primitives::{
-	Balance, CurrencyId, CurrencyIdMapping, OraclePriceProvider, Price, PriceDetail,
-	TimeStampedPrice, TokenInfo,
+	Balance, CurrencyId, CurrencyIdMapping, Moment, OraclePriceProvider, Price, PriceDetail,
+	PriceSnapshot, TimeStampedPrice, TokenInfo, VtokenMintingInterface,
 };
-use frame_support::{dispatch::DispatchClass, pallet_prelude::*, transactional};
+use bifrost_stable_pool::traits::StablePoolHandler;
+use frame_support::{dispatch::DispatchClass, pallet_prelude::*, traits::Time, transactional};
 use frame_system::pallet_prelude::*;
 use log;
 use orml_oracle::{DataFeeder, DataProvider, DataProviderExtended};
 pub use pallet::*;
 use pallet_traits::*;
-use sp_runtime::{traits::CheckedDiv, FixedU128};
+use sp_runtime::{
+	traits::{CheckedDiv, CheckedMul, One, Saturating, Zero},
+	FixedPointNumber, FixedU128, Permill,
+};
 use sp_std::vec::Vec;
-use xcm::v3::MultiLocation;
+use xcm::{v3::MultiLocation, v4::Location};
 
 #[cfg(test)]
 mod mock;
@@ -44,6 +62,38 @@ mod tests;
 
 pub mod weights;
 
+/// The bounds the price of an asset is aggregated within.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PriceConfig {
+	/// The max age of a source price, in milliseconds.
+	pub max_age: Moment,
+	/// The max deviation of an aggregated price from the last accepted one.
+	pub max_deviation: Permill,
+	/// The min number of fresh sources to aggregate a price from.
+	pub min_sources: u32,
+	/// The asset paired in a stable pool to derive a TWAP against.
+	pub twap_base: Option<CurrencyId>,
+	/// The location allowed to deliver the price through XCM.
+	pub xcm_source: Option<Location>,
+}
+
+impl PriceConfig {
+	/// The number of sources enabled by the config, the oracle is always enabled.
+	pub fn source_count(&self) -> u32 {
+		1 + self.twap_base.is_some() as u32 + self.xcm_source.is_some() as u32
+	}
+}
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum CircuitBreakerReason {
+	/// Stale prices left fewer fresh sources than required.
+	Stale,
+	/// The aggregated price deviated too much from the last accepted one.
+	Deviation,
+	/// Fewer sources than required provide a price.
+	InsufficientSources,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -85,6 +135,45 @@ pub mod pallet {
 			AssetMetadata<BalanceOf<Self>>,
 		>;
 
+		/// Provides the exchange rate between tokens and vTokens.
+		type VtokenMinting: VtokenMintingInterface<Self::AccountId, CurrencyId, Balance>;
+
+		/// The max deviation of a derived vToken price from its direct feed.
+		#[pallet::constant]
+		type MaxVtokenPriceDeviation: Get<Permill>;
+
+		/// Time used to check the age of prices.
+		type Time: Time<Moment = Moment>;
+
+		/// Quotes the stable pools a TWAP is derived from.
+		type StablePoolHandler: StablePoolHandler<Balance = Balance, CurrencyId = CurrencyId>;
+
+		/// The origin which may deliver prices through XCM.
+		type XcmPriceOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Location>;
+
+		/// The period the TWAP of a stable pool is averaged over, in milliseconds. The TWAP is an
+		/// exponential moving average of the end of block spot prices, each weighted by the time
+		/// elapsed since the last one, so a spot price held for a single block moves the TWAP by
+		/// at most `elapsed / TwapPeriod` of the way.
+		#[pallet::constant]
+		type TwapPeriod: Get<Moment>;
+
+		/// The max number of assets with a price config.
+		#[pallet::constant]
+		type MaxPriceConfigs: Get<u32>;
+
+		/// The max number of assets with price snapshots.
+		#[pallet::constant]
+		type MaxSnapshotAssets: Get<u32>;
+
+		/// The max number of price snapshots kept for an asset.
+		#[pallet::constant]
+		type MaxPriceSnapshots: Get<u32>;
+
+		/// Price snapshots older than this number of blocks are pruned.
+		#[pallet::constant]
+		type PriceSnapshotMaxAge: Get<BlockNumberFor<Self>>;
+
 		/// Weight information
 		type WeightInfo: WeightInfo;
 	}
@@ -96,6 +185,40 @@ pub mod pallet {
 		SetPrice(CurrencyId, Price),
 		/// Reset emergency price. \[asset_id\]
 		ResetPrice(CurrencyId),
+		/// Set price config. \[asset_id, price_config\]
+		SetPriceConfig(CurrencyId, PriceConfig),
+		/// Remove price config. \[asset_id\]
+		RemovePriceConfig(CurrencyId),
+		/// Feed price through XCM. \[asset_id, price\]
+		FeedXcmPrice(CurrencyId, Price),
+		/// Circuit breaker tripped. \[asset_id, reason\]
+		CircuitBreakerTripped(CurrencyId, CircuitBreakerReason),
+		/// Reset circuit breaker. \[asset_id\]
+		ResetCircuitBreaker(CurrencyId),
+		/// Set price snapshot interval. \[asset_id, interval\]
+		SetPriceSnapshotInterval(CurrencyId, BlockNumberFor<T>),
+		/// Remove price snapshot interval. \[asset_id\]
+		RemovePriceSnapshotInterval(CurrencyId),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The price config is invalid
+		InvalidPriceConfig,
+		/// The price config doesn't exist
+		PriceConfigNotExist,
+		/// The number of price configs exceeds `MaxPriceConfigs`
+		TooManyPriceConfigs,
+		/// The origin isn't the XCM source of the asset
+		XcmSourceMismatch,
+		/// The circuit breaker of the asset isn't tripped
+		CircuitBreakerNotTripped,
+		/// The price snapshot interval can't be zero
+		InvalidSnapshotInterval,
+		/// The price snapshot interval doesn't exist
+		SnapshotIntervalNotExist,
+		/// The number of assets with price snapshots exceeds `MaxSnapshotAssets`
+		TooManySnapshotAssets,
 	}
 
 	/// Mapping from currency id to it's emergency price
@@ -108,6 +231,52 @@ pub mod pallet {
 	pub type ForeignToNativeAsset<T: Config> =
 		StorageMap<_, Twox64Concat, CurrencyId, CurrencyId, OptionQuery>;
 
+	/// Mapping from currency id to the bounds its price is aggregated within
+	#[pallet::storage]
+	pub type PriceConfigs<T: Config> =
+		CountedStorageMap<_, Twox64Concat, CurrencyId, PriceConfig, OptionQuery>;
+
+	/// Mapping from currency id to its last accepted aggregated price
+	#[pallet::storage]
+	pub type AggregatedPrices<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, TimeStampedPrice, OptionQuery>;
+
+	/// Mapping from currency id to its TWAP in the stable pool
+	#[pallet::storage]
+	pub type PoolTwaps<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, TimeStampedPrice, OptionQuery>;
+
+	/// Mapping from currency id to its price delivered through XCM
+	#[pallet::storage]
+	pub type XcmPrices<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, TimeStampedPrice, OptionQuery>;
+
+	/// Mapping from currency id to the reason its circuit breaker tripped
+	#[pallet::storage]
+	pub type CircuitBreakers<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, CircuitBreakerReason, OptionQuery>;
+
+	/// Currency ids whose circuit breaker was reset, the next aggregated price is accepted
+	/// without the deviation check
+	#[pallet::storage]
+	pub type CircuitBreakerResets<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, (), OptionQuery>;
+
+	/// Mapping from currency id to the interval in blocks its price snapshots are taken at
+	#[pallet::storage]
+	pub type PriceSnapshotIntervals<T: Config> =
+		CountedStorageMap<_, Twox64Concat, CurrencyId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Mapping from currency id to its price snapshots, oldest first
+	#[pallet::storage]
+	pub type PriceSnapshots<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		CurrencyId,
+		BoundedVec<PriceSnapshot<BlockNumberFor<T>>, T::MaxPriceSnapshots>,
+		ValueQuery,
+	>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -132,6 +301,33 @@ pub mod pallet {
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// the prices are aggregated in `on_finalize`
+			<T as Config>::WeightInfo::aggregate_prices(PriceConfigs::<T>::count()).saturating_add(
+				<T as Config>::WeightInfo::take_price_snapshots(
+					PriceSnapshotIntervals::<T>::count(),
+				),
+			)
+		}
+
+		fn on_finalize(n: BlockNumberFor<T>) {
+			// the timestamp of the block is only set by its inherent
+			let now = T::Time::now();
+			for (asset_id, config) in PriceConfigs::<T>::iter() {
+				Self::aggregate_price(asset_id, &config, now);
+			}
+
+			// snapshots are taken after the aggregation of the block
+			for (asset_id, interval) in PriceSnapshotIntervals::<T>::iter() {
+				if (n % interval).is_zero() {
+					Self::take_price_snapshot(asset_id, n);
+				}
+			}
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Set emergency price
@@ -178,12 +374,133 @@ pub mod pallet {
 			ForeignToNativeAsset::<T>::insert(foreign_asset_id, asset_id);
 			Ok(().into())
 		}
+
+		/// Set or remove the price config of an asset
+		#[pallet::call_index(3)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_price_config(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_price_config(
+			origin: OriginFor<T>,
+			asset_id: CurrencyId,
+			config: Option<PriceConfig>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			match config {
+				Some(config) => {
+					ensure!(
+						config.min_sources > 0 && config.min_sources <= config.source_count(),
+						Error::<T>::InvalidPriceConfig
+					);
+					let old_config = PriceConfigs::<T>::get(asset_id);
+					ensure!(
+						old_config.is_some() ||
+							PriceConfigs::<T>::count() < T::MaxPriceConfigs::get(),
+						Error::<T>::TooManyPriceConfigs
+					);
+					// a TWAP against another asset can't be reused
+					if old_config.map(|old_config| old_config.twap_base) != Some(config.twap_base) {
+						PoolTwaps::<T>::remove(asset_id);
+					}
+					PriceConfigs::<T>::insert(asset_id, config.clone());
+					Self::deposit_event(Event::SetPriceConfig(asset_id, config));
+				},
+				None => {
+					ensure!(
+						PriceConfigs::<T>::contains_key(asset_id),
+						Error::<T>::PriceConfigNotExist
+					);
+					PriceConfigs::<T>::remove(asset_id);
+					AggregatedPrices::<T>::remove(asset_id);
+					PoolTwaps::<T>::remove(asset_id);
+					XcmPrices::<T>::remove(asset_id);
+					CircuitBreakers::<T>::remove(asset_id);
+					CircuitBreakerResets::<T>::remove(asset_id);
+					Self::deposit_event(Event::RemovePriceConfig(asset_id));
+				},
+			}
+			Ok(().into())
+		}
+
+		/// Feed the price of an asset through XCM
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::feed_xcm_price())]
+		#[transactional]
+		pub fn feed_xcm_price(
+			origin: OriginFor<T>,
+			asset_id: CurrencyId,
+			price: Price,
+		) -> DispatchResultWithPostInfo {
+			let location = T::XcmPriceOrigin::ensure_origin(origin)?;
+			let config = PriceConfigs::<T>::get(asset_id).ok_or(Error::<T>::PriceConfigNotExist)?;
+			ensure!(config.xcm_source == Some(location), Error::<T>::XcmSourceMismatch);
+			XcmPrices::<T>::insert(
+				asset_id,
+				TimeStampedPrice { value: price, timestamp: T::Time::now() },
+			);
+			Self::deposit_event(Event::FeedXcmPrice(asset_id, price));
+			Ok(().into())
+		}
+
+		/// Reset the tripped circuit breaker of an asset
+		#[pallet::call_index(5)]
+		#[pallet::weight((<T as Config>::WeightInfo::reset_circuit_breaker(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn reset_circuit_breaker(
+			origin: OriginFor<T>,
+			asset_id: CurrencyId,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				CircuitBreakers::<T>::contains_key(asset_id),
+				Error::<T>::CircuitBreakerNotTripped
+			);
+			CircuitBreakers::<T>::remove(asset_id);
+			// the last accepted price is kept until the next aggregated price is accepted as is
+			CircuitBreakerResets::<T>::insert(asset_id, ());
+			Self::deposit_event(Event::ResetCircuitBreaker(asset_id));
+			Ok(().into())
+		}
+
+		/// Set or remove the interval price snapshots of an asset are taken at, the
+		/// snapshots are removed with the interval
+		#[pallet::call_index(6)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_price_snapshot_interval(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_price_snapshot_interval(
+			origin: OriginFor<T>,
+			asset_id: CurrencyId,
+			interval: Option<BlockNumberFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			match interval {
+				Some(interval) => {
+					ensure!(!interval.is_zero(), Error::<T>::InvalidSnapshotInterval);
+					ensure!(
+						PriceSnapshotIntervals::<T>::contains_key(asset_id) ||
+							PriceSnapshotIntervals::<T>::count() < T::MaxSnapshotAssets::get(),
+						Error::<T>::TooManySnapshotAssets
+					);
+					PriceSnapshotIntervals::<T>::insert(asset_id, interval);
+					Self::deposit_event(Event::SetPriceSnapshotInterval(asset_id, interval));
+				},
+				None => {
+					ensure!(
+						PriceSnapshotIntervals::<T>::contains_key(asset_id),
+						Error::<T>::SnapshotIntervalNotExist
+					);
+					PriceSnapshotIntervals::<T>::remove(asset_id);
+					PriceSnapshots::<T>::remove(asset_id);
+					Self::deposit_event(Event::RemovePriceSnapshotInterval(asset_id));
+				},
+			}
+			Ok(().into())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
 	// get emergency price, the timestamp is zero
-	fn get_emergency_price(asset_id: &CurrencyId) -> Option<PriceDetail> {
+	pub fn get_emergency_price(asset_id: &CurrencyId) -> Option<PriceDetail> {
 		EmergencyPrice::<T>::get(asset_id).and_then(|p| {
 			let mantissa = Self::get_asset_mantissa(asset_id)?;
 			log::trace!(
@@ -197,8 +514,21 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn get_storage_price(asset_id: &CurrencyId) -> Option<Price> {
-		EmergencyPrice::<T>::get(asset_id)
-			.or_else(|| T::Source::get(asset_id).and_then(|price| Some(price.value)))
+		EmergencyPrice::<T>::get(asset_id).or_else(|| {
+			Self::get_feed_price(asset_id, T::Source::get).and_then(|price| Some(price.value))
+		})
+	}
+
+	// get the raw price of an asset, the aggregated price is used if the asset has a price config
+	fn get_feed_price(
+		asset_id: &CurrencyId,
+		get: impl Fn(&CurrencyId) -> Option<TimeStampedPrice>,
+	) -> Option<TimeStampedPrice> {
+		if PriceConfigs::<T>::contains_key(asset_id) {
+			AggregatedPrices::<T>::get(asset_id)
+		} else {
+			Self::get_source_price(asset_id, get)
+		}
 	}
 
 	fn get_asset_mantissa(asset_id: &CurrencyId) -> Option<u128> {
@@ -213,11 +543,220 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
-	fn get_special_asset_price(
-		_asset_id: CurrencyId,
-		_base_price: TimeStampedPrice,
+	// get the raw price of an asset from the source, the price of a vToken is derived from
+	// its token if possible
+	fn get_source_price(
+		asset_id: &CurrencyId,
+		get: impl Fn(&CurrencyId) -> Option<TimeStampedPrice>,
+	) -> Option<TimeStampedPrice> {
+		let direct_price = get(asset_id);
+		match Self::get_vtoken_price(asset_id, &get) {
+			Some(derived_price) => match direct_price {
+				Some(direct_price)
+					if Self::price_deviation_exceeded(
+						derived_price.value,
+						direct_price.value,
+						T::MaxVtokenPriceDeviation::get(),
+					) =>
+				{
+					log::warn!(
+						target: "prices::get_source_price",
+						"asset_id: {:?}, derived price: {:?} deviates from direct price: {:?}",
+						asset_id,
+						derived_price.value,
+						direct_price.value
+					);
+					None
+				},
+				_ => Some(derived_price),
+			},
+			None => direct_price,
+		}
+	}
+
+	// vtoken_price = token_price * token_amount / token_mantissa, where token_amount is the
+	// amount of token one vToken can be redeemed for
+	fn get_vtoken_price(
+		asset_id: &CurrencyId,
+		get: impl Fn(&CurrencyId) -> Option<TimeStampedPrice>,
 	) -> Option<TimeStampedPrice> {
-		None
+		let token_id = asset_id.to_token().ok()?;
+		let token_price = match EmergencyPrice::<T>::get(token_id) {
+			Some(value) => TimeStampedPrice { value, timestamp: 0 },
+			None if PriceConfigs::<T>::contains_key(token_id) =>
+				AggregatedPrices::<T>::get(token_id)?,
+			None => get(&token_id)?,
+		};
+		let vtoken_mantissa = Self::get_asset_mantissa(asset_id)?;
+		let token_mantissa = Self::get_asset_mantissa(&token_id)?;
+		let token_amount = T::VtokenMinting::get_currency_amount_by_v_currency_amount(
+			token_id,
+			*asset_id,
+			vtoken_mantissa,
+		)
+		.ok()
+		.filter(|amount| !amount.is_zero())?;
+		let value = token_price
+			.value
+			.checked_mul(&FixedU128::from_inner(token_amount))?
+			.checked_div(&FixedU128::from_inner(token_mantissa))?;
+		Some(TimeStampedPrice { value, timestamp: token_price.timestamp })
+	}
+
+	fn price_deviation_exceeded(price: Price, base_price: Price, max_deviation: Permill) -> bool {
+		let deviation = if price > base_price {
+			price.saturating_sub(base_price)
+		} else {
+			base_price.saturating_sub(price)
+		};
+		deviation > FixedU128::from_inner(max_deviation.mul_floor(base_price.into_inner()))
+	}
+
+	// aggregate the median of the fresh source prices, a price breaching the bounds of the
+	// config trips the circuit breaker
+	fn aggregate_price(asset_id: CurrencyId, config: &PriceConfig, now: Moment) {
+		if let Some(base_id) = config.twap_base {
+			Self::update_pool_twap(asset_id, base_id, now);
+		}
+		if CircuitBreakers::<T>::contains_key(asset_id) {
+			return;
+		}
+
+		let oracle_price = Self::get_source_price(&asset_id, T::Source::get);
+		let twap_price = config.twap_base.and_then(|_| PoolTwaps::<T>::get(asset_id));
+		let xcm_price = config.xcm_source.as_ref().and_then(|_| XcmPrices::<T>::get(asset_id));
+		let source_prices: Vec<TimeStampedPrice> =
+			[oracle_price, twap_price, xcm_price].into_iter().flatten().collect();
+		let source_count = source_prices.len() as u32;
+		let mut fresh_prices: Vec<Price> = source_prices
+			.into_iter()
+			.filter(|price| now.saturating_sub(price.timestamp) <= config.max_age)
+			.map(|price| price.value)
+			.collect();
+
+		if (fresh_prices.len() as u32) < config.min_sources {
+			let reason = if source_count < config.min_sources {
+				CircuitBreakerReason::InsufficientSources
+			} else {
+				CircuitBreakerReason::Stale
+			};
+			return Self::trip_circuit_breaker(asset_id, reason);
+		}
+		let Some(price) = Self::median(&mut fresh_prices) else {
+			return;
+		};
+		if !CircuitBreakerResets::<T>::contains_key(asset_id) {
+			if let Some(last_price) = AggregatedPrices::<T>::get(asset_id) {
+				if Self::price_deviation_exceeded(price, last_price.value, config.max_deviation) {
+					return Self::trip_circuit_breaker(asset_id, CircuitBreakerReason::Deviation);
+				}
+			}
+		}
+		CircuitBreakerResets::<T>::remove(asset_id);
+		AggregatedPrices::<T>::insert(asset_id, TimeStampedPrice { value: price, timestamp: now });
+	}
+
+	fn median(prices: &mut [Price]) -> Option<Price> {
+		prices.sort();
+		let mid = prices.len() / 2;
+		if prices.len() % 2 == 0 {
+			let lower = prices.get(mid.checked_sub(1)?)?;
+			let upper = prices.get(mid)?;
+			lower.saturating_add(*upper).checked_div(&Price::saturating_from_integer(2))
+		} else {
+			prices.get(mid).copied()
+		}
+	}
+
+	fn trip_circuit_breaker(asset_id: CurrencyId, reason: CircuitBreakerReason) {
+		log::warn!(
+			target: "prices::trip_circuit_breaker",
+			"asset_id: {:?}, reason: {:?}",
+			asset_id,
+			reason
+		);
+		CircuitBreakers::<T>::insert(asset_id, reason);
+		Self::deposit_event(Event::CircuitBreakerTripped(asset_id, reason));
+	}
+
+	// twap = last_twap * (1 - elapsed / period) + spot_price * elapsed / period, sampled once per
+	// block after its swaps
+	fn update_pool_twap(asset_id: CurrencyId, base_id: CurrencyId, now: Moment) {
+		let Some(spot_price) = Self::get_pool_price(&asset_id, &base_id) else {
+			return;
+		};
+		let period = T::TwapPeriod::get();
+		let value = match PoolTwaps::<T>::get(asset_id) {
+			Some(twap) if !period.is_zero() => {
+				let elapsed = now.saturating_sub(twap.timestamp).min(period);
+				let weight = FixedU128::saturating_from_rational(elapsed, period);
+				twap.value
+					.saturating_mul(FixedU128::one().saturating_sub(weight))
+					.saturating_add(spot_price.saturating_mul(weight))
+			},
+			_ => spot_price,
+		};
+		PoolTwaps::<T>::insert(asset_id, TimeStampedPrice { value, timestamp: now });
+	}
+
+	// the raw price of an asset by swapping one unit of it for the base asset in a stable pool
+	fn get_pool_price(asset_id: &CurrencyId, base_id: &CurrencyId) -> Option<Price> {
+		let (pool_id, asset_index, base_index) =
+			T::StablePoolHandler::get_pool_id(asset_id, base_id)?;
+		let asset_mantissa = Self::get_asset_mantissa(asset_id)?;
+		let base_mantissa = Self::get_asset_mantissa(base_id)?;
+		let base_amount =
+			T::StablePoolHandler::get_swap_output(pool_id, asset_index, base_index, asset_mantissa)
+				.ok()
+				.filter(|amount| !amount.is_zero())?;
+		Self::get_storage_price(base_id)?
+			.checked_mul(&FixedU128::from_inner(base_amount))?
+			.checked_div(&FixedU128::from_inner(base_mantissa))
+	}
+
+	// the oldest snapshots are dropped when they are expired or the buffer is full
+	fn take_price_snapshot(asset_id: CurrencyId, n: BlockNumberFor<T>) {
+		let Some((price, timestamp)) = Self::get_price(&asset_id) else {
+			return;
+		};
+		PriceSnapshots::<T>::mutate(asset_id, |snapshots| {
+			let expired_at = n.saturating_sub(T::PriceSnapshotMaxAge::get());
+			snapshots.retain(|snapshot| snapshot.block_number >= expired_at);
+			if snapshots.is_full() {
+				snapshots.remove(0);
+			}
+			let _ = snapshots.try_push(PriceSnapshot { block_number: n, price, timestamp });
+		});
+	}
+
+	/// Returns the price of a vToken derived from its token, in the format of `get_price`.
+	pub fn get_derived_price(asset_id: &CurrencyId) -> Option<PriceDetail> {
+		let mantissa = Self::get_asset_mantissa(asset_id)?;
+		Self::get_vtoken_price(asset_id, T::Source::get)
+			.and_then(|price| Self::normalize_detail_price(price, mantissa))
+	}
+
+	/// Returns the price snapshots of an asset taken within `[from, to]`, oldest first.
+	pub fn get_price_history(
+		asset_id: &CurrencyId,
+		from: BlockNumberFor<T>,
+		to: BlockNumberFor<T>,
+	) -> Vec<PriceSnapshot<BlockNumberFor<T>>> {
+		PriceSnapshots::<T>::get(asset_id)
+			.into_iter()
+			.filter(|snapshot| snapshot.block_number >= from && snapshot.block_number <= to)
+			.collect()
+	}
+
+	/// Returns the latest price snapshot of an asset taken at or before `block_number`.
+	pub fn get_price_at(
+		asset_id: &CurrencyId,
+		block_number: BlockNumberFor<T>,
+	) -> Option<PriceSnapshot<BlockNumberFor<T>>> {
+		PriceSnapshots::<T>::get(asset_id)
+			.into_iter()
+			.rev()
+			.find(|snapshot| snapshot.block_number <= block_number)
 	}
 
 	fn normalize_detail_price(price: TimeStampedPrice, mantissa: u128) -> Option<PriceDetail> {
@@ -240,9 +779,7 @@ impl<T: Config> OraclePriceProvider for Pallet<T> {
 		// if emergency price exists, return it
 		Self::get_emergency_price(asset_id).or_else(|| {
 			let mantissa = Self::get_asset_mantissa(asset_id)?;
-			T::Source::get(&T::RelayCurrency::get())
-				.and_then(|base_price| Self::get_special_asset_price(*asset_id, base_price))
-				.or_else(|| T::Source::get(asset_id))
+			Self::get_feed_price(asset_id, T::Source::get)
 				.and_then(|price| Self::normalize_detail_price(price, mantissa))
 		})
 	}
@@ -316,12 +853,16 @@ impl<T: Config> EmergencyOraclePriceProvider<CurrencyId, Price> for Pallet<T> {
 impl<T: Config> DataProviderExtended<CurrencyId, TimeStampedPrice> for Pallet<T> {
 	fn get_no_op(asset_id: &CurrencyId) -> Option<TimeStampedPrice> {
 		let _mantissa = Self::get_asset_mantissa(asset_id)?;
-		T::Source::get_no_op(&T::RelayCurrency::get())
-			.and_then(|base_price| Self::get_special_asset_price(*asset_id, base_price))
-			.or_else(|| T::Source::get_no_op(asset_id))
+		Self::get_feed_price(asset_id, T::Source::get_no_op)
 	}
 
 	fn get_all_values() -> Vec<(CurrencyId, Option<TimeStampedPrice>)> {
 		T::Source::get_all_values()
 	}
 }
+
+impl<T: Config> PriceCircuitBreaker<CurrencyId> for Pallet<T> {
+	fn is_circuit_breaker_tripped(asset_id: &CurrencyId) -> bool {
+		CircuitBreakers::<T>::contains_key(asset_id)
+	}
+}
@@ -17,9 +17,9 @@
 use super::*;
 use frame_support::{
 	construct_runtime, derive_impl, ord_parameter_types, parameter_types,
-	traits::{AsEnsureOriginWithArg, Nothing},
+	traits::{AsEnsureOriginWithArg, Everything, Nothing},
 };
-use frame_system::{EnsureRoot, EnsureSigned, EnsureSignedBy};
+use frame_system::{EnsureRoot, EnsureSigned, EnsureSignedBy, EnsureWithSuccess};
 use sp_runtime::{traits::IdentityLookup, FixedPointNumber};
 
 use bifrost_asset_registry::AssetIdMaps;
@@ -27,8 +27,10 @@ pub use bifrost_primitives::{
 	currency::{FIL, VFIL},
 	DOT, KSM, MANTA, VDOT,
 };
-use bifrost_primitives::{Moment, ASTR, BNC, DOT_U, GLMR};
+use bifrost_primitives::{Moment, RedeemType, ASTR, BNC, DOT_U, GLMR};
+use bifrost_stable_pool::{PoolTokenIndex, StableAssetPoolId};
 use sp_runtime::BuildStorage;
+use xcm::v4::Junction::Parachain;
 
 pub type AccountId = u128;
 pub type BlockNumber = u64;
@@ -169,6 +171,209 @@ impl pallet_assets::Config for Test {
 parameter_types! {
 	pub const RelayCurrency: CurrencyId = DOT;
 	pub const NativeCurrencyId: CurrencyId = BNC;
+	pub const MaxVtokenPriceDeviation: Permill = Permill::from_percent(5);
+	pub const TwapPeriod: Moment = 60_000;
+	pub const MaxPriceConfigs: u32 = 10;
+	pub const MaxSnapshotAssets: u32 = 2;
+	pub const MaxPriceSnapshots: u32 = 3;
+	pub const PriceSnapshotMaxAge: BlockNumber = 20;
+	pub XcmPriceSource: Location = Location::new(1, [Parachain(2004)]);
+	pub static Now: Moment = 0;
+	pub static StablePoolRates: Vec<(CurrencyId, CurrencyId, FixedU128)> = vec![];
+	pub static VtokenExchangeRates: Vec<(CurrencyId, FixedU128)> =
+		vec![(VDOT, FixedU128::from_rational(3, 2))];
+}
+
+pub struct MockTime;
+
+impl Time for MockTime {
+	type Moment = Moment;
+
+	fn now() -> Moment {
+		Now::get()
+	}
+}
+
+/// Swaps in the pools of `StablePoolRates`, the pool id is the index of the rate.
+pub struct MockStablePool;
+
+impl StablePoolHandler for MockStablePool {
+	type Balance = Balance;
+	type AccountId = AccountId;
+	type CurrencyId = CurrencyId;
+
+	fn add_liquidity(
+		_who: Self::AccountId,
+		_pool_id: StableAssetPoolId,
+		_amounts: Vec<Self::Balance>,
+		_min_mint_amount: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn swap(
+		_who: &Self::AccountId,
+		_pool_id: StableAssetPoolId,
+		_currency_id_in: PoolTokenIndex,
+		_currency_id_out: PoolTokenIndex,
+		_amount: Self::Balance,
+		_min_dy: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn redeem_single(
+		_who: Self::AccountId,
+		_pool_id: StableAssetPoolId,
+		_amount: Self::Balance,
+		_i: PoolTokenIndex,
+		_min_redeem_amount: Self::Balance,
+		_asset_length: u32,
+	) -> Result<(Self::Balance, Self::Balance), DispatchError> {
+		Ok((0, 0))
+	}
+
+	fn redeem_multi(
+		_who: Self::AccountId,
+		_pool_id: StableAssetPoolId,
+		_amounts: Vec<Self::Balance>,
+		_max_redeem_amount: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn redeem_proportion(
+		_who: Self::AccountId,
+		_pool_id: StableAssetPoolId,
+		_amount: Self::Balance,
+		_min_redeem_amounts: Vec<Self::Balance>,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn get_pool_token_index(
+		_pool_id: StableAssetPoolId,
+		_currency_id: CurrencyId,
+	) -> Option<PoolTokenIndex> {
+		None
+	}
+
+	fn get_swap_output(
+		pool_id: StableAssetPoolId,
+		_currency_id_in: PoolTokenIndex,
+		_currency_id_out: PoolTokenIndex,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		StablePoolRates::get()
+			.get(pool_id as usize)
+			.map(|(_, _, rate)| rate.saturating_mul_int(amount))
+			.ok_or(DispatchError::Other("NoPool"))
+	}
+
+	fn get_swap_input(
+		_pool_id: StableAssetPoolId,
+		_currency_id_in: PoolTokenIndex,
+		_currency_id_out: PoolTokenIndex,
+		_amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Ok(0)
+	}
+
+	fn get_pool_id(
+		currency_id_in: &Self::CurrencyId,
+		currency_id_out: &Self::CurrencyId,
+	) -> Option<(StableAssetPoolId, PoolTokenIndex, PoolTokenIndex)> {
+		StablePoolRates::get()
+			.iter()
+			.position(|(asset_id, base_id, _)| {
+				asset_id == currency_id_in && base_id == currency_id_out
+			})
+			.map(|pool_id| (pool_id as StableAssetPoolId, 0, 1))
+	}
+}
+
+/// Redeems vtokens for tokens at the rates of `VtokenExchangeRates`.
+pub struct MockVtokenMinting;
+
+impl VtokenMintingInterface<AccountId, CurrencyId, Balance> for MockVtokenMinting {
+	fn mint(
+		exchanger: AccountId,
+		token_id: CurrencyId,
+		token_amount: Balance,
+		remark: BoundedVec<u8, ConstU32<32>>,
+		channel_id: Option<u32>,
+	) -> Result<Balance, DispatchError> {
+		<() as VtokenMintingInterface<AccountId, CurrencyId, Balance>>::mint(
+			exchanger,
+			token_id,
+			token_amount,
+			remark,
+			channel_id,
+		)
+	}
+
+	fn redeem(
+		exchanger: AccountId,
+		vtoken_id: CurrencyId,
+		vtoken_amount: Balance,
+	) -> DispatchResultWithPostInfo {
+		<() as VtokenMintingInterface<AccountId, CurrencyId, Balance>>::redeem(
+			exchanger,
+			vtoken_id,
+			vtoken_amount,
+		)
+	}
+
+	fn slpx_redeem(
+		exchanger: AccountId,
+		vtoken_id: CurrencyId,
+		vtoken_amount: Balance,
+		redeem: RedeemType<AccountId>,
+	) -> DispatchResultWithPostInfo {
+		<() as VtokenMintingInterface<AccountId, CurrencyId, Balance>>::slpx_redeem(
+			exchanger,
+			vtoken_id,
+			vtoken_amount,
+			redeem,
+		)
+	}
+
+	fn get_v_currency_amount_by_currency_amount(
+		_token_id: CurrencyId,
+		vtoken_id: CurrencyId,
+		token_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		VtokenExchangeRates::get()
+			.into_iter()
+			.find(|(currency_id, _)| *currency_id == vtoken_id)
+			.and_then(|(_, rate)| rate.reciprocal())
+			.map(|rate| rate.saturating_mul_int(token_amount))
+			.ok_or(DispatchError::Other("NoExchangeRate"))
+	}
+
+	fn get_currency_amount_by_v_currency_amount(
+		_token_id: CurrencyId,
+		vtoken_id: CurrencyId,
+		vtoken_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		VtokenExchangeRates::get()
+			.into_iter()
+			.find(|(currency_id, _)| *currency_id == vtoken_id)
+			.map(|(_, rate)| rate.saturating_mul_int(vtoken_amount))
+			.ok_or(DispatchError::Other("NoExchangeRate"))
+	}
+
+	fn get_token_pool(_currency_id: CurrencyId) -> Balance {
+		0
+	}
+
+	fn get_minimums_redeem(_vtoken_id: CurrencyId) -> Balance {
+		0
+	}
+
+	fn get_moonbeam_parachain_id() -> u32 {
+		0
+	}
 }
 
 impl crate::Config for Test {
@@ -179,6 +384,17 @@ impl crate::Config for Test {
 	type RelayCurrency = RelayCurrency;
 	type CurrencyIdConvert = AssetIdMaps<Test>;
 	type Assets = Currencies;
+	type VtokenMinting = MockVtokenMinting;
+	type MaxVtokenPriceDeviation = MaxVtokenPriceDeviation;
+	type Time = MockTime;
+	type StablePoolHandler = MockStablePool;
+	type XcmPriceOrigin =
+		EnsureWithSuccess<EnsureSignedBy<One, AccountId>, AccountId, XcmPriceSource>;
+	type TwapPeriod = TwapPeriod;
+	type MaxPriceConfigs = MaxPriceConfigs;
+	type MaxSnapshotAssets = MaxSnapshotAssets;
+	type MaxPriceSnapshots = MaxPriceSnapshots;
+	type PriceSnapshotMaxAge = PriceSnapshotMaxAge;
 	type WeightInfo = ();
 }
 
@@ -188,6 +404,7 @@ pub type AdaptedBasicCurrency =
 
 impl bifrost_currencies::Config for Test {
 	type GetNativeCurrencyId = NativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type WeightInfo = ();
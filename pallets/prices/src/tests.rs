@@ -293,3 +293,436 @@ fn get_oracle_amount_by_currency_and_amount_in() {
 		);
 	});
 }
+
+#[test]
+fn get_vtoken_price_derived_from_exchange_rate() {
+	new_test_ext().execute_with(|| {
+		// 1 VDOT = 1.5 DOT, which matches the direct feed of VDOT
+		assert_eq!(
+			Prices::get_price(&VDOT),
+			Some((Price::from_inner(15_000_000_000 * PRICE_ONE), 0))
+		);
+
+		// VKSM has no direct feed, 1 VKSM = 1.2 KSM
+		VtokenExchangeRates::set(vec![
+			(VDOT, FixedU128::from_rational(3, 2)),
+			(VKSM, FixedU128::from_rational(6, 5)),
+		]);
+		assert_eq!(Prices::get_price(&VKSM), Some((Price::from_inner(600 * 1_000_000), 0)));
+		assert_eq!(
+			Prices::get_oracle_amount_by_currency_and_amount_in(&BNC, 300 * 10u128.pow(12), &VKSM),
+			Some((
+				100_000_000_000,
+				Price::from_inner(200_000_000_000_000_000),
+				Price::saturating_from_integer(600)
+			))
+		);
+
+		// the emergency price of the token is used to derive the price
+		EmergencyPrice::<Test>::insert(KSM, Price::saturating_from_integer(400));
+		assert_eq!(Prices::get_price(&VKSM), Some((Price::from_inner(480 * 1_000_000), 0)));
+	});
+}
+
+#[test]
+fn get_vtoken_price_should_reject_deviated_price() {
+	new_test_ext().execute_with(|| {
+		// 1 VDOT = 1.52 DOT deviates 1.33% from the direct feed of VDOT
+		VtokenExchangeRates::set(vec![(VDOT, FixedU128::from_rational(38, 25))]);
+		assert_eq!(
+			Prices::get_price(&VDOT),
+			Some((Price::from_inner(15_200_000_000 * PRICE_ONE), 0))
+		);
+
+		// 1 VDOT = 1.2 DOT deviates 20% from the direct feed of VDOT
+		VtokenExchangeRates::set(vec![(VDOT, FixedU128::from_rational(6, 5))]);
+		assert_eq!(Prices::get_price(&VDOT), None);
+		assert_eq!(
+			Prices::get_oracle_amount_by_currency_and_amount_in(&BNC, 10u128.pow(12), &VDOT),
+			None
+		);
+
+		// without an exchange rate the direct feed is used
+		VtokenExchangeRates::set(vec![]);
+		assert_eq!(
+			Prices::get_price(&VDOT),
+			Some((Price::from_inner(15_000_000_000 * PRICE_ONE), 0))
+		);
+	});
+}
+
+fn dot_price_config(min_sources: u32) -> PriceConfig {
+	PriceConfig {
+		max_age: 12_000,
+		max_deviation: Permill::from_percent(10),
+		min_sources,
+		twap_base: None,
+		xcm_source: Some(XcmPriceSource::get()),
+	}
+}
+
+#[test]
+fn set_price_config_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			Prices::set_price_config(
+				RuntimeOrigin::signed(CHARLIE),
+				DOT,
+				Some(dot_price_config(2))
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			Prices::set_price_config(RuntimeOrigin::signed(ALICE), DOT, Some(dot_price_config(0))),
+			Error::<Test>::InvalidPriceConfig
+		);
+		assert_noop!(
+			Prices::set_price_config(RuntimeOrigin::signed(ALICE), DOT, Some(dot_price_config(3))),
+			Error::<Test>::InvalidPriceConfig
+		);
+		assert_noop!(
+			Prices::set_price_config(RuntimeOrigin::signed(ALICE), DOT, None),
+			Error::<Test>::PriceConfigNotExist
+		);
+
+		assert_ok!(Prices::set_price_config(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Some(dot_price_config(2))
+		));
+		assert_eq!(PriceConfigs::<Test>::get(DOT), Some(dot_price_config(2)));
+		System::assert_last_event(RuntimeEvent::Prices(crate::Event::SetPriceConfig(
+			DOT,
+			dot_price_config(2),
+		)));
+
+		// only the configured location can feed the price
+		assert_noop!(
+			Prices::feed_xcm_price(RuntimeOrigin::signed(CHARLIE), DOT, Price::from(110)),
+			BadOrigin
+		);
+		assert_noop!(
+			Prices::feed_xcm_price(RuntimeOrigin::signed(ALICE), KSM, Price::from(110)),
+			Error::<Test>::PriceConfigNotExist
+		);
+		assert_ok!(Prices::set_price_config(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Some(PriceConfig { xcm_source: None, ..dot_price_config(1) })
+		));
+		assert_noop!(
+			Prices::feed_xcm_price(RuntimeOrigin::signed(ALICE), KSM, Price::from(110)),
+			Error::<Test>::XcmSourceMismatch
+		);
+
+		// the config is aggregated from the next block
+		assert_eq!(Prices::get_price(&DOT), None);
+
+		assert_ok!(Prices::set_price_config(RuntimeOrigin::signed(ALICE), DOT, None));
+		assert_eq!(PriceConfigs::<Test>::get(DOT), None);
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(10_000_000_000 * PRICE_ONE), 0))
+		);
+	});
+}
+
+#[test]
+fn aggregate_price_should_use_median() {
+	new_test_ext().execute_with(|| {
+		Now::set(1_000);
+		// 1 DOT = 0.21 KSM in the stable pool
+		StablePoolRates::set(vec![(DOT, KSM, FixedU128::saturating_from_integer(21))]);
+		assert_ok!(Prices::set_price_config(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Some(PriceConfig { twap_base: Some(KSM), ..dot_price_config(2) })
+		));
+		assert_ok!(Prices::feed_xcm_price(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Price::saturating_from_integer(110)
+		));
+
+		// median of the oracle 100, the TWAP 105 and XCM 110
+		Prices::on_finalize(1);
+		assert_eq!(
+			PoolTwaps::<Test>::get(DOT).map(|twap| (twap.value, twap.timestamp)),
+			Some((Price::saturating_from_integer(105), 1_000))
+		);
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(10_500_000_000 * PRICE_ONE), 1_000))
+		);
+		assert_eq!(
+			Prices::get_oracle_amount_by_currency_and_amount_in(&BNC, 105 * 10u128.pow(12), &DOT),
+			Some((
+				2_000_000_000,
+				Price::from_inner(200_000_000_000_000_000),
+				Price::saturating_from_integer(105)
+			))
+		);
+
+		// the TWAP moves halfway to the spot price of 115 after half of the period
+		Now::set(31_000);
+		StablePoolRates::set(vec![(DOT, KSM, FixedU128::saturating_from_integer(23))]);
+		assert_ok!(Prices::set_price_config(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Some(PriceConfig {
+				max_age: 60_000,
+				xcm_source: None,
+				twap_base: Some(KSM),
+				..dot_price_config(2)
+			})
+		));
+		Prices::on_finalize(2);
+		assert_eq!(
+			PoolTwaps::<Test>::get(DOT).map(|twap| (twap.value, twap.timestamp)),
+			Some((Price::saturating_from_integer(110), 31_000))
+		);
+		// median of the oracle 100 and the TWAP 110
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(10_500_000_000 * PRICE_ONE), 31_000))
+		);
+	});
+}
+
+#[test]
+fn circuit_breaker_should_trip_on_deviation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Now::set(1_000);
+		assert_ok!(Prices::set_price_config(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Some(dot_price_config(2))
+		));
+		assert_ok!(Prices::feed_xcm_price(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Price::saturating_from_integer(100)
+		));
+		Prices::on_finalize(1);
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(10_000_000_000 * PRICE_ONE), 1_000))
+		);
+
+		// the median moves 20% to 120
+		Now::set(2_000);
+		assert_ok!(Prices::feed_xcm_price(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Price::saturating_from_integer(140)
+		));
+		Prices::on_finalize(2);
+		assert_eq!(CircuitBreakers::<Test>::get(DOT), Some(CircuitBreakerReason::Deviation));
+		assert!(Prices::is_circuit_breaker_tripped(&DOT));
+		System::assert_last_event(RuntimeEvent::Prices(crate::Event::CircuitBreakerTripped(
+			DOT,
+			CircuitBreakerReason::Deviation,
+		)));
+		// the last accepted price is kept
+		Prices::on_finalize(3);
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(10_000_000_000 * PRICE_ONE), 1_000))
+		);
+
+		assert_noop!(Prices::reset_circuit_breaker(RuntimeOrigin::signed(CHARLIE), DOT), BadOrigin);
+		assert_ok!(Prices::reset_circuit_breaker(RuntimeOrigin::signed(ALICE), DOT));
+		assert!(!Prices::is_circuit_breaker_tripped(&DOT));
+		assert_noop!(
+			Prices::reset_circuit_breaker(RuntimeOrigin::signed(ALICE), DOT),
+			Error::<Test>::CircuitBreakerNotTripped
+		);
+		// the last accepted price is kept until the next aggregation
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(10_000_000_000 * PRICE_ONE), 1_000))
+		);
+
+		// the next aggregated price is accepted as is
+		Prices::on_finalize(4);
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(12_000_000_000 * PRICE_ONE), 2_000))
+		);
+		assert!(!CircuitBreakerResets::<Test>::contains_key(DOT));
+	});
+}
+
+#[test]
+fn circuit_breaker_should_trip_on_stale_or_insufficient_sources() {
+	new_test_ext().execute_with(|| {
+		Now::set(1_000);
+		assert_ok!(Prices::set_price_config(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Some(dot_price_config(2))
+		));
+
+		// no price is delivered through XCM
+		Prices::on_finalize(1);
+		assert_eq!(
+			CircuitBreakers::<Test>::get(DOT),
+			Some(CircuitBreakerReason::InsufficientSources)
+		);
+		assert_eq!(Prices::get_price(&DOT), None);
+
+		assert_ok!(Prices::reset_circuit_breaker(RuntimeOrigin::signed(ALICE), DOT));
+		assert_ok!(Prices::feed_xcm_price(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Price::saturating_from_integer(100)
+		));
+		Prices::on_finalize(2);
+		assert!(!Prices::is_circuit_breaker_tripped(&DOT));
+
+		// the oracle price is 20s old
+		Now::set(20_000);
+		assert_ok!(Prices::feed_xcm_price(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Price::saturating_from_integer(100)
+		));
+		Prices::on_finalize(3);
+		assert_eq!(CircuitBreakers::<Test>::get(DOT), Some(CircuitBreakerReason::Stale));
+	});
+}
+
+#[test]
+fn set_price_snapshot_interval_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			Prices::set_price_snapshot_interval(RuntimeOrigin::signed(CHARLIE), DOT, Some(10)),
+			BadOrigin
+		);
+		assert_noop!(
+			Prices::set_price_snapshot_interval(RuntimeOrigin::signed(ALICE), DOT, Some(0)),
+			Error::<Test>::InvalidSnapshotInterval
+		);
+		assert_ok!(Prices::set_price_snapshot_interval(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Some(10)
+		));
+		assert_ok!(Prices::set_price_snapshot_interval(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Some(10)
+		));
+		assert_noop!(
+			Prices::set_price_snapshot_interval(RuntimeOrigin::signed(ALICE), FIL, Some(10)),
+			Error::<Test>::TooManySnapshotAssets
+		);
+		// the interval of an asset with snapshots can still be updated
+		assert_ok!(Prices::set_price_snapshot_interval(RuntimeOrigin::signed(ALICE), DOT, Some(5)));
+		assert_eq!(PriceSnapshotIntervals::<Test>::get(DOT), Some(5));
+		System::assert_last_event(RuntimeEvent::Prices(crate::Event::SetPriceSnapshotInterval(
+			DOT, 5,
+		)));
+
+		// removing the interval removes the snapshots
+		Prices::on_finalize(5);
+		assert_eq!(PriceSnapshots::<Test>::get(DOT).len(), 1);
+		assert_ok!(Prices::set_price_snapshot_interval(RuntimeOrigin::signed(ALICE), DOT, None));
+		assert_eq!(PriceSnapshotIntervals::<Test>::get(DOT), None);
+		assert!(PriceSnapshots::<Test>::get(DOT).is_empty());
+		System::assert_last_event(RuntimeEvent::Prices(crate::Event::RemovePriceSnapshotInterval(
+			DOT,
+		)));
+		assert_noop!(
+			Prices::set_price_snapshot_interval(RuntimeOrigin::signed(ALICE), DOT, None),
+			Error::<Test>::SnapshotIntervalNotExist
+		);
+	});
+}
+
+#[test]
+fn price_snapshots_should_be_bounded_and_pruned() {
+	new_test_ext().execute_with(|| {
+		let snapshot_blocks = || {
+			PriceSnapshots::<Test>::get(DOT)
+				.iter()
+				.map(|snapshot| snapshot.block_number)
+				.collect::<Vec<_>>()
+		};
+		assert_ok!(Prices::set_price_snapshot_interval(RuntimeOrigin::signed(ALICE), DOT, Some(5)));
+		for n in 1..=14 {
+			Prices::on_finalize(n);
+		}
+		assert_eq!(snapshot_blocks(), vec![5, 10]);
+
+		// the new price is taken by the following snapshots
+		assert_ok!(Prices::set_price(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Price::saturating_from_integer(99)
+		));
+		for n in 15..=20 {
+			Prices::on_finalize(n);
+		}
+		// the oldest snapshot is dropped when the buffer is full
+		assert_eq!(snapshot_blocks(), vec![10, 15, 20]);
+
+		assert_eq!(
+			Prices::get_price_at(&DOT, 14),
+			Some(PriceSnapshot {
+				block_number: 10,
+				price: Price::from_inner(10_000_000_000 * PRICE_ONE),
+				timestamp: 0
+			})
+		);
+		assert_eq!(
+			Prices::get_price_at(&DOT, 100),
+			Some(PriceSnapshot {
+				block_number: 20,
+				price: Price::from_inner(9_900_000_000 * PRICE_ONE),
+				timestamp: 0
+			})
+		);
+		assert_eq!(Prices::get_price_at(&DOT, 9), None);
+		assert_eq!(
+			Prices::get_price_history(&DOT, 11, 20)
+				.iter()
+				.map(|snapshot| snapshot.block_number)
+				.collect::<Vec<_>>(),
+			vec![15, 20]
+		);
+
+		// snapshots older than `PriceSnapshotMaxAge` are pruned
+		assert_ok!(Prices::set_price_snapshot_interval(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Some(15)
+		));
+		for n in 21..=30 {
+			Prices::on_finalize(n);
+		}
+		assert_eq!(snapshot_blocks(), vec![15, 20, 30]);
+		for n in 31..=45 {
+			Prices::on_finalize(n);
+		}
+		assert_eq!(snapshot_blocks(), vec![30, 45]);
+	});
+}
+
+#[test]
+fn get_derived_price_should_work() {
+	new_test_ext().execute_with(|| {
+		// the derived price is returned even if it deviates from the direct feed
+		VtokenExchangeRates::set(vec![(VDOT, FixedU128::from_rational(6, 5))]);
+		assert_eq!(Prices::get_price(&VDOT), None);
+		assert_eq!(
+			Prices::get_derived_price(&VDOT),
+			Some((Price::from_inner(12_000_000_000 * PRICE_ONE), 0))
+		);
+		assert_eq!(Prices::get_derived_price(&DOT), None);
+	});
+}
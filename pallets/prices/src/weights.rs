@@ -29,6 +29,12 @@ pub trait WeightInfo {
 	fn set_price() -> Weight;
 	fn reset_price() -> Weight;
 	fn set_foreign_asset() -> Weight;
+	fn set_price_config() -> Weight;
+	fn feed_xcm_price() -> Weight;
+	fn reset_circuit_breaker() -> Weight;
+	fn aggregate_prices(n: u32) -> Weight;
+	fn set_price_snapshot_interval() -> Weight;
+	fn take_price_snapshots(n: u32) -> Weight;
 }
 
 /// Weights for pallet_prices using the Substrate node and recommended hardware.
@@ -49,6 +55,40 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	fn set_price_config() -> Weight {
+		Weight::from_parts(25_123_000 as u64, 3979)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	fn feed_xcm_price() -> Weight {
+		Weight::from_parts(24_602_000 as u64, 3979)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn reset_circuit_breaker() -> Weight {
+		Weight::from_parts(21_845_000 as u64, 3979)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn aggregate_prices(n: u32) -> Weight {
+		Weight::from_parts(3_164_000 as u64, 1489)
+			.saturating_add(Weight::from_parts(45_816_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((11 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
+	}
+	fn set_price_snapshot_interval() -> Weight {
+		Weight::from_parts(22_704_000 as u64, 3979)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn take_price_snapshots(n: u32) -> Weight {
+		Weight::from_parts(2_918_000 as u64, 1489)
+			.saturating_add(Weight::from_parts(38_207_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((6 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -68,4 +108,38 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	fn set_price_config() -> Weight {
+		Weight::from_parts(25_123_000 as u64, 3979)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	fn feed_xcm_price() -> Weight {
+		Weight::from_parts(24_602_000 as u64, 3979)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn reset_circuit_breaker() -> Weight {
+		Weight::from_parts(21_845_000 as u64, 3979)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn aggregate_prices(n: u32) -> Weight {
+		Weight::from_parts(3_164_000 as u64, 1489)
+			.saturating_add(Weight::from_parts(45_816_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((11 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
+	}
+	fn set_price_snapshot_interval() -> Weight {
+		Weight::from_parts(22_704_000 as u64, 3979)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn take_price_snapshots(n: u32) -> Weight {
+		Weight::from_parts(2_918_000 as u64, 1489)
+			.saturating_add(Weight::from_parts(38_207_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((6 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
 }
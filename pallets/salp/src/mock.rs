@@ -175,6 +175,7 @@ pub type BifrostToken =
 
 impl bifrost_currencies::Config for Test {
 	type GetNativeCurrencyId = NativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = BifrostToken;
 	type WeightInfo = ();
@@ -357,6 +358,14 @@ impl bifrost_stable_pool::Config for Test {
 	type VtokenMinting = VtokenMinting;
 	type CurrencyIdConversion = AssetIdMaps<Test>;
 	type CurrencyIdRegister = AssetIdMaps<Test>;
+	type DexOperator = ZenlinkProtocol;
+	type ParachainId = ParaInfo;
+	type MaxSwapHops = ConstU32<3>;
+	type MaxRouterPools = ConstU32<10>;
+	type OraclePriceProvider = ();
+	type LendMarket = ();
+	type Farming = ();
+	type AssetIdMaps = AssetIdMaps<Test>;
 }
 
 parameter_types! {
@@ -146,6 +146,7 @@ pub type BifrostToken = bifrost_currencies::BasicCurrencyAdapter<Runtime, Balanc
 
 impl bifrost_currencies::Config for Runtime {
 	type GetNativeCurrencyId = NativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = BifrostToken;
 	type WeightInfo = ();
@@ -28,7 +28,7 @@ use bifrost_primitives::{
 	Amount, Balance, BifrostEntranceAccount, BifrostExitAccount, BifrostFeeAccount, CurrencyId,
 	IncentivePoolAccount, MockXcmExecutor, MockXcmRouter, MoonbeamChainId,
 	ParachainStakingPalletId, SlpxOperator, StableAssetPalletId, TokenSymbol,
-	XcmDestWeightAndFeeHandler, XcmOperationType,
+	XcmDestWeightAndFeeHandler, XcmOperationType, ZenlinkPalletId,
 };
 pub use cumulus_primitives_core::ParaId;
 use frame_support::{
@@ -40,17 +40,20 @@ use frame_support::{
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use hex_literal::hex;
-use orml_traits::{location::RelativeReserveProvider, parameter_type_with_key};
+use orml_traits::{location::RelativeReserveProvider, parameter_type_with_key, MultiCurrency};
 use parity_scale_codec::{Decode, Encode};
 use sp_core::{bounded::BoundedVec, hashing::blake2_256};
 use sp_runtime::{
-	traits::{AccountIdConversion, Convert, TrailingZeroInput},
-	AccountId32, BuildStorage,
+	traits::{AccountIdConversion, Convert, TrailingZeroInput, UniqueSaturatedInto},
+	AccountId32, BuildStorage, DispatchError, SaturatedConversion,
 };
-use sp_std::{boxed::Box, vec::Vec};
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
 use xcm::v3::{prelude::*, Weight};
 use xcm_builder::{FixedWeightBounds, FrameTransactionalProcessor};
 use xcm_executor::traits::{Properties, ShouldExecute};
+use zenlink_protocol::{
+	AssetBalance, AssetId as ZenlinkAssetId, LocalAssetHandler, PairLpGenerate, ZenlinkMultiAssets,
+};
 
 pub type AccountId = AccountId32;
 pub type Block = frame_system::mocking::MockBlock<Runtime>;
@@ -74,6 +77,7 @@ construct_runtime!(
 		PolkadotXcm: pallet_xcm,
 		StableAsset: bifrost_stable_asset,
 		StablePool: bifrost_stable_pool,
+		ZenlinkProtocol: zenlink_protocol,
 	}
 );
 
@@ -86,6 +90,90 @@ impl bifrost_stable_pool::Config for Runtime {
 	type VtokenMinting = VtokenMinting;
 	type CurrencyIdConversion = AssetIdMaps<Runtime>;
 	type CurrencyIdRegister = AssetIdMaps<Runtime>;
+	type DexOperator = ZenlinkProtocol;
+	type ParachainId = ParachainId;
+	type MaxSwapHops = ConstU32<3>;
+	type MaxRouterPools = ConstU32<10>;
+	type OraclePriceProvider = ();
+	type LendMarket = ();
+	type Farming = ();
+	type AssetIdMaps = AssetIdMaps<Runtime>;
+}
+
+parameter_types! {
+	pub const SelfParaId: u32 = 2001;
+}
+
+impl zenlink_protocol::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MultiAssetsHandler = MultiAssets;
+	type PalletId = ZenlinkPalletId;
+	type SelfParaId = SelfParaId;
+	type TargetChains = ();
+	type WeightInfo = ();
+	type AssetId = ZenlinkAssetId;
+	type LpGenerate = PairLpGenerate<Self>;
+}
+
+type MultiAssets = ZenlinkMultiAssets<ZenlinkProtocol, Balances, LocalAssetAdaptor<Currencies>>;
+
+// Below is the implementation of tokens manipulation functions other than native token.
+pub struct LocalAssetAdaptor<Local>(PhantomData<Local>);
+
+impl<Local, AccountId> LocalAssetHandler<AccountId> for LocalAssetAdaptor<Local>
+where
+	Local: MultiCurrency<AccountId, CurrencyId = CurrencyId>,
+{
+	fn local_balance_of(asset_id: ZenlinkAssetId, who: &AccountId) -> AssetBalance {
+		let currency_id: CurrencyId = asset_id.try_into().unwrap();
+		Local::free_balance(currency_id, &who).saturated_into()
+	}
+
+	fn local_total_supply(asset_id: ZenlinkAssetId) -> AssetBalance {
+		let currency_id: CurrencyId = asset_id.try_into().unwrap();
+		Local::total_issuance(currency_id).saturated_into()
+	}
+
+	fn local_is_exists(asset_id: ZenlinkAssetId) -> bool {
+		let rs: Result<CurrencyId, _> = asset_id.try_into();
+		match rs {
+			Ok(_) => true,
+			Err(_) => false,
+		}
+	}
+
+	fn local_transfer(
+		asset_id: ZenlinkAssetId,
+		origin: &AccountId,
+		target: &AccountId,
+		amount: AssetBalance,
+	) -> DispatchResult {
+		let currency_id: CurrencyId = asset_id.try_into().unwrap();
+		Local::transfer(currency_id, &origin, &target, amount.unique_saturated_into())?;
+
+		Ok(())
+	}
+
+	fn local_deposit(
+		asset_id: ZenlinkAssetId,
+		origin: &AccountId,
+		amount: AssetBalance,
+	) -> Result<AssetBalance, DispatchError> {
+		let currency_id: CurrencyId = asset_id.try_into().unwrap();
+		Local::deposit(currency_id, &origin, amount.unique_saturated_into())?;
+		return Ok(amount);
+	}
+
+	fn local_withdraw(
+		asset_id: ZenlinkAssetId,
+		origin: &AccountId,
+		amount: AssetBalance,
+	) -> Result<AssetBalance, DispatchError> {
+		let currency_id: CurrencyId = asset_id.try_into().unwrap();
+		Local::withdraw(currency_id, &origin, amount.unique_saturated_into())?;
+
+		Ok(amount)
+	}
 }
 
 pub struct EnsurePoolAssetId;
@@ -195,6 +283,7 @@ pub type BifrostToken = bifrost_currencies::BasicCurrencyAdapter<Runtime, Balanc
 
 impl bifrost_currencies::Config for Runtime {
 	type GetNativeCurrencyId = NativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = BifrostToken;
 	type WeightInfo = ();
@@ -17,17 +17,31 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::*;
+use crate::{
+	types::{
+		AccountIdOf, AccountType, CallbackConfiguration, DestinationInfo, FailedOrder, Order,
+		OrderCallback, OrderType, ASTAR_DESTINATION, MOONBEAM_DESTINATION,
+		MOONBEAM_NATIVE_PALLET_INSTANCE, ORDER_STATUS_SUCCESS,
+	},
+	*,
+};
 use bifrost_asset_registry::CurrencyIdToLocations;
 use bifrost_primitives::{KSM, VKSM};
 use frame_benchmarking::v2::*;
-use frame_support::{assert_ok, sp_runtime::traits::UniqueSaturatedFrom, BoundedVec};
+use frame_support::{
+	assert_ok,
+	sp_runtime::traits::{UniqueSaturatedFrom, Zero},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 
 fn init_whitelist<T: Config + bifrost_asset_registry::Config>() -> (T::AccountId, H160) {
 	let caller: T::AccountId = whitelisted_caller();
+	for (destination_id, info) in crate::migration::v3::legacy_destinations::<T>() {
+		Destinations::<T>::insert(destination_id, info);
+	}
 	WhitelistAccountId::<T>::insert(
-		SupportChain::Astar,
+		ASTAR_DESTINATION,
 		BoundedVec::try_from(vec![caller.clone()]).unwrap(),
 	);
 	let addr: [u8; 20] = hex_literal::hex!["3Cd0A705a2DC65e5b1E1205896BaA2be8A07c6e0"].into();
@@ -51,6 +65,42 @@ fn init_whitelist<T: Config + bifrost_asset_registry::Config>() -> (T::AccountId
 	(caller, receiver)
 }
 
+fn set_moonbeam_callback<T: Config>(contract: H160) {
+	Destinations::<T>::mutate(MOONBEAM_DESTINATION, |info| {
+		if let Some(info) = info {
+			info.callback = Some(CallbackConfiguration {
+				contract: Some(contract),
+				fee_pallet_instance: MOONBEAM_NATIVE_PALLET_INSTANCE,
+				xcm_fee: 1_000_000_000_000_000,
+				xcm_weight: Weight::from_parts(4_000_000_000, 50_000),
+			});
+		}
+	});
+}
+
+fn new_order<T: Config>(
+	caller: T::AccountId,
+	source_chain_caller: OrderCaller<T::AccountId>,
+	receiver: H160,
+) -> Order<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>> {
+	Order {
+		source_chain_caller,
+		source_chain_id: Default::default(),
+		source_chain_block_number: None,
+		bifrost_chain_caller: caller,
+		derivative_account: Pallet::<T>::h160_to_account_id(&receiver),
+		create_block_number: frame_system::Pallet::<T>::block_number(),
+		currency_id: KSM,
+		currency_amount: BalanceOf::<T>::unique_saturated_from(100_000_000_000_000u128),
+		order_type: OrderType::Mint,
+		remark: BoundedVec::default(),
+		target_chain: TargetChain::Astar(receiver),
+		channel_id: 0,
+		callback: None,
+		redeem_fee: None,
+	}
+}
+
 #[benchmarks(where  T: Config + bifrost_asset_registry::Config + orml_tokens::Config<CurrencyId = CurrencyId>)]
 mod benchmarks {
 	use super::*;
@@ -62,7 +112,7 @@ mod benchmarks {
 		let receiver = H160::from(addr);
 
 		#[extrinsic_call]
-		_(RawOrigin::Root, SupportChain::Astar, receiver);
+		_(RawOrigin::Root, ASTAR_DESTINATION, receiver);
 	}
 
 	#[benchmark]
@@ -71,12 +121,12 @@ mod benchmarks {
 		let address = H160::from_slice(&address);
 
 		let _ =
-			crate::Pallet::<T>::add_whitelist(RawOrigin::Root.into(), SupportChain::Astar, address);
+			crate::Pallet::<T>::add_whitelist(RawOrigin::Root.into(), ASTAR_DESTINATION, address);
 
 		#[extrinsic_call]
-		_(RawOrigin::Root, SupportChain::Astar, address);
+		_(RawOrigin::Root, ASTAR_DESTINATION, address);
 
-		assert_eq!(WhitelistAccountId::<T>::get(SupportChain::Astar).first(), None);
+		assert_eq!(WhitelistAccountId::<T>::get(ASTAR_DESTINATION).first(), None);
 	}
 
 	#[benchmark]
@@ -90,9 +140,9 @@ mod benchmarks {
 	#[benchmark]
 	fn set_transfer_to_fee() {
 		#[extrinsic_call]
-		_(RawOrigin::Root, SupportChain::Moonbeam, 10u32.into());
+		_(RawOrigin::Root, MOONBEAM_DESTINATION, 10u32.into());
 
-		assert_eq!(TransferToFee::<T>::get(SupportChain::Moonbeam), Some(10u32.into()));
+		assert_eq!(TransferToFee::<T>::get(MOONBEAM_DESTINATION), Some(10u32.into()));
 	}
 
 	#[benchmark]
@@ -124,6 +174,34 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn create_order_with_callback() {
+		let (caller, receiver) = init_whitelist::<T>();
+		WhitelistAccountId::<T>::insert(
+			MOONBEAM_DESTINATION,
+			BoundedVec::try_from(vec![caller.clone()]).unwrap(),
+		);
+		set_moonbeam_callback::<T>(receiver);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			receiver,
+			KSM,
+			TargetChain::Moonbeam(receiver),
+			BoundedVec::default(),
+			0u32,
+			OrderCallback {
+				order_id: 0,
+				source_chain: MOONBEAM_DESTINATION,
+				contract: Some(receiver),
+				selector: [0u8; 4],
+			},
+		);
+
+		assert_eq!(OrderQueueLength::<T>::get(), 1);
+	}
+
 	#[benchmark]
 	fn redeem() {
 		let (caller, receiver) = init_whitelist::<T>();
@@ -131,6 +209,127 @@ mod benchmarks {
 		_(RawOrigin::Signed(caller), receiver, VKSM, TargetChain::Astar(receiver));
 	}
 
+	#[benchmark]
+	fn handle_mint_order() {
+		let (caller, receiver) = init_whitelist::<T>();
+		assert_ok!(Pallet::<T>::mint(
+			RawOrigin::Signed(caller).into(),
+			receiver,
+			KSM,
+			TargetChain::Astar(receiver),
+			BoundedVec::default(),
+		));
+		let current_block = frame_system::Pallet::<T>::block_number();
+
+		#[block]
+		{
+			assert_ok!(Pallet::<T>::handle_order_queue(
+				current_block,
+				Weight::MAX,
+				&mut Weight::zero()
+			));
+		}
+
+		assert_eq!(OrderQueueLength::<T>::get(), 0);
+	}
+
+	#[benchmark]
+	fn handle_redeem_order() {
+		let (caller, receiver) = init_whitelist::<T>();
+		assert_ok!(Pallet::<T>::redeem(
+			RawOrigin::Signed(caller).into(),
+			receiver,
+			VKSM,
+			TargetChain::Astar(receiver),
+		));
+		let current_block = frame_system::Pallet::<T>::block_number();
+
+		#[block]
+		{
+			assert_ok!(Pallet::<T>::handle_order_queue(
+				current_block,
+				Weight::MAX,
+				&mut Weight::zero()
+			));
+		}
+
+		assert_eq!(OrderQueueLength::<T>::get(), 0);
+	}
+
+	#[benchmark]
+	fn set_destination() {
+		let info = DestinationInfo {
+			para_id: 2004,
+			account_type: AccountType::AccountKey20,
+			open: false,
+			fee_currency: Some(BNC),
+			xcm_weight: Some(Weight::from_parts(4_000_000_000, 0)),
+			callback: Some(CallbackConfiguration {
+				contract: Some(H160::default()),
+				fee_pallet_instance: MOONBEAM_NATIVE_PALLET_INSTANCE,
+				xcm_fee: 1_000_000_000_000_000,
+				xcm_weight: Weight::from_parts(4_000_000_000, 50_000),
+			}),
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, 10, Some(info.clone()));
+
+		assert_eq!(Destinations::<T>::get(10), Some(info));
+	}
+
+	#[benchmark]
+	fn send_order_callback() {
+		let (caller, receiver) = init_whitelist::<T>();
+		set_moonbeam_callback::<T>(receiver);
+		let mut order = new_order::<T>(caller, OrderCaller::Evm(receiver), receiver);
+		order.target_chain = TargetChain::Moonbeam(receiver);
+		order.callback = Some(OrderCallback {
+			order_id: 0,
+			source_chain: MOONBEAM_DESTINATION,
+			contract: Some(receiver),
+			selector: [0u8; 4],
+		});
+
+		#[block]
+		{
+			Pallet::<T>::send_order_callback(&order, 100u32.into(), ORDER_STATUS_SUCCESS);
+		}
+	}
+
+	#[benchmark]
+	fn retry_failed_order() {
+		let (caller, receiver) = init_whitelist::<T>();
+		let order = new_order::<T>(caller, OrderCaller::Evm(receiver), receiver);
+		FailedOrders::<T>::insert(
+			0,
+			FailedOrder { order, retry_count: 0, next_retry_block: Zero::zero() },
+		);
+		let current_block = frame_system::Pallet::<T>::block_number();
+
+		#[block]
+		{
+			assert_ok!(Pallet::<T>::handle_failed_orders(
+				current_block,
+				Weight::MAX,
+				&mut Weight::zero()
+			));
+		}
+	}
+
+	#[benchmark]
+	fn claim_refund() {
+		let (caller, receiver) = init_whitelist::<T>();
+		let order =
+			new_order::<T>(caller.clone(), OrderCaller::Substrate(caller.clone()), receiver);
+		RefundableOrders::<T>::insert(0, order);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0);
+
+		assert!(RefundableOrders::<T>::get(0).is_none());
+	}
+
 	//   `cargo test -p pallet-example-basic --all-features`, you will see one line per case:
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
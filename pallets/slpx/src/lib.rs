@@ -18,16 +18,19 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 use crate::types::{
-	AccountIdOf, BalanceOf, CurrencyIdOf, EthereumCallConfiguration, EthereumXcmCall,
-	EthereumXcmTransaction, EthereumXcmTransactionV2, MoonbeamCall, Order, OrderCaller, OrderType,
-	SupportChain, TargetChain, EVM_FUNCTION_SELECTOR, MAX_GAS_LIMIT,
+	AccountIdOf, AccountType, BalanceOf, CurrencyIdOf, DestinationAccount, DestinationId,
+	DestinationInfo, EthereumCallConfiguration, EthereumXcmCall, EthereumXcmTransaction,
+	EthereumXcmTransactionV2, FailedOrder, MoonbeamCall, Order, OrderBlockInfo, OrderCallback,
+	OrderCaller, OrderType, TargetChain, ASTAR_DESTINATION, EVM_FUNCTION_SELECTOR, MAX_GAS_LIMIT,
+	MOONBEAM_DESTINATION, MOONBEAM_NATIVE_PALLET_INSTANCE, ORDER_STATUS_REFUNDED,
+	ORDER_STATUS_SUCCESS,
 };
 use bifrost_asset_registry::AssetMetadata;
 use bifrost_primitives::{
-	currency::{BNC, MOVR, VFIL},
-	AstarChainId, AstarEvmChainId, Balance, BifrostKusamaChainId, CurrencyId, CurrencyIdMapping,
-	HydrationChainId, InterlayChainId, MantaChainId, MoonbeamEvmChainId, MoonriverEvmChainId,
-	RedeemType, SlpxOperator, TokenInfo, VtokenMintingInterface, GLMR,
+	currency::{BNC, MOVR},
+	AstarEvmChainId, Balance, BifrostKusamaChainId, CurrencyId, CurrencyIdMapping,
+	MoonbeamEvmChainId, MoonriverEvmChainId, RedeemType, SlpxOperator, TokenInfo,
+	VtokenMintingInterface, GLMR,
 };
 use cumulus_primitives_core::ParaId;
 use ethereum::TransactionAction;
@@ -82,7 +85,7 @@ pub mod pallet {
 	};
 	use frame_system::ensure_root;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -124,6 +127,15 @@ pub mod pallet {
 		/// The maximum number of order is 500
 		#[pallet::constant]
 		type MaxOrderSize: Get<u32>;
+		/// The maximum number of orders handled in one block
+		#[pallet::constant]
+		type MaxOrdersPerBlock: Get<u32>;
+		/// The maximum number of retries of a failed order before it is refunded
+		#[pallet::constant]
+		type MaxOrderRetries: Get<u32>;
+		/// The base interval of retrying a failed order, doubled on every retry
+		#[pallet::constant]
+		type OrderRetryInterval: Get<BlockNumberFor<Self>>;
 	}
 
 	#[pallet::event]
@@ -131,8 +143,8 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// Add the contract account to the whitelist
 		AddWhitelistAccountId {
-			/// The support chain of Slpx
-			support_chain: SupportChain,
+			/// The destination chain of Slpx
+			support_chain: DestinationId,
 			/// The contract address of the contract
 			contract_address: H160,
 			/// Xcm derivative account id
@@ -140,8 +152,8 @@ pub mod pallet {
 		},
 		/// Remove the contract account from the whitelist
 		RemoveWhitelistAccountId {
-			/// The support chain of Slpx
-			support_chain: SupportChain,
+			/// The destination chain of Slpx
+			support_chain: DestinationId,
 			/// The contract address of the contract
 			contract_address: H160,
 			/// Xcm derivative account id
@@ -149,11 +161,18 @@ pub mod pallet {
 		},
 		/// Set the transfer fee for the currency, only for Moonbeam
 		SetTransferToFee {
-			/// The support chain of Slpx
-			support_chain: SupportChain,
+			/// The destination chain of Slpx
+			support_chain: DestinationId,
 			/// The transfer fee of the token
 			transfer_to_fee: BalanceOf<T>,
 		},
+		/// Set the destination chain
+		SetDestination {
+			/// The id of the destination chain
+			destination_id: DestinationId,
+			/// The destination chain info, removed if `None`
+			info: Option<DestinationInfo<CurrencyIdOf<T>>>,
+		},
 		/// Set the execution fee for the order
 		SetExecutionFee {
 			/// The currency id of the token
@@ -214,6 +233,54 @@ pub mod pallet {
 		OrderFailed {
 			order: Order<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
 		},
+		/// Failed order is scheduled to retry
+		OrderRetryScheduled {
+			/// The id of the failed order
+			order_id: u64,
+			/// The number of retries already made
+			retry_count: u32,
+			/// The order will be retried after this block
+			next_retry_block: BlockNumberFor<T>,
+		},
+		/// Failed order is refunded
+		OrderRefunded {
+			/// The id of the failed order
+			order_id: u64,
+			/// The currency id of the refund
+			currency_id: CurrencyId,
+			/// The amount of the refund
+			amount: BalanceOf<T>,
+		},
+		/// Failed order refund failed, it will be retried
+		OrderRefundFailed {
+			/// The id of the failed order
+			order_id: u64,
+			/// The error
+			error: DispatchError,
+		},
+		/// Failed order can be refunded by the caller with `claim_refund`
+		OrderRefundable {
+			/// The id of the failed order
+			order_id: u64,
+		},
+		/// Order callback is sent to the source chain
+		OrderCallbackSent {
+			/// The order id assigned by the caller
+			order_id: u64,
+			/// The contract called back
+			contract: H160,
+			/// The amount reported by the callback, depending on the status
+			amount: BalanceOf<T>,
+			/// The status of the order
+			status: u8,
+		},
+		/// Order callback failed to be sent
+		OrderCallbackFailed {
+			/// The order id assigned by the caller
+			order_id: u64,
+			/// The error
+			error: DispatchError,
+		},
 		/// Xcm oracle failed
 		XcmOracleFailed { error: DispatchError },
 		/// Withdraw xcm fee
@@ -252,6 +319,18 @@ pub mod pallet {
 		ErrorChargeFee,
 		ErrorArguments,
 		Unsupported,
+		/// The failed order does not exist
+		FailedOrderNotFound,
+		/// The caller is not the caller of the order
+		NotOrderCaller,
+		/// The destination chain is not registered
+		DestinationNotFound,
+		/// The receiver does not match the account type of the destination chain
+		InvalidReceiver,
+		/// The callback is not supported by the destination chain
+		InvalidCallback,
+		/// The destination chain info is invalid
+		InvalidDestination,
 	}
 
 	/// Contract whitelist
@@ -259,7 +338,7 @@ pub mod pallet {
 	pub type WhitelistAccountId<T: Config> = StorageMap<
 		_,
 		Blake2_128Concat,
-		SupportChain,
+		DestinationId,
 		BoundedVec<AccountIdOf<T>, ConstU32<10>>,
 		ValueQuery,
 	>;
@@ -269,10 +348,15 @@ pub mod pallet {
 	pub type ExecutionFee<T: Config> =
 		StorageMap<_, Blake2_128Concat, CurrencyId, BalanceOf<T>, OptionQuery>;
 
-	/// XCM fee for transferring to Moonbeam(BNC)
+	/// XCM fee for transferring to the destination chain, paid with its fee currency
 	#[pallet::storage]
 	pub type TransferToFee<T: Config> =
-		StorageMap<_, Blake2_128Concat, SupportChain, BalanceOf<T>, OptionQuery>;
+		StorageMap<_, Blake2_128Concat, DestinationId, BalanceOf<T>, OptionQuery>;
+
+	/// Destination chains registered by governance
+	#[pallet::storage]
+	pub type Destinations<T: Config> =
+		StorageMap<_, Twox64Concat, DestinationId, DestinationInfo<CurrencyIdOf<T>>, OptionQuery>;
 
 	/// Xcm Oracle configuration
 	#[pallet::storage]
@@ -290,16 +374,58 @@ pub mod pallet {
 		StorageValue<_, BoundedVec<CurrencyId, ConstU32<100>>, ValueQuery>;
 
 	/// Order queue
+	///
+	/// double_map (create_block_number, index) => Order
 	#[pallet::storage]
-	pub type OrderQueue<T: Config> = StorageValue<
+	pub type OrderQueue<T: Config> = StorageDoubleMap<
 		_,
-		BoundedVec<
-			Order<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
-			T::MaxOrderSize,
-		>,
-		ValueQuery,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Twox64Concat,
+		u32,
+		Order<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
+	>;
+
+	/// The orders of each create block number, linked in the order of creation
+	#[pallet::storage]
+	pub type OrderBlocks<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, OrderBlockInfo<BlockNumberFor<T>>>;
+
+	/// The first and the last create block number in the order queue
+	#[pallet::storage]
+	pub type OrderQueueBounds<T: Config> =
+		StorageValue<_, (BlockNumberFor<T>, BlockNumberFor<T>), OptionQuery>;
+
+	/// The number of orders in the order queue
+	#[pallet::storage]
+	pub type OrderQueueLength<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Failed orders waiting to be retried or refunded
+	#[pallet::storage]
+	pub type FailedOrders<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		u64,
+		FailedOrder<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
 	>;
 
+	/// The last failed order visited by the previous block, the next block resumes after it
+	#[pallet::storage]
+	pub type FailedOrdersCursor<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+	/// Failed orders of substrate callers whose retries are exhausted, waiting to be claimed
+	#[pallet::storage]
+	pub type RefundableOrders<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		u64,
+		Order<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
+	>;
+
+	/// The id of the next failed order
+	#[pallet::storage]
+	pub type NextFailedOrderId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
 	/// Delay block
 	#[pallet::storage]
 	pub type DelayBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
@@ -323,7 +449,8 @@ pub mod pallet {
 			}
 
 			if !is_handle_xcm_oracle {
-				let _ = Self::handle_order_queue(n, &mut weight);
+				let _ = Self::handle_order_queue(n, limit, &mut weight);
+				let _ = Self::handle_failed_orders(n, limit, &mut weight);
 			}
 			weight
 		}
@@ -359,6 +486,8 @@ pub mod pallet {
 				remark,
 				0u32,
 				target_chain,
+				None,
+				None,
 			)
 		}
 
@@ -380,15 +509,12 @@ pub mod pallet {
 			let (source_chain_caller, frontier_derivative_account, bifrost_chain_caller) =
 				Self::ensure_singer_on_whitelist(origin, evm_caller, &target_chain)?;
 
-			if vtoken_id == VFIL {
-				let fee_amount = Self::get_moonbeam_transfer_to_fee();
-				T::MultiCurrency::transfer(
-					BNC,
-					&evm_contract_account_id,
-					&frontier_derivative_account,
-					fee_amount,
-				)?;
-			}
+			let redeem_fee = Self::prepay_redeem_fee(
+				vtoken_id,
+				&target_chain,
+				&evm_contract_account_id,
+				&frontier_derivative_account,
+			)?;
 
 			Self::do_create_order(
 				source_chain_caller,
@@ -400,18 +526,20 @@ pub mod pallet {
 				Default::default(),
 				0u32,
 				target_chain,
+				None,
+				redeem_fee,
 			)
 		}
 
 		/// Add the contract account to the whitelist
 		/// Parameters:
-		/// - `support_chain`: The support chain of Slpx
+		/// - `support_chain`: The destination chain of Slpx
 		/// - `contract_address`: The contract address of the contract
 		#[pallet::call_index(4)]
 		#[pallet::weight(<T as Config>::WeightInfo::add_whitelist())]
 		pub fn add_whitelist(
 			origin: OriginFor<T>,
-			support_chain: SupportChain,
+			support_chain: DestinationId,
 			contract_address: H160,
 		) -> DispatchResultWithPostInfo {
 			// Check the validity of origin
@@ -437,13 +565,13 @@ pub mod pallet {
 
 		/// Remove the contract account from the whitelist
 		/// Parameters:
-		/// - `support_chain`: The support chain of Slpx
+		/// - `support_chain`: The destination chain of Slpx
 		/// - `contract_address`: The contract address of the contract
 		#[pallet::call_index(5)]
 		#[pallet::weight(<T as Config>::WeightInfo::remove_whitelist())]
 		pub fn remove_whitelist(
 			origin: OriginFor<T>,
-			support_chain: SupportChain,
+			support_chain: DestinationId,
 			contract_address: H160,
 		) -> DispatchResultWithPostInfo {
 			// Check the validity of origin
@@ -485,13 +613,13 @@ pub mod pallet {
 
 		/// Set the transfer fee for the currency
 		/// Parameters:
-		/// - `support_chain`: The support chain of Slpx
+		/// - `support_chain`: The destination chain of Slpx
 		/// - `transfer_to_fee`: The transfer fee of the token
 		#[pallet::call_index(7)]
 		#[pallet::weight(<T as Config>::WeightInfo::set_transfer_to_fee())]
 		pub fn set_transfer_to_fee(
 			origin: OriginFor<T>,
-			support_chain: SupportChain,
+			support_chain: DestinationId,
 			transfer_to_fee: BalanceOf<T>,
 		) -> DispatchResultWithPostInfo {
 			// Check the validity of origin
@@ -638,6 +766,8 @@ pub mod pallet {
 				remark,
 				channel_id,
 				target_chain,
+				None,
+				None,
 			)
 		}
 
@@ -671,6 +801,8 @@ pub mod pallet {
 				remark,
 				channel_id,
 				target_chain,
+				None,
+				None,
 			)
 		}
 
@@ -719,6 +851,118 @@ pub mod pallet {
 				remark,
 				channel_id,
 				send_to,
+				None,
+				None,
+			)
+		}
+
+		/// Claim the refund of a failed order created by a substrate caller, once its retries are
+		/// exhausted.
+		/// Parameters:
+		/// - `order_id`: The id of the failed order
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_refund())]
+		pub fn claim_refund(origin: OriginFor<T>, order_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// Only the orders whose retries are exhausted can be refunded
+			let order =
+				RefundableOrders::<T>::get(order_id).ok_or(Error::<T>::FailedOrderNotFound)?;
+			ensure!(
+				order.source_chain_caller == OrderCaller::Substrate(who.clone()),
+				Error::<T>::NotOrderCaller
+			);
+
+			let amount =
+				T::MultiCurrency::free_balance(order.currency_id, &order.derivative_account)
+					.min(order.currency_amount);
+			if order.derivative_account != who {
+				T::MultiCurrency::transfer(
+					order.currency_id,
+					&order.derivative_account,
+					&who,
+					amount,
+				)?;
+			}
+			RefundableOrders::<T>::remove(order_id);
+
+			Self::deposit_event(Event::OrderRefunded {
+				order_id,
+				currency_id: order.currency_id,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Register, update or remove a destination chain
+		/// Parameters:
+		/// - `destination_id`: The id of the destination chain
+		/// - `info`: The destination chain info, removed if `None`
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_destination())]
+		pub fn set_destination(
+			origin: OriginFor<T>,
+			destination_id: DestinationId,
+			info: Option<DestinationInfo<CurrencyIdOf<T>>>,
+		) -> DispatchResultWithPostInfo {
+			// Check the validity of origin
+			T::ControlOrigin::ensure_origin(origin)?;
+			if let Some(info) = &info {
+				Self::ensure_valid_destination(info)?;
+			}
+			Destinations::<T>::set(destination_id, info.clone());
+			Self::deposit_event(Event::SetDestination { destination_id, info });
+			Ok(().into())
+		}
+
+		/// vtoken mint or redeem and transfer to target chain, the contract on the source chain
+		/// is called back after the order is handled
+		/// Parameters:
+		/// - `evm_caller`: The caller of the EVM contract
+		/// - `currency_id`: The currency id of the token to be minted or vtoken to be redeemed
+		/// - `target_chain`: The target chain to transfer the token to
+		/// - `remark`: The remark of the order
+		/// - `channel_id`: The channel id of the order
+		/// - `callback`: The callback of the order, the signer must be a contract whitelisted on
+		///   its source chain
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_order_with_callback())]
+		pub fn create_order_with_callback(
+			origin: OriginFor<T>,
+			evm_caller: H160,
+			currency_id: CurrencyIdOf<T>,
+			target_chain: TargetChain<AccountIdOf<T>>,
+			remark: BoundedVec<u8, ConstU32<32>>,
+			channel_id: u32,
+			callback: OrderCallback,
+		) -> DispatchResultWithPostInfo {
+			let bifrost_chain_caller = ensure_signed(origin)?;
+
+			// The callback is sent back to the chain of the contract creating the order
+			ensure!(
+				WhitelistAccountId::<T>::get(callback.source_chain).contains(&bifrost_chain_caller),
+				Error::<T>::AccountNotFound
+			);
+
+			let redeem_fee = Self::prepay_redeem_fee(
+				currency_id,
+				&target_chain,
+				&bifrost_chain_caller,
+				&Self::h160_to_account_id(&evm_caller),
+			)?;
+
+			Self::do_create_order(
+				OrderCaller::Evm(evm_caller),
+				Default::default(),
+				None,
+				bifrost_chain_caller,
+				currency_id,
+				Default::default(),
+				remark,
+				channel_id,
+				target_chain,
+				Some(callback),
+				redeem_fee,
 			)
 		}
 
@@ -751,13 +995,13 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
-	fn match_source_chain_id(source_chain_id: u64) -> Option<SupportChain> {
+	fn match_source_chain_id(source_chain_id: u64) -> Option<DestinationId> {
 		if source_chain_id == AstarEvmChainId::get() {
-			Some(SupportChain::Astar)
+			Some(ASTAR_DESTINATION)
 		} else if source_chain_id == MoonriverEvmChainId::get() ||
 			source_chain_id == MoonbeamEvmChainId::get()
 		{
-			Some(SupportChain::Moonbeam)
+			Some(MOONBEAM_DESTINATION)
 		} else {
 			None
 		}
@@ -783,31 +1027,16 @@ impl<T: Config> Pallet<T> {
 
 	/// According to Xcm, return the account id
 	fn xcm_derivative_account(
-		support_chain: SupportChain,
+		support_chain: DestinationId,
 		contract_address: H160,
 	) -> Result<T::AccountId, Error<T>> {
-		let location = match support_chain {
-			SupportChain::Astar => {
-				let account_id = Self::h160_to_account_id(&contract_address);
-				let id: [u8; 32] =
-					account_id.encode().try_into().map_err(|_| Error::<T>::ErrorEncode)?;
-				Location::new(
-					1,
-					[Parachain(AstarChainId::get()), AccountId32 { network: None, id }],
-				)
-			},
-			SupportChain::Moonbeam => Location::new(
-				1,
-				[
-					Parachain(T::VtokenMintingInterface::get_moonbeam_parachain_id()),
-					AccountKey20 { network: None, key: contract_address.to_fixed_bytes() },
-				],
-			),
-			_ => {
-				ensure!(false, Error::<T>::Unsupported);
-				Location::default()
-			},
+		let info = Destinations::<T>::get(support_chain).ok_or(Error::<T>::DestinationNotFound)?;
+		let contract = match info.account_type {
+			AccountType::AccountId32 =>
+				DestinationAccount::AccountId32(Self::h160_to_account_id(&contract_address)),
+			AccountType::AccountKey20 => DestinationAccount::AccountKey20(contract_address),
 		};
+		let location = Self::destination_location(info.para_id, &contract)?;
 		let raw_account =
 			HashedDescription::<[u8; 32], DescribeFamily<DescribeAllTerminal>>::convert_location(
 				&location,
@@ -828,9 +1057,28 @@ impl<T: Config> Pallet<T> {
 		remark: BoundedVec<u8, ConstU32<32>>,
 		channel_id: u32,
 		target_chain: TargetChain<T::AccountId>,
+		callback: Option<OrderCallback>,
+		redeem_fee: Option<(CurrencyIdOf<T>, BalanceOf<T>)>,
 	) -> DispatchResultWithPostInfo {
 		let order_type = Self::order_type(currency_id)?;
 		let derivative_account = Self::frontier_derivative_account(&source_chain_caller);
+		let (destination_id, info, receiver) = Self::destination(&target_chain)?;
+		let target_chain = TargetChain::Destination(destination_id, receiver);
+		if let Some(callback) = &callback {
+			// The callback is sent by ethereum-xcm from the account derived from the evm caller,
+			// only supported by the evm source chains
+			let source_info = Destinations::<T>::get(callback.source_chain)
+				.ok_or(Error::<T>::DestinationNotFound)?;
+			ensure!(
+				matches!(source_chain_caller, OrderCaller::Evm(_)) &&
+					source_info.account_type == AccountType::AccountKey20 &&
+					source_info
+						.callback
+						.as_ref()
+						.is_some_and(|config| callback.contract.or(config.contract).is_some()),
+				Error::<T>::InvalidCallback
+			);
+		}
 		let order = Order {
 			create_block_number: <frame_system::Pallet<T>>::block_number(),
 			order_type,
@@ -844,51 +1092,99 @@ impl<T: Config> Pallet<T> {
 			derivative_account,
 			target_chain,
 			channel_id,
+			callback,
+			redeem_fee,
 		};
 
-		OrderQueue::<T>::mutate(|order_queue| -> DispatchResultWithPostInfo {
-			order_queue
-				.try_push(order.clone())
-				.map_err(|_| Error::<T>::OrderQueueOverflow)?;
-			Self::deposit_event(Event::<T>::CreateOrder { order });
-			Ok(().into())
-		})
+		Self::push_order(order.clone())?;
+		Self::deposit_event(Event::<T>::CreateOrder { order });
+		Ok(().into())
+	}
+
+	pub(crate) fn push_order(
+		order: Order<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
+	) -> DispatchResult {
+		let index = Self::reserve_order_index(order.create_block_number)?;
+		OrderQueue::<T>::insert(order.create_block_number, index, order);
+		Ok(())
+	}
+
+	/// Reserve the queue index of an order created at `create_block_number`, linking the block
+	/// to the tail of the order queue if it is the first order of the block.
+	pub(crate) fn reserve_order_index(
+		create_block_number: BlockNumberFor<T>,
+	) -> Result<u32, DispatchError> {
+		let length = OrderQueueLength::<T>::get();
+		ensure!(length < T::MaxOrderSize::get(), Error::<T>::OrderQueueOverflow);
+
+		let mut block_info = OrderBlocks::<T>::get(create_block_number).unwrap_or_default();
+		if block_info.total == 0 {
+			// Link the block to the tail of the order queue
+			match OrderQueueBounds::<T>::get() {
+				Some((head, tail)) => {
+					OrderBlocks::<T>::mutate(tail, |maybe_tail_info| {
+						if let Some(tail_info) = maybe_tail_info {
+							tail_info.next = Some(create_block_number);
+						}
+					});
+					OrderQueueBounds::<T>::put((head, create_block_number));
+				},
+				None => OrderQueueBounds::<T>::put((create_block_number, create_block_number)),
+			}
+		}
+
+		let index = block_info.total;
+		block_info.total = block_info.total.saturating_add(1);
+		OrderBlocks::<T>::insert(create_block_number, block_info);
+		OrderQueueLength::<T>::put(length.saturating_add(1));
+		Ok(index)
 	}
 
-	fn send_xcm_to_set_token_amount(
+	/// Send an ethereum-xcm transact to the evm parachain, paying the fee with its native token
+	/// of the pallet instance `fee_pallet_instance`.
+	///
+	/// The fee is always paid by the sovereign account of Bifrost, the transact is sent from the
+	/// sovereign account or from the account derived from `descend_origin` on the evm parachain.
+	fn send_ethereum_xcm_transact(
+		para_id: u32,
+		fee_pallet_instance: u8,
 		call: Vec<u8>,
 		xcm_weight: Weight,
 		xcm_fee: u128,
+		descend_origin: Option<Junction>,
 	) -> DispatchResult {
-		let dest =
-			Location::new(1, [Parachain(T::VtokenMintingInterface::get_moonbeam_parachain_id())]);
+		let dest = Location::new(1, [Parachain(para_id)]);
 
-		// Moonbeam Native Token
 		let asset = Asset {
-			id: AssetId::from(Location::new(0, [PalletInstance(10)])),
+			id: AssetId::from(Location::new(0, [PalletInstance(fee_pallet_instance)])),
 			fun: Fungible(xcm_fee),
 		};
 
-		let xcm_message = Xcm(vec![
+		let mut instructions = vec![
 			WithdrawAsset(asset.clone().into()),
 			BuyExecution { fees: asset, weight_limit: Unlimited },
+		];
+		// Descend only after the fee is bought, so the surplus goes back to the sovereign account
+		if let Some(junction) = descend_origin {
+			instructions.push(DescendOrigin([junction].into()));
+		}
+		let beneficiary = Location::new(
+			0,
+			[AccountKey20 {
+				network: None,
+				key: Sibling::from(T::ParachainId::get()).into_account_truncating(),
+			}],
+		);
+		instructions.extend([
 			Transact {
 				origin_kind: OriginKind::SovereignAccount,
 				require_weight_at_most: xcm_weight,
 				call: call.into(),
 			},
 			RefundSurplus,
-			DepositAsset {
-				assets: AllCounted(8).into(),
-				beneficiary: Location::new(
-					0,
-					[AccountKey20 {
-						network: None,
-						key: Sibling::from(T::ParachainId::get()).into_account_truncating(),
-					}],
-				),
-			},
+			DepositAsset { assets: AllCounted(8).into(), beneficiary },
 		]);
+		let xcm_message = Xcm(instructions);
 
 		// Send to sovereign
 		let (ticket, _price) = T::XcmSender::validate(&mut Some(dest), &mut Some(xcm_message))
@@ -925,16 +1221,137 @@ impl<T: Config> Pallet<T> {
 		vtoken_amount: BalanceOf<T>,
 	) -> Result<Vec<u8>, Error<T>> {
 		let ethereum_call = Self::encode_ethereum_call(currency_id, token_amount, vtoken_amount);
+		Self::encode_ethereum_xcm_transact(contract, ethereum_call)
+	}
+
+	/// callback(uint64,uint256,uint8) with the selector of the caller
+	pub fn encode_callback_call(
+		contract: H160,
+		selector: [u8; 4],
+		order_id: u64,
+		amount: BalanceOf<T>,
+		status: u8,
+	) -> Result<Vec<u8>, Error<T>> {
+		let mut call = ethabi::encode(&[
+			ethabi::Token::Uint(U256::from(order_id)),
+			ethabi::Token::Uint(U256::from(amount.saturated_into::<u128>())),
+			ethabi::Token::Uint(U256::from(status)),
+		]);
+		call.splice(0..0, selector);
+		Self::encode_ethereum_xcm_transact(contract, call)
+	}
+
+	fn encode_ethereum_xcm_transact(contract: H160, input: Vec<u8>) -> Result<Vec<u8>, Error<T>> {
 		let transaction = EthereumXcmTransaction::V2(EthereumXcmTransactionV2 {
 			gas_limit: U256::from(MAX_GAS_LIMIT),
 			action: TransactionAction::Call(contract),
 			value: U256::zero(),
-			input: BoundedVec::try_from(ethereum_call).map_err(|_| Error::<T>::ErrorEncode)?,
+			input: BoundedVec::try_from(input).map_err(|_| Error::<T>::ErrorEncode)?,
 			access_list: None,
 		});
 		Ok(MoonbeamCall::EthereumXcm(EthereumXcmCall::Transact(transaction)).encode())
 	}
 
+	/// The native currency of Moonbeam, or Moonriver on Kusama
+	fn moonbeam_fee_currency() -> CurrencyIdOf<T> {
+		if T::ParachainId::get() == Id::from(BifrostKusamaChainId::get()) {
+			MOVR
+		} else {
+			GLMR
+		}
+	}
+
+	/// The xcm fee is paid by the sovereign account on the evm chain, burn the same amount of
+	/// its native currency `fee_currency` from the treasury
+	fn burn_xcm_fee(fee_currency: CurrencyIdOf<T>, xcm_fee: u128) {
+		// Will not check results and will be sent regardless of the success of
+		// the burning
+		if T::MultiCurrency::withdraw(
+			fee_currency,
+			&T::TreasuryAccount::get(),
+			BalanceOf::<T>::unique_saturated_from(xcm_fee),
+		)
+		.is_err()
+		{
+			Self::deposit_event(Event::InsufficientAssets);
+		}
+	}
+
+	/// Call back the contract on the source chain with the `status` of the order and the
+	/// `amount` reported for it, from the account derived from the evm caller of the order.
+	/// The xcm fee is paid by the sovereign account, and charged from the order beforehand by
+	/// `charge_callback_fee`.
+	pub(crate) fn send_order_callback(
+		order: &Order<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
+		amount: BalanceOf<T>,
+		status: u8,
+	) {
+		let Some(callback) = &order.callback else {
+			return;
+		};
+		let result = || -> Result<H160, DispatchError> {
+			let OrderCaller::Evm(evm_caller) = order.source_chain_caller else {
+				return Err(Error::<T>::InvalidCallback.into());
+			};
+			let info = Destinations::<T>::get(callback.source_chain)
+				.ok_or(Error::<T>::DestinationNotFound)?;
+			let config = info.callback.ok_or(Error::<T>::InvalidCallback)?;
+			let contract =
+				callback.contract.or(config.contract).ok_or(Error::<T>::InvalidCallback)?;
+			let call = Self::encode_callback_call(
+				contract,
+				callback.selector,
+				callback.order_id,
+				amount,
+				status,
+			)?;
+			Self::send_ethereum_xcm_transact(
+				info.para_id,
+				config.fee_pallet_instance,
+				call,
+				config.xcm_weight,
+				config.xcm_fee,
+				Some(AccountKey20 { network: None, key: evm_caller.to_fixed_bytes() }),
+			)?;
+			Ok(contract)
+		}();
+
+		match result {
+			Ok(contract) => Self::deposit_event(Event::<T>::OrderCallbackSent {
+				order_id: callback.order_id,
+				contract,
+				amount,
+				status,
+			}),
+			Err(error) => Self::deposit_event(Event::<T>::OrderCallbackFailed {
+				order_id: callback.order_id,
+				error,
+			}),
+		}
+	}
+
+	/// Check that a destination chain can be sent to, and that its callback configuration can
+	/// pay for the ethereum-xcm transact
+	fn ensure_valid_destination(info: &DestinationInfo<CurrencyIdOf<T>>) -> DispatchResult {
+		ensure!(
+			info.para_id != 0 && Id::from(info.para_id) != T::ParachainId::get(),
+			Error::<T>::InvalidDestination
+		);
+		ensure!(
+			!info.xcm_weight.is_some_and(|xcm_weight| xcm_weight.is_zero()),
+			Error::<T>::InvalidDestination
+		);
+		if let Some(config) = &info.callback {
+			ensure!(
+				info.account_type == AccountType::AccountKey20 &&
+					config.xcm_fee > 0 &&
+					!config.xcm_weight.is_zero(),
+				Error::<T>::InvalidCallback
+			);
+		}
+		Ok(())
+	}
+
 	/// Check if the signer is in the whitelist
 	fn ensure_singer_on_whitelist(
 		origin: OriginFor<T>,
@@ -943,26 +1360,25 @@ impl<T: Config> Pallet<T> {
 	) -> Result<(OrderCaller<AccountIdOf<T>>, AccountIdOf<T>, AccountIdOf<T>), DispatchError> {
 		let bifrost_chain_caller = ensure_signed(origin)?;
 
-		match target_chain {
-			TargetChain::Hydradx(_) | TargetChain::Manta(_) | TargetChain::Interlay(_) => Ok((
+		// Open destinations are used by substrate callers directly
+		let (destination_id, info, _) = Self::destination(target_chain)?;
+		if info.open {
+			return Ok((
 				OrderCaller::Substrate(bifrost_chain_caller.clone()),
 				bifrost_chain_caller.clone(),
 				bifrost_chain_caller,
-			)),
-			_ => {
-				let whitelist_account_ids =
-					WhitelistAccountId::<T>::get(target_chain.support_chain());
-				ensure!(
-					whitelist_account_ids.contains(&bifrost_chain_caller),
-					Error::<T>::AccountNotFound
-				);
-				Ok((
-					OrderCaller::Evm(evm_caller),
-					Self::h160_to_account_id(&evm_caller),
-					bifrost_chain_caller,
-				))
-			},
+			));
 		}
+
+		ensure!(
+			WhitelistAccountId::<T>::get(destination_id).contains(&bifrost_chain_caller),
+			Error::<T>::AccountNotFound
+		);
+		Ok((
+			OrderCaller::Evm(evm_caller),
+			Self::h160_to_account_id(&evm_caller),
+			bifrost_chain_caller,
+		))
 	}
 
 	/// Charge an execution fee
@@ -987,6 +1403,18 @@ impl<T: Config> Pallet<T> {
 		Ok(balance_exclude_fee)
 	}
 
+	/// Charge the fee of the callback of the order, the same amount as its execution fee, and
+	/// return the amount of the order left. Orders without callback are not charged.
+	fn charge_callback_fee(
+		order: &Order<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
+		currency_amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		if order.callback.is_none() {
+			return Ok(currency_amount);
+		}
+		Self::charge_execution_fee(order.currency_id, currency_amount, &order.derivative_account)
+	}
+
 	fn transfer_to(
 		caller: AccountIdOf<T>,
 		evm_contract_account_id: &AccountIdOf<T>,
@@ -994,74 +1422,111 @@ impl<T: Config> Pallet<T> {
 		amount: BalanceOf<T>,
 		target_chain: &TargetChain<AccountIdOf<T>>,
 	) -> DispatchResult {
-		let dest = match target_chain {
-			TargetChain::Astar(receiver) => Location::new(
-				1,
-				[
-					Parachain(AstarChainId::get()),
-					AccountId32 {
-						network: None,
-						id: Self::h160_to_account_id(receiver)
-							.encode()
-							.try_into()
-							.map_err(|_| Error::<T>::ErrorEncode)?,
-					},
-				],
-			),
-			TargetChain::Moonbeam(receiver) => Location::new(
-				1,
-				[
-					Parachain(T::VtokenMintingInterface::get_moonbeam_parachain_id()),
-					AccountKey20 { network: None, key: receiver.to_fixed_bytes() },
-				],
-			),
-			TargetChain::Hydradx(receiver) => Location::new(
-				1,
-				[
-					Parachain(HydrationChainId::get()),
-					AccountId32 {
-						network: None,
-						id: receiver.encode().try_into().map_err(|_| Error::<T>::ErrorEncode)?,
-					},
-				],
-			),
-			TargetChain::Interlay(receiver) => Location::new(
-				1,
-				[
-					Parachain(InterlayChainId::get()),
-					AccountId32 {
-						network: None,
-						id: receiver.encode().try_into().map_err(|_| Error::<T>::ErrorEncode)?,
-					},
-				],
-			),
-			TargetChain::Manta(receiver) => Location::new(
-				1,
-				[
-					Parachain(MantaChainId::get()),
-					AccountId32 {
-						network: None,
-						id: receiver.encode().try_into().map_err(|_| Error::<T>::ErrorEncode)?,
-					},
-				],
-			),
-		};
+		let (destination_id, info, receiver) = Self::destination(target_chain)?;
+		let dest = Self::destination_location(info.para_id, &receiver)?;
+		let dest_weight_limit = info.xcm_weight.map_or(Unlimited, Limited);
 
-		if let TargetChain::Moonbeam(_) = target_chain {
-			if SupportXcmFeeList::<T>::get().contains(&currency_id) {
-				T::XcmTransfer::transfer(caller, currency_id, amount, dest, Unlimited)?;
-			} else {
-				let fee_amount = Self::get_moonbeam_transfer_to_fee();
-				T::MultiCurrency::transfer(BNC, evm_contract_account_id, &caller, fee_amount)?;
-				let assets = vec![(currency_id, amount), (BNC, fee_amount)];
-				T::XcmTransfer::transfer_multicurrencies(caller, assets, 1, dest, Unlimited)?;
-			}
-		} else {
-			T::XcmTransfer::transfer(caller, currency_id, amount, dest, Unlimited)?;
+		match Self::destination_fee(destination_id, &info, currency_id) {
+			Some((fee_currency, fee_amount)) => {
+				T::MultiCurrency::transfer(
+					fee_currency,
+					evm_contract_account_id,
+					&caller,
+					fee_amount,
+				)?;
+				let assets = vec![(currency_id, amount), (fee_currency, fee_amount)];
+				T::XcmTransfer::transfer_multicurrencies(
+					caller,
+					assets,
+					1,
+					dest,
+					dest_weight_limit,
+				)?;
+			},
+			None => {
+				T::XcmTransfer::transfer(caller, currency_id, amount, dest, dest_weight_limit)?;
+			},
 		}
 		Ok(())
 	}
 
+	/// The xcm fee of the destination chain paid with its fee currency, if it can not be paid
+	/// with the transferred `currency_id`
+	fn destination_fee(
+		destination_id: DestinationId,
+		info: &DestinationInfo<CurrencyIdOf<T>>,
+		currency_id: CurrencyIdOf<T>,
+	) -> Option<(CurrencyIdOf<T>, BalanceOf<T>)> {
+		let fee_currency = info.fee_currency.filter(|fee_currency| {
+			*fee_currency != currency_id && !SupportXcmFeeList::<T>::get().contains(&currency_id)
+		})?;
+		let fee_amount = TransferToFee::<T>::get(destination_id)
+			.unwrap_or_else(|| Self::get_default_fee(fee_currency));
+		Some((fee_currency, fee_amount))
+	}
+
+	/// Prepay the xcm fee of the redeemed token to the destination chain, from the whitelisted
+	/// contract account to the derivative account redeeming it, and return the prepaid fee
+	fn prepay_redeem_fee(
+		currency_id: CurrencyIdOf<T>,
+		target_chain: &TargetChain<AccountIdOf<T>>,
+		evm_contract_account_id: &AccountIdOf<T>,
+		derivative_account: &AccountIdOf<T>,
+	) -> Result<Option<(CurrencyIdOf<T>, BalanceOf<T>)>, DispatchError> {
+		if Self::order_type(currency_id)? != OrderType::Redeem {
+			return Ok(None);
+		}
+		let token_id = currency_id.to_token().map_err(|_| Error::<T>::ErrorConvertVtoken)?;
+		let (destination_id, info, _) = Self::destination(target_chain)?;
+		let redeem_fee = Self::destination_fee(destination_id, &info, token_id);
+		if let Some((fee_currency, fee_amount)) = redeem_fee {
+			T::MultiCurrency::transfer(
+				fee_currency,
+				evm_contract_account_id,
+				derivative_account,
+				fee_amount,
+			)?;
+		}
+		Ok(redeem_fee)
+	}
+
+	/// Resolve the target chain to the registered destination chain and the receiver on it
+	pub(crate) fn destination(
+		target_chain: &TargetChain<AccountIdOf<T>>,
+	) -> Result<
+		(DestinationId, DestinationInfo<CurrencyIdOf<T>>, DestinationAccount<AccountIdOf<T>>),
+		Error<T>,
+	> {
+		let receiver = match target_chain.clone() {
+			TargetChain::Astar(receiver) =>
+				DestinationAccount::AccountId32(Self::h160_to_account_id(&receiver)),
+			TargetChain::Moonbeam(receiver) => DestinationAccount::AccountKey20(receiver),
+			TargetChain::Hydradx(receiver) |
+			TargetChain::Interlay(receiver) |
+			TargetChain::Manta(receiver) => DestinationAccount::AccountId32(receiver),
+			TargetChain::Destination(_, receiver) => receiver,
+		};
+		let destination_id = target_chain.destination_id();
+		let info = Destinations::<T>::get(destination_id).ok_or(Error::<T>::DestinationNotFound)?;
+		ensure!(info.account_type == receiver.account_type(), Error::<T>::InvalidReceiver);
+		Ok((destination_id, info, receiver))
+	}
+
+	fn destination_location(
+		para_id: u32,
+		receiver: &DestinationAccount<AccountIdOf<T>>,
+	) -> Result<Location, Error<T>> {
+		let account = match receiver {
+			DestinationAccount::AccountId32(account_id) => AccountId32 {
+				network: None,
+				id: account_id.encode().try_into().map_err(|_| Error::<T>::ErrorEncode)?,
+			},
+			DestinationAccount::AccountKey20(key) =>
+				AccountKey20 { network: None, key: key.to_fixed_bytes() },
+		};
+		Ok(Location::new(1, [Parachain(para_id), account]))
+	}
+
 	fn h160_to_account_id(address: &H160) -> AccountIdOf<T> {
 		let mut data = [0u8; 24];
 		data[0..4].copy_from_slice(b"evm:");
@@ -1084,17 +1549,19 @@ impl<T: Config> Pallet<T> {
 		BalanceOf::<T>::saturated_from(10u128.saturating_pow(decimals).saturating_div(100u128))
 	}
 
+	/// Handle the order, return the amount of vtoken minted or redeemed
 	#[transactional]
 	pub fn handle_order(
 		order: &Order<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
-	) -> DispatchResult {
+	) -> Result<BalanceOf<T>, DispatchError> {
 		let currency_amount = Self::charge_execution_fee(
 			order.currency_id,
 			order.currency_amount,
 			&order.derivative_account,
 		)
+		.and_then(|currency_amount| Self::charge_callback_fee(order, currency_amount))
 		.map_err(|_| Error::<T>::ErrorChargeFee)?;
-		match order.order_type {
+		let vtoken_amount = match order.order_type {
 			OrderType::Mint => {
 				let vtoken_id =
 					order.currency_id.to_vtoken().map_err(|_| Error::<T>::ErrorConvertVtoken)?;
@@ -1124,17 +1591,17 @@ impl<T: Config> Pallet<T> {
 					&order.target_chain,
 				)
 				.map_err(|_| Error::<T>::ErrorTransferTo)?;
+				vtoken_amount
 			},
 			OrderType::Redeem => {
-				let redeem_type = match order.target_chain.clone() {
-					TargetChain::Astar(receiver) => {
-						let receiver = Self::h160_to_account_id(&receiver);
-						RedeemType::Astar(receiver)
-					},
-					TargetChain::Moonbeam(receiver) => RedeemType::Moonbeam(receiver),
-					TargetChain::Hydradx(receiver) => RedeemType::Hydradx(receiver),
-					TargetChain::Interlay(receiver) => RedeemType::Interlay(receiver),
-					TargetChain::Manta(receiver) => RedeemType::Manta(receiver),
+				let (_, info, receiver) = Self::destination(&order.target_chain)?;
+				// The xcm fee is prepaid to the derivative account when the order is created
+				let redeem_type = RedeemType::Destination {
+					location: Self::destination_location(info.para_id, &receiver)?,
+					weight_limit: info.xcm_weight,
+					fee: order.redeem_fee.map(|(fee_currency, fee_amount)| {
+						(fee_currency, fee_amount.saturated_into::<u128>())
+					}),
 				};
 				T::VtokenMintingInterface::slpx_redeem(
 					order.derivative_account.clone(),
@@ -1143,51 +1610,239 @@ impl<T: Config> Pallet<T> {
 					redeem_type,
 				)
 				.map_err(|_| Error::<T>::ErrorVtokenMiting)?;
+				currency_amount
 			},
 		};
-		Ok(())
+		Ok(vtoken_amount)
 	}
 
+	/// Handle the orders in the order queue whose delay block has passed, until the queue is
+	/// empty, `MaxOrdersPerBlock` orders are handled or the weight `limit` is reached.
 	#[transactional]
 	pub fn handle_order_queue(
 		current_block_number: BlockNumberFor<T>,
+		limit: Weight,
 		weight: &mut Weight,
 	) -> DispatchResult {
-		OrderQueue::<T>::mutate(|order_queue| -> DispatchResult {
-			if order_queue.is_empty() {
-				*weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 0));
-				return Ok(());
+		let delay_block = DelayBlock::<T>::get();
+		*weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 0));
+
+		for _ in 0..T::MaxOrdersPerBlock::get() {
+			let Some((head, tail)) = OrderQueueBounds::<T>::get() else {
+				break;
+			};
+			if current_block_number.saturating_sub(head) < delay_block {
+				break;
+			}
+			let Some(mut block_info) = OrderBlocks::<T>::get(head) else {
+				OrderQueueBounds::<T>::kill();
+				break;
 			};
 
-			if current_block_number - order_queue[0].create_block_number >= DelayBlock::<T>::get() {
-				let mut order = order_queue.remove(0);
-				// For compatibility with older versions
-				if order.currency_amount == Default::default() {
-					order.currency_amount = T::MultiCurrency::free_balance(
-						order.currency_id,
-						&order.derivative_account,
-					);
-				} else {
-					// Ensure that the currency amount is not greater than the free balance
-					let free_balance = T::MultiCurrency::free_balance(
-						order.currency_id,
-						&order.derivative_account,
-					);
-					order.currency_amount = order.currency_amount.min(free_balance);
-				}
-				match Self::handle_order(&order) {
-					Ok(_) => {
-						Self::deposit_event(Event::<T>::OrderHandled { order: order.clone() });
-					},
-					Err(_) => {
-						Self::deposit_event(Event::<T>::OrderFailed { order });
-					},
+			if let Some(order) = OrderQueue::<T>::get(head, block_info.handled) {
+				let mut order_weight = match order.order_type {
+					OrderType::Mint => T::WeightInfo::handle_mint_order(),
+					OrderType::Redeem => T::WeightInfo::handle_redeem_order(),
 				};
-				*weight = weight.saturating_add(T::DbWeight::get().reads_writes(12, 8));
-			};
-			*weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 0));
-			Ok(())
-		})
+				if order.callback.is_some() {
+					order_weight =
+						order_weight.saturating_add(T::WeightInfo::send_order_callback());
+				}
+				if weight.saturating_add(order_weight).any_gt(limit) {
+					break;
+				}
+				*weight = weight.saturating_add(order_weight);
+
+				OrderQueue::<T>::remove(head, block_info.handled);
+				OrderQueueLength::<T>::mutate(|length| *length = length.saturating_sub(1));
+				Self::handle_queued_order(order);
+			}
+
+			block_info.handled = block_info.handled.saturating_add(1);
+			if block_info.handled >= block_info.total {
+				OrderBlocks::<T>::remove(head);
+				match block_info.next {
+					Some(next) => OrderQueueBounds::<T>::put((next, tail)),
+					None => OrderQueueBounds::<T>::kill(),
+				}
+			} else {
+				OrderBlocks::<T>::insert(head, block_info);
+			}
+		}
+		Ok(())
+	}
+
+	fn handle_queued_order(
+		mut order: Order<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
+	) {
+		// For compatibility with older versions
+		if order.currency_amount == Default::default() {
+			order.currency_amount =
+				T::MultiCurrency::free_balance(order.currency_id, &order.derivative_account);
+		} else {
+			// Ensure that the currency amount is not greater than the free balance
+			let free_balance =
+				T::MultiCurrency::free_balance(order.currency_id, &order.derivative_account);
+			order.currency_amount = order.currency_amount.min(free_balance);
+		}
+		match Self::handle_order(&order) {
+			Ok(vtoken_amount) => {
+				Self::send_order_callback(&order, vtoken_amount, ORDER_STATUS_SUCCESS);
+				Self::deposit_event(Event::<T>::OrderHandled { order });
+			},
+			Err(_) => {
+				Self::deposit_event(Event::<T>::OrderFailed { order: order.clone() });
+				Self::push_failed_order(order);
+			},
+		};
+	}
+
+	fn push_failed_order(
+		order: Order<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
+	) {
+		let order_id = NextFailedOrderId::<T>::mutate(|id| {
+			let order_id = *id;
+			*id = id.saturating_add(1);
+			order_id
+		});
+		let next_retry_block =
+			<frame_system::Pallet<T>>::block_number().saturating_add(T::OrderRetryInterval::get());
+		FailedOrders::<T>::insert(
+			order_id,
+			FailedOrder { order, retry_count: 0, next_retry_block },
+		);
+		Self::deposit_event(Event::<T>::OrderRetryScheduled {
+			order_id,
+			retry_count: 0,
+			next_retry_block,
+		});
+	}
+
+	/// Retry the failed orders whose backoff has passed, and refund the orders whose retries are
+	/// exhausted, until `MaxOrdersPerBlock` orders are handled or the weight `limit` is reached.
+	/// The failed orders are visited from `FailedOrdersCursor`, so every order is eventually
+	/// visited however many orders are waiting.
+	pub fn handle_failed_orders(
+		current_block_number: BlockNumberFor<T>,
+		limit: Weight,
+		weight: &mut Weight,
+	) -> DispatchResult {
+		*weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+		let iter = match FailedOrdersCursor::<T>::get() {
+			Some(order_id) =>
+				FailedOrders::<T>::iter_from(FailedOrders::<T>::hashed_key_for(order_id)),
+			None => FailedOrders::<T>::iter(),
+		};
+
+		let mut due_orders = Vec::new();
+		let mut cursor = None;
+		let mut finished = true;
+		for (order_id, failed_order) in iter {
+			let mut order_weight = T::WeightInfo::retry_failed_order();
+			if failed_order.order.callback.is_some() {
+				order_weight = order_weight.saturating_add(T::WeightInfo::send_order_callback());
+			}
+			if due_orders.len() as u32 >= T::MaxOrdersPerBlock::get() ||
+				weight.saturating_add(order_weight).any_gt(limit)
+			{
+				finished = false;
+				break;
+			}
+			*weight = weight.saturating_add(T::DbWeight::get().reads(1));
+			if failed_order.next_retry_block <= current_block_number {
+				*weight = weight.saturating_add(order_weight);
+				due_orders.push((order_id, failed_order));
+			}
+			cursor = Some(order_id);
+		}
+
+		// Start over from the first failed order once the last one is visited
+		if finished {
+			FailedOrdersCursor::<T>::kill();
+		} else if let Some(order_id) = cursor {
+			FailedOrdersCursor::<T>::put(order_id);
+		}
+
+		for (order_id, failed_order) in due_orders {
+			Self::retry_failed_order(order_id, failed_order, current_block_number);
+		}
+		Ok(())
+	}
+
+	fn retry_failed_order(
+		order_id: u64,
+		mut failed_order: FailedOrder<
+			AccountIdOf<T>,
+			CurrencyIdOf<T>,
+			BalanceOf<T>,
+			BlockNumberFor<T>,
+		>,
+		current_block_number: BlockNumberFor<T>,
+	) {
+		let order = &mut failed_order.order;
+		let free_balance =
+			T::MultiCurrency::free_balance(order.currency_id, &order.derivative_account);
+		order.currency_amount = order.currency_amount.min(free_balance);
+
+		if failed_order.retry_count < T::MaxOrderRetries::get() {
+			if let Ok(vtoken_amount) = Self::handle_order(order) {
+				Self::send_order_callback(order, vtoken_amount, ORDER_STATUS_SUCCESS);
+				FailedOrders::<T>::remove(order_id);
+				Self::deposit_event(Event::<T>::OrderHandled { order: failed_order.order });
+				return;
+			}
+		} else if let OrderCaller::Substrate(_) = order.source_chain_caller {
+			// Substrate callers claim the refund themselves, nothing is sent to the target chain
+			FailedOrders::<T>::remove(order_id);
+			RefundableOrders::<T>::insert(order_id, failed_order.order);
+			Self::deposit_event(Event::<T>::OrderRefundable { order_id });
+			return;
+		} else {
+			match Self::refund_order(order) {
+				Ok(amount) => {
+					Self::send_order_callback(order, amount, ORDER_STATUS_REFUNDED);
+					FailedOrders::<T>::remove(order_id);
+					Self::deposit_event(Event::<T>::OrderRefunded {
+						order_id,
+						currency_id: order.currency_id,
+						amount,
+					});
+					return;
+				},
+				Err(error) => {
+					Self::deposit_event(Event::<T>::OrderRefundFailed { order_id, error });
+				},
+			}
+		}
+
+		// Exponential backoff, the interval is doubled on every retry
+		let backoff = T::OrderRetryInterval::get()
+			.saturating_mul(BlockNumberFor::<T>::from(1u32 << failed_order.retry_count.min(16)));
+		failed_order.retry_count = failed_order.retry_count.saturating_add(1);
+		failed_order.next_retry_block = current_block_number.saturating_add(backoff);
+		Self::deposit_event(Event::<T>::OrderRetryScheduled {
+			order_id,
+			retry_count: failed_order.retry_count,
+			next_retry_block: failed_order.next_retry_block,
+		});
+		FailedOrders::<T>::insert(order_id, failed_order);
+	}
+
+	/// Transfer the currency of the order back to the caller on the target chain, return the
+	/// amount refunded after the fee of its callback.
+	#[transactional]
+	fn refund_order(
+		order: &Order<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let amount = Self::charge_callback_fee(order, order.currency_amount)?;
+		Self::transfer_to(
+			order.derivative_account.clone(),
+			&order.bifrost_chain_caller,
+			order.currency_id,
+			amount,
+			&order.target_chain,
+		)?;
+		Ok(amount)
 	}
 
 	#[transactional]
@@ -1219,8 +1874,15 @@ impl<T: Config> Pallet<T> {
 				)
 				.map_err(|_| Error::<T>::ErrorEncode)?;
 
-				Self::send_xcm_to_set_token_amount(encoded_call, config.xcm_weight, config.xcm_fee)
-					.map_err(|_| Error::<T>::ErrorDelivering)?;
+				Self::send_ethereum_xcm_transact(
+					T::VtokenMintingInterface::get_moonbeam_parachain_id(),
+					MOONBEAM_NATIVE_PALLET_INSTANCE,
+					encoded_call,
+					config.xcm_weight,
+					config.xcm_fee,
+					None,
+				)
+				.map_err(|_| Error::<T>::ErrorDelivering)?;
 
 				Self::deposit_event(Event::XcmOracle {
 					currency_id,
@@ -1229,22 +1891,7 @@ impl<T: Config> Pallet<T> {
 					v_currency_total_supply,
 				});
 
-				let mut target_fee_currency_id = GLMR;
-				if T::ParachainId::get() == Id::from(BifrostKusamaChainId::get()) {
-					target_fee_currency_id = MOVR;
-				}
-
-				// Will not check results and will be sent regardless of the success of
-				// the burning
-				if T::MultiCurrency::withdraw(
-					target_fee_currency_id,
-					&T::TreasuryAccount::get(),
-					BalanceOf::<T>::unique_saturated_from(config.xcm_fee),
-				)
-				.is_err()
-				{
-					Self::deposit_event(Event::InsufficientAssets);
-				}
+				Self::burn_xcm_fee(Self::moonbeam_fee_currency(), config.xcm_fee);
 
 				config.last_block = current_block_number;
 				XcmEthereumCallConfiguration::<T>::put(config);
@@ -1269,7 +1916,6 @@ impl<T: Config> Pallet<T> {
 // Functions to be called by other pallets.
 impl<T: Config> SlpxOperator<BalanceOf<T>> for Pallet<T> {
 	fn get_moonbeam_transfer_to_fee() -> BalanceOf<T> {
-		TransferToFee::<T>::get(SupportChain::Moonbeam)
-			.unwrap_or_else(|| Self::get_default_fee(BNC))
+		TransferToFee::<T>::get(MOONBEAM_DESTINATION).unwrap_or_else(|| Self::get_default_fee(BNC))
 	}
 }
@@ -168,17 +168,71 @@ pub mod v1 {
 
 pub mod v2 {
 	use super::*;
-	use frame_support::traits::GetStorageVersion;
+	use frame_support::{
+		pallet_prelude::{OptionQuery, StorageVersion, ValueQuery},
+		traits::GetStorageVersion,
+		Blake2_128Concat,
+	};
+	use parity_scale_codec::{Decode, Encode};
+
+	/// The released order layout, kept in a bounded vec.
+	#[derive(Encode, Decode, Clone)]
+	pub struct Order<AccountId, CurrencyId, Balance, BlockNumber> {
+		pub source_chain_caller: OrderCaller<AccountId>,
+		pub source_chain_id: u64,
+		pub source_chain_block_number: Option<u128>,
+		pub bifrost_chain_caller: AccountId,
+		pub derivative_account: AccountId,
+		pub create_block_number: BlockNumber,
+		pub currency_id: CurrencyId,
+		pub currency_amount: Balance,
+		pub order_type: OrderType,
+		pub remark: BoundedVec<u8, ConstU32<32>>,
+		pub target_chain: TargetChain<AccountId>,
+		pub channel_id: u32,
+	}
+
+	#[storage_alias]
+	pub(super) type OrderQueue<T: Config> = StorageValue<
+		Pallet<T>,
+		BoundedVec<
+			Order<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
+			ConstU32<1000>,
+		>,
+		ValueQuery,
+	>;
+
+	#[derive(Encode, Decode)]
+	pub enum SupportChain {
+		Astar,
+		Moonbeam,
+		Hydradx,
+		Interlay,
+		Manta,
+	}
+
+	#[storage_alias]
+	pub(super) type WhitelistAccountId<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		SupportChain,
+		BoundedVec<AccountIdOf<T>, ConstU32<10>>,
+		ValueQuery,
+	>;
+
+	#[storage_alias]
+	pub(super) type TransferToFee<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, SupportChain, BalanceOf<T>, OptionQuery>;
 
 	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
 	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
 		fn on_runtime_upgrade() -> Weight {
+			// The v3 migration runs in the same upgrade, so only the on-chain version is checked
 			let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
-			let in_code_storage_version = Pallet::<T>::in_code_storage_version();
-			if on_chain_storage_version == 1 && in_code_storage_version == 2 {
+			if on_chain_storage_version == 1 {
 				let weight_consumed = migrate_to_v2::<T>();
 				log::info!("Migrating slpx storage to v2");
-				in_code_storage_version.put::<Pallet<T>>();
+				StorageVersion::new(2).put::<Pallet<T>>();
 				weight_consumed.saturating_add(T::DbWeight::get().writes(1))
 			} else {
 				log::warn!("slpx migration should be removed.");
@@ -189,12 +243,119 @@ pub mod v2 {
 		#[cfg(feature = "try-runtime")]
 		fn post_upgrade(_: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
 			ensure!(
-				Pallet::<T>::on_chain_storage_version() == 2,
-				"on_chain_storage_version should be 2"
+				Pallet::<T>::on_chain_storage_version() >= 2,
+				"on_chain_storage_version should be at least 2"
 			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+	use crate::types::{
+		AccountType, DestinationId, DestinationInfo, ASTAR_DESTINATION, HYDRADX_DESTINATION,
+		INTERLAY_DESTINATION, MANTA_DESTINATION, MOONBEAM_DESTINATION,
+	};
+	use bifrost_primitives::{AstarChainId, HydrationChainId, InterlayChainId, MantaChainId};
+	use frame_support::traits::GetStorageVersion;
+	use v2::SupportChain;
+
+	impl SupportChain {
+		pub(super) fn destination_id(&self) -> DestinationId {
+			match self {
+				SupportChain::Astar => ASTAR_DESTINATION,
+				SupportChain::Moonbeam => MOONBEAM_DESTINATION,
+				SupportChain::Hydradx => HYDRADX_DESTINATION,
+				SupportChain::Interlay => INTERLAY_DESTINATION,
+				SupportChain::Manta => MANTA_DESTINATION,
+			}
+		}
+	}
+
+	/// The destination chains supported before the registry was introduced.
+	pub fn legacy_destinations<T: Config>() -> Vec<(DestinationId, DestinationInfo<CurrencyIdOf<T>>)>
+	{
+		let destination = |para_id: u32, account_type: AccountType, open: bool| DestinationInfo {
+			para_id,
+			account_type,
+			open,
+			fee_currency: None,
+			xcm_weight: None,
+			callback: None,
+		};
+		// Only Astar and Moonbeam required whitelisted contracts
+		vec![
+			(ASTAR_DESTINATION, destination(AstarChainId::get(), AccountType::AccountId32, false)),
+			(
+				MOONBEAM_DESTINATION,
+				DestinationInfo {
+					fee_currency: Some(BNC),
+					..destination(
+						T::VtokenMintingInterface::get_moonbeam_parachain_id(),
+						AccountType::AccountKey20,
+						false,
+					)
+				},
+			),
+			(
+				HYDRADX_DESTINATION,
+				destination(HydrationChainId::get(), AccountType::AccountId32, true),
+			),
+			(
+				INTERLAY_DESTINATION,
+				destination(InterlayChainId::get(), AccountType::AccountId32, true),
+			),
+			(MANTA_DESTINATION, destination(MantaChainId::get(), AccountType::AccountId32, true)),
+		]
+	}
+
+	/// Migrate the released v2 storage to the order queue indexed by block, the destination
+	/// registry and the orders with callbacks.
+	pub struct MigrateToV3<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+			let in_code_storage_version = Pallet::<T>::in_code_storage_version();
+			if on_chain_storage_version == 2 && in_code_storage_version == 3 {
+				let weight_consumed = migrate_to_v3::<T>();
+				log::info!("Migrating slpx storage to v3");
+				in_code_storage_version.put::<Pallet<T>>();
+				weight_consumed.saturating_add(T::DbWeight::get().writes(1))
+			} else {
+				log::warn!("slpx migration should be removed.");
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+			let count = (
+				v2::OrderQueue::<T>::get().len() as u32,
+				v2::WhitelistAccountId::<T>::iter().count() as u32,
+			);
+			log::info!("slpx before migration: order and whitelist count: {:?}", count);
+
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+			let (order_count, whitelist_count) = <(u32, u32)>::decode(&mut &state[..])
+				.map_err(|_| sp_runtime::DispatchError::Other("Invalid state"))?;
 			ensure!(
-				Pallet::<T>::in_code_storage_version() == 2,
-				"in_code_storage_version should be 2"
+				Pallet::<T>::on_chain_storage_version() == 3,
+				"on_chain_storage_version should be 3"
+			);
+			ensure!(OrderQueueLength::<T>::get() == order_count, "OrderQueueLength not match");
+			ensure!(
+				crate::WhitelistAccountId::<T>::iter().count() as u32 == whitelist_count,
+				"whitelist count not match"
+			);
+			ensure!(
+				OrderQueue::<T>::iter_values()
+					.all(|order| matches!(order.target_chain, TargetChain::Destination(..))),
+				"order target chain not migrated"
 			);
 			Ok(())
 		}
@@ -237,7 +398,7 @@ pub fn migrate_to_v2<T: Config>() -> Weight {
 
 	let old_order_queue = v1::OrderQueue::<T>::take();
 	for old_order in old_order_queue.into_iter() {
-		let order = Order {
+		let order = v2::Order {
 			source_chain_caller: old_order.source_chain_caller,
 			source_chain_id: 0,
 			source_chain_block_number: None,
@@ -252,7 +413,7 @@ pub fn migrate_to_v2<T: Config>() -> Weight {
 			channel_id: old_order.channel_id,
 		};
 
-		OrderQueue::<T>::mutate(|order_queue| -> DispatchResultWithPostInfo {
+		v2::OrderQueue::<T>::mutate(|order_queue| -> DispatchResultWithPostInfo {
 			order_queue.try_push(order.clone()).map_err(|_| Error::<T>::ErrorArguments)?;
 			Ok(().into())
 		})
@@ -263,3 +424,58 @@ pub fn migrate_to_v2<T: Config>() -> Weight {
 
 	weight
 }
+
+pub fn migrate_to_v3<T: Config>() -> Weight {
+	let mut weight: Weight = Weight::zero();
+
+	for (destination_id, info) in v3::legacy_destinations::<T>() {
+		Destinations::<T>::insert(destination_id, info);
+		weight = weight.saturating_add(T::DbWeight::get().writes(1));
+	}
+
+	for (support_chain, whitelist) in v2::WhitelistAccountId::<T>::drain() {
+		WhitelistAccountId::<T>::insert(support_chain.destination_id(), whitelist);
+		weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+	}
+
+	for (support_chain, fee) in v2::TransferToFee::<T>::drain() {
+		TransferToFee::<T>::insert(support_chain.destination_id(), fee);
+		weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+	}
+
+	// The orders are indexed by their create block, the chain specific target chains are
+	// converted to the registered destinations and the orders have no callback
+	weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+	for order in v2::OrderQueue::<T>::take().into_iter() {
+		let target_chain = match Pallet::<T>::destination(&order.target_chain) {
+			Ok((destination_id, _, receiver)) => TargetChain::Destination(destination_id, receiver),
+			Err(_) => {
+				log::error!("slpx order target chain can not be migrated");
+				order.target_chain
+			},
+		};
+		let order = Order {
+			source_chain_caller: order.source_chain_caller,
+			source_chain_id: order.source_chain_id,
+			source_chain_block_number: order.source_chain_block_number,
+			bifrost_chain_caller: order.bifrost_chain_caller,
+			derivative_account: order.derivative_account,
+			create_block_number: order.create_block_number,
+			currency_id: order.currency_id,
+			currency_amount: order.currency_amount,
+			order_type: order.order_type,
+			remark: order.remark,
+			target_chain,
+			channel_id: order.channel_id,
+			callback: None,
+			// the released orders didn't prepay the xcm fee of the destination chain
+			redeem_fee: None,
+		};
+		if Pallet::<T>::push_order(order).is_err() {
+			log::error!("slpx order queue overflow during migration");
+		}
+		weight = weight.saturating_add(T::DbWeight::get().reads_writes(4, 5));
+	}
+
+	weight
+}
@@ -28,7 +28,7 @@ use frame_support::{
 	construct_runtime, derive_impl, ord_parameter_types,
 	pallet_prelude::*,
 	parameter_types,
-	traits::{Everything, Nothing},
+	traits::{ConstU64, Everything, Nothing},
 	PalletId,
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
@@ -111,6 +111,7 @@ pub type AdaptedBasicCurrency =
 
 impl bifrost_currencies::Config for Test {
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type WeightInfo = ();
@@ -318,12 +319,20 @@ impl slpx::Config for Test {
 	type ParachainId = ParachainId;
 	type WeightInfo = ();
 	type MaxOrderSize = ConstU32<500>;
+	type MaxOrdersPerBlock = ConstU32<50>;
+	type MaxOrderRetries = ConstU32<2>;
+	type OrderRetryInterval = ConstU64<10>;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 	let mut ext = sp_io::TestExternalities::new(t);
-	ext.execute_with(|| System::set_block_number(0));
+	ext.execute_with(|| {
+		System::set_block_number(0);
+		for (destination_id, info) in crate::migration::v3::legacy_destinations::<Test>() {
+			Destinations::<Test>::insert(destination_id, info);
+		}
+	});
 	ext
 }
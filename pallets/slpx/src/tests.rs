@@ -19,10 +19,14 @@
 
 use crate::{
 	mock::*,
-	types::{EthereumXcmCall, EthereumXcmTransaction, EthereumXcmTransactionV2, MoonbeamCall},
+	types::{
+		AccountType, CallbackConfiguration, DestinationAccount, DestinationInfo, EthereumXcmCall,
+		EthereumXcmTransaction, EthereumXcmTransactionV2, MoonbeamCall, OrderCallback,
+		ASTAR_DESTINATION, MOONBEAM_DESTINATION, MOONBEAM_NATIVE_PALLET_INSTANCE,
+	},
 	*,
 };
-use bifrost_primitives::{TokenSymbol, DOT, VDOT};
+use bifrost_primitives::{TokenSymbol, BNC, DOT, VDOT};
 use ethereum::TransactionAction;
 use frame_support::{assert_noop, assert_ok, dispatch::RawOrigin, traits::OnIdle};
 use hex_literal::hex;
@@ -56,8 +60,7 @@ fn test_account_convert_work() {
 fn xcm_derivative_account() {
 	new_test_ext().execute_with(|| {
 		let address = H160::from_slice(&ASTAR_SLPX_ADDR);
-		let derivative_account =
-			Slpx::xcm_derivative_account(SupportChain::Astar, address).unwrap();
+		let derivative_account = Slpx::xcm_derivative_account(ASTAR_DESTINATION, address).unwrap();
 		assert_eq!(
 			derivative_account,
 			sp_runtime::AccountId32::from_ss58check(
@@ -68,7 +71,7 @@ fn xcm_derivative_account() {
 
 		let address = H160::from_slice(&MOONBEAM_SLPX_ADDR);
 		let derivative_account =
-			Slpx::xcm_derivative_account(SupportChain::Moonbeam, address).unwrap();
+			Slpx::xcm_derivative_account(MOONBEAM_DESTINATION, address).unwrap();
 		assert_eq!(
 			derivative_account,
 			sp_runtime::AccountId32::from_ss58check(
@@ -92,23 +95,19 @@ fn add_whitelist() {
 			"gWEvf2EDMzxR7JHyrEHXf3nqxKLGvHaFbk7HUkJnNPUxDts",
 		)
 		.unwrap();
-		assert_ok!(Slpx::add_whitelist(
-			RuntimeOrigin::root(),
-			SupportChain::Astar,
-			astar_slpx_addr
-		));
+		assert_ok!(Slpx::add_whitelist(RuntimeOrigin::root(), ASTAR_DESTINATION, astar_slpx_addr));
 		assert_eq!(
-			WhitelistAccountId::<Test>::get(SupportChain::Astar).to_vec(),
+			WhitelistAccountId::<Test>::get(ASTAR_DESTINATION).to_vec(),
 			vec![astar_slpx_account_id]
 		);
 
 		assert_ok!(Slpx::add_whitelist(
 			RuntimeOrigin::root(),
-			SupportChain::Moonbeam,
+			MOONBEAM_DESTINATION,
 			moonbeam_slpx_addr
 		));
 		assert_eq!(
-			WhitelistAccountId::<Test>::get(SupportChain::Moonbeam).to_vec(),
+			WhitelistAccountId::<Test>::get(MOONBEAM_DESTINATION).to_vec(),
 			vec![moonbeam_slpx_account_id]
 		);
 	});
@@ -122,18 +121,14 @@ fn add_whitelist_account_id_already_in_whitelist() {
 			"g96o4GVpsAop1MJiArnmUYtXUjEisfkbfcpsuqmXrS28MEr",
 		)
 		.unwrap();
-		assert_ok!(Slpx::add_whitelist(
-			RuntimeOrigin::root(),
-			SupportChain::Astar,
-			astar_slpx_addr
-		));
+		assert_ok!(Slpx::add_whitelist(RuntimeOrigin::root(), ASTAR_DESTINATION, astar_slpx_addr));
 		assert_eq!(
-			WhitelistAccountId::<Test>::get(SupportChain::Astar).to_vec(),
+			WhitelistAccountId::<Test>::get(ASTAR_DESTINATION).to_vec(),
 			vec![astar_slpx_account_id]
 		);
 
 		assert_noop!(
-			Slpx::add_whitelist(RuntimeOrigin::root(), SupportChain::Astar, astar_slpx_addr),
+			Slpx::add_whitelist(RuntimeOrigin::root(), ASTAR_DESTINATION, astar_slpx_addr),
 			Error::<Test>::AccountAlreadyExists
 		);
 	});
@@ -147,22 +142,18 @@ fn remove_whitelist() {
 			"g96o4GVpsAop1MJiArnmUYtXUjEisfkbfcpsuqmXrS28MEr",
 		)
 		.unwrap();
-		assert_ok!(Slpx::add_whitelist(
-			RuntimeOrigin::root(),
-			SupportChain::Astar,
-			astar_slpx_addr
-		));
+		assert_ok!(Slpx::add_whitelist(RuntimeOrigin::root(), ASTAR_DESTINATION, astar_slpx_addr));
 		assert_eq!(
-			WhitelistAccountId::<Test>::get(SupportChain::Astar).to_vec(),
+			WhitelistAccountId::<Test>::get(ASTAR_DESTINATION).to_vec(),
 			vec![astar_slpx_account_id]
 		);
 
 		assert_ok!(Slpx::remove_whitelist(
 			RuntimeOrigin::root(),
-			SupportChain::Astar,
+			ASTAR_DESTINATION,
 			astar_slpx_addr
 		));
-		assert_eq!(WhitelistAccountId::<Test>::get(SupportChain::Astar).to_vec(), vec![]);
+		assert_eq!(WhitelistAccountId::<Test>::get(ASTAR_DESTINATION).to_vec(), vec![]);
 	});
 }
 
@@ -171,7 +162,7 @@ fn remove_whitelist_account_id_not_in_whitelist() {
 	new_test_ext().execute_with(|| {
 		let astar_slpx_addr = H160::from_slice(&ASTAR_SLPX_ADDR);
 		assert_noop!(
-			Slpx::remove_whitelist(RuntimeOrigin::root(), SupportChain::Astar, astar_slpx_addr),
+			Slpx::remove_whitelist(RuntimeOrigin::root(), ASTAR_DESTINATION, astar_slpx_addr),
 			Error::<Test>::AccountNotFound
 		);
 	});
@@ -195,10 +186,10 @@ fn test_execution_fee_work() {
 
 		assert_ok!(Slpx::set_transfer_to_fee(
 			RuntimeOrigin::root(),
-			SupportChain::Moonbeam,
+			MOONBEAM_DESTINATION,
 			10 * 1_000_000_000
 		));
-		assert_eq!(TransferToFee::<Test>::get(SupportChain::Moonbeam), Some(10 * 1_000_000_000));
+		assert_eq!(TransferToFee::<Test>::get(MOONBEAM_DESTINATION), Some(10 * 1_000_000_000));
 	});
 }
 
@@ -366,7 +357,7 @@ fn test_set_currency_to_support_xcm_fee() {
 fn test_add_order() {
 	new_test_ext().execute_with(|| {
 		WhitelistAccountId::<Test>::insert(
-			SupportChain::Astar,
+			ASTAR_DESTINATION,
 			BoundedVec::try_from(vec![ALICE]).unwrap(),
 		);
 
@@ -378,14 +369,14 @@ fn test_add_order() {
 			TargetChain::Astar(source_chain_caller),
 			BoundedVec::default()
 		));
-		assert_eq!(OrderQueue::<Test>::get().len(), 1usize);
+		assert_eq!(OrderQueueLength::<Test>::get(), 1);
 		assert_ok!(Slpx::redeem(
 			RuntimeOrigin::signed(ALICE),
 			source_chain_caller,
 			VDOT,
 			TargetChain::Astar(source_chain_caller)
 		));
-		assert_eq!(OrderQueue::<Test>::get().len(), 2usize);
+		assert_eq!(OrderQueueLength::<Test>::get(), 2);
 		assert_ok!(Slpx::force_add_order(
 			RuntimeOrigin::root(),
 			OrderCaller::Evm(source_chain_caller),
@@ -395,9 +386,9 @@ fn test_add_order() {
 			BoundedVec::default(),
 			0
 		));
-		assert_eq!(OrderQueue::<Test>::get().len(), 3usize);
-
-		println!("{:?}", OrderQueue::<Test>::get());
+		assert_eq!(OrderQueueLength::<Test>::get(), 3);
+		assert_eq!(OrderBlocks::<Test>::get(0).unwrap().total, 3);
+		assert_eq!(OrderQueueBounds::<Test>::get(), Some((0, 0)));
 	})
 }
 
@@ -405,7 +396,7 @@ fn test_add_order() {
 fn test_mint_with_channel_id() {
 	new_test_ext().execute_with(|| {
 		WhitelistAccountId::<Test>::insert(
-			SupportChain::Astar,
+			ASTAR_DESTINATION,
 			BoundedVec::try_from(vec![ALICE]).unwrap(),
 		);
 
@@ -418,14 +409,52 @@ fn test_mint_with_channel_id() {
 			BoundedVec::default(),
 			0u32
 		));
-		assert_eq!(OrderQueue::<Test>::get().len(), 1usize);
+		assert_eq!(OrderQueueLength::<Test>::get(), 1);
 		assert_ok!(Slpx::redeem(
 			RuntimeOrigin::signed(ALICE),
 			source_chain_caller,
 			VDOT,
 			TargetChain::Astar(source_chain_caller)
 		));
-		assert_eq!(OrderQueue::<Test>::get().len(), 2usize);
+		assert_eq!(OrderQueueLength::<Test>::get(), 2);
+	})
+}
+
+#[test]
+fn test_redeem_prepays_destination_fee() {
+	new_test_ext().execute_with(|| {
+		WhitelistAccountId::<Test>::insert(
+			MOONBEAM_DESTINATION,
+			BoundedVec::try_from(vec![ALICE]).unwrap(),
+		);
+		let fee = 20 * 1_000_000_000;
+		assert_ok!(Slpx::set_transfer_to_fee(RuntimeOrigin::root(), MOONBEAM_DESTINATION, fee));
+		assert_ok!(Currencies::deposit(BNC, &ALICE, 100 * 1_000_000_000));
+
+		// DOT can't pay the xcm fee on Moonbeam, the BNC fee is prepaid to the derivative account.
+		let receiver = H160::from(EVM_ADDR);
+		assert_ok!(Slpx::redeem(
+			RuntimeOrigin::signed(ALICE),
+			receiver,
+			VDOT,
+			TargetChain::Moonbeam(receiver)
+		));
+		assert_eq!(Currencies::free_balance(BNC, &ALICE), 80 * 1_000_000_000);
+		assert_eq!(Currencies::free_balance(BNC, &Slpx::h160_to_account_id(&receiver)), fee);
+		assert_eq!(
+			OrderQueue::<Test>::iter_values().next().and_then(|order| order.redeem_fee),
+			Some((BNC, fee))
+		);
+
+		// Minted vtokens pay the fee when they are transferred.
+		assert_ok!(Slpx::mint(
+			RuntimeOrigin::signed(ALICE),
+			receiver,
+			DOT,
+			TargetChain::Moonbeam(receiver),
+			BoundedVec::default()
+		));
+		assert_eq!(Currencies::free_balance(BNC, &ALICE), 80 * 1_000_000_000);
 	})
 }
 
@@ -433,7 +462,7 @@ fn test_mint_with_channel_id() {
 fn test_hook() {
 	new_test_ext().execute_with(|| {
 		WhitelistAccountId::<Test>::insert(
-			SupportChain::Astar,
+			ASTAR_DESTINATION,
 			BoundedVec::try_from(vec![ALICE]).unwrap(),
 		);
 		let source_chain_caller = H160::default();
@@ -444,21 +473,585 @@ fn test_hook() {
 			TargetChain::Astar(source_chain_caller),
 			BoundedVec::default()
 		));
-		assert_eq!(OrderQueue::<Test>::get().len(), 1usize);
+		assert_eq!(OrderQueueLength::<Test>::get(), 1);
 		<frame_system::Pallet<Test>>::set_block_number(2u32.into());
 
 		assert_ok!(Tokens::set_balance(
 			RuntimeOrigin::root(),
-			OrderQueue::<Test>::get()[0].derivative_account.clone(),
+			OrderQueue::<Test>::get(0, 0).unwrap().derivative_account.clone(),
 			DOT,
 			10_000_000_000_000_000_000,
 			0
 		));
 
 		let current_block = <frame_system::Pallet<Test>>::block_number();
-		Slpx::on_idle(current_block, Weight::default());
-		assert_eq!(OrderQueue::<Test>::get().len(), 0usize);
+		Slpx::on_idle(current_block, Weight::MAX);
+		assert_eq!(OrderQueueLength::<Test>::get(), 0);
+		assert_eq!(OrderQueueBounds::<Test>::get(), None);
 
 		println!("{}", Currencies::free_balance(VDOT, &BOB));
 	})
 }
+
+#[test]
+fn test_handle_order_queue_in_batches() {
+	new_test_ext().execute_with(|| {
+		WhitelistAccountId::<Test>::insert(
+			ASTAR_DESTINATION,
+			BoundedVec::try_from(vec![ALICE]).unwrap(),
+		);
+		assert_ok!(Slpx::set_delay_block(RuntimeOrigin::root(), 2));
+
+		let source_chain_caller = H160::default();
+		for block_number in [0u64, 0, 1, 3] {
+			<frame_system::Pallet<Test>>::set_block_number(block_number);
+			assert_ok!(Slpx::mint(
+				RuntimeOrigin::signed(ALICE),
+				source_chain_caller,
+				DOT,
+				TargetChain::Astar(source_chain_caller),
+				BoundedVec::default()
+			));
+		}
+		assert_eq!(OrderQueueLength::<Test>::get(), 4);
+		assert_eq!(OrderQueueBounds::<Test>::get(), Some((0, 3)));
+		assert_eq!(OrderBlocks::<Test>::get(0).unwrap().next, Some(1));
+		assert_eq!(OrderBlocks::<Test>::get(1).unwrap().next, Some(3));
+
+		// The weight limit only allows two orders.
+		let limit = <() as WeightInfo>::handle_mint_order().saturating_mul(2);
+		let mut weight = Weight::zero();
+		assert_ok!(Slpx::handle_order_queue(3, limit, &mut weight));
+		assert_eq!(OrderQueueLength::<Test>::get(), 2);
+		assert_eq!(OrderQueueBounds::<Test>::get(), Some((1, 3)));
+		assert_eq!(OrderBlocks::<Test>::get(0), None);
+
+		// The orders created at block 3 are still delayed.
+		let mut weight = Weight::zero();
+		assert_ok!(Slpx::handle_order_queue(3, Weight::MAX, &mut weight));
+		assert_eq!(OrderQueueLength::<Test>::get(), 1);
+		assert_eq!(OrderQueueBounds::<Test>::get(), Some((3, 3)));
+
+		let mut weight = Weight::zero();
+		assert_ok!(Slpx::handle_order_queue(5, Weight::MAX, &mut weight));
+		assert_eq!(OrderQueueLength::<Test>::get(), 0);
+		assert_eq!(OrderQueueBounds::<Test>::get(), None);
+		assert_eq!(OrderQueue::<Test>::iter().count(), 0);
+	})
+}
+
+#[test]
+fn test_retry_and_refund_failed_order() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Slpx::force_add_order(
+			RuntimeOrigin::root(),
+			OrderCaller::Evm(H160::default()),
+			ALICE,
+			DOT,
+			TargetChain::Astar(H160::default()),
+			BoundedVec::default(),
+			0
+		));
+
+		// The derivative account has no balance, so the order fails.
+		<frame_system::Pallet<Test>>::set_block_number(2);
+		assert_ok!(Slpx::handle_order_queue(2, Weight::MAX, &mut Weight::zero()));
+		assert_eq!(OrderQueueLength::<Test>::get(), 0);
+		let failed_order = FailedOrders::<Test>::get(0).unwrap();
+		assert_eq!(failed_order.retry_count, 0);
+		assert_eq!(failed_order.next_retry_block, 12);
+		assert_eq!(NextFailedOrderId::<Test>::get(), 1);
+
+		// Not due yet
+		assert_ok!(Slpx::handle_failed_orders(11, Weight::MAX, &mut Weight::zero()));
+		assert_eq!(FailedOrders::<Test>::get(0).unwrap().retry_count, 0);
+
+		// The retry interval is doubled on every retry.
+		assert_ok!(Slpx::handle_failed_orders(12, Weight::MAX, &mut Weight::zero()));
+		let failed_order = FailedOrders::<Test>::get(0).unwrap();
+		assert_eq!(failed_order.retry_count, 1);
+		assert_eq!(failed_order.next_retry_block, 22);
+
+		assert_ok!(Slpx::handle_failed_orders(22, Weight::MAX, &mut Weight::zero()));
+		let failed_order = FailedOrders::<Test>::get(0).unwrap();
+		assert_eq!(failed_order.retry_count, 2);
+		assert_eq!(failed_order.next_retry_block, 42);
+
+		// The retries are exhausted, the order is refunded to the caller on the target chain.
+		assert_ok!(Slpx::handle_failed_orders(42, Weight::MAX, &mut Weight::zero()));
+		assert_eq!(FailedOrders::<Test>::get(0), None);
+		System::assert_has_event(RuntimeEvent::Slpx(Event::OrderRefunded {
+			order_id: 0,
+			currency_id: DOT,
+			amount: 0,
+		}));
+	})
+}
+
+#[test]
+fn test_claim_refund_of_failed_substrate_order() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Slpx::force_add_order(
+			RuntimeOrigin::root(),
+			OrderCaller::Substrate(BOB),
+			BOB,
+			DOT,
+			TargetChain::Astar(H160::default()),
+			BoundedVec::default(),
+			0
+		));
+
+		<frame_system::Pallet<Test>>::set_block_number(2);
+		assert_ok!(Slpx::handle_order_queue(2, Weight::MAX, &mut Weight::zero()));
+		assert_eq!(FailedOrders::<Test>::get(0).unwrap().retry_count, 0);
+
+		// The order is refundable only once its retries are exhausted.
+		assert_noop!(
+			Slpx::claim_refund(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::FailedOrderNotFound
+		);
+		assert_ok!(Slpx::handle_failed_orders(12, Weight::MAX, &mut Weight::zero()));
+		assert_ok!(Slpx::handle_failed_orders(22, Weight::MAX, &mut Weight::zero()));
+		assert_ok!(Slpx::handle_failed_orders(42, Weight::MAX, &mut Weight::zero()));
+		assert_eq!(FailedOrders::<Test>::get(0), None);
+		assert!(RefundableOrders::<Test>::get(0).is_some());
+		System::assert_has_event(RuntimeEvent::Slpx(Event::OrderRefundable { order_id: 0 }));
+
+		// The substrate caller claims the refund, nothing is sent to the target chain.
+		assert_noop!(
+			Slpx::claim_refund(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::NotOrderCaller
+		);
+		assert_ok!(Slpx::claim_refund(RuntimeOrigin::signed(BOB), 0));
+		assert_eq!(RefundableOrders::<Test>::get(0), None);
+		System::assert_has_event(RuntimeEvent::Slpx(Event::OrderRefunded {
+			order_id: 0,
+			currency_id: DOT,
+			amount: 0,
+		}));
+		assert_noop!(
+			Slpx::claim_refund(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::FailedOrderNotFound
+		);
+	})
+}
+
+#[test]
+fn test_handle_failed_orders_in_batches() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..3 {
+			assert_ok!(Slpx::force_add_order(
+				RuntimeOrigin::root(),
+				OrderCaller::Evm(H160::default()),
+				ALICE,
+				DOT,
+				TargetChain::Astar(H160::default()),
+				BoundedVec::default(),
+				0
+			));
+		}
+		<frame_system::Pallet<Test>>::set_block_number(2);
+		assert_ok!(Slpx::handle_order_queue(2, Weight::MAX, &mut Weight::zero()));
+		assert_eq!(FailedOrders::<Test>::iter().count(), 3);
+
+		// The weight limit only allows two orders.
+		let limit = <() as WeightInfo>::retry_failed_order().saturating_mul(2);
+		assert_ok!(Slpx::handle_failed_orders(12, limit, &mut Weight::zero()));
+		assert_eq!(
+			FailedOrders::<Test>::iter().filter(|(_, order)| order.retry_count == 1).count(),
+			2
+		);
+	})
+}
+
+#[test]
+fn test_set_destination() {
+	new_test_ext().execute_with(|| {
+		let info = DestinationInfo {
+			para_id: 2004,
+			account_type: AccountType::AccountKey20,
+			open: true,
+			fee_currency: Some(BNC),
+			xcm_weight: None,
+			callback: None,
+		};
+		assert_noop!(
+			Slpx::set_destination(RuntimeOrigin::signed(ALICE), 10, Some(info.clone())),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Slpx::set_destination(
+				RuntimeOrigin::root(),
+				10,
+				Some(DestinationInfo { para_id: 0, ..info.clone() })
+			),
+			Error::<Test>::InvalidDestination
+		);
+		assert_noop!(
+			Slpx::set_destination(
+				RuntimeOrigin::root(),
+				10,
+				Some(DestinationInfo { xcm_weight: Some(Weight::zero()), ..info.clone() })
+			),
+			Error::<Test>::InvalidDestination
+		);
+		assert_noop!(
+			Slpx::set_destination(
+				RuntimeOrigin::root(),
+				10,
+				Some(DestinationInfo {
+					account_type: AccountType::AccountId32,
+					callback: Some(CallbackConfiguration {
+						contract: None,
+						fee_pallet_instance: MOONBEAM_NATIVE_PALLET_INSTANCE,
+						xcm_fee: 1_000_000_000,
+						xcm_weight: Weight::from_parts(4_000_000_000, 50_000),
+					}),
+					..info.clone()
+				})
+			),
+			Error::<Test>::InvalidCallback
+		);
+		assert_ok!(Slpx::set_destination(RuntimeOrigin::root(), 10, Some(info.clone())));
+		assert_eq!(Destinations::<Test>::get(10), Some(info.clone()));
+
+		// Open destinations are used by substrate callers directly.
+		let receiver = H160::from(EVM_ADDR);
+		assert_ok!(Slpx::mint(
+			RuntimeOrigin::signed(ALICE),
+			H160::default(),
+			DOT,
+			TargetChain::Destination(10, DestinationAccount::AccountKey20(receiver)),
+			BoundedVec::default()
+		));
+		let order = OrderQueue::<Test>::get(0, 0).unwrap();
+		assert_eq!(order.source_chain_caller, OrderCaller::Substrate(ALICE));
+		assert_eq!(
+			order.target_chain,
+			TargetChain::Destination(10, DestinationAccount::AccountKey20(receiver))
+		);
+
+		assert_noop!(
+			Slpx::mint(
+				RuntimeOrigin::signed(ALICE),
+				H160::default(),
+				DOT,
+				TargetChain::Destination(10, DestinationAccount::AccountId32(BOB)),
+				BoundedVec::default()
+			),
+			Error::<Test>::InvalidReceiver
+		);
+		assert_noop!(
+			Slpx::mint(
+				RuntimeOrigin::signed(ALICE),
+				H160::default(),
+				DOT,
+				TargetChain::Destination(11, DestinationAccount::AccountKey20(receiver)),
+				BoundedVec::default()
+			),
+			Error::<Test>::DestinationNotFound
+		);
+
+		// The other destinations only accept whitelisted contracts, even without a whitelist.
+		assert_ok!(Slpx::set_destination(
+			RuntimeOrigin::root(),
+			10,
+			Some(DestinationInfo { open: false, ..info })
+		));
+		assert_noop!(
+			Slpx::mint(
+				RuntimeOrigin::signed(ALICE),
+				H160::default(),
+				DOT,
+				TargetChain::Destination(10, DestinationAccount::AccountKey20(receiver)),
+				BoundedVec::default()
+			),
+			Error::<Test>::AccountNotFound
+		);
+
+		assert_ok!(Slpx::set_destination(RuntimeOrigin::root(), 10, None));
+		assert_eq!(Destinations::<Test>::get(10), None);
+	})
+}
+
+#[test]
+fn test_legacy_target_chain() {
+	new_test_ext().execute_with(|| {
+		WhitelistAccountId::<Test>::insert(
+			ASTAR_DESTINATION,
+			BoundedVec::try_from(vec![ALICE]).unwrap(),
+		);
+
+		let receiver = H160::from(EVM_ADDR);
+		assert_ok!(Slpx::mint(
+			RuntimeOrigin::signed(ALICE),
+			receiver,
+			DOT,
+			TargetChain::Astar(receiver),
+			BoundedVec::default()
+		));
+		let order = OrderQueue::<Test>::get(0, 0).unwrap();
+		assert_eq!(order.source_chain_caller, OrderCaller::Evm(receiver));
+		assert_eq!(
+			order.target_chain,
+			TargetChain::Destination(
+				ASTAR_DESTINATION,
+				DestinationAccount::AccountId32(Slpx::h160_to_account_id(&receiver))
+			)
+		);
+
+		// The whitelisted destination rejects other callers.
+		assert_noop!(
+			Slpx::mint(
+				RuntimeOrigin::signed(BOB),
+				receiver,
+				DOT,
+				TargetChain::Astar(receiver),
+				BoundedVec::default()
+			),
+			Error::<Test>::AccountNotFound
+		);
+	})
+}
+
+#[test]
+fn test_handle_failed_orders_resumes_from_cursor() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..3 {
+			assert_ok!(Slpx::force_add_order(
+				RuntimeOrigin::root(),
+				OrderCaller::Evm(H160::default()),
+				ALICE,
+				DOT,
+				TargetChain::Astar(H160::default()),
+				BoundedVec::default(),
+				0
+			));
+		}
+		<frame_system::Pallet<Test>>::set_block_number(2);
+		assert_ok!(Slpx::handle_order_queue(2, Weight::MAX, &mut Weight::zero()));
+		assert_eq!(FailedOrders::<Test>::iter().count(), 3);
+
+		// The weight limit only allows one order per block, each block resumes after the
+		// order retried by the previous one.
+		let limit = <() as WeightInfo>::retry_failed_order();
+		let mut visited = Vec::new();
+		for retried in 1..=2 {
+			assert_ok!(Slpx::handle_failed_orders(12, limit, &mut Weight::zero()));
+			let cursor = FailedOrdersCursor::<Test>::get().unwrap();
+			assert!(!visited.contains(&cursor));
+			visited.push(cursor);
+			assert_eq!(
+				FailedOrders::<Test>::iter().filter(|(_, order)| order.retry_count == 1).count(),
+				retried
+			);
+		}
+
+		// The last failed order is retried, the next block starts over.
+		assert_ok!(Slpx::handle_failed_orders(12, limit, &mut Weight::zero()));
+		assert_eq!(FailedOrdersCursor::<Test>::get(), None);
+		assert_eq!(
+			FailedOrders::<Test>::iter().filter(|(_, order)| order.retry_count == 1).count(),
+			3
+		);
+	})
+}
+
+#[test]
+fn test_create_order_with_callback() {
+	new_test_ext().execute_with(|| {
+		WhitelistAccountId::<Test>::insert(
+			MOONBEAM_DESTINATION,
+			BoundedVec::try_from(vec![ALICE]).unwrap(),
+		);
+		let receiver = H160::from(EVM_ADDR);
+		let callback = OrderCallback {
+			order_id: 7,
+			source_chain: MOONBEAM_DESTINATION,
+			contract: Some(receiver),
+			selector: [1, 2, 3, 4],
+		};
+
+		// Destinations without a callback configuration don't accept callbacks.
+		assert_noop!(
+			Slpx::create_order_with_callback(
+				RuntimeOrigin::signed(ALICE),
+				receiver,
+				DOT,
+				TargetChain::Moonbeam(receiver),
+				BoundedVec::default(),
+				0,
+				callback.clone()
+			),
+			Error::<Test>::InvalidCallback
+		);
+		Destinations::<Test>::mutate(MOONBEAM_DESTINATION, |info| {
+			info.as_mut().unwrap().callback = Some(CallbackConfiguration {
+				contract: None,
+				fee_pallet_instance: MOONBEAM_NATIVE_PALLET_INSTANCE,
+				xcm_fee: 1_000_000_000,
+				xcm_weight: Weight::from_parts(4_000_000_000, 50_000),
+			})
+		});
+		assert_ok!(Slpx::create_order_with_callback(
+			RuntimeOrigin::signed(ALICE),
+			receiver,
+			DOT,
+			TargetChain::Moonbeam(receiver),
+			BoundedVec::default(),
+			0,
+			callback.clone()
+		));
+		let order = OrderQueue::<Test>::get(0, 0).unwrap();
+		assert_eq!(order.source_chain_caller, OrderCaller::Evm(receiver));
+		assert_eq!(order.callback, Some(callback.clone()));
+
+		// The callback goes back to the source chain, whatever the target chain is.
+		assert_ok!(Slpx::create_order_with_callback(
+			RuntimeOrigin::signed(ALICE),
+			receiver,
+			DOT,
+			TargetChain::Hydradx(BOB),
+			BoundedVec::default(),
+			0,
+			callback.clone()
+		));
+
+		// The caller must be whitelisted on the source chain of the callback.
+		assert_noop!(
+			Slpx::create_order_with_callback(
+				RuntimeOrigin::signed(BOB),
+				receiver,
+				DOT,
+				TargetChain::Moonbeam(receiver),
+				BoundedVec::default(),
+				0,
+				callback.clone()
+			),
+			Error::<Test>::AccountNotFound
+		);
+
+		// The callback is only supported by the evm source chains.
+		WhitelistAccountId::<Test>::insert(
+			ASTAR_DESTINATION,
+			BoundedVec::try_from(vec![ALICE]).unwrap(),
+		);
+		assert_noop!(
+			Slpx::create_order_with_callback(
+				RuntimeOrigin::signed(ALICE),
+				receiver,
+				DOT,
+				TargetChain::Moonbeam(receiver),
+				BoundedVec::default(),
+				0,
+				OrderCallback { source_chain: ASTAR_DESTINATION, ..callback.clone() }
+			),
+			Error::<Test>::InvalidCallback
+		);
+
+		// Without the callback contract of the destination chain, the contract is required.
+		let callback = OrderCallback { order_id: 8, contract: None, ..callback };
+		assert_noop!(
+			Slpx::create_order_with_callback(
+				RuntimeOrigin::signed(ALICE),
+				receiver,
+				DOT,
+				TargetChain::Moonbeam(receiver),
+				BoundedVec::default(),
+				0,
+				callback.clone()
+			),
+			Error::<Test>::InvalidCallback
+		);
+		Destinations::<Test>::mutate(MOONBEAM_DESTINATION, |info| {
+			info.as_mut().unwrap().callback.as_mut().unwrap().contract = Some(receiver)
+		});
+		assert_ok!(Slpx::create_order_with_callback(
+			RuntimeOrigin::signed(ALICE),
+			receiver,
+			DOT,
+			TargetChain::Moonbeam(receiver),
+			BoundedVec::default(),
+			0,
+			callback
+		));
+		assert_eq!(OrderQueueLength::<Test>::get(), 3);
+	})
+}
+
+#[test]
+fn test_refund_order_with_callback_charges_callback_fee() {
+	new_test_ext().execute_with(|| {
+		WhitelistAccountId::<Test>::insert(
+			MOONBEAM_DESTINATION,
+			BoundedVec::try_from(vec![ALICE]).unwrap(),
+		);
+		Destinations::<Test>::mutate(MOONBEAM_DESTINATION, |info| {
+			info.as_mut().unwrap().callback = Some(CallbackConfiguration {
+				contract: None,
+				fee_pallet_instance: MOONBEAM_NATIVE_PALLET_INSTANCE,
+				xcm_fee: 1_000_000_000,
+				xcm_weight: Weight::from_parts(4_000_000_000, 50_000),
+			})
+		});
+		let receiver = H160::from(EVM_ADDR);
+		assert_ok!(Slpx::create_order_with_callback(
+			RuntimeOrigin::signed(ALICE),
+			receiver,
+			DOT,
+			TargetChain::Moonbeam(receiver),
+			BoundedVec::default(),
+			0,
+			OrderCallback {
+				order_id: 7,
+				source_chain: MOONBEAM_DESTINATION,
+				contract: Some(receiver),
+				selector: [1, 2, 3, 4],
+			}
+		));
+		let mut order = OrderQueue::<Test>::take(0, 0).unwrap();
+		let amount = 100 * 1_000_000_000;
+		order.currency_amount = amount;
+		assert_ok!(Currencies::deposit(DOT, &order.derivative_account, amount));
+		FailedOrders::<Test>::insert(0, FailedOrder { order, retry_count: 2, next_retry_block: 2 });
+
+		// The callback is paid by the sovereign account, the order is charged for it.
+		let fee = 10 * 1_000_000_000;
+		assert_ok!(Slpx::set_execution_fee(RuntimeOrigin::root(), DOT, fee));
+		<frame_system::Pallet<Test>>::set_block_number(2);
+		assert_ok!(Slpx::handle_failed_orders(2, Weight::MAX, &mut Weight::zero()));
+		assert_eq!(FailedOrders::<Test>::get(0), None);
+		assert_eq!(Currencies::free_balance(DOT, &BifrostFeeAccount::get()), fee);
+		System::assert_has_event(RuntimeEvent::Slpx(Event::OrderRefunded {
+			order_id: 0,
+			currency_id: DOT,
+			amount: amount - fee,
+		}));
+	})
+}
+
+#[test]
+fn test_encode_callback_call() {
+	new_test_ext().execute_with(|| {
+		let contract = H160::from(MOONBEAM_SLPX_ADDR);
+		let mut input = vec![1u8, 2, 3, 4];
+		input.extend(ethabi::encode(&[
+			ethabi::Token::Uint(U256::from(7u64)),
+			ethabi::Token::Uint(U256::from(123u128)),
+			ethabi::Token::Uint(U256::zero()),
+		]));
+		let transaction = EthereumXcmTransaction::V2(EthereumXcmTransactionV2 {
+			gas_limit: U256::from(720000),
+			action: TransactionAction::Call(contract),
+			value: U256::zero(),
+			input: input.try_into().unwrap(),
+			access_list: None,
+		});
+		let call = MoonbeamCall::EthereumXcm(EthereumXcmCall::Transact(transaction));
+
+		assert_eq!(
+			Slpx::encode_callback_call(contract, [1, 2, 3, 4], 7, 123u128, 0).unwrap(),
+			call.encode()
+		);
+	})
+}
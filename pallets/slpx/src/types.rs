@@ -42,15 +42,76 @@ pub type CurrencyIdOf<T> = <<T as pallet::Config>::MultiCurrency as MultiCurrenc
 pub type BalanceOf<T> =
 	<<T as pallet::Config>::MultiCurrency as MultiCurrency<AccountIdOf<T>>>::Balance;
 
+/// The id of a destination chain in the slpx registry.
+pub type DestinationId = u32;
+
+/// The destination ids of the chains supported before the registry was introduced.
+pub const ASTAR_DESTINATION: DestinationId = 0;
+pub const MOONBEAM_DESTINATION: DestinationId = 1;
+pub const HYDRADX_DESTINATION: DestinationId = 2;
+pub const INTERLAY_DESTINATION: DestinationId = 3;
+pub const MANTA_DESTINATION: DestinationId = 4;
+
+/// The account format used by a destination chain.
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AccountType {
+	AccountId32,
+	AccountKey20,
+}
+
+/// A destination chain registered by governance.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DestinationInfo<CurrencyId> {
+	/// The parachain id of the destination chain
+	pub para_id: u32,
+	/// The account format of the destination chain
+	pub account_type: AccountType,
+	/// Whether substrate callers may create orders to the destination chain directly, the
+	/// orders of the other destination chains are only accepted from whitelisted contracts
+	pub open: bool,
+	/// The currency paying the xcm fee on the destination chain, if it can not be paid with
+	/// the transferred currency
+	pub fee_currency: Option<CurrencyId>,
+	/// The xcm weight limit on the destination chain, unlimited if `None`
+	pub xcm_weight: Option<Weight>,
+	/// The configuration of the callbacks sent to the destination chain, callbacks are not
+	/// supported if `None`
+	pub callback: Option<CallbackConfiguration>,
+}
+
+/// The ethereum-xcm configuration of the callbacks sent to an evm destination chain.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CallbackConfiguration {
+	/// The contract called back, if the order doesn't name one
+	pub contract: Option<H160>,
+	/// The pallet instance of the native currency paying the xcm fee on the destination chain
+	pub fee_pallet_instance: u8,
+	/// The xcm fee paid on the destination chain by the sovereign account of Bifrost
+	pub xcm_fee: u128,
+	/// The weight limit of the transact on the destination chain
+	pub xcm_weight: Weight,
+}
+
+/// The receiver of an order on the destination chain.
 #[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub enum SupportChain {
-	Astar,
-	Moonbeam,
-	Hydradx,
-	Interlay,
-	Manta,
+pub enum DestinationAccount<AccountId> {
+	AccountId32(AccountId),
+	AccountKey20(H160),
+}
+
+impl<AccountId> DestinationAccount<AccountId> {
+	pub fn account_type(&self) -> AccountType {
+		match self {
+			DestinationAccount::AccountId32(_) => AccountType::AccountId32,
+			DestinationAccount::AccountKey20(_) => AccountType::AccountKey20,
+		}
+	}
 }
 
+/// The target chain of an order.
+///
+/// The chain specific variants are kept for the contracts deployed before the registry was
+/// introduced, they are converted to `Destination` when an order is created.
 #[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum TargetChain<AccountId> {
 	Astar(H160),
@@ -58,16 +119,18 @@ pub enum TargetChain<AccountId> {
 	Hydradx(AccountId),
 	Interlay(AccountId),
 	Manta(AccountId),
+	Destination(DestinationId, DestinationAccount<AccountId>),
 }
 
 impl<AccountId> TargetChain<AccountId> {
-	pub fn support_chain(self: &TargetChain<AccountId>) -> SupportChain {
+	pub fn destination_id(&self) -> DestinationId {
 		match self {
-			TargetChain::Astar(_) => SupportChain::Astar,
-			TargetChain::Moonbeam(_) => SupportChain::Moonbeam,
-			TargetChain::Hydradx(_) => SupportChain::Hydradx,
-			TargetChain::Interlay(_) => SupportChain::Interlay,
-			TargetChain::Manta(_) => SupportChain::Manta,
+			TargetChain::Astar(_) => ASTAR_DESTINATION,
+			TargetChain::Moonbeam(_) => MOONBEAM_DESTINATION,
+			TargetChain::Hydradx(_) => HYDRADX_DESTINATION,
+			TargetChain::Interlay(_) => INTERLAY_DESTINATION,
+			TargetChain::Manta(_) => MANTA_DESTINATION,
+			TargetChain::Destination(destination_id, _) => *destination_id,
 		}
 	}
 }
@@ -145,4 +208,56 @@ pub struct Order<AccountId, CurrencyId, Balance, BlockNumber> {
 	pub remark: BoundedVec<u8, ConstU32<32>>,
 	pub target_chain: TargetChain<AccountId>,
 	pub channel_id: u32,
+	pub callback: Option<OrderCallback>,
+	/// The xcm fee of the destination chain prepaid to the derivative account of a redeem order.
+	pub redeem_fee: Option<(CurrencyId, Balance)>,
+}
+
+/// The status of a handled order reported by the callback, with the vtoken amount minted or
+/// the token amount redeemed.
+pub const ORDER_STATUS_SUCCESS: u8 = 0;
+/// The status of a refunded order reported by the callback, with the refunded amount.
+pub const ORDER_STATUS_REFUNDED: u8 = 1;
+
+/// The pallet instance of the native currency on Moonbeam.
+pub const MOONBEAM_NATIVE_PALLET_INSTANCE: u8 = 10;
+
+/// The contract called back on the source chain after an order is handled, with the arguments
+/// `(uint64 orderId, uint256 vtokenAmount, uint8 status)`.
+///
+/// The callback is sent from the account derived from the evm caller of the order on the source
+/// chain, never from the sovereign account of Bifrost. The xcm fee is paid by the sovereign
+/// account, and an execution fee is charged from the order for it.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct OrderCallback {
+	/// The order id assigned by the caller.
+	pub order_id: u64,
+	/// The source chain of the order, the caller must be a contract whitelisted on it.
+	pub source_chain: DestinationId,
+	/// The contract to call, the callback contract of the destination chain if `None`.
+	pub contract: Option<H160>,
+	/// The function selector of the callback.
+	pub selector: [u8; 4],
+}
+
+/// The orders created in the same block.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct OrderBlockInfo<BlockNumber> {
+	/// The number of orders already handled.
+	pub handled: u32,
+	/// The number of orders created in the block.
+	pub total: u32,
+	/// The next block with orders in the queue.
+	pub next: Option<BlockNumber>,
+}
+
+/// A failed order waiting to be retried or refunded.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FailedOrder<AccountId, CurrencyId, Balance, BlockNumber> {
+	/// The failed order.
+	pub order: Order<AccountId, CurrencyId, Balance, BlockNumber>,
+	/// The number of retries already made.
+	pub retry_count: u32,
+	/// The order will be retried or refunded after this block.
+	pub next_retry_block: BlockNumber,
 }
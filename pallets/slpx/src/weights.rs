@@ -62,6 +62,13 @@ pub trait WeightInfo {
 	fn redeem() -> Weight;
 	fn zenlink_swap() -> Weight;
 	fn stable_pool_swap() -> Weight;
+	fn handle_mint_order() -> Weight;
+	fn handle_redeem_order() -> Weight;
+	fn retry_failed_order() -> Weight;
+	fn claim_refund() -> Weight;
+	fn set_destination() -> Weight;
+	fn send_order_callback() -> Weight;
+	fn create_order_with_callback() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -255,4 +262,174 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(17_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: Slpx OrderQueueBounds (r:1 w:1)
+	/// Proof: Slpx OrderQueueBounds (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Slpx OrderBlocks (r:1 w:1)
+	/// Proof: Slpx OrderBlocks (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	/// Storage: Slpx OrderQueue (r:1 w:1)
+	/// Proof: Slpx OrderQueue (max_values: None, max_size: Some(330), added: 2805, mode: MaxEncodedLen)
+	/// Storage: Slpx OrderQueueLength (r:1 w:1)
+	/// Proof: Slpx OrderQueueLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:5 w:5)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	/// Storage: Slpx ExecutionFee (r:1 w:0)
+	/// Proof: Slpx ExecutionFee (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	/// Storage: Slpx Destinations (r:1 w:0)
+	/// Proof: Slpx Destinations (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	/// Storage: Slpx SupportXcmFeeList (r:1 w:0)
+	/// Proof: Slpx SupportXcmFeeList (max_values: Some(1), max_size: Some(1402), added: 1897, mode: MaxEncodedLen)
+	/// Storage: Slpx TransferToFee (r:1 w:0)
+	/// Proof: Slpx TransferToFee (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting MinimumMint (r:1 w:0)
+	/// Proof: VtokenMinting MinimumMint (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting TokenPool (r:1 w:1)
+	/// Proof: VtokenMinting TokenPool (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting Fees (r:1 w:0)
+	/// Proof: VtokenMinting Fees (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn handle_mint_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2356`
+		//  Estimated: `11362`
+		// Minimum execution time: 173_049_000 picoseconds.
+		Weight::from_parts(178_402_000, 11362)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: Slpx OrderQueueBounds (r:1 w:1)
+	/// Proof: Slpx OrderQueueBounds (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Slpx OrderBlocks (r:1 w:1)
+	/// Proof: Slpx OrderBlocks (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	/// Storage: Slpx OrderQueue (r:1 w:1)
+	/// Proof: Slpx OrderQueue (max_values: None, max_size: Some(330), added: 2805, mode: MaxEncodedLen)
+	/// Storage: Slpx OrderQueueLength (r:1 w:1)
+	/// Proof: Slpx OrderQueueLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	/// Storage: Slpx ExecutionFee (r:1 w:0)
+	/// Proof: Slpx ExecutionFee (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	/// Storage: Slpx Destinations (r:1 w:0)
+	/// Proof: Slpx Destinations (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting PrepaidRedeemFees (r:0 w:1)
+	/// Proof: VtokenMinting PrepaidRedeemFees (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting TokenPool (r:1 w:1)
+	/// Proof: VtokenMinting TokenPool (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting TokenUnlockLedger (r:0 w:1)
+	/// Proof: VtokenMinting TokenUnlockLedger (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting UserUnlockLedger (r:1 w:1)
+	/// Proof: VtokenMinting UserUnlockLedger (max_values: None, max_size: Some(1151), added: 3626, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn handle_redeem_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2514`
+		//  Estimated: `12105`
+		// Minimum execution time: 198_047_000 picoseconds.
+		Weight::from_parts(204_173_000, 12105)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: Slpx FailedOrders (r:1 w:1)
+	/// Proof: Slpx FailedOrders (max_values: None, max_size: Some(346), added: 2821, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:5 w:5)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	/// Storage: Slpx ExecutionFee (r:1 w:0)
+	/// Proof: Slpx ExecutionFee (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	/// Storage: Slpx Destinations (r:1 w:0)
+	/// Proof: Slpx Destinations (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	/// Storage: Slpx SupportXcmFeeList (r:1 w:0)
+	/// Proof: Slpx SupportXcmFeeList (max_values: Some(1), max_size: Some(1402), added: 1897, mode: MaxEncodedLen)
+	/// Storage: Slpx TransferToFee (r:1 w:0)
+	/// Proof: Slpx TransferToFee (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting MinimumMint (r:1 w:0)
+	/// Proof: VtokenMinting MinimumMint (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting TokenPool (r:1 w:1)
+	/// Proof: VtokenMinting TokenPool (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting Fees (r:1 w:0)
+	/// Proof: VtokenMinting Fees (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn retry_failed_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2376`
+		//  Estimated: `11362`
+		// Minimum execution time: 166_387_000 picoseconds.
+		Weight::from_parts(171_534_000, 11362)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: Slpx RefundableOrders (r:1 w:1)
+	/// Proof: Slpx RefundableOrders (max_values: None, max_size: Some(330), added: 2805, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:0)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	fn claim_refund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `3795`
+		// Minimum execution time: 30_267_000 picoseconds.
+		Weight::from_parts(31_204_000, 3795)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Slpx Destinations (r:0 w:1)
+	/// Proof: Slpx Destinations (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	fn set_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_515_000 picoseconds.
+		Weight::from_parts(11_872_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Slpx Destinations (r:1 w:0)
+	/// Proof: Slpx Destinations (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	/// Storage: ParachainInfo ParachainId (r:1 w:0)
+	/// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	/// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	/// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	fn send_order_callback() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1300`
+		//  Estimated: `4765`
+		// Minimum execution time: 60_837_000 picoseconds.
+		Weight::from_parts(62_719_000, 4765)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Slpx WhitelistAccountId (r:1 w:0)
+	/// Proof: Slpx WhitelistAccountId (max_values: None, max_size: Some(338), added: 2813, mode: MaxEncodedLen)
+	/// Storage: Slpx Destinations (r:1 w:0)
+	/// Proof: Slpx Destinations (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	/// Storage: Slpx OrderQueueLength (r:1 w:1)
+	/// Proof: Slpx OrderQueueLength (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Slpx OrderBlocks (r:2 w:2)
+	/// Proof: Slpx OrderBlocks (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	/// Storage: Slpx OrderQueueBounds (r:1 w:1)
+	/// Proof: Slpx OrderQueueBounds (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Slpx OrderQueue (r:0 w:1)
+	/// Proof Skipped: Slpx OrderQueue (max_values: None, max_size: None, mode: Measured)
+	fn create_order_with_callback() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `338`
+		//  Estimated: `3803`
+		// Minimum execution time: 51_328_000 picoseconds.
+		Weight::from_parts(52_916_000, 3803)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
 }
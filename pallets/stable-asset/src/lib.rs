@@ -41,7 +41,7 @@ use scale_info::TypeInfo;
 use sp_core::{U256, U512};
 use sp_runtime::{
 	traits::{AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One, Zero},
-	ArithmeticError, DispatchError, SaturatedConversion,
+	ArithmeticError, DispatchError, Permill, SaturatedConversion,
 };
 use sp_std::prelude::*;
 
@@ -72,6 +72,17 @@ pub struct StableAssetPoolInfo<AssetId, AtLeast64BitUnsigned, Balance, AccountId
 	pub precision: AtLeast64BitUnsigned,
 }
 
+/// Where the token rate of a pool asset is read from when it is refreshed.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+pub enum TokenRateSource<AssetId> {
+	/// The vtoken-minting exchange rate between the vToken and its underlying token.
+	VtokenMinting,
+	/// The ratio between the oracle prices of the asset and the given reference asset.
+	Oracle(AssetId),
+	/// The lend-market exchange rate of the market of the given underlying asset.
+	LendMarket(AssetId),
+}
+
 pub trait WeightInfo {
 	fn create_pool() -> Weight;
 	fn modify_a() -> Weight;
@@ -335,11 +346,12 @@ pub mod traits {
 
 #[frame_support::pallet]
 pub mod pallet {
-	use super::{PoolTokenIndex, StableAssetPoolId, StableAssetPoolInfo};
+	use super::{PoolTokenIndex, StableAssetPoolId, StableAssetPoolInfo, TokenRateSource};
 	use crate::{
 		traits::{StableAsset, ValidateAssetId},
 		WeightInfo,
 	};
+	use bifrost_primitives::PoolId;
 	use frame_support::{
 		dispatch::DispatchResult, pallet_prelude::*, traits::EnsureOrigin, transactional, PalletId,
 	};
@@ -434,6 +446,32 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type TokenRateHardcap<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, Permill>;
 
+	/// The source the token rate of a pool asset is refreshed from. Each refresh is capped by
+	/// the `TokenRateHardcap` of the asset.
+	#[pallet::storage]
+	pub type TokenRateSources<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		StableAssetPoolId,
+		Twox64Concat,
+		T::AssetId,
+		TokenRateSource<T::AssetId>,
+	>;
+
+	/// The offpeg fee multiplier of the pool, scaled by `FeePrecision`. When it is set, the swap
+	/// fee rises as the pool moves away from balance.
+	#[pallet::storage]
+	pub type OffpegFeeMultipliers<T: Config> =
+		StorageMap<_, Twox64Concat, StableAssetPoolId, T::AtLeast64BitUnsigned>;
+
+	/// The farming pool that stakes the pool asset of the pool.
+	#[pallet::storage]
+	pub type FarmingPools<T: Config> = StorageMap<_, Twox64Concat, StableAssetPoolId, PoolId>;
+
+	/// The pools routed swaps are searched through.
+	#[pallet::storage]
+	pub type RouterPools<T: Config> = StorageValue<_, Vec<StableAssetPoolId>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -620,6 +658,20 @@ pub mod pallet {
 			/// The new redeem fee.
 			redeem_fee: T::AtLeast64BitUnsigned,
 		},
+		/// The offpeg fee multiplier of the pool is modified.
+		OffpegFeeMultiplierModified {
+			/// The pool id.
+			pool_id: StableAssetPoolId,
+			/// The new offpeg fee multiplier, `None` if the dynamic fee is disabled.
+			offpeg_fee_multiplier: Option<T::AtLeast64BitUnsigned>,
+		},
+		/// The farming pool of the pool is modified.
+		FarmingPoolModified {
+			/// The pool id.
+			pool_id: StableAssetPoolId,
+			/// The farming pool that stakes the pool asset, `None` if the pool is unlinked.
+			farming_pool_id: Option<PoolId>,
+		},
 		/// The pool recipients are modified.
 		RecipientModified {
 			/// The pool id.
@@ -653,6 +705,46 @@ pub mod pallet {
 			/// The pool id.
 			pool_id: StableAssetPoolId,
 		},
+		/// The token rate source of a pool asset is configured.
+		TokenRateSourceConfigured {
+			/// The pool id.
+			pool_id: StableAssetPoolId,
+			/// The asset whose token rate is refreshed from the source.
+			asset: T::AssetId,
+			/// The token rate source.
+			source: TokenRateSource<T::AssetId>,
+			/// The hardcap of the token rate.
+			hardcap: Permill,
+		},
+		/// The token rate source of a pool asset is removed.
+		TokenRateSourceRemoved {
+			/// The pool id.
+			pool_id: StableAssetPoolId,
+			/// The asset whose token rate source is removed.
+			asset: T::AssetId,
+		},
+		/// Token is swapped through a multi-hop route.
+		RouteSwapped {
+			/// The account who swapped the token.
+			swapper: T::AccountId,
+			/// The input asset type.
+			input_asset: T::AssetId,
+			/// The output asset type.
+			output_asset: T::AssetId,
+			/// The input amount of the input asset.
+			input_amount: T::Balance,
+			/// The expected minimum output amount of the output asset.
+			min_output_amount: T::Balance,
+			/// Actual output amount of the output asset.
+			output_amount: T::Balance,
+			/// The number of hops of the route.
+			hops: u32,
+		},
+		/// The pools routed swaps are searched through are set.
+		RouterPoolsSet {
+			/// The pool ids.
+			pool_ids: Vec<StableAssetPoolId>,
+		},
 	}
 
 	#[pallet::error]
@@ -1248,10 +1340,17 @@ impl<T: Config> Pallet<T> {
 			.ok_or(Error::<T>::Math)?
 			.checked_div(&pool_info.precisions[output_index_usize])
 			.ok_or(Error::<T>::Math)?;
-		if pool_info.swap_fee > zero {
+		let swap_fee = Self::get_dynamic_fee(
+			pool_info.pool_id,
+			balances[input_index_usize],
+			y,
+			pool_info.swap_fee,
+		)
+		.ok_or(Error::<T>::Math)?;
+		if swap_fee > zero {
 			let fee_amount = u128::try_from(
 				U256::from(dy.saturated_into::<u128>())
-					.checked_mul(U256::from(pool_info.swap_fee.saturated_into::<u128>()))
+					.checked_mul(U256::from(swap_fee.saturated_into::<u128>()))
 					.ok_or(Error::<T>::Math)?
 					.checked_div(U256::from(fee_denominator.saturated_into::<u128>()))
 					.ok_or(Error::<T>::Math)?,
@@ -1304,17 +1403,6 @@ impl<T: Config> Pallet<T> {
 		}
 		let fee_denominator: T::AtLeast64BitUnsigned = T::FeePrecision::get();
 		let swap_exact_over_amount = T::SwapExactOverAmount::get();
-		if pool_info.swap_fee > zero {
-			let diff = fee_denominator.checked_sub(&pool_info.swap_fee)?;
-			dy = u128::try_from(
-				U256::from(dy.saturated_into::<u128>())
-					.checked_mul(U256::from(fee_denominator.saturated_into::<u128>()))?
-					.checked_div(U256::from(diff.saturated_into::<u128>()))?,
-			)
-			.ok()?
-			.into();
-		}
-
 		let a: T::AtLeast64BitUnsigned = Self::get_a(
 			pool_info.a,
 			pool_info.a_block,
@@ -1324,6 +1412,31 @@ impl<T: Config> Pallet<T> {
 		let d: T::AtLeast64BitUnsigned = pool_info.total_supply.into();
 		let mut balances: Vec<T::AtLeast64BitUnsigned> =
 			Self::convert_vec_balance_to_number(pool_info.balances.clone());
+		let mut swap_fee = pool_info.swap_fee;
+		if OffpegFeeMultipliers::<T>::contains_key(pool_info.pool_id) && swap_fee > zero {
+			// Estimate the balances after the trade to price the dynamic fee.
+			let mut new_balances = balances.clone();
+			new_balances[output_index_usize] = new_balances[output_index_usize]
+				.checked_sub(&dy.checked_mul(&pool_info.precisions[output_index_usize])?)?;
+			let y: T::AtLeast64BitUnsigned = Self::get_y(&new_balances, input_index, d, a)?;
+			swap_fee = Self::get_dynamic_fee(
+				pool_info.pool_id,
+				y,
+				new_balances[output_index_usize],
+				swap_fee,
+			)?;
+		}
+		if swap_fee > zero {
+			let diff = fee_denominator.checked_sub(&swap_fee)?;
+			dy = u128::try_from(
+				U256::from(dy.saturated_into::<u128>())
+					.checked_mul(U256::from(fee_denominator.saturated_into::<u128>()))?
+					.checked_div(U256::from(diff.saturated_into::<u128>()))?,
+			)
+			.ok()?
+			.into();
+		}
+
 		balances[output_index_usize] = balances[output_index_usize]
 			.checked_sub(&dy.checked_mul(&pool_info.precisions[output_index_usize])?)?;
 		let y: T::AtLeast64BitUnsigned = Self::get_y(&balances, input_index, d, a)?;
@@ -1341,6 +1454,49 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Returns the swap fee of the pool for a trade leaving the balances `xpi` and `xpj` of the
+	/// two traded assets, following Curve's `offpeg_fee_multiplier`:
+	///
+	/// `fee * m / ((m - FEE_DENOMINATOR) * 4 * xpi * xpj / (xpi + xpj)^2 + FEE_DENOMINATOR)`
+	///
+	/// The fee equals `fee` for a balanced pool and rises up to `fee * m / FEE_DENOMINATOR` as
+	/// the pool moves away from balance.
+	pub fn get_dynamic_fee(
+		pool_id: StableAssetPoolId,
+		xpi: T::AtLeast64BitUnsigned,
+		xpj: T::AtLeast64BitUnsigned,
+		fee: T::AtLeast64BitUnsigned,
+	) -> Option<T::AtLeast64BitUnsigned> {
+		let fee_denominator: T::AtLeast64BitUnsigned = T::FeePrecision::get();
+		let multiplier = match OffpegFeeMultipliers::<T>::get(pool_id) {
+			Some(multiplier) if multiplier > fee_denominator => multiplier,
+			_ => return Some(fee),
+		};
+		let fee_denominator_u256 = U256::from(fee_denominator.saturated_into::<u128>());
+		let multiplier_u256 = U256::from(multiplier.saturated_into::<u128>());
+		let xpi = U256::from(xpi.saturated_into::<u128>());
+		let xpj = U256::from(xpj.saturated_into::<u128>());
+		let xps = xpi.checked_add(xpj)?;
+		let xps2 = xps.checked_mul(xps)?;
+		if xps2.is_zero() {
+			return Some(fee);
+		}
+
+		let denominator = multiplier_u256
+			.checked_sub(fee_denominator_u256)?
+			.checked_mul(U256::from(4u8))?
+			.checked_mul(xpi)?
+			.checked_mul(xpj)?
+			.checked_div(xps2)?
+			.checked_add(fee_denominator_u256)?;
+		let dynamic_fee = multiplier_u256
+			.checked_mul(U256::from(fee.saturated_into::<u128>()))?
+			.checked_div(denominator)?;
+		// The fee must stay below 100%.
+		let max_fee = fee_denominator_u256.checked_sub(U256::one())?;
+		u128::try_from(dynamic_fee.min(max_fee)).ok().map(Into::into)
+	}
+
 	pub fn get_redeem_proportion_amount(
 		pool_info: &StableAssetPoolInfo<
 			T::AssetId,
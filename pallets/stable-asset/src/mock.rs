@@ -22,7 +22,7 @@ use frame_support::{
 	derive_impl,
 	dispatch::DispatchResult,
 	parameter_types,
-	traits::{ConstU128, ConstU32, Currency, EnsureOrigin, Nothing, OnUnbalanced},
+	traits::{ConstU128, ConstU32, Currency, EnsureOrigin, Everything, Nothing, OnUnbalanced},
 };
 use frame_system::RawOrigin;
 use orml_traits::MultiCurrency;
@@ -96,6 +96,7 @@ pub type AdaptedBasicCurrency =
 
 impl bifrost_currencies::Config for Test {
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type WeightInfo = ();
@@ -18,11 +18,12 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use bifrost_primitives::Balance;
+use bifrost_primitives::{Balance, CurrencyId, SwapRouteStep};
 use sp_api::decl_runtime_apis;
 use sp_std::vec::Vec;
 
 decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait StablePoolRuntimeApi
 	{
 		fn get_swap_output(
@@ -36,5 +37,27 @@ decl_runtime_apis! {
 			pool_id: u32,
 			amounts: Vec<Balance>,
 		) -> Balance;
+
+		#[api_version(2)]
+		fn get_route_swap_output(
+			currency_id_in: CurrencyId,
+			currency_id_out: CurrencyId,
+			amount: Balance,
+		) -> Balance;
+
+		#[api_version(2)]
+		fn get_route_swap_input(
+			currency_id_in: CurrencyId,
+			currency_id_out: CurrencyId,
+			amount: Balance,
+		) -> Balance;
+
+		#[api_version(2)]
+		fn get_best_route(
+			currency_id_in: CurrencyId,
+			currency_id_out: CurrencyId,
+			amount: Balance,
+			exact_in: bool,
+		) -> Vec<SwapRouteStep<CurrencyId>>;
 	}
 }
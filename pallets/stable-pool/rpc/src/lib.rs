@@ -18,7 +18,7 @@
 
 use std::{marker::PhantomData, sync::Arc};
 
-use bifrost_primitives::Balance;
+use bifrost_primitives::{Balance, CurrencyId, SwapRouteStep};
 pub use bifrost_stable_pool_rpc_runtime_api::{self as runtime_api, StablePoolRuntimeApi};
 use jsonrpsee::{
 	core::{async_trait, RpcResult},
@@ -50,6 +50,37 @@ pub trait StablePoolRpcApi<BlockHash> {
 		amounts: Vec<Balance>,
 		at: Option<BlockHash>,
 	) -> RpcResult<NumberOrHex>;
+
+	/// rpc method for getting the output amount of the best route for an exact input amount
+	#[method(name = "stable_pool_getRouteSwapOutputAmount")]
+	fn get_route_swap_output_amount(
+		&self,
+		currency_id_in: CurrencyId,
+		currency_id_out: CurrencyId,
+		amount: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<NumberOrHex>;
+
+	/// rpc method for getting the input amount of the best route for an exact output amount
+	#[method(name = "stable_pool_getRouteSwapInputAmount")]
+	fn get_route_swap_input_amount(
+		&self,
+		currency_id_in: CurrencyId,
+		currency_id_out: CurrencyId,
+		amount: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<NumberOrHex>;
+
+	/// rpc method for getting the best route, to be passed to the stable_pool router calls
+	#[method(name = "stable_pool_getBestRoute")]
+	fn get_best_route(
+		&self,
+		currency_id_in: CurrencyId,
+		currency_id_out: CurrencyId,
+		amount: Balance,
+		exact_in: bool,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<SwapRouteStep<CurrencyId>>>;
 }
 
 #[derive(Clone, Debug)]
@@ -115,4 +146,73 @@ where
 			)),
 		}
 	}
+
+	fn get_route_swap_output_amount(
+		&self,
+		currency_id_in: CurrencyId,
+		currency_id_out: CurrencyId,
+		amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<NumberOrHex> {
+		let lm_rpc_api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let rs: Result<Balance, _> =
+			lm_rpc_api.get_route_swap_output(at, currency_id_in, currency_id_out, amount);
+
+		match rs {
+			Ok(amount) => Ok(NumberOrHex::Hex(amount.into())),
+			Err(e) => Err(ErrorObject::owned(
+				ErrorCode::InternalError.code(),
+				"Failed to get stable_pool route swap output amount.",
+				Some(format!("{:?}", e)),
+			)),
+		}
+	}
+
+	fn get_route_swap_input_amount(
+		&self,
+		currency_id_in: CurrencyId,
+		currency_id_out: CurrencyId,
+		amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<NumberOrHex> {
+		let lm_rpc_api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let rs: Result<Balance, _> =
+			lm_rpc_api.get_route_swap_input(at, currency_id_in, currency_id_out, amount);
+
+		match rs {
+			Ok(amount) => Ok(NumberOrHex::Hex(amount.into())),
+			Err(e) => Err(ErrorObject::owned(
+				ErrorCode::InternalError.code(),
+				"Failed to get stable_pool route swap input amount.",
+				Some(format!("{:?}", e)),
+			)),
+		}
+	}
+
+	fn get_best_route(
+		&self,
+		currency_id_in: CurrencyId,
+		currency_id_out: CurrencyId,
+		amount: Balance,
+		exact_in: bool,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<SwapRouteStep<CurrencyId>>> {
+		let lm_rpc_api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let rs = lm_rpc_api.get_best_route(at, currency_id_in, currency_id_out, amount, exact_in);
+
+		match rs {
+			Ok(route) => Ok(route),
+			Err(e) => Err(ErrorObject::owned(
+				ErrorCode::InternalError.code(),
+				"Failed to get stable_pool best route.",
+				Some(format!("{:?}", e)),
+			)),
+		}
+	}
 }
@@ -24,6 +24,60 @@ use frame_support::assert_ok;
 use frame_system::RawOrigin;
 use sp_std::vec;
 
+const ROUTER_ASSETS: [CurrencyId; 4] = [BNC, KSM, DOT, VDOT];
+
+/// Creates the router pools `BNC-KSM`, `KSM-DOT` and `DOT-VDOT` with liquidity, followed by
+/// `p - 3` more `BNC-KSM` pools, and sets all of them as router pools.
+fn create_router_pools<T: Config>(p: u32) -> T::AccountId {
+	let fee_account: T::AccountId = account("seed", 1, 1);
+	for asset in ROUTER_ASSETS {
+		assert_ok!(T::MultiCurrency::deposit(
+			asset.into(),
+			&fee_account,
+			<T as bifrost_stable_asset::Config>::Balance::from(1000_000_000_000_000u128.into())
+		));
+	}
+	let pairs = ROUTER_ASSETS
+		.windows(2)
+		.map(|pair| (pair[0], pair[1]))
+		.chain((3..p).map(|_| (BNC, KSM)))
+		.collect::<Vec<_>>();
+	for (pool_id, (asset0, asset1)) in pairs.into_iter().enumerate() {
+		assert_ok!(StablePool::<T>::create_pool(
+			RawOrigin::Root.into(),
+			vec![asset0.into(), asset1.into()],
+			vec![1u128.into(), 1u128.into()],
+			10000000u128.into(),
+			20000000u128.into(),
+			50000000u128.into(),
+			10000u128.into(),
+			fee_account.clone(),
+			fee_account.clone(),
+			1000000000000000000u128.into()
+		));
+		let pool_id = pool_id as StableAssetPoolId;
+		assert_ok!(StablePool::<T>::edit_token_rate(
+			RawOrigin::Root.into(),
+			pool_id,
+			vec![
+				(asset0.into(), (1u128.into(), 1u128.into())),
+				(asset1.into(), (1u128.into(), 1u128.into()))
+			]
+		));
+		assert_ok!(StablePool::<T>::add_liquidity(
+			RawOrigin::Signed(fee_account.clone()).into(),
+			pool_id,
+			vec![
+				<T as bifrost_stable_asset::Config>::Balance::from(100_000_000_000u128.into()),
+				<T as bifrost_stable_asset::Config>::Balance::from(100_000_000_000u128.into())
+			],
+			<T as bifrost_stable_asset::Config>::Balance::zero()
+		));
+	}
+	assert_ok!(StablePool::<T>::set_router_pools(RawOrigin::Root.into(), (0..p).collect()));
+	fee_account
+}
+
 benchmarks! {
 	create_pool {
 		let fee_account: T::AccountId = whitelisted_caller();
@@ -288,5 +342,148 @@ benchmarks! {
 			1000000000000u128.into()));
 	}: _(RawOrigin::Root, 0, Some(test_account.clone()), Some(test_account))
 
+	router_swap_exact_in {
+		let n in 1 .. T::MaxSwapHops::get().min(3);
+		let p in 3 .. T::MaxRouterPools::get();
+		let fee_account = create_router_pools::<T>(p);
+	}: _(RawOrigin::Signed(fee_account), BNC.into(), ROUTER_ASSETS[n as usize].into(), <T as bifrost_stable_asset::Config>::Balance::from(50_000_000_000u128.into()), <T as bifrost_stable_asset::Config>::Balance::zero(), 100u32.into())
+
+	router_swap_exact_out {
+		let n in 1 .. T::MaxSwapHops::get().min(3);
+		let p in 3 .. T::MaxRouterPools::get();
+		let fee_account = create_router_pools::<T>(p);
+	}: _(RawOrigin::Signed(fee_account), BNC.into(), ROUTER_ASSETS[n as usize].into(), <T as bifrost_stable_asset::Config>::Balance::from(10_000_000_000u128.into()), <T as bifrost_stable_asset::Config>::Balance::from(50_000_000_000u128.into()), 100u32.into())
+
+	set_router_pools {
+		let n in 0 .. T::MaxRouterPools::get();
+		let fee_account: T::AccountId = account("seed",1,1);
+		for _ in 0..n {
+			assert_ok!(StablePool::<T>::create_pool(
+			RawOrigin::Root.into(),
+			vec![BNC.into(), KSM.into()],
+			vec![1u128.into(), 1u128.into()],
+			0u128.into(),
+			0u128.into(),
+			0u128.into(),
+			220u128.into(),
+			fee_account.clone(),
+			fee_account.clone(),
+			1000000000000u128.into()));
+		}
+	}: _(RawOrigin::Root, (0..n).collect())
+
+	config_token_rate_source {
+		let fee_account: T::AccountId = account("seed",1,1);
+		T::MultiCurrency::deposit(
+			VDOT.into(),
+			&fee_account,
+			<T as bifrost_stable_asset::Config>::Balance::from(100_000_000_000u128.into())
+		)?;
+		assert_ok!(T::VtokenMinting::increase_token_pool(DOT.into(), <T as bifrost_stable_asset::Config>::Balance::from(110_000_000_000u128.into())));
+		assert_ok!(StablePool::<T>::create_pool(
+		RawOrigin::Root.into(),
+		vec![DOT.into(), VDOT.into()],
+		vec![1u128.into(), 1u128.into()],
+		10000000u128.into(),
+		20000000u128.into(),
+		50000000u128.into(),
+		10000u128.into(),
+		fee_account.clone(),
+		fee_account.clone(),
+		1000000000000000000u128.into()));
+	}: _(RawOrigin::Root, 0, VDOT.into(), TokenRateSource::VtokenMinting, Permill::from_percent(10))
+
+	remove_token_rate_source {
+		let fee_account: T::AccountId = account("seed",1,1);
+		T::MultiCurrency::deposit(
+			VDOT.into(),
+			&fee_account,
+			<T as bifrost_stable_asset::Config>::Balance::from(100_000_000_000u128.into())
+		)?;
+		assert_ok!(T::VtokenMinting::increase_token_pool(DOT.into(), <T as bifrost_stable_asset::Config>::Balance::from(110_000_000_000u128.into())));
+		assert_ok!(StablePool::<T>::create_pool(
+		RawOrigin::Root.into(),
+		vec![DOT.into(), VDOT.into()],
+		vec![1u128.into(), 1u128.into()],
+		10000000u128.into(),
+		20000000u128.into(),
+		50000000u128.into(),
+		10000u128.into(),
+		fee_account.clone(),
+		fee_account.clone(),
+		1000000000000000000u128.into()));
+		assert_ok!(StablePool::<T>::config_token_rate_source(RawOrigin::Root.into(), 0, VDOT.into(), TokenRateSource::VtokenMinting, Permill::from_percent(10)));
+	}: _(RawOrigin::Root, 0, VDOT.into())
+
+	refresh_token_rate_source {
+		let fee_account: T::AccountId = account("seed",1,1);
+		T::MultiCurrency::deposit(
+			VDOT.into(),
+			&fee_account,
+			<T as bifrost_stable_asset::Config>::Balance::from(100_000_000_000u128.into())
+		)?;
+		assert_ok!(T::VtokenMinting::increase_token_pool(DOT.into(), <T as bifrost_stable_asset::Config>::Balance::from(110_000_000_000u128.into())));
+		assert_ok!(StablePool::<T>::create_pool(
+		RawOrigin::Root.into(),
+		vec![DOT.into(), VDOT.into()],
+		vec![1u128.into(), 1u128.into()],
+		10000000u128.into(),
+		20000000u128.into(),
+		50000000u128.into(),
+		10000u128.into(),
+		fee_account.clone(),
+		fee_account.clone(),
+		1000000000000000000u128.into()));
+		assert_ok!(StablePool::<T>::config_token_rate_source(RawOrigin::Root.into(), 0, VDOT.into(), TokenRateSource::VtokenMinting, Permill::from_percent(10)));
+		assert_ok!(T::VtokenMinting::increase_token_pool(DOT.into(), <T as bifrost_stable_asset::Config>::Balance::from(1_000_000_000u128.into())));
+	}: {
+		StablePool::<T>::refresh_token_rate_sources(0);
+	}
+
+	modify_offpeg_fee_multiplier {
+		let fee_account: T::AccountId = account("seed",1,1);
+		let coin0 = BNC;
+		let coin1 = KSM;
+		assert_ok!(StablePool::<T>::create_pool(
+			RawOrigin::Root.into(),
+			vec![coin0.into(), coin1.into()],
+			vec![1u128.into(), 1u128.into()],
+			0u128.into(),
+			0u128.into(),
+			0u128.into(),
+			220u128.into(),
+			fee_account.clone(),
+			fee_account.clone(),
+			1000000000000u128.into()));
+	}: _(RawOrigin::Root, 0, Some(20000000000u128.into()))
+
+	add_liquidity_single {
+		let fee_account: T::AccountId = account("seed",1,1);
+		T::MultiCurrency::deposit(
+			BNC.into(),
+			&fee_account,
+			<T as bifrost_stable_asset::Config>::Balance::from(1000_000_000_000u128.into())
+		)?;
+		T::MultiCurrency::deposit(
+			KSM.into(),
+			&fee_account,
+			<T as bifrost_stable_asset::Config>::Balance::from(1000_000_000_000u128.into())
+		)?;
+		let amounts = vec![<T as bifrost_stable_asset::Config>::Balance::from(100_000_000_000u128.into()), <T as bifrost_stable_asset::Config>::Balance::from(100_000_000_000u128.into())];
+		assert_ok!(StablePool::<T>::create_pool(
+		RawOrigin::Root.into(),
+		vec![BNC.into(), KSM.into()],
+		vec![1u128.into(), 1u128.into()],
+		10000000u128.into(),
+		20000000u128.into(),
+		50000000u128.into(),
+		10000u128.into(),
+		fee_account.clone(),
+		fee_account.clone(),
+		1000000000000000000u128.into()));
+		assert_ok!(StablePool::<T>::edit_token_rate(RawOrigin::Root.into(), 0, vec![(BNC.into(), (1u128.into(), 1u128.into())), (KSM.into(), (1u128.into(), 1u128.into()))]));
+		assert_ok!(StablePool::<T>::add_liquidity(RawOrigin::Signed(fee_account.clone()).into(), 0, amounts, <T as bifrost_stable_asset::Config>::Balance::zero()));
+	}: _(RawOrigin::Signed(fee_account), 0, 0, <T as bifrost_stable_asset::Config>::Balance::from(10_000_000_000u128.into()), <T as bifrost_stable_asset::Config>::Balance::zero(), false)
+
 	impl_benchmark_test_suite!(StablePool, crate::mock::ExtBuilder::default().build(), crate::mock::Test);
 }
@@ -30,21 +30,29 @@ pub mod weights;
 pub use weights::*;
 pub mod traits;
 
+use bifrost_asset_registry::AssetMetadata;
+pub use bifrost_primitives::SwapRouteStep;
 use bifrost_primitives::{
-	CurrencyId, CurrencyIdConversion, CurrencyIdExt, CurrencyIdRegister, TimeUnit,
-	VtokenMintingOperator,
+	Balance, CurrencyId, CurrencyIdConversion, CurrencyIdExt, CurrencyIdMapping,
+	CurrencyIdRegister, FarmingOperator, OraclePriceProvider, PoolId, Rate, TimeUnit,
+	TryConvertFrom, VtokenMintingOperator,
 };
 pub use bifrost_stable_asset::{
-	MintResult, PoolCount, PoolTokenIndex, Pools, RedeemMultiResult, RedeemProportionResult,
-	RedeemSingleResult, StableAsset, StableAssetPoolId, StableAssetPoolInfo, SwapResult,
-	TokenRateHardcap,
+	FarmingPools, MintResult, OffpegFeeMultipliers, PoolCount, PoolTokenIndex, Pools,
+	RedeemMultiResult, RedeemProportionResult, RedeemSingleResult, RouterPools, StableAsset,
+	StableAssetPoolId, StableAssetPoolInfo, SwapResult, TokenRateHardcap, TokenRateSource,
+	TokenRateSources,
 };
+use cumulus_primitives_core::ParaId;
 use frame_support::{self, pallet_prelude::*, sp_runtime::traits::Zero, transactional};
 use frame_system::pallet_prelude::*;
 use orml_traits::MultiCurrency;
+use pallet_traits::LendMarketMarketDataProvider;
 use sp_core::U256;
-use sp_runtime::{Permill, SaturatedConversion};
+use sp_runtime::{FixedPointNumber, Permill, SaturatedConversion};
 use sp_std::prelude::*;
+use xcm::v3::MultiLocation;
+use zenlink_protocol::{AssetBalance, AssetId, ExportZenlink};
 
 #[allow(type_alias_bounds)]
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
@@ -54,6 +62,7 @@ pub type AssetIdOf<T> = <T as Config>::CurrencyId;
 
 #[allow(type_alias_bounds)]
 pub type AtLeast64BitUnsignedOf<T> = <T as bifrost_stable_asset::Config>::AtLeast64BitUnsigned;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -103,6 +112,26 @@ pub mod pallet {
 		type CurrencyIdConversion: CurrencyIdConversion<AssetIdOf<Self>>;
 		/// Type for registering currency IDs.
 		type CurrencyIdRegister: CurrencyIdRegister<AssetIdOf<Self>>;
+		/// Zenlink interface, used as an additional liquidity source by the router.
+		type DexOperator: ExportZenlink<Self::AccountId, AssetId>;
+		/// The parachain id, used to convert currency ids into Zenlink asset ids.
+		#[pallet::constant]
+		type ParachainId: Get<ParaId>;
+		/// The maximum number of hops a routed swap may take.
+		#[pallet::constant]
+		type MaxSwapHops: Get<u32>;
+		/// The maximum number of pools routed swaps are searched through.
+		#[pallet::constant]
+		type MaxRouterPools: Get<u32>;
+		/// Oracle price provider, used by oracle token rate sources.
+		type OraclePriceProvider: OraclePriceProvider;
+		/// Lend-market data provider, used by lend-market token rate sources.
+		type LendMarket: LendMarketMarketDataProvider<AssetIdOf<Self>, Self::Balance>;
+		/// Farming interface, used to stake the pool asset into the linked farming pool.
+		type Farming: FarmingOperator<AccountIdOf<Self>, Self::Balance, AssetIdOf<Self>>;
+		/// Asset registry, used to check the status of pool assets. Liquidity can only be added
+		/// and swapped while all involved assets are active.
+		type AssetIdMaps: CurrencyIdMapping<CurrencyId, MultiLocation, AssetMetadata<Balance>>;
 	}
 
 	#[pallet::error]
@@ -117,6 +146,24 @@ pub mod pallet {
 		RedeemOverMax,
 		/// The token rate is not set, preventing related operations.
 		TokenRateNotSet,
+		/// No route between the input and output assets was found.
+		NoRouteFound,
+		/// The deadline of the routed swap has passed.
+		DeadlineExpired,
+		/// The required input amount exceeds the allowed maximum.
+		SwapOverMax,
+		/// The token rate source cannot provide a token rate for the asset.
+		TokenRateSourceUnavailable,
+		/// The pool is not linked to a farming pool.
+		FarmingPoolNotLinked,
+		/// The farming pool does not stake the pool asset one to one.
+		FarmingPoolMismatch,
+		/// The unstaked pool asset is still locked by the farming pool.
+		FarmingWithdrawLocked,
+		/// An asset of the pool isn't active, so no liquidity can be added or swapped.
+		AssetNotActive,
+		/// More than `MaxRouterPools` router pools were given.
+		TooManyRouterPools,
 	}
 
 	#[pallet::call]
@@ -167,7 +214,8 @@ pub mod pallet {
 
 		/// Adds liquidity to an existing pool.
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_liquidity())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_liquidity()
+			.saturating_add(Pallet::<T>::refresh_token_rate_sources_weight(1)))]
 		pub fn add_liquidity(
 			origin: OriginFor<T>,
 			pool_id: StableAssetPoolId,
@@ -181,7 +229,8 @@ pub mod pallet {
 
 		/// Swaps one asset for another in a specified pool.
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::swap())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::swap()
+			.saturating_add(Pallet::<T>::refresh_token_rate_sources_weight(1)))]
 		pub fn swap(
 			origin: OriginFor<T>,
 			pool_id: StableAssetPoolId,
@@ -197,7 +246,8 @@ pub mod pallet {
 
 		/// Redeems a proportion of assets from a liquidity pool.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::redeem_proportion())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::redeem_proportion()
+			.saturating_add(Pallet::<T>::refresh_token_rate_sources_weight(1)))]
 		pub fn redeem_proportion(
 			origin: OriginFor<T>,
 			pool_id: StableAssetPoolId,
@@ -211,7 +261,8 @@ pub mod pallet {
 
 		/// Redeems a single asset from a liquidity pool.
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::redeem_single())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::redeem_single()
+			.saturating_add(Pallet::<T>::refresh_token_rate_sources_weight(1)))]
 		pub fn redeem_single(
 			origin: OriginFor<T>,
 			pool_id: StableAssetPoolId,
@@ -228,7 +279,8 @@ pub mod pallet {
 
 		/// Redeems multiple assets from a liquidity pool.
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::redeem_multi())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::redeem_multi()
+			.saturating_add(Pallet::<T>::refresh_token_rate_sources_weight(1)))]
 		pub fn redeem_multi(
 			origin: OriginFor<T>,
 			pool_id: StableAssetPoolId,
@@ -304,6 +356,130 @@ pub mod pallet {
 			})
 		}
 
+		/// Sets or clears the offpeg fee multiplier of a liquidity pool.
+		///
+		/// With a multiplier above `FeePrecision`, the swap fee rises as the pool moves away from
+		/// balance after the trade. `None` restores the fixed swap fee.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::modify_offpeg_fee_multiplier())]
+		pub fn modify_offpeg_fee_multiplier(
+			origin: OriginFor<T>,
+			pool_id: StableAssetPoolId,
+			offpeg_fee_multiplier: Option<T::AtLeast64BitUnsigned>,
+		) -> DispatchResult {
+			// Ensure the caller has the necessary control origin
+			T::ControlOrigin::ensure_origin(origin)?;
+			ensure!(
+				Pools::<T>::contains_key(pool_id),
+				bifrost_stable_asset::Error::<T>::PoolNotFound
+			);
+			let fee_denominator: T::AtLeast64BitUnsigned = T::FeePrecision::get();
+			ensure!(
+				offpeg_fee_multiplier.map(|x| x >= fee_denominator).unwrap_or(true),
+				bifrost_stable_asset::Error::<T>::ArgumentsError
+			);
+
+			match offpeg_fee_multiplier {
+				Some(multiplier) => OffpegFeeMultipliers::<T>::insert(pool_id, multiplier),
+				None => OffpegFeeMultipliers::<T>::remove(pool_id),
+			}
+
+			// Emit an event indicating that the offpeg fee multiplier has been modified
+			bifrost_stable_asset::Pallet::<T>::deposit_event(
+				bifrost_stable_asset::Event::<T>::OffpegFeeMultiplierModified {
+					pool_id,
+					offpeg_fee_multiplier,
+				},
+			);
+			Ok(())
+		}
+
+		/// Links the pool to the farming pool that stakes its pool asset, or unlinks it.
+		///
+		/// The farming pool must stake the pool asset one to one.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::modify_farming_pool())]
+		pub fn modify_farming_pool(
+			origin: OriginFor<T>,
+			pool_id: StableAssetPoolId,
+			farming_pool_id: Option<PoolId>,
+		) -> DispatchResult {
+			// Ensure the caller has the necessary control origin
+			T::ControlOrigin::ensure_origin(origin)?;
+			let pool_info = T::StableAsset::pool(pool_id)
+				.ok_or(bifrost_stable_asset::Error::<T>::PoolNotFound)?;
+
+			match farming_pool_id {
+				Some(farming_pool_id) => {
+					ensure!(
+						T::Farming::get_staking_token(farming_pool_id) ==
+							Some(pool_info.pool_asset),
+						Error::<T>::FarmingPoolMismatch
+					);
+					FarmingPools::<T>::insert(pool_id, farming_pool_id);
+				},
+				None => FarmingPools::<T>::remove(pool_id),
+			}
+
+			// Emit an event indicating that the farming pool has been modified
+			bifrost_stable_asset::Pallet::<T>::deposit_event(
+				bifrost_stable_asset::Event::<T>::FarmingPoolModified { pool_id, farming_pool_id },
+			);
+			Ok(())
+		}
+
+		/// Adds liquidity to the pool and stakes the minted pool asset into the linked farming
+		/// pool.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_liquidity_and_stake()
+			.saturating_add(Pallet::<T>::refresh_token_rate_sources_weight(1)))]
+		pub fn add_liquidity_and_stake(
+			origin: OriginFor<T>,
+			pool_id: StableAssetPoolId,
+			amounts: Vec<T::Balance>,
+			min_mint_amount: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::add_liquidity_inner(&who, pool_id, amounts, min_mint_amount, true)
+		}
+
+		/// Unstakes the pool asset from the linked farming pool and redeems it proportionally.
+		///
+		/// The farming pool must release the unstaked pool asset immediately.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unstake_and_redeem()
+			.saturating_add(Pallet::<T>::refresh_token_rate_sources_weight(1)))]
+		pub fn unstake_and_redeem(
+			origin: OriginFor<T>,
+			pool_id: StableAssetPoolId,
+			amount: T::Balance,
+			min_redeem_amounts: Vec<T::Balance>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::unstake_and_redeem_inner(&who, pool_id, amount, min_redeem_amounts)
+		}
+
+		/// Adds liquidity from a single asset.
+		///
+		/// Part of the deposit is first swapped into the other pool assets following the pool's
+		/// balance ratio, then everything is minted into the pool asset, which is optionally
+		/// staked into the linked farming pool.
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_liquidity_single()
+			.saturating_add(Pallet::<T>::refresh_token_rate_sources_weight(T::PoolAssetLimit::get())))]
+		pub fn add_liquidity_single(
+			origin: OriginFor<T>,
+			pool_id: StableAssetPoolId,
+			i: PoolTokenIndex,
+			amount: T::Balance,
+			min_mint_amount: T::Balance,
+			stake: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let amounts = Self::zap_amounts(&who, pool_id, i, amount)?;
+			Self::add_liquidity_inner(&who, pool_id, amounts, min_mint_amount, stake)
+		}
+
 		/// Modifies the fee and yield recipients of a liquidity pool.
 		#[pallet::call_index(8)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::modify_recipients())]
@@ -406,6 +582,180 @@ pub mod pallet {
 			);
 			Ok(())
 		}
+
+		/// Swaps an exact amount of `asset_in` for `asset_out`.
+		///
+		/// The route is searched on-chain through the router pools and Zenlink pairs between
+		/// their assets, taking at most `MaxSwapHops` hops, and the one with the largest output
+		/// is used.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::router_swap_exact_in(
+			T::MaxSwapHops::get(),
+			T::MaxRouterPools::get(),
+		)
+		.saturating_add(Pallet::<T>::refresh_token_rate_sources_weight(T::MaxSwapHops::get())))]
+		pub fn router_swap_exact_in(
+			origin: OriginFor<T>,
+			asset_in: AssetIdOf<T>,
+			asset_out: AssetIdOf<T>,
+			amount_in: T::Balance,
+			min_amount_out: T::Balance,
+			deadline: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= deadline,
+				Error::<T>::DeadlineExpired
+			);
+
+			let (route, _) = Self::find_best_route(asset_in, asset_out, amount_in, true)
+				.ok_or(Error::<T>::NoRouteFound)?;
+			Self::execute_route(&who, asset_in, asset_out, &route, amount_in, min_amount_out)
+		}
+
+		/// Swaps `asset_in` for an exact amount of `asset_out`.
+		///
+		/// The route requiring the least input is searched on-chain like for
+		/// `router_swap_exact_in`. The required input is quoted backwards along the route and
+		/// then raised until a forward quote covers `amount_out`, so per-hop rounding can not
+		/// make the swap fall short of `amount_out`.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::router_swap_exact_out(
+			T::MaxSwapHops::get(),
+			T::MaxRouterPools::get(),
+		)
+		.saturating_add(Pallet::<T>::refresh_token_rate_sources_weight(T::MaxSwapHops::get())))]
+		pub fn router_swap_exact_out(
+			origin: OriginFor<T>,
+			asset_in: AssetIdOf<T>,
+			asset_out: AssetIdOf<T>,
+			amount_out: T::Balance,
+			max_amount_in: T::Balance,
+			deadline: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= deadline,
+				Error::<T>::DeadlineExpired
+			);
+
+			let (route, _) = Self::find_best_route(asset_in, asset_out, amount_out, false)
+				.ok_or(Error::<T>::NoRouteFound)?;
+			let amount_in = Self::route_exact_out_input(&route, amount_out)?;
+			ensure!(amount_in <= max_amount_in, Error::<T>::SwapOverMax);
+			Self::execute_route(&who, asset_in, asset_out, &route, amount_in, amount_out)
+		}
+
+		/// Configures the source the token rate of a pool asset is refreshed from.
+		///
+		/// The token rate is refreshed from the source before each pool operation, and each
+		/// refresh may move it up or down by at most the `TokenRateHardcap` of the asset, which
+		/// is set to `hardcap`. The asset keeps a single hardcap, shared with the vToken
+		/// auto-refresh, so removing it also stops the refreshes from the source. Only an
+		/// authorized origin can call this function.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::config_token_rate_source())]
+		pub fn config_token_rate_source(
+			origin: OriginFor<T>,
+			pool_id: StableAssetPoolId,
+			asset: AssetIdOf<T>,
+			source: TokenRateSource<AssetIdOf<T>>,
+			hardcap: Permill,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			let pool_info = T::StableAsset::pool(pool_id)
+				.ok_or(bifrost_stable_asset::Error::<T>::PoolNotFound)?;
+			ensure!(
+				pool_info.assets.contains(&asset),
+				bifrost_stable_asset::Error::<T>::ArgumentsError
+			);
+			if source == TokenRateSource::VtokenMinting {
+				ensure!(
+					CurrencyId::is_vtoken(&asset.into()),
+					bifrost_stable_asset::Error::<T>::ArgumentsError
+				);
+			}
+			let token_rate = Self::get_source_token_rate(asset, source)
+				.ok_or(Error::<T>::TokenRateSourceUnavailable)?;
+
+			TokenRateSources::<T>::insert(pool_id, asset, source);
+			TokenRateHardcap::<T>::insert(asset, hardcap);
+			// Seed the token rate from the source if it has never been set.
+			if bifrost_stable_asset::Pallet::<T>::get_token_rate(pool_id, asset).is_none() {
+				bifrost_stable_asset::Pallet::<T>::set_token_rate(
+					pool_id,
+					sp_std::vec![(asset, token_rate)],
+				)?;
+			}
+
+			bifrost_stable_asset::Pallet::<T>::deposit_event(
+				bifrost_stable_asset::Event::<T>::TokenRateSourceConfigured {
+					pool_id,
+					asset,
+					source,
+					hardcap,
+				},
+			);
+			Ok(())
+		}
+
+		/// Removes the token rate source of a pool asset.
+		///
+		/// The cached token rate is kept and can still be edited by `edit_token_rate`.
+		/// Only an authorized origin can call this function.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_token_rate_source())]
+		pub fn remove_token_rate_source(
+			origin: OriginFor<T>,
+			pool_id: StableAssetPoolId,
+			asset: AssetIdOf<T>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				TokenRateSources::<T>::contains_key(pool_id, asset),
+				bifrost_stable_asset::Error::<T>::ArgumentsError
+			);
+			TokenRateSources::<T>::remove(pool_id, asset);
+
+			bifrost_stable_asset::Pallet::<T>::deposit_event(
+				bifrost_stable_asset::Event::<T>::TokenRateSourceRemoved { pool_id, asset },
+			);
+			Ok(())
+		}
+
+		/// Sets the pools routed swaps are searched through, replacing the previous ones.
+		///
+		/// At most `MaxRouterPools` pools can be set, which bounds the on-chain route search.
+		/// Only an authorized origin can call this function.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_router_pools(pool_ids.len() as u32))]
+		pub fn set_router_pools(
+			origin: OriginFor<T>,
+			mut pool_ids: Vec<StableAssetPoolId>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			pool_ids.sort();
+			pool_ids.dedup();
+			ensure!(
+				pool_ids.len() as u32 <= T::MaxRouterPools::get(),
+				Error::<T>::TooManyRouterPools
+			);
+			for pool_id in pool_ids.iter() {
+				ensure!(
+					Pools::<T>::contains_key(pool_id),
+					bifrost_stable_asset::Error::<T>::PoolNotFound
+				);
+			}
+			RouterPools::<T>::put(pool_ids.clone());
+
+			bifrost_stable_asset::Pallet::<T>::deposit_event(
+				bifrost_stable_asset::Event::<T>::RouterPoolsSet { pool_ids },
+			);
+			Ok(())
+		}
 	}
 }
 
@@ -423,7 +773,8 @@ impl<T: Config> Pallet<T> {
 					hardcap,
 				));
 			}
-		} else if let Some(hardcap) = Self::get_token_rate_hardcap(token_out) {
+		}
+		if let Some(hardcap) = Self::get_token_rate_hardcap(token_out) {
 			if T::CurrencyIdConversion::convert_to_token(token_out).ok() == Some(token_in) {
 				return Some((
 					token_out,
@@ -442,6 +793,7 @@ impl<T: Config> Pallet<T> {
 		vtoken_issuance: AtLeast64BitUnsignedOf<T>,
 		token_pool_amount: AtLeast64BitUnsignedOf<T>,
 		hardcap: Permill,
+		allow_decrease: bool,
 	) -> Option<()> {
 		if let Some((demoninator, numerator)) =
 			bifrost_stable_asset::Pallet::<T>::get_token_rate(pool_id, vtoken)
@@ -459,8 +811,13 @@ impl<T: Config> Pallet<T> {
 			let old_price = U256::from(fee_denominator)
 				.checked_mul(numerator_u256)?
 				.checked_div(demoninator_u256)?;
-			// Skip if the new price is less than old price.
-			if new_price <= delta.checked_add(old_price)? && new_price > old_price {
+			// Skip if the new price is less than old price, unless the source may lower it.
+			let within_hardcap = if new_price > old_price {
+				new_price <= delta.checked_add(old_price)?
+			} else {
+				allow_decrease && old_price <= delta.checked_add(new_price)?
+			};
+			if within_hardcap && new_price != old_price {
 				return bifrost_stable_asset::Pallet::<T>::set_token_rate(
 					pool_id,
 					sp_std::vec![(vtoken, (vtoken_issuance, token_pool_amount))],
@@ -478,6 +835,171 @@ impl<T: Config> Pallet<T> {
 		TokenRateHardcap::<T>::get(vtoken)
 	}
 
+	/// The weight of refreshing the token rates from their sources in `operations` pool
+	/// operations, each pool asset may have a source.
+	pub fn refresh_token_rate_sources_weight(operations: u32) -> Weight {
+		<T as pallet::Config>::WeightInfo::refresh_token_rate_source()
+			.saturating_mul(T::PoolAssetLimit::get().into())
+			.saturating_mul(operations.into())
+	}
+
+	/// Refreshes the token rates of the pool assets that have a token rate source configured.
+	pub(crate) fn refresh_token_rate_sources(pool_id: StableAssetPoolId) {
+		for (asset, source) in TokenRateSources::<T>::iter_prefix(pool_id) {
+			let refreshed = Self::get_token_rate_hardcap(asset).and_then(|hardcap| {
+				let (denominator, numerator) = Self::get_source_token_rate(asset, source)?;
+				Self::refresh_token_rate(pool_id, asset, denominator, numerator, hardcap, true)
+			});
+			if refreshed.is_none() {
+				bifrost_stable_asset::Pallet::<T>::deposit_event(
+					bifrost_stable_asset::Event::<T>::TokenRateRefreshFailed { pool_id },
+				)
+			}
+		}
+	}
+
+	/// Reads the token rate of `asset` as (denominator, numerator) from the given source.
+	pub fn get_source_token_rate(
+		asset: AssetIdOf<T>,
+		source: TokenRateSource<AssetIdOf<T>>,
+	) -> Option<(AtLeast64BitUnsignedOf<T>, AtLeast64BitUnsignedOf<T>)> {
+		let (denominator, numerator): (AtLeast64BitUnsignedOf<T>, AtLeast64BitUnsignedOf<T>) =
+			match source {
+				TokenRateSource::VtokenMinting => {
+					let token = T::CurrencyIdConversion::convert_to_token(asset).ok()?;
+					(
+						T::MultiCurrency::total_issuance(asset).into(),
+						T::VtokenMinting::get_token_pool(token).into(),
+					)
+				},
+				TokenRateSource::Oracle(reference) => {
+					let (asset_price, _) = T::OraclePriceProvider::get_price(&asset.into())?;
+					let (reference_price, _) =
+						T::OraclePriceProvider::get_price(&reference.into())?;
+					(reference_price.into_inner().into(), asset_price.into_inner().into())
+				},
+				TokenRateSource::LendMarket(underlying) => {
+					let market_status = T::LendMarket::get_market_status(underlying).ok()?;
+					(Rate::DIV.into(), market_status.exchange_rate.into_inner().into())
+				},
+			};
+		if denominator.is_zero() || numerator.is_zero() {
+			return None;
+		}
+		Some((denominator, numerator))
+	}
+
+	#[transactional]
+	fn add_liquidity_inner(
+		who: &AccountIdOf<T>,
+		pool_id: StableAssetPoolId,
+		amounts: Vec<T::Balance>,
+		min_mint_amount: T::Balance,
+		stake: bool,
+	) -> DispatchResult {
+		if !stake {
+			return Self::mint_inner(who, pool_id, amounts, min_mint_amount);
+		}
+
+		let farming_pool_id =
+			FarmingPools::<T>::get(pool_id).ok_or(Error::<T>::FarmingPoolNotLinked)?;
+		let pool_asset = T::StableAsset::pool(pool_id)
+			.ok_or(bifrost_stable_asset::Error::<T>::PoolNotFound)?
+			.pool_asset;
+		let balance_before = Self::balance_to_u128(T::MultiCurrency::free_balance(pool_asset, who));
+		Self::mint_inner(who, pool_id, amounts, min_mint_amount)?;
+		let balance_after = Self::balance_to_u128(T::MultiCurrency::free_balance(pool_asset, who));
+		let mint_amount = Self::u128_to_balance(balance_after.saturating_sub(balance_before));
+		T::Farming::deposit(who, farming_pool_id, mint_amount)
+	}
+
+	#[transactional]
+	fn unstake_and_redeem_inner(
+		who: &AccountIdOf<T>,
+		pool_id: StableAssetPoolId,
+		amount: T::Balance,
+		min_redeem_amounts: Vec<T::Balance>,
+	) -> DispatchResult {
+		let farming_pool_id =
+			FarmingPools::<T>::get(pool_id).ok_or(Error::<T>::FarmingPoolNotLinked)?;
+		let pool_asset = T::StableAsset::pool(pool_id)
+			.ok_or(bifrost_stable_asset::Error::<T>::PoolNotFound)?
+			.pool_asset;
+		let balance_before = Self::balance_to_u128(T::MultiCurrency::free_balance(pool_asset, who));
+		T::Farming::withdraw(who, farming_pool_id, amount)?;
+		let balance_after = Self::balance_to_u128(T::MultiCurrency::free_balance(pool_asset, who));
+		ensure!(
+			balance_after.saturating_sub(balance_before) >= Self::balance_to_u128(amount),
+			Error::<T>::FarmingWithdrawLocked
+		);
+		Self::redeem_proportion_inner(who, pool_id, amount, min_redeem_amounts)
+	}
+
+	/// Swaps part of `amount` of the `i`th pool asset into the other pool assets following the
+	/// pool's balance ratio, and returns the amounts to mint with.
+	#[transactional]
+	fn zap_amounts(
+		who: &AccountIdOf<T>,
+		pool_id: StableAssetPoolId,
+		i: PoolTokenIndex,
+		amount: T::Balance,
+	) -> Result<Vec<T::Balance>, DispatchError> {
+		let pool_info =
+			T::StableAsset::pool(pool_id).ok_or(bifrost_stable_asset::Error::<T>::PoolNotFound)?;
+		let i_usize = i as usize;
+		ensure!(i_usize < pool_info.assets.len(), bifrost_stable_asset::Error::<T>::ArgumentsError);
+		ensure!(!amount.is_zero(), bifrost_stable_asset::Error::<T>::ArgumentsError);
+
+		let total_balance = pool_info.balances.iter().fold(U256::zero(), |acc, balance| {
+			acc.saturating_add(U256::from(Self::balance_to_u128(*balance)))
+		});
+		let mut amounts = sp_std::vec![Zero::zero(); pool_info.assets.len()];
+		let mut remaining = Self::balance_to_u128(amount);
+		if !total_balance.is_zero() {
+			for (j, balance) in pool_info.balances.iter().enumerate() {
+				if j == i_usize {
+					continue;
+				}
+				let swap_amount: u128 = U256::from(Self::balance_to_u128(amount))
+					.saturating_mul(U256::from(Self::balance_to_u128(*balance)))
+					.checked_div(total_balance)
+					.and_then(|n| TryInto::<u128>::try_into(n).ok())
+					.ok_or(bifrost_stable_asset::Error::<T>::Math)?;
+				if swap_amount.is_zero() {
+					continue;
+				}
+				let asset_j = pool_info.assets[j];
+				let balance_before =
+					Self::balance_to_u128(T::MultiCurrency::free_balance(asset_j, who));
+				Self::on_swap(
+					who,
+					pool_id,
+					i,
+					j as PoolTokenIndex,
+					Self::u128_to_balance(swap_amount),
+					Zero::zero(),
+				)?;
+				let balance_after =
+					Self::balance_to_u128(T::MultiCurrency::free_balance(asset_j, who));
+				amounts[j] = Self::u128_to_balance(balance_after.saturating_sub(balance_before));
+				remaining = remaining.saturating_sub(swap_amount);
+			}
+		}
+		amounts[i_usize] = Self::u128_to_balance(remaining);
+		Ok(amounts)
+	}
+
+	/// Ensures all `assets` are active in the asset registry.
+	fn ensure_assets_active(assets: &[AssetIdOf<T>]) -> DispatchResult {
+		for asset in assets {
+			ensure!(
+				T::AssetIdMaps::get_asset_status((*asset).into()).is_active(),
+				Error::<T>::AssetNotActive
+			);
+		}
+		Ok(())
+	}
+
 	#[transactional]
 	fn mint_inner(
 		who: &AccountIdOf<T>,
@@ -485,8 +1007,10 @@ impl<T: Config> Pallet<T> {
 		mut amounts: Vec<T::Balance>,
 		min_mint_amount: T::Balance,
 	) -> DispatchResult {
+		Self::refresh_token_rate_sources(pool_id);
 		let mut pool_info =
 			T::StableAsset::pool(pool_id).ok_or(bifrost_stable_asset::Error::<T>::PoolNotFound)?;
+		Self::ensure_assets_active(&pool_info.assets)?;
 		let amounts_old = amounts.clone();
 		for (i, amount) in amounts.iter_mut().enumerate() {
 			*amount = Self::upscale(
@@ -571,6 +1095,7 @@ impl<T: Config> Pallet<T> {
 		amount: T::Balance,
 		min_redeem_amounts: Vec<T::Balance>,
 	) -> DispatchResult {
+		Self::refresh_token_rate_sources(pool_id);
 		let mut pool_info =
 			T::StableAsset::pool(pool_id).ok_or(bifrost_stable_asset::Error::<T>::PoolNotFound)?;
 		T::StableAsset::collect_yield(pool_id, &mut pool_info)?;
@@ -663,6 +1188,7 @@ impl<T: Config> Pallet<T> {
 		amounts: Vec<T::Balance>,
 		max_redeem_amount: T::Balance,
 	) -> DispatchResult {
+		Self::refresh_token_rate_sources(pool_id);
 		let mut pool_info =
 			T::StableAsset::pool(pool_id).ok_or(bifrost_stable_asset::Error::<T>::PoolNotFound)?;
 		T::StableAsset::collect_yield(pool_id, &mut pool_info)?;
@@ -744,6 +1270,7 @@ impl<T: Config> Pallet<T> {
 		min_redeem_amount: T::Balance,
 		asset_length: u32,
 	) -> Result<(T::Balance, T::Balance), DispatchError> {
+		Self::refresh_token_rate_sources(pool_id);
 		let mut pool_info =
 			T::StableAsset::pool(pool_id).ok_or(bifrost_stable_asset::Error::<T>::PoolNotFound)?;
 
@@ -813,6 +1340,7 @@ impl<T: Config> Pallet<T> {
 		amount: T::Balance,
 		min_dy: T::Balance,
 	) -> DispatchResult {
+		Self::refresh_token_rate_sources(pool_id);
 		let mut pool_info =
 			T::StableAsset::pool(pool_id).ok_or(bifrost_stable_asset::Error::<T>::PoolNotFound)?;
 
@@ -824,6 +1352,7 @@ impl<T: Config> Pallet<T> {
 			.assets
 			.get(currency_id_out as usize)
 			.ok_or(bifrost_stable_asset::Error::<T>::ArgumentsMismatch)?;
+		Self::ensure_assets_active(&[token_in, token_out])?;
 		T::StableAsset::collect_yield(pool_id, &mut pool_info)?;
 		let dx = Self::upscale(amount, pool_id, token_in)?;
 		let SwapResult { dx: _, dy, y, balance_i } =
@@ -881,6 +1410,7 @@ impl<T: Config> Pallet<T> {
 		);
 		if let Some((vtoken, vtoken_issuance, token_pool_amount, hardcap)) =
 			Self::ensure_can_refresh(token_in, token_out)
+				.filter(|(vtoken, ..)| !TokenRateSources::<T>::contains_key(pool_id, vtoken))
 		{
 			if Self::refresh_token_rate(
 				pool_id,
@@ -888,6 +1418,7 @@ impl<T: Config> Pallet<T> {
 				vtoken_issuance,
 				token_pool_amount,
 				hardcap,
+				false,
 			)
 			.is_none()
 			{
@@ -1042,6 +1573,324 @@ impl<T: Config> Pallet<T> {
 		Ok(mint_amount)
 	}
 
+	/// Quotes the best route from `asset_in` to `asset_out` and returns the output amount for
+	/// an exact input amount.
+	pub fn get_route_swap_output(
+		asset_in: AssetIdOf<T>,
+		asset_out: AssetIdOf<T>,
+		amount_in: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		Self::find_best_route(asset_in, asset_out, amount_in, true)
+			.map(|(_, amount_out)| amount_out)
+			.ok_or(Error::<T>::NoRouteFound.into())
+	}
+
+	/// Quotes the best route from `asset_in` to `asset_out` and returns the input amount
+	/// required for an exact output amount.
+	pub fn get_route_swap_input(
+		asset_in: AssetIdOf<T>,
+		asset_out: AssetIdOf<T>,
+		amount_out: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		let (route, _) = Self::find_best_route(asset_in, asset_out, amount_out, false)
+			.ok_or(Error::<T>::NoRouteFound)?;
+		Self::route_exact_out_input(&route, amount_out)
+	}
+
+	/// Quotes `route`. Returns the output amount for `exact_in`, otherwise the required input
+	/// amount. Returns `None` if a step of the route cannot be used.
+	pub fn quote_route(
+		route: &[SwapRouteStep<AssetIdOf<T>>],
+		amount: T::Balance,
+		exact_in: bool,
+	) -> Option<T::Balance> {
+		if exact_in {
+			route
+				.iter()
+				.try_fold(amount, |amount, step| Self::quote_route_step(step, amount, true))
+		} else {
+			route
+				.iter()
+				.rev()
+				.try_fold(amount, |amount, step| Self::quote_route_step(step, amount, false))
+		}
+	}
+
+	/// Returns an input amount whose forward quote along `route` covers `amount_out`.
+	///
+	/// Each backward quote rounds on its own, so the backward quote of the whole route may
+	/// fall a little short once swapped forward. The input is raised by the relative
+	/// shortfall until the forward quote covers `amount_out`, at most once per hop.
+	fn route_exact_out_input(
+		route: &[SwapRouteStep<AssetIdOf<T>>],
+		amount_out: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		let amount_out = Self::balance_to_u128(amount_out);
+		let mut amount_in = Self::balance_to_u128(
+			Self::quote_route(route, Self::u128_to_balance(amount_out), false)
+				.ok_or(Error::<T>::NoRouteFound)?,
+		);
+		for _ in 0..=route.len() {
+			let quoted = Self::balance_to_u128(
+				Self::quote_route(route, Self::u128_to_balance(amount_in), true)
+					.ok_or(Error::<T>::NoRouteFound)?,
+			);
+			if quoted >= amount_out {
+				return Ok(Self::u128_to_balance(amount_in));
+			}
+			let shortfall = amount_out.saturating_sub(quoted);
+			amount_in = amount_in
+				.saturating_add(amount_in.saturating_mul(shortfall) / quoted)
+				.saturating_add(1);
+		}
+		Err(Error::<T>::SwapUnderMin.into())
+	}
+
+	/// Finds the best route of at most `MaxSwapHops` hops across the router pools and Zenlink
+	/// pairs between their assets.
+	///
+	/// Only the at most `MaxRouterPools` router pools are searched, which keeps the search
+	/// bounded for the router calls.
+	///
+	/// For `exact_in` the route maximizing the output amount is returned together with that
+	/// amount, otherwise the route minimizing the required input amount is returned together
+	/// with that amount.
+	pub fn find_best_route(
+		asset_in: AssetIdOf<T>,
+		asset_out: AssetIdOf<T>,
+		amount: T::Balance,
+		exact_in: bool,
+	) -> Option<(Vec<SwapRouteStep<AssetIdOf<T>>>, T::Balance)> {
+		if asset_in == asset_out || amount == Zero::zero() {
+			return None;
+		}
+		let pools: Vec<(StableAssetPoolId, Vec<AssetIdOf<T>>)> = RouterPools::<T>::get()
+			.into_iter()
+			.filter_map(|pool_id| Some((pool_id, Pools::<T>::get(pool_id)?.assets)))
+			.collect();
+		let mut assets: Vec<AssetIdOf<T>> = pools
+			.iter()
+			.flat_map(|(_, pool_assets)| pool_assets.iter().copied())
+			.chain([asset_in, asset_out])
+			.collect();
+		assets.sort();
+		assets.dedup();
+
+		// Exact-out routes are searched backwards from the output asset.
+		let (start, target) = if exact_in { (asset_in, asset_out) } else { (asset_out, asset_in) };
+		let mut best = None;
+		Self::search_route(
+			&pools,
+			&assets,
+			start,
+			target,
+			amount,
+			exact_in,
+			T::MaxSwapHops::get(),
+			&mut sp_std::vec![start],
+			&mut Vec::new(),
+			&mut best,
+		);
+		best.map(|(mut route, amount)| {
+			if !exact_in {
+				route.reverse();
+			}
+			(route, amount)
+		})
+	}
+
+	#[allow(clippy::too_many_arguments)]
+	fn search_route(
+		pools: &[(StableAssetPoolId, Vec<AssetIdOf<T>>)],
+		assets: &[AssetIdOf<T>],
+		current: AssetIdOf<T>,
+		target: AssetIdOf<T>,
+		amount: T::Balance,
+		exact_in: bool,
+		hops_left: u32,
+		visited: &mut Vec<AssetIdOf<T>>,
+		route: &mut Vec<SwapRouteStep<AssetIdOf<T>>>,
+		best: &mut Option<(Vec<SwapRouteStep<AssetIdOf<T>>>, T::Balance)>,
+	) {
+		if hops_left == 0 {
+			return;
+		}
+		for next in assets.iter().copied() {
+			// The last hop has to reach the target.
+			if visited.contains(&next) || (hops_left == 1 && next != target) {
+				continue;
+			}
+			let (from, to) = if exact_in { (current, next) } else { (next, current) };
+			for step in Self::route_steps(pools, from, to) {
+				let quoted = match Self::quote_route_step(&step, amount, exact_in) {
+					Some(quoted) => quoted,
+					None => continue,
+				};
+				route.push(step);
+				if next == target {
+					let is_better = match best {
+						Some((_, best_amount)) =>
+							if exact_in {
+								quoted > *best_amount
+							} else {
+								quoted < *best_amount
+							},
+						None => true,
+					};
+					if is_better {
+						*best = Some((route.clone(), quoted));
+					}
+				} else {
+					visited.push(next);
+					Self::search_route(
+						pools,
+						assets,
+						next,
+						target,
+						quoted,
+						exact_in,
+						hops_left - 1,
+						visited,
+						route,
+						best,
+					);
+					visited.pop();
+				}
+				route.pop();
+			}
+		}
+	}
+
+	fn route_steps(
+		pools: &[(StableAssetPoolId, Vec<AssetIdOf<T>>)],
+		from: AssetIdOf<T>,
+		to: AssetIdOf<T>,
+	) -> Vec<SwapRouteStep<AssetIdOf<T>>> {
+		let mut steps: Vec<SwapRouteStep<AssetIdOf<T>>> = pools
+			.iter()
+			.filter_map(|(pool_id, assets)| {
+				let i = assets.iter().position(|asset| *asset == from)?;
+				let j = assets.iter().position(|asset| *asset == to)?;
+				Some(SwapRouteStep::StablePool(*pool_id, i as PoolTokenIndex, j as PoolTokenIndex))
+			})
+			.collect();
+		steps.push(SwapRouteStep::Zenlink(from, to));
+		steps
+	}
+
+	/// Quotes a single route step. Returns the output amount for `exact_in`, otherwise the
+	/// required input amount. Returns `None` if the step cannot be used.
+	fn quote_route_step(
+		step: &SwapRouteStep<AssetIdOf<T>>,
+		amount: T::Balance,
+		exact_in: bool,
+	) -> Option<T::Balance> {
+		let quoted = match *step {
+			SwapRouteStep::StablePool(pool_id, i, j) =>
+				if exact_in {
+					Self::get_swap_output(pool_id, i, j, amount).ok()?
+				} else {
+					Self::get_swap_input(pool_id, i, j, amount).ok()?
+				},
+			SwapRouteStep::Zenlink(asset_in, asset_out) => {
+				let path = [Self::zenlink_asset_id(asset_in)?, Self::zenlink_asset_id(asset_out)?];
+				let amount = Self::balance_to_u128(amount);
+				let quoted = if exact_in {
+					*T::DexOperator::get_amount_out_by_path(amount, &path).ok()?.last()?
+				} else {
+					*T::DexOperator::get_amount_in_by_path(amount, &path).ok()?.first()?
+				};
+				Self::u128_to_balance(quoted)
+			},
+		};
+		if quoted == Zero::zero() {
+			return None;
+		}
+		Some(quoted)
+	}
+
+	#[transactional]
+	fn execute_route(
+		who: &AccountIdOf<T>,
+		asset_in: AssetIdOf<T>,
+		asset_out: AssetIdOf<T>,
+		route: &[SwapRouteStep<AssetIdOf<T>>],
+		amount_in: T::Balance,
+		min_amount_out: T::Balance,
+	) -> DispatchResult {
+		let mut amount = amount_in;
+		for step in route {
+			amount = Self::execute_route_step(who, step, amount)?;
+		}
+		ensure!(amount >= min_amount_out, Error::<T>::SwapUnderMin);
+
+		bifrost_stable_asset::Pallet::<T>::deposit_event(
+			bifrost_stable_asset::Event::<T>::RouteSwapped {
+				swapper: who.clone(),
+				input_asset: asset_in,
+				output_asset: asset_out,
+				input_amount: amount_in,
+				min_output_amount: min_amount_out,
+				output_amount: amount,
+				hops: route.len() as u32,
+			},
+		);
+		Ok(())
+	}
+
+	/// Executes a single route step and returns the amount received by `who`.
+	fn execute_route_step(
+		who: &AccountIdOf<T>,
+		step: &SwapRouteStep<AssetIdOf<T>>,
+		amount: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		let asset_out = match *step {
+			SwapRouteStep::StablePool(pool_id, _, j) => *T::StableAsset::pool(pool_id)
+				.ok_or(bifrost_stable_asset::Error::<T>::PoolNotFound)?
+				.assets
+				.get(j as usize)
+				.ok_or(bifrost_stable_asset::Error::<T>::ArgumentsMismatch)?,
+			SwapRouteStep::Zenlink(_, asset_out) => asset_out,
+		};
+		let balance_before = Self::balance_to_u128(T::MultiCurrency::free_balance(asset_out, who));
+
+		match *step {
+			SwapRouteStep::StablePool(pool_id, i, j) =>
+				Self::on_swap(who, pool_id, i, j, amount, Zero::zero())?,
+			SwapRouteStep::Zenlink(asset_in, asset_out) => {
+				let path = [
+					Self::zenlink_asset_id(asset_in).ok_or(Error::<T>::NoRouteFound)?,
+					Self::zenlink_asset_id(asset_out).ok_or(Error::<T>::NoRouteFound)?,
+				];
+				T::DexOperator::inner_swap_exact_assets_for_assets(
+					who,
+					Self::balance_to_u128(amount),
+					Zero::zero(),
+					&path,
+					who,
+				)?;
+			},
+		}
+
+		let balance_after = Self::balance_to_u128(T::MultiCurrency::free_balance(asset_out, who));
+		Ok(Self::u128_to_balance(balance_after.saturating_sub(balance_before)))
+	}
+
+	fn zenlink_asset_id(currency_id: AssetIdOf<T>) -> Option<AssetId> {
+		let currency_id: CurrencyId = currency_id.into();
+		AssetId::try_convert_from(currency_id, T::ParachainId::get().into()).ok()
+	}
+
+	fn balance_to_u128(amount: T::Balance) -> AssetBalance {
+		let amount: AtLeast64BitUnsignedOf<T> = amount.into();
+		amount.saturated_into::<u128>()
+	}
+
+	fn u128_to_balance(amount: AssetBalance) -> T::Balance {
+		let amount: AtLeast64BitUnsignedOf<T> = amount.into();
+		amount.into()
+	}
+
 	fn get_pool_id(
 		currency_id_in: &AssetIdOf<T>,
 		currency_id_out: &AssetIdOf<T>,
@@ -23,20 +23,29 @@ pub use bifrost_primitives::{
 	GLMR, VBNC, VDOT,
 };
 use bifrost_primitives::{
-	BifrostEntranceAccount, BifrostExitAccount, IncentivePoolAccount, MoonbeamChainId,
-	StableAssetPalletId, KSM, KUSD,
+	BifrostEntranceAccount, BifrostExitAccount, FarmingOperator, IncentivePoolAccount,
+	MoonbeamChainId, OraclePriceProvider, PoolId, Price, PriceDetail, StableAssetPalletId,
+	ZenlinkPalletId, KSM, KUSD,
 };
 use bifrost_runtime_common::milli;
+use cumulus_primitives_core::ParaId;
 use frame_support::{
 	derive_impl, ord_parameter_types, parameter_types,
-	traits::{ConstU128, ConstU32, Everything, Nothing},
+	traits::{ConstU128, ConstU32, Everything, Get, Nothing},
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
-use orml_traits::{location::RelativeReserveProvider, parameter_type_with_key};
-use sp_runtime::{traits::IdentityLookup, BuildStorage};
+use orml_traits::{location::RelativeReserveProvider, parameter_type_with_key, MultiCurrency};
+use sp_runtime::{
+	traits::{IdentityLookup, UniqueSaturatedInto},
+	BuildStorage, DispatchError, DispatchResult, SaturatedConversion,
+};
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap, marker::PhantomData};
 use xcm::{prelude::*, v3::Weight};
 use xcm_builder::{FixedWeightBounds, FrameTransactionalProcessor};
 use xcm_executor::XcmExecutor;
+use zenlink_protocol::{
+	AssetBalance, AssetId as ZenlinkAssetId, LocalAssetHandler, PairLpGenerate, ZenlinkMultiAssets,
+};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -53,6 +62,7 @@ frame_support::construct_runtime!(
 		StableAsset: bifrost_stable_asset,
 		StablePool: bifrost_stable_pool,
 		VtokenMinting: bifrost_vtoken_minting,
+		ZenlinkProtocol: zenlink_protocol,
 	}
 );
 
@@ -110,6 +120,7 @@ pub type AdaptedBasicCurrency =
 
 impl bifrost_currencies::Config for Test {
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type WeightInfo = ();
@@ -251,6 +262,190 @@ impl bifrost_stable_pool::Config for Test {
 	type VtokenMinting = VtokenMinting;
 	type CurrencyIdConversion = AssetIdMaps<Test>;
 	type CurrencyIdRegister = AssetIdMaps<Test>;
+	type DexOperator = ZenlinkProtocol;
+	type ParachainId = ParachainId;
+	type MaxSwapHops = ConstU32<3>;
+	type MaxRouterPools = ConstU32<10>;
+	type OraclePriceProvider = MockOraclePriceProvider;
+	type LendMarket = ();
+	type Farming = MockFarming;
+	type AssetIdMaps = AssetIdMaps<Test>;
+}
+
+pub struct MockOraclePriceProvider;
+
+impl MockOraclePriceProvider {
+	thread_local! {
+		pub static PRICES: RefCell<BTreeMap<CurrencyId, Price>> = RefCell::new(BTreeMap::new());
+	}
+
+	pub fn set_price(asset_id: CurrencyId, price: Price) {
+		Self::PRICES.with(|prices| {
+			prices.borrow_mut().insert(asset_id, price);
+		});
+	}
+}
+
+impl OraclePriceProvider for MockOraclePriceProvider {
+	fn get_price(asset_id: &CurrencyId) -> Option<PriceDetail> {
+		Self::PRICES.with(|prices| prices.borrow().get(asset_id).map(|&price| (price, 1)))
+	}
+
+	fn get_amount_by_prices(
+		_currency_in: &CurrencyId,
+		_amount_in: Balance,
+		_currency_in_price: Price,
+		_currency_out: &CurrencyId,
+		_currency_out_price: Price,
+	) -> Option<Balance> {
+		None
+	}
+
+	fn get_oracle_amount_by_currency_and_amount_in(
+		_currency_in: &CurrencyId,
+		_amount_in: Balance,
+		_currency_out: &CurrencyId,
+	) -> Option<(Balance, Price, Price)> {
+		None
+	}
+}
+
+pub const FARMING_KEEPER: u128 = 100;
+
+pub struct MockFarming;
+
+impl MockFarming {
+	thread_local! {
+		pub static SHARES: RefCell<BTreeMap<(u128, PoolId), Balance>> = RefCell::new(BTreeMap::new());
+		pub static WITHDRAW_LOCKED: RefCell<bool> = RefCell::new(false);
+	}
+
+	pub fn shares(who: u128, pool_id: PoolId) -> Balance {
+		Self::SHARES.with(|shares| shares.borrow().get(&(who, pool_id)).copied().unwrap_or(0))
+	}
+
+	pub fn set_withdraw_locked(locked: bool) {
+		Self::WITHDRAW_LOCKED.with(|withdraw_locked| *withdraw_locked.borrow_mut() = locked);
+	}
+}
+
+impl FarmingOperator<u128, Balance, CurrencyId> for MockFarming {
+	fn get_staking_token(pool_id: PoolId) -> Option<CurrencyId> {
+		(pool_id == 0).then_some(CurrencyId::BLP(0))
+	}
+
+	fn deposit(who: &u128, pool_id: PoolId, amount: Balance) -> DispatchResult {
+		<Currencies as MultiCurrency<u128>>::transfer(
+			CurrencyId::BLP(0),
+			who,
+			&FARMING_KEEPER,
+			amount,
+		)?;
+		Self::SHARES.with(|shares| {
+			*shares.borrow_mut().entry((*who, pool_id)).or_default() += amount;
+		});
+		Ok(())
+	}
+
+	fn withdraw(who: &u128, pool_id: PoolId, amount: Balance) -> DispatchResult {
+		let share = Self::shares(*who, pool_id);
+		if share < amount {
+			return Err(DispatchError::Other("InvalidRemoveAmount"));
+		}
+		Self::SHARES.with(|shares| shares.borrow_mut().insert((*who, pool_id), share - amount));
+		if !Self::WITHDRAW_LOCKED.with(|withdraw_locked| *withdraw_locked.borrow()) {
+			<Currencies as MultiCurrency<u128>>::transfer(
+				CurrencyId::BLP(0),
+				&FARMING_KEEPER,
+				who,
+				amount,
+			)?;
+		}
+		Ok(())
+	}
+}
+
+pub struct ParachainId;
+impl Get<ParaId> for ParachainId {
+	fn get() -> ParaId {
+		2001.into()
+	}
+}
+
+parameter_types! {
+	pub const SelfParaId: u32 = 2001;
+}
+
+impl zenlink_protocol::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type MultiAssetsHandler = MultiAssets;
+	type PalletId = ZenlinkPalletId;
+	type SelfParaId = SelfParaId;
+	type TargetChains = ();
+	type WeightInfo = ();
+	type AssetId = ZenlinkAssetId;
+	type LpGenerate = PairLpGenerate<Self>;
+}
+
+type MultiAssets = ZenlinkMultiAssets<ZenlinkProtocol, Balances, LocalAssetAdaptor<Currencies>>;
+
+// Below is the implementation of tokens manipulation functions other than native token.
+pub struct LocalAssetAdaptor<Local>(PhantomData<Local>);
+
+impl<Local, AccountId> LocalAssetHandler<AccountId> for LocalAssetAdaptor<Local>
+where
+	Local: MultiCurrency<AccountId, CurrencyId = CurrencyId>,
+{
+	fn local_balance_of(asset_id: ZenlinkAssetId, who: &AccountId) -> AssetBalance {
+		let currency_id: CurrencyId = asset_id.try_into().unwrap();
+		Local::free_balance(currency_id, &who).saturated_into()
+	}
+
+	fn local_total_supply(asset_id: ZenlinkAssetId) -> AssetBalance {
+		let currency_id: CurrencyId = asset_id.try_into().unwrap();
+		Local::total_issuance(currency_id).saturated_into()
+	}
+
+	fn local_is_exists(asset_id: ZenlinkAssetId) -> bool {
+		let rs: Result<CurrencyId, _> = asset_id.try_into();
+		match rs {
+			Ok(_) => true,
+			Err(_) => false,
+		}
+	}
+
+	fn local_transfer(
+		asset_id: ZenlinkAssetId,
+		origin: &AccountId,
+		target: &AccountId,
+		amount: AssetBalance,
+	) -> DispatchResult {
+		let currency_id: CurrencyId = asset_id.try_into().unwrap();
+		Local::transfer(currency_id, &origin, &target, amount.unique_saturated_into())?;
+
+		Ok(())
+	}
+
+	fn local_deposit(
+		asset_id: ZenlinkAssetId,
+		origin: &AccountId,
+		amount: AssetBalance,
+	) -> Result<AssetBalance, DispatchError> {
+		let currency_id: CurrencyId = asset_id.try_into().unwrap();
+		Local::deposit(currency_id, &origin, amount.unique_saturated_into())?;
+		return Ok(amount);
+	}
+
+	fn local_withdraw(
+		asset_id: ZenlinkAssetId,
+		origin: &AccountId,
+		amount: AssetBalance,
+	) -> Result<AssetBalance, DispatchError> {
+		let currency_id: CurrencyId = asset_id.try_into().unwrap();
+		Local::withdraw(currency_id, &origin, amount.unique_saturated_into())?;
+
+		Ok(amount)
+	}
 }
 
 parameter_types! {
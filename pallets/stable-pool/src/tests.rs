@@ -15,12 +15,17 @@
 
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
-use crate::{mock::*, AssetIdOf, AtLeast64BitUnsignedOf, Error};
-use bifrost_primitives::{StableAssetPalletId, VtokenMintingOperator};
+use crate::{
+	mock::*, AssetIdOf, AtLeast64BitUnsignedOf, Error, RouterPools, SwapRouteStep, TokenRateSource,
+};
+use bifrost_asset_registry::AssetStatuses;
+use bifrost_primitives::{AssetStatus, StableAssetPalletId, VtokenMintingOperator};
 use bifrost_stable_asset::{PoolCount, Pools, StableAssetPoolInfo};
 use frame_support::{assert_noop, assert_ok, BoundedVec};
 use orml_traits::MultiCurrency;
-use sp_runtime::{traits::AccountIdConversion, Permill};
+use sp_runtime::{
+	traits::AccountIdConversion, DispatchError::BadOrigin, FixedPointNumber, FixedU128, Permill,
+};
 
 pub const BALANCE_OFF: u128 = 0;
 
@@ -800,6 +805,36 @@ fn bnc_add_liquidity_should_work() {
 	});
 }
 
+#[test]
+fn inactive_asset_should_not_add_liquidity_or_swap() {
+	ExtBuilder::default().new_test_ext().build().execute_with(|| {
+		assert_ok!(VtokenMinting::set_minimum_mint(RuntimeOrigin::signed(1), DOT, 0));
+		assert_ok!(VtokenMinting::mint(
+			Some(3).into(),
+			DOT,
+			100_000_000,
+			BoundedVec::default(),
+			None
+		));
+		let (_coin0, coin1, _pool_asset, _swap_id) = create_pool();
+		let amounts = vec![10000000u128, 20000000u128];
+
+		AssetStatuses::<Test>::insert(coin1, AssetStatus::Deprecated);
+		assert_noop!(
+			StablePool::add_liquidity(RuntimeOrigin::signed(3).into(), 0, amounts.clone(), 0),
+			Error::<Test>::AssetNotActive
+		);
+		assert_noop!(
+			StablePool::swap(RuntimeOrigin::signed(3).into(), 0, 0, 1, 5000000u128, 0),
+			Error::<Test>::AssetNotActive
+		);
+
+		AssetStatuses::<Test>::remove(coin1);
+		assert_ok!(StablePool::add_liquidity(RuntimeOrigin::signed(3).into(), 0, amounts, 0));
+		assert_ok!(StablePool::swap(RuntimeOrigin::signed(3).into(), 0, 0, 1, 5000000u128, 0));
+	});
+}
+
 #[test]
 fn edit_token_rate() {
 	ExtBuilder::default().new_test_ext().build().execute_with(|| {
@@ -1032,3 +1067,553 @@ fn remove_vtoken_auto_refresh_should_work() {
 		);
 	});
 }
+
+#[test]
+fn vtoken_minting_token_rate_source_should_work() {
+	ExtBuilder::default().new_test_ext().build().execute_with(|| {
+		let (coin0, coin1, _pool_asset, _swap_id) = init();
+		assert_ok!(StablePool::edit_token_rate(
+			RuntimeOrigin::root(),
+			0,
+			vec![(coin0, (1, 1)), (coin1, (1, 1))]
+		));
+		assert_ok!(StablePool::config_token_rate_source(
+			RuntimeOrigin::root(),
+			0,
+			VDOT,
+			TokenRateSource::VtokenMinting,
+			Permill::from_percent(10)
+		));
+
+		assert_ok!(<Test as crate::Config>::VtokenMinting::increase_token_pool(DOT, 1000));
+		assert_ok!(StablePool::on_swap(&3u128, 0, 0, 1, 5000000u128, 0));
+		assert_eq!(
+			bifrost_stable_asset::TokenRateCaches::<Test>::get(0, coin1),
+			Some((100000000, 100001000))
+		);
+	});
+}
+
+#[test]
+fn oracle_token_rate_source_should_work() {
+	ExtBuilder::default().new_test_ext().build().execute_with(|| {
+		let (coin0, coin1, _pool_asset, _swap_id) = init();
+		MockOraclePriceProvider::set_price(DOT, FixedU128::from_u32(1));
+		MockOraclePriceProvider::set_price(VDOT, FixedU128::saturating_from_rational(11, 10));
+
+		assert_ok!(StablePool::config_token_rate_source(
+			RuntimeOrigin::root(),
+			0,
+			VDOT,
+			TokenRateSource::Oracle(DOT),
+			Permill::from_percent(20)
+		));
+		// The token rate is seeded from the oracle and the hardcap is kept for the asset.
+		assert_eq!(
+			bifrost_stable_asset::TokenRateSources::<Test>::get(0, coin1),
+			Some(TokenRateSource::Oracle(DOT))
+		);
+		assert_eq!(
+			bifrost_stable_asset::TokenRateHardcap::<Test>::get(VDOT),
+			Some(Permill::from_percent(20))
+		);
+		assert_eq!(
+			bifrost_stable_asset::TokenRateCaches::<Test>::get(0, coin1),
+			Some((1_000_000_000_000_000_000, 1_100_000_000_000_000_000))
+		);
+
+		assert_ok!(StablePool::edit_token_rate(
+			RuntimeOrigin::root(),
+			0,
+			vec![(coin0, (1, 1)), (coin1, (1, 1))]
+		));
+		assert_ok!(StablePool::on_swap(&3u128, 0, 0, 1, 5000000u128, 0));
+		assert_eq!(
+			bifrost_stable_asset::TokenRateCaches::<Test>::get(0, coin1),
+			Some((1_000_000_000_000_000_000, 1_100_000_000_000_000_000))
+		);
+
+		// The source may also lower the token rate within the hardcap.
+		MockOraclePriceProvider::set_price(VDOT, FixedU128::from_u32(1));
+		assert_ok!(StablePool::on_swap(&3u128, 0, 0, 1, 5000000u128, 0));
+		assert_eq!(
+			bifrost_stable_asset::TokenRateCaches::<Test>::get(0, coin1),
+			Some((1_000_000_000_000_000_000, 1_000_000_000_000_000_000))
+		);
+
+		// Over the hardcap, the token rate is kept, in both directions.
+		MockOraclePriceProvider::set_price(VDOT, FixedU128::saturating_from_rational(3, 2));
+		System::reset_events();
+		assert_ok!(StablePool::on_swap(&3u128, 0, 0, 1, 5000000u128, 0));
+		assert_eq!(
+			bifrost_stable_asset::TokenRateCaches::<Test>::get(0, coin1),
+			Some((1_000_000_000_000_000_000, 1_000_000_000_000_000_000))
+		);
+		System::assert_has_event(RuntimeEvent::StableAsset(
+			bifrost_stable_asset::Event::TokenRateRefreshFailed { pool_id: 0 },
+		));
+		MockOraclePriceProvider::set_price(VDOT, FixedU128::saturating_from_rational(1, 2));
+		System::reset_events();
+		assert_ok!(StablePool::on_swap(&3u128, 0, 0, 1, 5000000u128, 0));
+		assert_eq!(
+			bifrost_stable_asset::TokenRateCaches::<Test>::get(0, coin1),
+			Some((1_000_000_000_000_000_000, 1_000_000_000_000_000_000))
+		);
+		System::assert_has_event(RuntimeEvent::StableAsset(
+			bifrost_stable_asset::Event::TokenRateRefreshFailed { pool_id: 0 },
+		));
+
+		// Without the hardcap of the asset, the source can not refresh the token rate.
+		assert_ok!(StablePool::remove_vtoken_auto_refresh(RuntimeOrigin::root(), VDOT));
+		MockOraclePriceProvider::set_price(VDOT, FixedU128::saturating_from_rational(11, 10));
+		System::reset_events();
+		assert_ok!(StablePool::on_swap(&3u128, 0, 0, 1, 5000000u128, 0));
+		assert_eq!(
+			bifrost_stable_asset::TokenRateCaches::<Test>::get(0, coin1),
+			Some((1_000_000_000_000_000_000, 1_000_000_000_000_000_000))
+		);
+		System::assert_has_event(RuntimeEvent::StableAsset(
+			bifrost_stable_asset::Event::TokenRateRefreshFailed { pool_id: 0 },
+		));
+
+		// Without a source, the token rate is no longer refreshed.
+		assert_ok!(StablePool::remove_token_rate_source(RuntimeOrigin::root(), 0, VDOT));
+		MockOraclePriceProvider::set_price(VDOT, FixedU128::saturating_from_rational(11, 10));
+		assert_ok!(StablePool::on_swap(&3u128, 0, 0, 1, 5000000u128, 0));
+		assert_eq!(
+			bifrost_stable_asset::TokenRateCaches::<Test>::get(0, coin1),
+			Some((1_000_000_000_000_000_000, 1_000_000_000_000_000_000))
+		);
+	});
+}
+
+#[test]
+fn config_token_rate_source_should_not_work() {
+	ExtBuilder::default().new_test_ext().build().execute_with(|| {
+		init();
+		assert_noop!(
+			StablePool::config_token_rate_source(
+				RuntimeOrigin::root(),
+				0,
+				DOT,
+				TokenRateSource::VtokenMinting,
+				Permill::from_percent(10)
+			),
+			bifrost_stable_asset::Error::<Test>::ArgumentsError
+		);
+		assert_noop!(
+			StablePool::config_token_rate_source(
+				RuntimeOrigin::root(),
+				0,
+				KSM,
+				TokenRateSource::Oracle(DOT),
+				Permill::from_percent(10)
+			),
+			bifrost_stable_asset::Error::<Test>::ArgumentsError
+		);
+		assert_noop!(
+			StablePool::config_token_rate_source(
+				RuntimeOrigin::root(),
+				0,
+				VDOT,
+				TokenRateSource::Oracle(DOT),
+				Permill::from_percent(10)
+			),
+			Error::<Test>::TokenRateSourceUnavailable
+		);
+		assert_noop!(
+			StablePool::config_token_rate_source(
+				RuntimeOrigin::root(),
+				0,
+				VDOT,
+				TokenRateSource::LendMarket(DOT),
+				Permill::from_percent(10)
+			),
+			Error::<Test>::TokenRateSourceUnavailable
+		);
+		assert_noop!(
+			StablePool::remove_token_rate_source(RuntimeOrigin::root(), 0, VDOT),
+			bifrost_stable_asset::Error::<Test>::ArgumentsError
+		);
+	});
+}
+
+#[test]
+fn offpeg_fee_multiplier_should_work() {
+	ExtBuilder::default().new_test_ext().build().execute_with(|| {
+		System::set_block_number(2);
+		assert_ok!(VtokenMinting::set_minimum_mint(RuntimeOrigin::signed(1), DOT, 0));
+		assert_ok!(VtokenMinting::mint(
+			Some(3).into(),
+			DOT,
+			100_000_000,
+			BoundedVec::default(),
+			None
+		));
+		let (coin0, coin1, _pool_asset, _swap_id) = create_pool();
+		assert_ok!(StablePool::edit_token_rate(
+			RuntimeOrigin::root(),
+			0,
+			vec![(coin0, (1, 1)), (coin1, (1, 1))]
+		));
+		assert_ok!(StablePool::mint_inner(&3, 0, vec![10000000u128, 20000000u128], 0));
+		let fixed_output = StablePool::get_swap_output(0, 1, 0, 5000000u128).unwrap();
+		let fixed_input = StablePool::get_swap_input(0, 1, 0, 5000000u128).unwrap();
+
+		assert_ok!(StablePool::modify_offpeg_fee_multiplier(
+			RuntimeOrigin::root(),
+			0,
+			Some(100_000_000_000u128)
+		));
+		assert_eq!(
+			bifrost_stable_asset::OffpegFeeMultipliers::<Test>::get(0),
+			Some(100_000_000_000u128)
+		);
+		// The fee of a balanced pool is the swap fee, and rises with the imbalance.
+		let x = 1_000_000_000_000_000_000u128;
+		assert_eq!(StableAsset::get_dynamic_fee(0, x, x, 20000000u128), Some(20000000u128));
+		assert_eq!(StableAsset::get_dynamic_fee(0, x, 3 * x, 20000000u128), Some(25806451u128));
+		assert_eq!(StableAsset::get_dynamic_fee(0, x, 9 * x, 20000000u128), Some(47169811u128));
+		// Draining the scarce DOT skews the pool further, so the quotes reflect a higher fee.
+		assert!(StablePool::get_swap_output(0, 1, 0, 5000000u128).unwrap() < fixed_output);
+		assert!(StablePool::get_swap_input(0, 1, 0, 5000000u128).unwrap() > fixed_input);
+
+		assert_ok!(StablePool::modify_offpeg_fee_multiplier(RuntimeOrigin::root(), 0, None));
+		assert_eq!(bifrost_stable_asset::OffpegFeeMultipliers::<Test>::get(0), None);
+		assert_eq!(StablePool::get_swap_output(0, 1, 0, 5000000u128).ok(), Some(fixed_output));
+		assert_eq!(StablePool::get_swap_input(0, 1, 0, 5000000u128).ok(), Some(fixed_input));
+	});
+}
+
+#[test]
+fn modify_offpeg_fee_multiplier_should_not_work() {
+	ExtBuilder::default().new_test_ext().build().execute_with(|| {
+		create_pool();
+		assert_noop!(
+			StablePool::modify_offpeg_fee_multiplier(
+				RuntimeOrigin::root(),
+				1,
+				Some(100_000_000_000u128)
+			),
+			bifrost_stable_asset::Error::<Test>::PoolNotFound
+		);
+		assert_noop!(
+			StablePool::modify_offpeg_fee_multiplier(
+				RuntimeOrigin::root(),
+				0,
+				Some(1_000_000_000u128)
+			),
+			bifrost_stable_asset::Error::<Test>::ArgumentsError
+		);
+	});
+}
+
+fn init_farming_pool() -> (CurrencyId, CurrencyId, CurrencyId) {
+	System::set_block_number(2);
+	assert_ok!(VtokenMinting::set_minimum_mint(RuntimeOrigin::signed(1), DOT, 0));
+	assert_ok!(VtokenMinting::mint(Some(3).into(), DOT, 100_000_000, BoundedVec::default(), None));
+	let (coin0, coin1, pool_asset, _swap_id) = create_pool();
+	assert_ok!(StablePool::edit_token_rate(
+		RuntimeOrigin::root(),
+		0,
+		vec![(coin0, (1, 1)), (coin1, (1, 1))]
+	));
+	assert_ok!(StablePool::modify_farming_pool(RuntimeOrigin::root(), 0, Some(0)));
+	(coin0, coin1, pool_asset)
+}
+
+#[test]
+fn add_liquidity_and_stake_should_work() {
+	ExtBuilder::default().new_test_ext().build().execute_with(|| {
+		let (coin0, _coin1, pool_asset) = init_farming_pool();
+		assert_eq!(bifrost_stable_asset::FarmingPools::<Test>::get(0), Some(0));
+
+		assert_ok!(StablePool::add_liquidity_and_stake(
+			RuntimeOrigin::signed(3),
+			0,
+			vec![10000000u128, 20000000u128],
+			0
+		));
+		let staked = Tokens::free_balance(pool_asset, &FARMING_KEEPER);
+		assert!(staked > 0);
+		assert_eq!(MockFarming::shares(3, 0), staked);
+		assert_eq!(Tokens::free_balance(pool_asset, &3), 0);
+
+		let dot_before = Tokens::free_balance(coin0, &3);
+		assert_ok!(StablePool::unstake_and_redeem(
+			RuntimeOrigin::signed(3),
+			0,
+			staked / 2,
+			vec![0, 0]
+		));
+		assert_eq!(MockFarming::shares(3, 0), staked - staked / 2);
+		assert_eq!(Tokens::free_balance(pool_asset, &3), 0);
+		assert!(Tokens::free_balance(coin0, &3) > dot_before);
+
+		MockFarming::set_withdraw_locked(true);
+		assert_noop!(
+			StablePool::unstake_and_redeem(RuntimeOrigin::signed(3), 0, 1000, vec![0, 0]),
+			Error::<Test>::FarmingWithdrawLocked
+		);
+	});
+}
+
+#[test]
+fn modify_farming_pool_should_not_work() {
+	ExtBuilder::default().new_test_ext().build().execute_with(|| {
+		create_pool();
+		assert_noop!(
+			StablePool::add_liquidity_and_stake(
+				RuntimeOrigin::signed(3),
+				0,
+				vec![10000000u128, 20000000u128],
+				0
+			),
+			Error::<Test>::FarmingPoolNotLinked
+		);
+		assert_noop!(
+			StablePool::modify_farming_pool(RuntimeOrigin::root(), 0, Some(1)),
+			Error::<Test>::FarmingPoolMismatch
+		);
+		assert_noop!(
+			StablePool::modify_farming_pool(RuntimeOrigin::root(), 1, Some(0)),
+			bifrost_stable_asset::Error::<Test>::PoolNotFound
+		);
+
+		assert_ok!(StablePool::modify_farming_pool(RuntimeOrigin::root(), 0, Some(0)));
+		assert_ok!(StablePool::modify_farming_pool(RuntimeOrigin::root(), 0, None));
+		assert_eq!(bifrost_stable_asset::FarmingPools::<Test>::get(0), None);
+	});
+}
+
+#[test]
+fn add_liquidity_single_should_work() {
+	ExtBuilder::default().new_test_ext().build().execute_with(|| {
+		let (coin0, coin1, pool_asset) = init_farming_pool();
+		assert_ok!(StablePool::add_liquidity(
+			RuntimeOrigin::signed(3),
+			0,
+			vec![10000000u128, 20000000u128],
+			0
+		));
+		let dot_before = Tokens::free_balance(coin0, &3);
+		let vdot_before = Tokens::free_balance(coin1, &3);
+		let lp_before = Tokens::free_balance(pool_asset, &3);
+
+		assert_ok!(StablePool::add_liquidity_single(
+			RuntimeOrigin::signed(3),
+			0,
+			0,
+			3000000u128,
+			0,
+			false
+		));
+		// Only DOT is spent, the swapped VDOT is minted as well.
+		assert_eq!(Tokens::free_balance(coin0, &3), dot_before - 3000000u128);
+		assert_eq!(Tokens::free_balance(coin1, &3), vdot_before);
+		let minted = Tokens::free_balance(pool_asset, &3) - lp_before;
+		assert!(minted > 0);
+
+		assert_ok!(StablePool::add_liquidity_single(
+			RuntimeOrigin::signed(3),
+			0,
+			1,
+			3000000u128,
+			0,
+			true
+		));
+		assert_eq!(Tokens::free_balance(coin0, &3), dot_before - 3000000u128);
+		assert_eq!(Tokens::free_balance(coin1, &3), vdot_before - 3000000u128);
+		assert_eq!(Tokens::free_balance(pool_asset, &3), lp_before + minted);
+		assert!(MockFarming::shares(3, 0) > 0);
+
+		assert_noop!(
+			StablePool::add_liquidity_single(RuntimeOrigin::signed(3), 0, 2, 3000000u128, 0, false),
+			bifrost_stable_asset::Error::<Test>::ArgumentsError
+		);
+	});
+}
+
+fn create_route_pools() {
+	for currency_id in [DOT, VDOT, KSM] {
+		assert_ok!(<Test as crate::Config>::MultiCurrency::deposit(
+			currency_id,
+			&6,
+			1_000_000_000_000u128
+		));
+	}
+	for (pool_id, assets) in [(0, vec![DOT, VDOT]), (1, vec![VDOT, KSM])] {
+		assert_ok!(StablePool::create_pool(
+			RuntimeOrigin::root(),
+			assets.clone(),
+			vec![1u128, 1u128],
+			0u128,
+			0u128,
+			0u128,
+			220u128,
+			5,
+			5,
+			1000000000000u128
+		));
+		assert_ok!(StablePool::edit_token_rate(
+			RuntimeOrigin::root(),
+			pool_id,
+			assets.iter().map(|currency_id| (*currency_id, (1, 1))).collect()
+		));
+		assert_ok!(StablePool::add_liquidity(
+			RuntimeOrigin::signed(6),
+			pool_id,
+			vec![100_000_000_000u128, 100_000_000_000u128],
+			0
+		));
+	}
+	assert_ok!(StablePool::set_router_pools(RuntimeOrigin::root(), vec![0, 1]));
+}
+
+#[test]
+fn router_swap_exact_in_should_work() {
+	ExtBuilder::default().new_test_ext().build().execute_with(|| {
+		create_route_pools();
+
+		let (route, amount_out) =
+			StablePool::find_best_route(DOT, KSM, 1_000_000_000u128, true).unwrap();
+		assert_eq!(
+			route,
+			vec![SwapRouteStep::StablePool(0, 0, 1), SwapRouteStep::StablePool(1, 0, 1)]
+		);
+		assert_eq!(StablePool::get_route_swap_output(DOT, KSM, 1_000_000_000u128), Ok(amount_out));
+
+		let dot_before = Tokens::free_balance(DOT, &6);
+		let vdot_before = Tokens::free_balance(VDOT, &6);
+		let ksm_before = Tokens::free_balance(KSM, &6);
+		assert_noop!(
+			StablePool::router_swap_exact_in(
+				RuntimeOrigin::signed(6),
+				DOT,
+				KSM,
+				1_000_000_000u128,
+				amount_out + 1,
+				100
+			),
+			Error::<Test>::SwapUnderMin
+		);
+		assert_ok!(StablePool::router_swap_exact_in(
+			RuntimeOrigin::signed(6),
+			DOT,
+			KSM,
+			1_000_000_000u128,
+			amount_out,
+			100
+		));
+		assert_eq!(Tokens::free_balance(DOT, &6), dot_before - 1_000_000_000u128);
+		assert_eq!(Tokens::free_balance(VDOT, &6), vdot_before);
+		assert_eq!(Tokens::free_balance(KSM, &6), ksm_before + amount_out);
+	});
+}
+
+#[test]
+fn router_swap_exact_out_should_work() {
+	ExtBuilder::default().new_test_ext().build().execute_with(|| {
+		create_route_pools();
+
+		let (route, _) = StablePool::find_best_route(DOT, KSM, 1_000_000_000u128, false).unwrap();
+		let amount_in = StablePool::get_route_swap_input(DOT, KSM, 1_000_000_000u128).unwrap();
+		assert!(amount_in > 1_000_000_000u128);
+		assert!(StablePool::quote_route(&route, amount_in, true).unwrap() >= 1_000_000_000u128);
+
+		let dot_before = Tokens::free_balance(DOT, &6);
+		let ksm_before = Tokens::free_balance(KSM, &6);
+		assert_noop!(
+			StablePool::router_swap_exact_out(
+				RuntimeOrigin::signed(6),
+				DOT,
+				KSM,
+				1_000_000_000u128,
+				amount_in - 1,
+				100
+			),
+			Error::<Test>::SwapOverMax
+		);
+		assert_ok!(StablePool::router_swap_exact_out(
+			RuntimeOrigin::signed(6),
+			DOT,
+			KSM,
+			1_000_000_000u128,
+			amount_in,
+			100
+		));
+		assert_eq!(Tokens::free_balance(DOT, &6), dot_before - amount_in);
+		assert!(Tokens::free_balance(KSM, &6) >= ksm_before + 1_000_000_000u128);
+	});
+}
+
+#[test]
+fn router_swap_should_fail_without_route_or_after_deadline() {
+	ExtBuilder::default().new_test_ext().build().execute_with(|| {
+		create_route_pools();
+		System::set_block_number(101);
+
+		assert_noop!(
+			StablePool::router_swap_exact_in(RuntimeOrigin::signed(6), DOT, KSM, 1_000u128, 0, 100),
+			Error::<Test>::DeadlineExpired
+		);
+		for (asset_in, asset_out) in [(DOT, DOT), (DOT, ASTR)] {
+			assert_noop!(
+				StablePool::router_swap_exact_in(
+					RuntimeOrigin::signed(6),
+					asset_in,
+					asset_out,
+					1_000u128,
+					0,
+					200
+				),
+				Error::<Test>::NoRouteFound
+			);
+			assert_noop!(
+				StablePool::router_swap_exact_out(
+					RuntimeOrigin::signed(6),
+					asset_in,
+					asset_out,
+					1_000u128,
+					u128::MAX,
+					200
+				),
+				Error::<Test>::NoRouteFound
+			);
+		}
+
+		// Pools that are not router pools are not searched.
+		assert_ok!(StablePool::set_router_pools(RuntimeOrigin::root(), vec![0]));
+		assert_eq!(
+			StablePool::find_best_route(DOT, VDOT, 1_000u128, true).map(|r| r.0),
+			Some(vec![SwapRouteStep::StablePool(0, 0, 1)])
+		);
+		assert_noop!(
+			StablePool::router_swap_exact_in(RuntimeOrigin::signed(6), DOT, KSM, 1_000u128, 0, 200),
+			Error::<Test>::NoRouteFound
+		);
+	});
+}
+
+#[test]
+fn set_router_pools_should_work() {
+	ExtBuilder::default().new_test_ext().build().execute_with(|| {
+		create_route_pools();
+
+		assert_noop!(StablePool::set_router_pools(RuntimeOrigin::signed(6), vec![0]), BadOrigin);
+		assert_noop!(
+			StablePool::set_router_pools(RuntimeOrigin::root(), vec![0, 2]),
+			bifrost_stable_asset::Error::<Test>::PoolNotFound
+		);
+		assert_noop!(
+			StablePool::set_router_pools(RuntimeOrigin::root(), (0..11).collect()),
+			Error::<Test>::TooManyRouterPools
+		);
+
+		assert_ok!(StablePool::set_router_pools(RuntimeOrigin::root(), vec![1, 0, 1]));
+		assert_eq!(RouterPools::<Test>::get(), vec![0, 1]);
+		System::assert_last_event(RuntimeEvent::StableAsset(
+			bifrost_stable_asset::Event::RouterPoolsSet { pool_ids: vec![0, 1] },
+		));
+	});
+}
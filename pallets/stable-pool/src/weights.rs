@@ -65,6 +65,17 @@ pub trait WeightInfo {
 	fn modify_a() -> Weight;
 	fn modify_fees() -> Weight;
 	fn modify_recipients() -> Weight;
+	fn router_swap_exact_in(n: u32, p: u32, ) -> Weight;
+	fn router_swap_exact_out(n: u32, p: u32, ) -> Weight;
+	fn config_token_rate_source() -> Weight;
+	fn remove_token_rate_source() -> Weight;
+	fn modify_offpeg_fee_multiplier() -> Weight;
+	fn modify_farming_pool() -> Weight;
+	fn add_liquidity_and_stake() -> Weight;
+	fn unstake_and_redeem() -> Weight;
+	fn add_liquidity_single() -> Weight;
+	fn refresh_token_rate_source() -> Weight;
+	fn set_router_pools(n: u32, ) -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -259,4 +270,255 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `StableAsset::Pools` (r:1 w:1)
+	/// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::TokenRateCaches` (r:2 w:0)
+	/// Proof: `StableAsset::TokenRateCaches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Tokens::Accounts` (r:3 w:3)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetRegistry::CurrencyMetadatas` (r:2 w:0)
+	/// Proof: `AssetRegistry::CurrencyMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::RouterPools` (r:1 w:0)
+	/// Proof: `StableAsset::RouterPools` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 3]`.
+	/// The range of component `p` is `[3, 10]`.
+	fn router_swap_exact_in(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2134 + n * (1025 ±0) + p * (512 ±0)`
+		//  Estimated: `6196 + n * (3598 ±0) + p * (2987 ±0)`
+		// Minimum execution time: 402_116_000 picoseconds.
+		Weight::from_parts(61_804_000, 6196)
+			// Standard Error: 48_210
+			.saturating_add(Weight::from_parts(347_902_000, 0).saturating_mul(n.into()))
+			// Standard Error: 21_547
+			.saturating_add(Weight::from_parts(4_812_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((9_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3598).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2987).saturating_mul(p.into()))
+	}
+	/// Storage: `StableAsset::Pools` (r:1 w:1)
+	/// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::TokenRateCaches` (r:2 w:0)
+	/// Proof: `StableAsset::TokenRateCaches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Tokens::Accounts` (r:3 w:3)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetRegistry::CurrencyMetadatas` (r:2 w:0)
+	/// Proof: `AssetRegistry::CurrencyMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::RouterPools` (r:1 w:0)
+	/// Proof: `StableAsset::RouterPools` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 3]`.
+	/// The range of component `p` is `[3, 10]`.
+	fn router_swap_exact_out(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2134 + n * (1025 ±0) + p * (512 ±0)`
+		//  Estimated: `6196 + n * (3598 ±0) + p * (2987 ±0)`
+		// Minimum execution time: 521_374_000 picoseconds.
+		Weight::from_parts(72_315_000, 6196)
+			// Standard Error: 48_210
+			.saturating_add(Weight::from_parts(452_188_000, 0).saturating_mul(n.into()))
+			// Standard Error: 21_547
+			.saturating_add(Weight::from_parts(6_245_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((9_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3598).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2987).saturating_mul(p.into()))
+	}
+	/// Storage: `StableAsset::Pools` (r:1 w:0)
+	/// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:0)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `VtokenMinting::TokenPool` (r:1 w:0)
+	/// Proof: `VtokenMinting::TokenPool` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::TokenRateCaches` (r:2 w:1)
+	/// Proof: `StableAsset::TokenRateCaches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::TokenRateHardcap` (r:0 w:1)
+	/// Proof: `StableAsset::TokenRateHardcap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::TokenRateSources` (r:0 w:1)
+	/// Proof: `StableAsset::TokenRateSources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn config_token_rate_source() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `663`
+		//  Estimated: `6128`
+		// Minimum execution time: 43_952_000 picoseconds.
+		Weight::from_parts(45_312_000, 6128)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `StableAsset::TokenRateSources` (r:1 w:1)
+	/// Proof: `StableAsset::TokenRateSources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_token_rate_source() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `148`
+		//  Estimated: `3613`
+		// Minimum execution time: 15_393_000 picoseconds.
+		Weight::from_parts(15_870_000, 3613)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StableAsset::Pools` (r:1 w:0)
+	/// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::OffpegFeeMultipliers` (r:0 w:1)
+	/// Proof: `StableAsset::OffpegFeeMultipliers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn modify_offpeg_fee_multiplier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `714`
+		//  Estimated: `4179`
+		// Minimum execution time: 17_657_000 picoseconds.
+		Weight::from_parts(18_204_000, 4179)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StableAsset::Pools` (r:1 w:0)
+	/// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Farming::PoolInfos` (r:1 w:0)
+	/// Proof: `Farming::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::FarmingPools` (r:0 w:1)
+	/// Proof: `StableAsset::FarmingPools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn modify_farming_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `811`
+		//  Estimated: `4276`
+		// Minimum execution time: 23_782_000 picoseconds.
+		Weight::from_parts(24_518_000, 4276)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StableAsset::FarmingPools` (r:1 w:0)
+	/// Proof: `StableAsset::FarmingPools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::Pools` (r:1 w:1)
+	/// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::TokenRateSources` (r:1 w:0)
+	/// Proof: `StableAsset::TokenRateSources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::TokenRateCaches` (r:2 w:0)
+	/// Proof: `StableAsset::TokenRateCaches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Tokens::Accounts` (r:6 w:6)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Farming::PoolInfos` (r:1 w:1)
+	/// Proof: `Farming::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Farming::SharesAndWithdrawnRewards` (r:1 w:1)
+	/// Proof: `Farming::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Farming::GaugePoolInfos` (r:1 w:0)
+	/// Proof: `Farming::GaugePoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_liquidity_and_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2614`
+		//  Estimated: `16530`
+		// Minimum execution time: 303_083_000 picoseconds.
+		Weight::from_parts(312_457_000, 16530)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `StableAsset::FarmingPools` (r:1 w:0)
+	/// Proof: `StableAsset::FarmingPools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::Pools` (r:1 w:1)
+	/// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::TokenRateSources` (r:1 w:0)
+	/// Proof: `StableAsset::TokenRateSources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::TokenRateCaches` (r:2 w:0)
+	/// Proof: `StableAsset::TokenRateCaches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Tokens::Accounts` (r:6 w:6)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Farming::PoolInfos` (r:1 w:1)
+	/// Proof: `Farming::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Farming::SharesAndWithdrawnRewards` (r:1 w:1)
+	/// Proof: `Farming::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Farming::GaugePoolInfos` (r:1 w:0)
+	/// Proof: `Farming::GaugePoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn unstake_and_redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2797`
+		//  Estimated: `16530`
+		// Minimum execution time: 328_793_000 picoseconds.
+		Weight::from_parts(338_962_000, 16530)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `StableAsset::Pools` (r:1 w:1)
+	/// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::TokenRateSources` (r:1 w:0)
+	/// Proof: `StableAsset::TokenRateSources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::TokenRateCaches` (r:2 w:0)
+	/// Proof: `StableAsset::TokenRateCaches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::TokenRateHardcap` (r:2 w:0)
+	/// Proof: `StableAsset::TokenRateHardcap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::OffpegFeeMultipliers` (r:1 w:0)
+	/// Proof: `StableAsset::OffpegFeeMultipliers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Tokens::Accounts` (r:5 w:5)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_liquidity_single() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1924`
+		//  Estimated: `13872`
+		// Minimum execution time: 278_492_000 picoseconds.
+		Weight::from_parts(287_106_000, 13872)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `StableAsset::TokenRateSources` (r:1 w:0)
+	/// Proof: `StableAsset::TokenRateSources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::TokenRateHardcap` (r:1 w:0)
+	/// Proof: `StableAsset::TokenRateHardcap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Prices::EmergencyPrice` (r:2 w:0)
+	/// Proof: `Prices::EmergencyPrice` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetRegistry::CurrencyMetadatas` (r:2 w:0)
+	/// Proof: `AssetRegistry::CurrencyMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Prices::PriceConfigs` (r:2 w:0)
+	/// Proof: `Prices::PriceConfigs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Prices::AggregatedPrices` (r:2 w:0)
+	/// Proof: `Prices::AggregatedPrices` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::TokenRateCaches` (r:1 w:1)
+	/// Proof: `StableAsset::TokenRateCaches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn refresh_token_rate_source() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1733`
+		//  Estimated: `6198`
+		// Minimum execution time: 40_048_000 picoseconds.
+		Weight::from_parts(41_287_000, 6198)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StableAsset::Pools` (r:10 w:0)
+	/// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StableAsset::RouterPools` (r:0 w:1)
+	/// Proof: `StableAsset::RouterPools` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 10]`.
+	fn set_router_pools(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (512 ±0)`
+		//  Estimated: `990 + n * (2987 ±0)`
+		// Minimum execution time: 8_135_000 picoseconds.
+		Weight::from_parts(9_021_000, 990)
+			// Standard Error: 6_402
+			.saturating_add(Weight::from_parts(4_310_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2987).saturating_mul(n.into()))
+	}
 }
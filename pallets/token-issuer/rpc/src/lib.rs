@@ -0,0 +1,107 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use bifrost_primitives::{Balance, BlockNumber, CurrencyId, IssuanceRecord};
+pub use bifrost_token_issuer_rpc_runtime_api::{self as runtime_api, TokenIssuerRuntimeApi};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{ErrorCode, ErrorObject},
+};
+use parity_scale_codec::Codec;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+#[rpc(client, server)]
+pub trait TokenIssuerRpcApi<BlockHash, AccountId> {
+	/// rpc method for getting a page of the issuance records of a currency, starting from
+	/// `from_id`
+	#[method(name = "token_issuer_getIssuanceRecords")]
+	fn get_issuance_records(
+		&self,
+		currency_id: CurrencyId,
+		from_id: u64,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(u64, IssuanceRecord<AccountId, NumberOrHex, BlockNumber>)>>;
+}
+
+#[derive(Clone, Debug)]
+pub struct TokenIssuerRpc<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> TokenIssuerRpc<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: PhantomData }
+	}
+}
+
+#[async_trait]
+impl<C, Block, AccountId> TokenIssuerRpcApiServer<<Block as BlockT>::Hash, AccountId>
+	for TokenIssuerRpc<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: TokenIssuerRuntimeApi<Block, AccountId>,
+	AccountId: Codec,
+{
+	fn get_issuance_records(
+		&self,
+		currency_id: CurrencyId,
+		from_id: u64,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(u64, IssuanceRecord<AccountId, NumberOrHex, BlockNumber>)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let rs: Result<Vec<(u64, IssuanceRecord<AccountId, Balance, BlockNumber>)>, _> =
+			api.get_issuance_records(at, currency_id, from_id, limit);
+
+		match rs {
+			Ok(records) => Ok(records
+				.into_iter()
+				.map(|(id, record)| {
+					(
+						id,
+						IssuanceRecord {
+							issuer: record.issuer,
+							dest: record.dest,
+							currency_id: record.currency_id,
+							amount: NumberOrHex::Hex(record.amount.into()),
+							reason: record.reason,
+							issued_at: record.issued_at,
+							approved: record.approved,
+						},
+					)
+				})
+				.collect()),
+			Err(e) => Err(ErrorObject::owned(
+				ErrorCode::InternalError.code(),
+				"Failed to get issuance records.",
+				Some(format!("{:?}", e)),
+			)),
+		}
+	}
+}
@@ -72,7 +72,12 @@ benchmarks! {
 
 		let original_balance = T::MultiCurrency::free_balance(currency_id, &caller);
 		let token_amount = BalanceOf::<T>::unique_saturated_from(1000u32 as u128);
-	}: _(RawOrigin::Signed(caller.clone()), caller.clone(), currency_id, token_amount)
+		let max_supply = BalanceOf::<T>::unique_saturated_from(u128::MAX);
+		TokenIssuer::<T>::set_max_supply(origin.clone(), currency_id, Some(max_supply))?;
+		let quota_period = BlockNumberFor::<T>::from(100u32);
+		TokenIssuer::<T>::set_issuer_quota(origin, currency_id, caller.clone(), Some((token_amount, quota_period)))?;
+		let reason = BoundedVec::try_from(vec![0u8; T::MaxReasonLength::get() as usize]).unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), caller.clone(), currency_id, token_amount, reason)
 	verify {
 		assert_eq!(T::MultiCurrency::free_balance(currency_id, &caller), token_amount + original_balance);
 	}
@@ -96,6 +101,78 @@ benchmarks! {
 		assert_eq!(T::MultiCurrency::free_balance(currency_id, &caller), caller_original_balance - transfer_token_amount);
 		assert_eq!(T::MultiCurrency::free_balance(currency_id, &receiver), transfer_token_amount+ receiver_original_balance);
 	}
+
+	set_max_supply {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let currency_id = CurrencyId::Token(TokenSymbol::KSM);
+		let max_supply = BalanceOf::<T>::unique_saturated_from(1000u32 as u128);
+		let call = Call::<T>::set_max_supply { currency_id, max_supply: Some(max_supply) };
+	}: {call.dispatch_bypass_filter(origin)?}
+
+	set_issuer_quota {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let issuer: T::AccountId = whitelisted_caller();
+		let currency_id = CurrencyId::Token(TokenSymbol::KSM);
+		let limit = BalanceOf::<T>::unique_saturated_from(1000u32 as u128);
+		let call = Call::<T>::set_issuer_quota { currency_id, issuer, quota: Some((limit, 100u32.into())) };
+	}: {call.dispatch_bypass_filter(origin)?}
+
+	set_approval_threshold {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let currency_id = CurrencyId::Token(TokenSymbol::KSM);
+		let threshold = BalanceOf::<T>::unique_saturated_from(1000u32 as u128);
+		let call = Call::<T>::set_approval_threshold { currency_id, threshold: Some(threshold) };
+	}: {call.dispatch_bypass_filter(origin)?}
+
+	approve_issue {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let issuance_id = create_issue_proposal::<T>(origin.clone())?;
+		let call = Call::<T>::approve_issue { issuance_id };
+	}: {call.dispatch_bypass_filter(origin)?}
+	verify {
+		assert!(IssuanceRecords::<T>::contains_key(issuance_id));
+	}
+
+	reject_issue {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let issuance_id = create_issue_proposal::<T>(origin.clone())?;
+		let call = Call::<T>::reject_issue { issuance_id };
+	}: {call.dispatch_bypass_filter(origin)?}
+	verify {
+		assert!(!IssueProposals::<T>::contains_key(issuance_id));
+	}
+
+	remove_expired_issue {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let issuance_id = create_issue_proposal::<T>(origin)?;
+		let expired_at = frame_system::Pallet::<T>::block_number() + T::ProposalExpiry::get() + 1u32.into();
+		frame_system::Pallet::<T>::set_block_number(expired_at);
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), issuance_id)
+	verify {
+		assert!(!IssueProposals::<T>::contains_key(issuance_id));
+	}
+}
+
+fn create_issue_proposal<T: Config>(
+	origin: <T as frame_system::Config>::RuntimeOrigin,
+) -> Result<u64, BenchmarkError> {
+	let caller: T::AccountId = whitelisted_caller();
+	let currency_id = CurrencyId::Token(TokenSymbol::KSM);
+	TokenIssuer::<T>::add_to_issue_whitelist(origin.clone(), currency_id, caller.clone())?;
+	TokenIssuer::<T>::set_approval_threshold(origin, currency_id, Some(Zero::zero()))?;
+
+	let issuance_id = NextIssuanceId::<T>::get();
+	let token_amount = BalanceOf::<T>::unique_saturated_from(1000u32 as u128);
+	let reason = BoundedVec::try_from(vec![0u8; T::MaxReasonLength::get() as usize]).unwrap();
+	TokenIssuer::<T>::issue(
+		RawOrigin::Signed(caller.clone()).into(),
+		caller,
+		currency_id,
+		token_amount,
+		reason,
+	)?;
+	Ok(issuance_id)
 }
 
 impl_benchmark_test_suite!(
@@ -22,11 +22,12 @@
 extern crate alloc;
 
 use alloc::{vec, vec::Vec};
-use bifrost_primitives::CurrencyId;
+use bifrost_primitives::{CurrencyId, IssuanceRecord};
 use frame_support::{ensure, pallet_prelude::*};
 use frame_system::pallet_prelude::*;
 use orml_traits::MultiCurrency;
 use sp_core::bounded::BoundedVec;
+use sp_runtime::{traits::Zero, Perbill};
 pub use weights::WeightInfo;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -42,6 +43,30 @@ type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<
 	<T as frame_system::Config>::AccountId,
 >>::Balance;
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+type IssuanceRecordOf<T> = IssuanceRecord<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+type IssueProposalOf<T> = IssueProposal<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+
+/// An issue above the approval threshold, waiting for the approval of `ControlOrigin`.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct IssueProposal<AccountId, Balance, BlockNumber> {
+	pub issuer: AccountId,
+	pub dest: AccountId,
+	pub currency_id: CurrencyId,
+	pub amount: Balance,
+	pub reason: Vec<u8>,
+	pub proposed_at: BlockNumber,
+}
+
+/// The rolling mint quota of an issuer. The used amount is replenished linearly, so that at
+/// most `limit` is issued within any `period` blocks.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct IssueQuota<Balance, BlockNumber> {
+	pub limit: Balance,
+	pub period: BlockNumber,
+	/// The amount counted against the limit at `updated_at`.
+	pub used: Balance,
+	pub updated_at: BlockNumber,
+}
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -61,6 +86,22 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type MaxLengthLimit: Get<u32>;
+
+		/// The max length of the reason of an issue
+		#[pallet::constant]
+		type MaxReasonLength: Get<u32>;
+
+		/// The max number of issuance records kept per currency, the oldest ones are pruned
+		#[pallet::constant]
+		type MaxIssuanceRecords: Get<u32>;
+
+		/// The max number of issue proposals of an issuer waiting for approval per currency
+		#[pallet::constant]
+		type MaxPendingProposals: Get<u32>;
+
+		/// The number of blocks after which an issue proposal expires and can't be approved
+		#[pallet::constant]
+		type ProposalExpiry: Get<BlockNumberFor<Self>>;
 	}
 
 	#[pallet::error]
@@ -75,6 +116,20 @@ pub mod pallet {
 		ConvertError,
 		/// Excceed the max length limit of BoundedVec
 		ExceedMaxLen,
+		/// The issue would exceed the max supply of the currency
+		ExceedMaxSupply,
+		/// The issue would exceed the mint quota of the issuer
+		ExceedIssuerQuota,
+		/// The quota period is zero
+		InvalidQuota,
+		/// The issue proposal doesn't exist
+		IssueProposalNotExist,
+		/// The issuer has too many issue proposals waiting for approval
+		TooManyProposals,
+		/// The issue proposal has expired
+		IssueProposalExpired,
+		/// The issue proposal hasn't expired yet
+		IssueProposalNotExpired,
 	}
 
 	#[pallet::event]
@@ -92,6 +147,21 @@ pub mod pallet {
 		Issued(T::AccountId, CurrencyId, BalanceOf<T>),
 		/// Token transferred success, \[origin, dest, currency_id, amount\]
 		Transferred(T::AccountId, T::AccountId, CurrencyId, BalanceOf<T>),
+		/// Issue above the approval threshold proposed, \[issuance_id, issuer, dest,
+		/// currency_id, amount\]
+		IssueProposed(u64, T::AccountId, T::AccountId, CurrencyId, BalanceOf<T>),
+		/// Issue proposal approved, \[issuance_id\]
+		IssueApproved(u64),
+		/// Issue proposal rejected, \[issuance_id\]
+		IssueRejected(u64),
+		/// Expired issue proposal removed, \[issuance_id\]
+		IssueExpired(u64),
+		/// Max supply set, \[currency_id, max_supply\]
+		MaxSupplySet(CurrencyId, Option<BalanceOf<T>>),
+		/// Issuer quota set, \[issuer, currency_id, (limit, period)\]
+		IssuerQuotaSet(T::AccountId, CurrencyId, Option<(BalanceOf<T>, BlockNumberFor<T>)>),
+		/// Approval threshold set, \[currency_id, threshold\]
+		ApprovalThresholdSet(CurrencyId, Option<BalanceOf<T>>),
 	}
 
 	/// The current storage version, we set to 2 our new version(after migrate stroage from vec t
@@ -108,6 +178,61 @@ pub mod pallet {
 	pub type TransferWhiteList<T: Config> =
 		StorageMap<_, Blake2_128Concat, CurrencyId, BoundedVec<AccountIdOf<T>, T::MaxLengthLimit>>;
 
+	/// The max total issuance of a currency that can be reached by issuing.
+	#[pallet::storage]
+	pub type MaxSupply<T: Config> = StorageMap<_, Blake2_128Concat, CurrencyId, BalanceOf<T>>;
+
+	/// The rolling mint quota of an issuer of a currency.
+	#[pallet::storage]
+	pub type IssuerQuotas<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		IssueQuota<BalanceOf<T>, BlockNumberFor<T>>,
+	>;
+
+	/// Issues of a currency above the threshold need the approval of `ControlOrigin`.
+	#[pallet::storage]
+	pub type ApprovalThresholds<T: Config> =
+		StorageMap<_, Blake2_128Concat, CurrencyId, BalanceOf<T>>;
+
+	/// The id of the next issuance, shared by proposals and records.
+	#[pallet::storage]
+	pub type NextIssuanceId<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// Issues waiting for approval, by issuance id.
+	#[pallet::storage]
+	pub type IssueProposals<T: Config> = StorageMap<_, Twox64Concat, u64, IssueProposalOf<T>>;
+
+	/// The number of issue proposals of an issuer waiting for approval per currency.
+	#[pallet::storage]
+	pub type PendingProposals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Records of the issuances, by issuance id. Only the latest `MaxIssuanceRecords` records of
+	/// each currency are kept.
+	#[pallet::storage]
+	pub type IssuanceRecords<T: Config> = StorageMap<_, Twox64Concat, u64, IssuanceRecordOf<T>>;
+
+	/// The ids of the issuance records of a currency, oldest first.
+	#[pallet::storage]
+	pub type CurrencyIssuances<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		BoundedVec<u64, T::MaxIssuanceRecords>,
+		ValueQuery,
+	>;
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -240,6 +365,9 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Issue some balance to an account. Issues above the approval threshold of the
+		/// currency are proposed instead, and minted once approved by `ControlOrigin` before
+		/// they expire. An issuer has at most `MaxPendingProposals` proposals per currency.
 		#[pallet::call_index(4)]
 		#[pallet::weight(T::WeightInfo::issue())]
 		pub fn issue(
@@ -247,6 +375,7 @@ pub mod pallet {
 			dest: AccountIdOf<T>,
 			currency_id: CurrencyId,
 			#[pallet::compact] amount: BalanceOf<T>,
+			reason: BoundedVec<u8, T::MaxReasonLength>,
 		) -> DispatchResult {
 			let issuer = ensure_signed(origin)?;
 
@@ -254,10 +383,57 @@ pub mod pallet {
 				IssueWhiteList::<T>::get(currency_id).ok_or(Error::<T>::NotAllowed)?;
 			ensure!(issue_whitelist.contains(&issuer), Error::<T>::NotAllowed);
 
-			T::MultiCurrency::deposit(currency_id, &dest, amount)?;
+			Self::ensure_within_max_supply(currency_id, amount)?;
 
-			Self::deposit_event(Event::Issued(dest, currency_id, amount));
-			Ok(())
+			let need_approval = ApprovalThresholds::<T>::get(currency_id)
+				.map_or(false, |threshold| amount > threshold);
+			if need_approval {
+				PendingProposals::<T>::try_mutate(currency_id, &issuer, |pending| {
+					ensure!(*pending < T::MaxPendingProposals::get(), Error::<T>::TooManyProposals);
+					*pending = pending.saturating_add(1);
+					Ok::<(), Error<T>>(())
+				})?;
+			}
+
+			let issuance_id = NextIssuanceId::<T>::mutate(|id| {
+				let issuance_id = *id;
+				*id = id.saturating_add(1);
+				issuance_id
+			});
+
+			if need_approval {
+				IssueProposals::<T>::insert(
+					issuance_id,
+					IssueProposal {
+						issuer: issuer.clone(),
+						dest: dest.clone(),
+						currency_id,
+						amount,
+						reason: reason.into_inner(),
+						proposed_at: frame_system::Pallet::<T>::block_number(),
+					},
+				);
+
+				Self::deposit_event(Event::IssueProposed(
+					issuance_id,
+					issuer,
+					dest,
+					currency_id,
+					amount,
+				));
+				return Ok(());
+			}
+
+			Self::update_issuer_quota(currency_id, &issuer, amount)?;
+			Self::do_issue(
+				issuance_id,
+				issuer,
+				dest,
+				currency_id,
+				amount,
+				reason.into_inner(),
+				false,
+			)
 		}
 
 		/// Destroy some balance from an account.
@@ -286,5 +462,234 @@ pub mod pallet {
 			Self::deposit_event(Event::Transferred(transferrer, dest, currency_id, amount));
 			Ok(())
 		}
+
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::set_max_supply())]
+		pub fn set_max_supply(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			max_supply: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			MaxSupply::<T>::set(currency_id, max_supply);
+
+			Self::deposit_event(Event::MaxSupplySet(currency_id, max_supply));
+			Ok(())
+		}
+
+		/// Set the max amount an issuer can issue within a period of blocks, or remove the
+		/// quota with `None`. Issues approved by `ControlOrigin` don't count against it.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::set_issuer_quota())]
+		pub fn set_issuer_quota(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			issuer: AccountIdOf<T>,
+			quota: Option<(BalanceOf<T>, BlockNumberFor<T>)>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			match quota {
+				Some((limit, period)) => {
+					ensure!(!period.is_zero(), Error::<T>::InvalidQuota);
+					IssuerQuotas::<T>::mutate(currency_id, &issuer, |issue_quota| {
+						// the amount already issued keeps counting against the new quota
+						let now = frame_system::Pallet::<T>::block_number();
+						let used = issue_quota
+							.as_ref()
+							.map(|issue_quota| Self::quota_used(issue_quota, now))
+							.unwrap_or_default();
+						*issue_quota = Some(IssueQuota { limit, period, used, updated_at: now });
+					});
+				},
+				None => IssuerQuotas::<T>::remove(currency_id, &issuer),
+			}
+
+			Self::deposit_event(Event::IssuerQuotaSet(issuer, currency_id, quota));
+			Ok(())
+		}
+
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_approval_threshold())]
+		pub fn set_approval_threshold(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			threshold: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			ApprovalThresholds::<T>::set(currency_id, threshold);
+
+			Self::deposit_event(Event::ApprovalThresholdSet(currency_id, threshold));
+			Ok(())
+		}
+
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::approve_issue())]
+		pub fn approve_issue(origin: OriginFor<T>, issuance_id: u64) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			let proposal = Self::remove_issue_proposal(issuance_id)?;
+			ensure!(!Self::is_expired(&proposal), Error::<T>::IssueProposalExpired);
+			Self::ensure_within_max_supply(proposal.currency_id, proposal.amount)?;
+
+			Self::do_issue(
+				issuance_id,
+				proposal.issuer,
+				proposal.dest,
+				proposal.currency_id,
+				proposal.amount,
+				proposal.reason,
+				true,
+			)?;
+
+			Self::deposit_event(Event::IssueApproved(issuance_id));
+			Ok(())
+		}
+
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::reject_issue())]
+		pub fn reject_issue(origin: OriginFor<T>, issuance_id: u64) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			Self::remove_issue_proposal(issuance_id)?;
+
+			Self::deposit_event(Event::IssueRejected(issuance_id));
+			Ok(())
+		}
+
+		/// Remove an expired issue proposal, freeing the room of its issuer. Anyone can remove
+		/// it.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::remove_expired_issue())]
+		pub fn remove_expired_issue(origin: OriginFor<T>, issuance_id: u64) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let proposal = Self::remove_issue_proposal(issuance_id)?;
+			ensure!(Self::is_expired(&proposal), Error::<T>::IssueProposalNotExpired);
+
+			Self::deposit_event(Event::IssueExpired(issuance_id));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The issuance records of a currency with their ids, ordered by id, starting from
+		/// `from_id`. At most `limit` records are returned.
+		pub fn get_issuance_records(
+			currency_id: CurrencyId,
+			from_id: u64,
+			limit: u32,
+		) -> Vec<(u64, IssuanceRecordOf<T>)> {
+			CurrencyIssuances::<T>::get(currency_id)
+				.into_iter()
+				.filter(|id| *id >= from_id)
+				.take(limit as usize)
+				.filter_map(|id| IssuanceRecords::<T>::get(id).map(|record| (id, record)))
+				.collect()
+		}
+
+		fn do_issue(
+			issuance_id: u64,
+			issuer: AccountIdOf<T>,
+			dest: AccountIdOf<T>,
+			currency_id: CurrencyId,
+			amount: BalanceOf<T>,
+			reason: Vec<u8>,
+			approved: bool,
+		) -> DispatchResult {
+			T::MultiCurrency::deposit(currency_id, &dest, amount)?;
+
+			CurrencyIssuances::<T>::mutate(currency_id, |issuance_ids| {
+				// Prune the oldest record of the currency to make room for the new one.
+				if issuance_ids.len() as u32 >= T::MaxIssuanceRecords::get() &&
+					!issuance_ids.is_empty()
+				{
+					IssuanceRecords::<T>::remove(issuance_ids.remove(0));
+				}
+				if issuance_ids.try_push(issuance_id).is_ok() {
+					IssuanceRecords::<T>::insert(
+						issuance_id,
+						IssuanceRecord {
+							issuer,
+							dest: dest.clone(),
+							currency_id,
+							amount,
+							reason,
+							issued_at: frame_system::Pallet::<T>::block_number(),
+							approved,
+						},
+					);
+				}
+			});
+
+			Self::deposit_event(Event::Issued(dest, currency_id, amount));
+			Ok(())
+		}
+
+		/// Remove an issue proposal, and release its room among the pending proposals of its
+		/// issuer.
+		fn remove_issue_proposal(issuance_id: u64) -> Result<IssueProposalOf<T>, DispatchError> {
+			let proposal =
+				IssueProposals::<T>::take(issuance_id).ok_or(Error::<T>::IssueProposalNotExist)?;
+			PendingProposals::<T>::mutate_exists(
+				proposal.currency_id,
+				&proposal.issuer,
+				|pending| {
+					*pending = pending
+						.map(|pending| pending.saturating_sub(1))
+						.filter(|pending| *pending > 0);
+				},
+			);
+			Ok(proposal)
+		}
+
+		fn is_expired(proposal: &IssueProposalOf<T>) -> bool {
+			frame_system::Pallet::<T>::block_number() >
+				proposal.proposed_at.saturating_add(T::ProposalExpiry::get())
+		}
+
+		fn ensure_within_max_supply(
+			currency_id: CurrencyId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			if let Some(max_supply) = MaxSupply::<T>::get(currency_id) {
+				ensure!(
+					T::MultiCurrency::total_issuance(currency_id).saturating_add(amount) <=
+						max_supply,
+					Error::<T>::ExceedMaxSupply
+				);
+			}
+			Ok(())
+		}
+
+		/// The amount counted against the quota at `now`, after replenishment.
+		fn quota_used(
+			issue_quota: &IssueQuota<BalanceOf<T>, BlockNumberFor<T>>,
+			now: BlockNumberFor<T>,
+		) -> BalanceOf<T> {
+			let elapsed = now.saturating_sub(issue_quota.updated_at).min(issue_quota.period);
+			let replenished =
+				Perbill::from_rational(elapsed, issue_quota.period) * issue_quota.limit;
+			issue_quota.used.saturating_sub(replenished)
+		}
+
+		fn update_issuer_quota(
+			currency_id: CurrencyId,
+			issuer: &AccountIdOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			IssuerQuotas::<T>::try_mutate(currency_id, issuer, |issue_quota| -> DispatchResult {
+				if let Some(issue_quota) = issue_quota {
+					let now = frame_system::Pallet::<T>::block_number();
+					let used = Self::quota_used(issue_quota, now).saturating_add(amount);
+					ensure!(used <= issue_quota.limit, Error::<T>::ExceedIssuerQuota);
+					issue_quota.used = used;
+					issue_quota.updated_at = now;
+				}
+				Ok(())
+			})
+		}
 	}
 }
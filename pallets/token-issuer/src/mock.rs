@@ -24,7 +24,11 @@ use bifrost_primitives::{
 	currency::{BNC, DOT, KSM, VDOT},
 	CurrencyId, ASG,
 };
-use frame_support::{derive_impl, parameter_types, traits::Nothing, PalletId};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{Everything, Nothing},
+	PalletId,
+};
 use frame_system::EnsureRoot;
 use sp_core::ConstU32;
 use sp_runtime::{
@@ -78,6 +82,7 @@ pub type AdaptedBasicCurrency =
 
 impl bifrost_currencies::Config for Runtime {
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type WeightInfo = ();
@@ -149,6 +154,10 @@ impl pallet_collective::Config<CouncilCollective> for Runtime {
 
 parameter_types! {
 	pub const MaxLengthLimit: u32 = 100;
+	pub const MaxReasonLength: u32 = 64;
+	pub const MaxIssuanceRecords: u32 = 3;
+	pub const MaxPendingProposals: u32 = 2;
+	pub const ProposalExpiry: BlockNumber = 10;
 }
 
 impl bifrost_token_issuer::Config for Runtime {
@@ -158,6 +167,10 @@ impl bifrost_token_issuer::Config for Runtime {
 		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
 	type WeightInfo = ();
 	type MaxLengthLimit = MaxLengthLimit;
+	type MaxReasonLength = MaxReasonLength;
+	type MaxIssuanceRecords = MaxIssuanceRecords;
+	type MaxPendingProposals = MaxPendingProposals;
+	type ProposalExpiry = ProposalExpiry;
 }
 
 pub struct ExtBuilder {
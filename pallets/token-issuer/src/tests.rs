@@ -31,7 +31,13 @@ fn initialize_charlie_as_issue_whitelist_member() {
 		CHARLIE
 	));
 	// Issue some ZLK to Charlie's account
-	assert_ok!(TokenIssuer::issue(RuntimeOrigin::signed(CHARLIE), CHARLIE, ZLK, 1000));
+	assert_ok!(TokenIssuer::issue(
+		RuntimeOrigin::signed(CHARLIE),
+		CHARLIE,
+		ZLK,
+		1000,
+		BoundedVec::default()
+	));
 }
 
 #[test]
@@ -39,7 +45,13 @@ fn add_to_issue_whitelist_should_work() {
 	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
 		// Charlie is not allowed to issue ZLK.
 		assert_noop!(
-			TokenIssuer::issue(RuntimeOrigin::signed(CHARLIE), ALICE, ZLK, 800),
+			TokenIssuer::issue(
+				RuntimeOrigin::signed(CHARLIE),
+				ALICE,
+				ZLK,
+				800,
+				BoundedVec::default()
+			),
 			Error::<Runtime>::NotAllowed
 		);
 		// Chalie is added to the issue whitelist to have the ability of issuing ZLK.
@@ -52,7 +64,13 @@ fn add_to_issue_whitelist_should_work() {
 		let bounded_list = BoundedVec::try_from(vec![CHARLIE]).unwrap();
 		assert_eq!(IssueWhiteList::<Runtime>::get(ZLK), Some(bounded_list));
 		// Charlie succuessfully issue 800 unit of ZLK to Alice account
-		assert_ok!(TokenIssuer::issue(RuntimeOrigin::signed(CHARLIE), ALICE, ZLK, 800));
+		assert_ok!(TokenIssuer::issue(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			ZLK,
+			800,
+			BoundedVec::default()
+		));
 		assert_eq!(Tokens::free_balance(ZLK, &ALICE), 800);
 	});
 }
@@ -77,7 +95,13 @@ fn remove_from_issue_whitelist_should_work() {
 		));
 
 		// Charlie succuessfully issue 800 unit of ZLK to Alice account
-		assert_ok!(TokenIssuer::issue(RuntimeOrigin::signed(CHARLIE), ALICE, ZLK, 800));
+		assert_ok!(TokenIssuer::issue(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			ZLK,
+			800,
+			BoundedVec::default()
+		));
 		assert_eq!(Tokens::free_balance(ZLK, &ALICE), 800);
 
 		// Successfully remove Charlie
@@ -88,7 +112,13 @@ fn remove_from_issue_whitelist_should_work() {
 		));
 		// Charlie is no longer able to issue token to any account
 		assert_noop!(
-			TokenIssuer::issue(RuntimeOrigin::signed(CHARLIE), ALICE, ZLK, 800),
+			TokenIssuer::issue(
+				RuntimeOrigin::signed(CHARLIE),
+				ALICE,
+				ZLK,
+				800,
+				BoundedVec::default()
+			),
 			Error::<Runtime>::NotAllowed
 		);
 	});
@@ -162,3 +192,293 @@ fn remove_from_transfer_whitelist_should_work() {
 		);
 	});
 }
+
+#[test]
+fn issue_should_respect_max_supply() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		initialize_charlie_as_issue_whitelist_member();
+
+		assert_noop!(
+			TokenIssuer::set_max_supply(RuntimeOrigin::signed(CHARLIE), ZLK, Some(1500)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(TokenIssuer::set_max_supply(
+			pallet_collective::RawOrigin::Members(2, 3).into(),
+			ZLK,
+			Some(1500)
+		));
+
+		// 1000 ZLK has been issued to Charlie
+		assert_noop!(
+			TokenIssuer::issue(
+				RuntimeOrigin::signed(CHARLIE),
+				ALICE,
+				ZLK,
+				501,
+				BoundedVec::default()
+			),
+			Error::<Runtime>::ExceedMaxSupply
+		);
+		assert_ok!(TokenIssuer::issue(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			ZLK,
+			500,
+			BoundedVec::default()
+		));
+		assert_eq!(Tokens::total_issuance(ZLK), 1500);
+
+		// remove the cap
+		assert_ok!(TokenIssuer::set_max_supply(
+			pallet_collective::RawOrigin::Members(2, 3).into(),
+			ZLK,
+			None
+		));
+		assert_ok!(TokenIssuer::issue(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			ZLK,
+			500,
+			BoundedVec::default()
+		));
+	});
+}
+
+#[test]
+fn issue_should_respect_issuer_quota() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		initialize_charlie_as_issue_whitelist_member();
+		System::set_block_number(1);
+
+		assert_noop!(
+			TokenIssuer::set_issuer_quota(
+				pallet_collective::RawOrigin::Members(2, 3).into(),
+				ZLK,
+				CHARLIE,
+				Some((1000, 0))
+			),
+			Error::<Runtime>::InvalidQuota
+		);
+		assert_ok!(TokenIssuer::set_issuer_quota(
+			pallet_collective::RawOrigin::Members(2, 3).into(),
+			ZLK,
+			CHARLIE,
+			Some((1000, 100))
+		));
+
+		assert_ok!(TokenIssuer::issue(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			ZLK,
+			800,
+			BoundedVec::default()
+		));
+		assert_noop!(
+			TokenIssuer::issue(
+				RuntimeOrigin::signed(CHARLIE),
+				ALICE,
+				ZLK,
+				201,
+				BoundedVec::default()
+			),
+			Error::<Runtime>::ExceedIssuerQuota
+		);
+
+		// half of the quota is replenished after half of the period
+		System::set_block_number(51);
+		assert_ok!(TokenIssuer::issue(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			ZLK,
+			700,
+			BoundedVec::default()
+		));
+		assert_eq!(
+			IssuerQuotas::<Runtime>::get(ZLK, CHARLIE),
+			Some(IssueQuota { limit: 1000, period: 100, used: 1000, updated_at: 51 })
+		);
+		assert_eq!(Tokens::free_balance(ZLK, &ALICE), 1500);
+	});
+}
+
+#[test]
+fn issue_above_approval_threshold_should_need_approval() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		initialize_charlie_as_issue_whitelist_member();
+		System::set_block_number(1);
+
+		assert_ok!(TokenIssuer::set_approval_threshold(
+			pallet_collective::RawOrigin::Members(2, 3).into(),
+			ZLK,
+			Some(500)
+		));
+		assert_ok!(TokenIssuer::set_issuer_quota(
+			pallet_collective::RawOrigin::Members(2, 3).into(),
+			ZLK,
+			CHARLIE,
+			Some((500, 100))
+		));
+
+		let reason: BoundedVec<u8, MaxReasonLength> =
+			b"liquidity incentives".to_vec().try_into().unwrap();
+		// the issue of the whitelist initialization has taken the id 0
+		assert_ok!(TokenIssuer::issue(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			ZLK,
+			800,
+			reason.clone()
+		));
+		assert_ok!(TokenIssuer::issue(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			ZLK,
+			600,
+			BoundedVec::default()
+		));
+		assert_eq!(Tokens::free_balance(ZLK, &ALICE), 0);
+		assert!(IssueProposals::<Runtime>::contains_key(1));
+		assert!(IssueProposals::<Runtime>::contains_key(2));
+
+		assert_noop!(
+			TokenIssuer::approve_issue(RuntimeOrigin::signed(CHARLIE), 1),
+			DispatchError::BadOrigin
+		);
+		// approved issues don't count against the quota of the issuer
+		assert_ok!(TokenIssuer::approve_issue(
+			pallet_collective::RawOrigin::Members(2, 3).into(),
+			1
+		));
+		assert_eq!(Tokens::free_balance(ZLK, &ALICE), 800);
+		assert_ok!(TokenIssuer::reject_issue(
+			pallet_collective::RawOrigin::Members(2, 3).into(),
+			2
+		));
+		assert_noop!(
+			TokenIssuer::approve_issue(pallet_collective::RawOrigin::Members(2, 3).into(), 2),
+			Error::<Runtime>::IssueProposalNotExist
+		);
+
+		assert_ok!(TokenIssuer::issue(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			ZLK,
+			500,
+			BoundedVec::default()
+		));
+		assert_eq!(Tokens::free_balance(ZLK, &ALICE), 1300);
+
+		let records = TokenIssuer::get_issuance_records(ZLK, 0, 10);
+		assert_eq!(records.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![0, 1, 3]);
+		assert_eq!(
+			records[1].1,
+			IssuanceRecord {
+				issuer: CHARLIE,
+				dest: ALICE,
+				currency_id: ZLK,
+				amount: 800,
+				reason: reason.into_inner(),
+				issued_at: 1,
+				approved: true,
+			}
+		);
+		assert!(!records[2].1.approved);
+	});
+}
+
+#[test]
+fn issue_proposals_should_be_bounded_and_expire() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		initialize_charlie_as_issue_whitelist_member();
+		System::set_block_number(1);
+		assert_ok!(TokenIssuer::set_approval_threshold(
+			pallet_collective::RawOrigin::Members(2, 3).into(),
+			ZLK,
+			Some(500)
+		));
+
+		// the issue of the whitelist initialization has taken the id 0
+		for _ in 0..2 {
+			assert_ok!(TokenIssuer::issue(
+				RuntimeOrigin::signed(CHARLIE),
+				ALICE,
+				ZLK,
+				600,
+				BoundedVec::default()
+			));
+		}
+		assert_eq!(PendingProposals::<Runtime>::get(ZLK, CHARLIE), 2);
+		assert_noop!(
+			TokenIssuer::issue(
+				RuntimeOrigin::signed(CHARLIE),
+				ALICE,
+				ZLK,
+				600,
+				BoundedVec::default()
+			),
+			Error::<Runtime>::TooManyProposals
+		);
+		assert_noop!(
+			TokenIssuer::remove_expired_issue(RuntimeOrigin::signed(ALICE), 1),
+			Error::<Runtime>::IssueProposalNotExpired
+		);
+
+		// the proposals expire after `ProposalExpiry` blocks
+		System::set_block_number(12);
+		assert_noop!(
+			TokenIssuer::approve_issue(pallet_collective::RawOrigin::Members(2, 3).into(), 1),
+			Error::<Runtime>::IssueProposalExpired
+		);
+		assert_ok!(TokenIssuer::remove_expired_issue(RuntimeOrigin::signed(ALICE), 1));
+		System::assert_last_event(RuntimeEvent::TokenIssuer(Event::IssueExpired(1)));
+		assert!(!IssueProposals::<Runtime>::contains_key(1));
+		assert_eq!(PendingProposals::<Runtime>::get(ZLK, CHARLIE), 1);
+
+		assert_ok!(TokenIssuer::issue(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			ZLK,
+			600,
+			BoundedVec::default()
+		));
+		assert_ok!(TokenIssuer::reject_issue(
+			pallet_collective::RawOrigin::Members(2, 3).into(),
+			2
+		));
+		assert_eq!(PendingProposals::<Runtime>::get(ZLK, CHARLIE), 1);
+	});
+}
+
+#[test]
+fn issuance_records_should_be_bounded_and_paginated() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		initialize_charlie_as_issue_whitelist_member();
+
+		for amount in 1..=4 {
+			assert_ok!(TokenIssuer::issue(
+				RuntimeOrigin::signed(CHARLIE),
+				ALICE,
+				ZLK,
+				amount,
+				BoundedVec::default()
+			));
+		}
+
+		// only the latest `MaxIssuanceRecords` records are kept
+		assert_eq!(CurrencyIssuances::<Runtime>::get(ZLK).into_inner(), vec![2, 3, 4]);
+		assert!(!IssuanceRecords::<Runtime>::contains_key(0));
+		assert!(!IssuanceRecords::<Runtime>::contains_key(1));
+
+		let ids = |from_id, limit| {
+			TokenIssuer::get_issuance_records(ZLK, from_id, limit)
+				.into_iter()
+				.map(|(id, _)| id)
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(ids(0, 10), vec![2, 3, 4]);
+		assert_eq!(ids(0, 2), vec![2, 3]);
+		assert_eq!(ids(4, 2), vec![4]);
+		assert_eq!(ids(5, 2), Vec::<u64>::new());
+		assert_eq!(TokenIssuer::get_issuance_records(ZLK, 4, 1)[0].1.amount, 4);
+	});
+}
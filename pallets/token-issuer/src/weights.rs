@@ -59,6 +59,12 @@ pub trait WeightInfo {
 	fn remove_from_transfer_whitelist() -> Weight;
 	fn issue() -> Weight;
 	fn transfer() -> Weight;
+	fn set_max_supply() -> Weight;
+	fn set_issuer_quota() -> Weight;
+	fn set_approval_threshold() -> Weight;
+	fn approve_issue() -> Weight;
+	fn reject_issue() -> Weight;
+	fn remove_expired_issue() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -115,14 +121,26 @@ impl WeightInfo for () {
 	/// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Tokens TotalIssuance (r:1 w:1)
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: TokenIssuer MaxSupply (r:1 w:0)
+	/// Proof Skipped: TokenIssuer MaxSupply (max_values: None, max_size: None, mode: Measured)
+	/// Storage: TokenIssuer NextIssuanceId (r:1 w:1)
+	/// Proof Skipped: TokenIssuer NextIssuanceId (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: TokenIssuer ApprovalThresholds (r:1 w:0)
+	/// Proof Skipped: TokenIssuer ApprovalThresholds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: TokenIssuer IssuerQuotas (r:1 w:1)
+	/// Proof Skipped: TokenIssuer IssuerQuotas (max_values: None, max_size: None, mode: Measured)
+	/// Storage: TokenIssuer IssuanceRecords (r:0 w:2)
+	/// Proof Skipped: TokenIssuer IssuanceRecords (max_values: None, max_size: None, mode: Measured)
+	/// Storage: TokenIssuer CurrencyIssuances (r:1 w:1)
+	/// Proof Skipped: TokenIssuer CurrencyIssuances (max_values: None, max_size: None, mode: Measured)
 	fn issue() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1870`
-		//  Estimated: `5335`
-		// Minimum execution time: 99_796_000 picoseconds.
-		Weight::from_parts(100_707_000, 5335)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `2105`
+		//  Estimated: `6204`
+		// Minimum execution time: 136_412_000 picoseconds.
+		Weight::from_parts(140_528_000, 6204)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: TokenIssuer TransferWhiteList (r:1 w:0)
 	/// Proof Skipped: TokenIssuer TransferWhiteList (max_values: None, max_size: None, mode: Measured)
@@ -139,4 +157,86 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: TokenIssuer MaxSupply (r:0 w:1)
+	/// Proof Skipped: TokenIssuer MaxSupply (max_values: None, max_size: None, mode: Measured)
+	fn set_max_supply() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 20_814_000 picoseconds.
+		Weight::from_parts(21_458_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TokenIssuer IssuerQuotas (r:1 w:1)
+	/// Proof Skipped: TokenIssuer IssuerQuotas (max_values: None, max_size: None, mode: Measured)
+	fn set_issuer_quota() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3607`
+		// Minimum execution time: 32_768_000 picoseconds.
+		Weight::from_parts(33_782_000, 3607)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TokenIssuer ApprovalThresholds (r:0 w:1)
+	/// Proof Skipped: TokenIssuer ApprovalThresholds (max_values: None, max_size: None, mode: Measured)
+	fn set_approval_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 20_493_000 picoseconds.
+		Weight::from_parts(21_127_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TokenIssuer IssueProposals (r:1 w:1)
+	/// Proof Skipped: TokenIssuer IssueProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: TokenIssuer PendingProposals (r:1 w:1)
+	/// Proof Skipped: TokenIssuer PendingProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: TokenIssuer MaxSupply (r:1 w:0)
+	/// Proof Skipped: TokenIssuer MaxSupply (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
+	/// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: TokenIssuer IssuanceRecords (r:0 w:2)
+	/// Proof Skipped: TokenIssuer IssuanceRecords (max_values: None, max_size: None, mode: Measured)
+	/// Storage: TokenIssuer CurrencyIssuances (r:1 w:1)
+	/// Proof Skipped: TokenIssuer CurrencyIssuances (max_values: None, max_size: None, mode: Measured)
+	fn approve_issue() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2147`
+		//  Estimated: `6012`
+		// Minimum execution time: 114_813_000 picoseconds.
+		Weight::from_parts(118_364_000, 6012)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: TokenIssuer IssueProposals (r:1 w:1)
+	/// Proof Skipped: TokenIssuer IssueProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: TokenIssuer PendingProposals (r:1 w:1)
+	/// Proof Skipped: TokenIssuer PendingProposals (max_values: None, max_size: None, mode: Measured)
+	fn reject_issue() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `330`
+		//  Estimated: `3795`
+		// Minimum execution time: 33_188_000 picoseconds.
+		Weight::from_parts(34_215_000, 3795)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TokenIssuer IssueProposals (r:1 w:1)
+	/// Proof Skipped: TokenIssuer IssueProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: TokenIssuer PendingProposals (r:1 w:1)
+	/// Proof Skipped: TokenIssuer PendingProposals (max_values: None, max_size: None, mode: Measured)
+	fn remove_expired_issue() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `395`
+		//  Estimated: `3860`
+		// Minimum execution time: 35_902_000 picoseconds.
+		Weight::from_parts(36_947_000, 3860)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
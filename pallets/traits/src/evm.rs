@@ -8,6 +8,9 @@ pub trait InspectEvmAccounts<AccountId, EvmAddress> {
 	/// Return the Substrate address bound to the EVM account. If not bound, returns `None`.
 	fn bound_account_id(evm_address: EvmAddress) -> Option<AccountId>;
 
+	/// Return the EVM address bound to the Substrate account. If not bound, returns `None`.
+	fn bound_evm_address(account_id: &AccountId) -> Option<EvmAddress>;
+
 	/// Get the Substrate address from the EVM address.
 	/// Returns the converted address if the address wasn't bind.
 	fn account_id(evm_address: EvmAddress) -> AccountId;
@@ -65,6 +65,20 @@ pub trait LendMarketMarketDataProvider<CurrencyId, Balance> {
 	fn get_full_interest_rate(asset_id: CurrencyId) -> Option<Rate>;
 }
 
+impl<CurrencyId, Balance> LendMarketMarketDataProvider<CurrencyId, Balance> for () {
+	fn get_market_info(_asset_id: CurrencyId) -> Result<MarketInfo, DispatchError> {
+		Err(DispatchError::Other("MarketDoesNotExist"))
+	}
+
+	fn get_market_status(_asset_id: CurrencyId) -> Result<MarketStatus<Balance>, DispatchError> {
+		Err(DispatchError::Other("MarketDoesNotExist"))
+	}
+
+	fn get_full_interest_rate(_asset_id: CurrencyId) -> Option<Rate> {
+		None
+	}
+}
+
 /// MarketInfo contains some static attrs as a subset of Market struct in LendMarket
 #[derive(Default, Copy, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct MarketInfo {
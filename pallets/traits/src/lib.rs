@@ -16,6 +16,16 @@ pub trait EmergencyOraclePriceProvider<CurrencyId, Price> {
 	fn reset_emergency_price(asset_id: CurrencyId);
 }
 
+pub trait PriceCircuitBreaker<CurrencyId> {
+	fn is_circuit_breaker_tripped(asset_id: &CurrencyId) -> bool;
+}
+
+impl<CurrencyId> PriceCircuitBreaker<CurrencyId> for () {
+	fn is_circuit_breaker_tripped(_asset_id: &CurrencyId) -> bool {
+		false
+	}
+}
+
 pub trait ConvertToBigUint {
 	fn get_big_uint(&self) -> BigUint;
 }
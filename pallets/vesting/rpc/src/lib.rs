@@ -0,0 +1,98 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use bifrost_primitives::{Balance, BlockNumber, VestingScheduleDetail};
+pub use bifrost_vesting_rpc_runtime_api::{self as runtime_api, VestingRuntimeApi};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{ErrorCode, ErrorObject},
+};
+use parity_scale_codec::Codec;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+#[rpc(client, server)]
+pub trait VestingRpcApi<BlockHash, AccountId> {
+	/// rpc method for getting the vesting schedules of an account
+	#[method(name = "vesting_getVestingSchedules")]
+	fn get_vesting_schedules(
+		&self,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<VestingScheduleDetail<NumberOrHex, BlockNumber>>>;
+}
+
+#[derive(Clone, Debug)]
+pub struct VestingRpc<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> VestingRpc<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: PhantomData }
+	}
+}
+
+#[async_trait]
+impl<C, Block, AccountId> VestingRpcApiServer<<Block as BlockT>::Hash, AccountId>
+	for VestingRpc<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: VestingRuntimeApi<Block, AccountId>,
+	AccountId: Codec,
+{
+	fn get_vesting_schedules(
+		&self,
+		who: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<VestingScheduleDetail<NumberOrHex, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let rs: Result<Vec<VestingScheduleDetail<Balance, BlockNumber>>, _> =
+			api.get_vesting_schedules(at, who);
+
+		match rs {
+			Ok(schedules) => Ok(schedules
+				.into_iter()
+				.map(|schedule| VestingScheduleDetail {
+					locked: NumberOrHex::Hex(schedule.locked.into()),
+					per_block: NumberOrHex::Hex(schedule.per_block.into()),
+					starting_block: schedule.starting_block,
+					cliff: schedule.cliff,
+					period: schedule.period,
+					revocable: schedule.revocable,
+					vested: NumberOrHex::Hex(schedule.vested.into()),
+					unvested: NumberOrHex::Hex(schedule.unvested.into()),
+				})
+				.collect()),
+			Err(e) => Err(ErrorObject::owned(
+				ErrorCode::InternalError.code(),
+				"Failed to get vesting schedules.",
+				Some(format!("{:?}", e)),
+			)),
+		}
+	}
+}
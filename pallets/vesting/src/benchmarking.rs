@@ -173,11 +173,7 @@ benchmarks! {
 
 		let transfer_amount = T::MinVestedTransfer::get();
 
-		let vesting_schedule = VestingInfo {
-			locked: transfer_amount,
-			per_block: 10u32.into(),
-			starting_block: 1u32.into(),
-		};
+		let vesting_schedule = VestingInfo::new(transfer_amount, 10u32.into(), 1u32.into());
 	}: _(RawOrigin::Signed(caller), target_lookup, vesting_schedule)
 	verify {
 		assert_eq!(
@@ -203,11 +199,7 @@ benchmarks! {
 
 		let transfer_amount = T::MinVestedTransfer::get();
 
-		let vesting_schedule = VestingInfo {
-			locked: transfer_amount,
-			per_block: 10u32.into(),
-			starting_block: 1u32.into(),
-		};
+		let vesting_schedule = VestingInfo::new(transfer_amount, 10u32.into(), 1u32.into());
 	}: _(RawOrigin::Root, source_lookup, target_lookup, vesting_schedule)
 	verify {
 		assert_eq!(
@@ -222,6 +214,90 @@ benchmarks! {
 		);
 	}
 
+	revoke_schedule {
+		let l in 0 .. MaxLocksOf::<T>::get();
+
+		let source: T::AccountId = account("source", 0, SEED);
+		let source_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(source.clone());
+		T::Currency::make_free_balance_be(&source, BalanceOf::<T>::max_value());
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(target.clone());
+		add_locks::<T>(&target, l as u8);
+
+		System::<T>::set_block_number(0u32.into());
+		Vesting::<T>::init_vesting_start_at(RawOrigin::Root.into(), 0u32.into())?;
+		let transfer_amount = T::MinVestedTransfer::get();
+		let vesting_schedule = VestingInfo::new_stepped(
+			transfer_amount,
+			10u32.into(),
+			1u32.into(),
+			10u32.into(),
+			5u32.into(),
+			true,
+		);
+		Vesting::<T>::force_vested_transfer(
+			RawOrigin::Root.into(),
+			source_lookup,
+			target_lookup.clone(),
+			vesting_schedule,
+		)?;
+		let treasury_balance = T::Currency::free_balance(&T::TreasuryAccount::get());
+	}: _(RawOrigin::Root, target_lookup, 0)
+	verify {
+		assert_eq!(Vesting::<T>::vesting_balance(&target), None, "Schedule not revoked");
+		assert_eq!(
+			T::Currency::free_balance(&T::TreasuryAccount::get()),
+			treasury_balance
+				.saturating_add(transfer_amount)
+				.saturating_sub(RevokedShortfall::<T>::get(&target)),
+			"Unvested amount not transferred",
+		);
+	}
+
+	collect_revoked_shortfall {
+		let l in 1 .. MaxLocksOf::<T>::get();
+
+		let source: T::AccountId = account("source", 0, SEED);
+		let source_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(source.clone());
+		T::Currency::make_free_balance_be(&source, BalanceOf::<T>::max_value());
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(target.clone());
+		add_locks::<T>(&target, l as u8);
+
+		System::<T>::set_block_number(0u32.into());
+		Vesting::<T>::init_vesting_start_at(RawOrigin::Root.into(), 0u32.into())?;
+		let transfer_amount = T::MinVestedTransfer::get();
+		let vesting_schedule = VestingInfo::new_stepped(
+			transfer_amount,
+			10u32.into(),
+			1u32.into(),
+			10u32.into(),
+			5u32.into(),
+			true,
+		);
+		Vesting::<T>::force_vested_transfer(
+			RawOrigin::Root.into(),
+			source_lookup,
+			target_lookup.clone(),
+			vesting_schedule,
+		)?;
+		// The other locks keep part of the unvested amount.
+		Vesting::<T>::revoke_schedule(RawOrigin::Root.into(), target_lookup.clone(), 0)?;
+		assert!(!RevokedShortfall::<T>::get(&target).is_zero(), "No shortfall recorded");
+		for id in 0..l as u8 {
+			T::Currency::remove_lock([id; 8], &target);
+		}
+		let treasury_balance = T::Currency::free_balance(&T::TreasuryAccount::get());
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), target_lookup)
+	verify {
+		assert!(RevokedShortfall::<T>::get(&target).is_zero(), "Shortfall not collected");
+		assert!(
+			T::Currency::free_balance(&T::TreasuryAccount::get()) > treasury_balance,
+			"Shortfall not transferred",
+		);
+	}
+
 	impl_benchmark_test_suite!(
 		Vesting,
 		crate::mock::ExtBuilder::default().existential_deposit(256).build(),
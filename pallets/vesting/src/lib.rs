@@ -43,6 +43,9 @@
 //! - `vest` - Update the lock, reducing it in line with the amount "vested" so far.
 //! - `vest_other` - Update the lock of another account, reducing it in line with the amount
 //!   "vested" so far.
+//! - `revoke_schedule` - Revoke a revocable schedule, sending the unvested amount to the treasury.
+//! - `collect_revoked_shortfall` - Send the unvested amount that other locks kept from the treasury
+//!   when revoking a schedule.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -57,11 +60,14 @@ mod vesting_info;
 pub mod migrations;
 pub mod weights;
 
+use bifrost_primitives::VestingScheduleDetail;
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	storage::bounded_vec::BoundedVec,
 	traits::{
+		fungible,
+		tokens::{Fortitude, Preservation},
 		Currency, ExistenceRequirement, Get, LockIdentifier, LockableCurrency, VestingSchedule,
 		WithdrawReasons,
 	},
@@ -73,7 +79,7 @@ use sp_runtime::{
 	codec::{Decode, Encode, MaxEncodedLen},
 	traits::{
 		AtLeast32BitUnsigned, Bounded, Convert, MaybeSerializeDeserialize, One, Saturating,
-		StaticLookup, Zero,
+		StaticLookup, UniqueSaturatedInto, Zero,
 	},
 	DispatchError, RuntimeDebug,
 };
@@ -97,6 +103,7 @@ const VESTING_ID: LockIdentifier = *b"vesting ";
 enum Releases {
 	V0,
 	V1,
+	V2,
 }
 
 impl Default for Releases {
@@ -161,7 +168,7 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// The currency trait.
-		type Currency: LockableCurrency<Self::AccountId>;
+		type Currency: LockableCurrency<Self::AccountId> + fungible::Inspect<Self::AccountId>;
 
 		/// Convert the block number into a balance.
 		type BlockNumberToBalance: Convert<BlockNumberFor<Self>, BalanceOf<Self>>;
@@ -177,6 +184,9 @@ pub mod pallet {
 		/// the unvested amount.
 		type UnvestedFundsAllowedWithdrawReasons: Get<WithdrawReasons>;
 
+		/// The account receiving the unvested amount of revoked schedules.
+		type TreasuryAccount: Get<Self::AccountId>;
+
 		/// Maximum number of vesting schedules an account may have at a given moment.
 		const MAX_VESTING_SCHEDULES: u32;
 	}
@@ -214,6 +224,12 @@ pub mod pallet {
 		BoundedVec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, MaxVestingSchedulesGet<T>>,
 	>;
 
+	/// The unvested amounts of revoked schedules that other locks kept from being transferred to
+	/// the treasury. They stay locked under the vesting lock until collected.
+	#[pallet::storage]
+	pub type RevokedShortfall<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
 	/// Storage version of the pallet.
 	///
 	/// New networks start with latest version, as determined by the genesis build.
@@ -235,7 +251,7 @@ pub mod pallet {
 			use sp_runtime::traits::Saturating;
 
 			// Genesis uses the latest storage version.
-			StorageVersion::<T>::put(Releases::V1);
+			StorageVersion::<T>::put(Releases::V2);
 
 			// Generate initial vesting configuration
 			// * who - Account which we are generating vesting configuration for
@@ -273,6 +289,19 @@ pub mod pallet {
 		VestingUpdated { account: T::AccountId, unvested: BalanceOf<T> },
 		/// An \[account\] has become fully vested.
 		VestingCompleted { account: T::AccountId },
+		/// A schedule of an account was revoked, and its unvested amount was transferred to the
+		/// treasury. The `shortfall` is the unvested amount that other locks kept.
+		VestingScheduleRevoked {
+			account: T::AccountId,
+			unvested: BalanceOf<T>,
+			shortfall: BalanceOf<T>,
+		},
+		/// Part of the revoked shortfall of an account was transferred to the treasury.
+		RevokedShortfallCollected {
+			account: T::AccountId,
+			collected: BalanceOf<T>,
+			shortfall: BalanceOf<T>,
+		},
 	}
 
 	/// Error for the vesting pallet.
@@ -297,6 +326,14 @@ pub mod pallet {
 		WrongLockedAmount,
 		/// Wrong vesting during cliff period
 		WrongCliffVesting,
+		/// The schedule can't be revoked.
+		ScheduleNotRevocable,
+		/// Only linear schedules without cliff that can't be revoked can be merged.
+		ScheduleNotMergeable,
+		/// The account has no revoked shortfall.
+		NoRevokedShortfall,
+		/// Only root can create revocable schedules.
+		RevocableScheduleNotAllowed,
 	}
 
 	#[pallet::call]
@@ -347,7 +384,7 @@ pub mod pallet {
 		/// The dispatch origin for this call must be _Signed_.
 		///
 		/// - `target`: The account receiving the vested funds.
-		/// - `schedule`: The vesting schedule attached to the transfer.
+		/// - `schedule`: The vesting schedule attached to the transfer, which can't be revocable.
 		///
 		/// Emits `VestingCreated`.
 		///
@@ -365,6 +402,7 @@ pub mod pallet {
 			schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
 		) -> DispatchResult {
 			let transactor = ensure_signed(origin)?;
+			ensure!(!schedule.revocable, Error::<T>::RevocableScheduleNotAllowed);
 			let transactor = <T::Lookup as StaticLookup>::unlookup(transactor);
 			Self::do_vested_transfer(transactor, target, schedule)
 		}
@@ -437,8 +475,11 @@ pub mod pallet {
 			ensure!(remained_vesting <= schedules[index].locked(), Error::<T>::WrongLockedAmount);
 
 			let mut new_start_offset = schedules[index].starting_block();
+			let mut new_cliff = schedules[index].cliff;
 			if now > old_start_at {
 				new_start_offset = now - absolute_start;
+				// the schedule restarts now, keep the end of the cliff unchanged
+				new_cliff = old_start_at.saturating_add(new_cliff).saturating_sub(now);
 			}
 
 			Vesting::<T>::mutate_exists(&target, |info| {
@@ -446,6 +487,7 @@ pub mod pallet {
 					vesting_info[index].locked = remained_vesting;
 					vesting_info[index].per_block = per_block;
 					vesting_info[index].starting_block = new_start_offset;
+					vesting_info[index].cliff = new_cliff;
 				}
 			});
 
@@ -517,6 +559,98 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Revoke a revocable vesting schedule. The vested amount stays with `target`, and the
+		/// unvested amount is transferred to the treasury account. The part of the unvested amount
+		/// that other locks of `target` keep is recorded as its revoked shortfall.
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// - `target`: The account whose schedule should be revoked.
+		/// - `schedule_index`: index of the schedule to revoke.
+		///
+		/// Emits `VestingScheduleRevoked`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(
+			T::WeightInfo::revoke_schedule(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+		)]
+		pub fn revoke_schedule(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			schedule_index: u32,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let who = T::Lookup::lookup(target)?;
+
+			let schedules = Vesting::<T>::get(&who).ok_or(Error::<T>::NotVesting)?;
+			let schedule = *schedules
+				.get(schedule_index as usize)
+				.ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+			ensure!(schedule.revocable, Error::<T>::ScheduleNotRevocable);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let start_at =
+				VestingStartAt::<T>::get().map(|st| st.saturating_add(schedule.starting_block()));
+			let unvested = schedule.locked_at::<T::BlockNumberToBalance>(now, start_at);
+
+			let remove_action = VestingAction::Remove { index: schedule_index as usize };
+			let (schedules, locked_now) = Self::exec_action(schedules.to_vec(), remove_action)?;
+
+			Self::write_vesting(&who, schedules)?;
+
+			let (unvested, shortfall) = Self::transfer_revoked(&who, unvested, locked_now)?;
+
+			Self::deposit_event(Event::<T>::VestingScheduleRevoked {
+				account: who,
+				unvested,
+				shortfall,
+			});
+
+			Ok(())
+		}
+
+		/// Transfer the revoked shortfall of `target` to the treasury account, as far as the
+		/// other locks of `target` allow it.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: The account whose revoked shortfall should be collected.
+		///
+		/// Emits `RevokedShortfallCollected`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(
+			T::WeightInfo::collect_revoked_shortfall(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+		)]
+		pub fn collect_revoked_shortfall(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(target)?;
+
+			let shortfall = RevokedShortfall::<T>::take(&who);
+			ensure!(!shortfall.is_zero(), Error::<T>::NoRevokedShortfall);
+
+			let locked_now = match Vesting::<T>::get(&who) {
+				Some(schedules) => {
+					let (schedules, locked_now) =
+						Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
+					Self::write_vesting(&who, schedules)?;
+					locked_now
+				},
+				None => Zero::zero(),
+			};
+
+			let (collected, shortfall) = Self::transfer_revoked(&who, shortfall, locked_now)?;
+
+			Self::deposit_event(Event::<T>::RevokedShortfallCollected {
+				account: who,
+				collected,
+				shortfall,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -601,12 +735,11 @@ impl<T: Config> Pallet<T> {
 		let source = T::Lookup::lookup(source)?;
 
 		// Check we can add to this account prior to any storage writes.
-		Self::can_add_vesting_schedule(
-			&target,
-			schedule.locked(),
-			schedule.per_block(),
-			schedule.starting_block(),
-		)?;
+		ensure!(
+			(Vesting::<T>::decode_len(&target).unwrap_or_default() as u32) <
+				T::MAX_VESTING_SCHEDULES,
+			Error::<T>::AtMaxVestingSchedules
+		);
 
 		T::Currency::transfer(
 			&source,
@@ -616,17 +749,58 @@ impl<T: Config> Pallet<T> {
 		)?;
 
 		// We can't let this fail because the currency transfer has already happened.
-		let res = Self::add_vesting_schedule(
-			&target,
-			schedule.locked(),
-			schedule.per_block(),
-			schedule.starting_block(),
-		);
+		let res = Self::do_add_vesting_schedule(&target, schedule);
 		debug_assert!(res.is_ok(), "Failed to add a schedule when we had to succeed.");
 
 		Ok(())
 	}
 
+	/// Add a validated `schedule` to `who` and update the lock.
+	fn do_add_vesting_schedule(
+		who: &T::AccountId,
+		schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+	) -> DispatchResult {
+		let mut schedules = Vesting::<T>::get(who).unwrap_or_default();
+
+		// NOTE: we must push the new schedule so that `exec_action`
+		// will give the correct new locked amount.
+		ensure!(schedules.try_push(schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
+
+		let (schedules, locked_now) =
+			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
+
+		Self::write_vesting(who, schedules)?;
+		Self::write_lock(who, locked_now);
+
+		Ok(())
+	}
+
+	/// Get the schedules of `who` with their vested and unvested amounts at the current block.
+	pub fn get_vesting_schedules(
+		who: &T::AccountId,
+	) -> Vec<VestingScheduleDetail<BalanceOf<T>, BlockNumberFor<T>>> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		Vesting::<T>::get(who)
+			.unwrap_or_default()
+			.into_iter()
+			.map(|schedule| {
+				let start_at = VestingStartAt::<T>::get()
+					.map(|st| st.saturating_add(schedule.starting_block()));
+				let unvested = schedule.locked_at::<T::BlockNumberToBalance>(now, start_at);
+				VestingScheduleDetail {
+					locked: schedule.locked(),
+					per_block: schedule.per_block(),
+					starting_block: schedule.starting_block(),
+					cliff: schedule.cliff,
+					period: schedule.period(),
+					revocable: schedule.revocable,
+					vested: schedule.locked().saturating_sub(unvested),
+					unvested,
+				}
+			})
+			.collect()
+	}
+
 	/// Iterate through the schedules to track the current locked amount and
 	/// filter out completed and specified schedules.
 	///
@@ -663,12 +837,12 @@ impl<T: Config> Pallet<T> {
 
 	/// Write an accounts updated vesting lock to storage.
 	fn write_lock(who: &T::AccountId, total_locked_now: BalanceOf<T>) {
+		// The revoked shortfall stays locked until it is collected.
+		let total_locked_now = total_locked_now.saturating_add(RevokedShortfall::<T>::get(who));
+		Self::set_vesting_lock(who, total_locked_now);
 		if total_locked_now.is_zero() {
-			T::Currency::remove_lock(VESTING_ID, who);
 			Self::deposit_event(Event::<T>::VestingCompleted { account: who.clone() });
 		} else {
-			let reasons = WithdrawReasons::except(T::UnvestedFundsAllowedWithdrawReasons::get());
-			T::Currency::set_lock(VESTING_ID, who, total_locked_now, reasons);
 			Self::deposit_event(Event::<T>::VestingUpdated {
 				account: who.clone(),
 				unvested: total_locked_now,
@@ -676,6 +850,16 @@ impl<T: Config> Pallet<T> {
 		};
 	}
 
+	/// Lock `locked` of `who`, removing the lock if nothing is locked.
+	fn set_vesting_lock(who: &T::AccountId, locked: BalanceOf<T>) {
+		if locked.is_zero() {
+			T::Currency::remove_lock(VESTING_ID, who);
+		} else {
+			let reasons = WithdrawReasons::except(T::UnvestedFundsAllowedWithdrawReasons::get());
+			T::Currency::set_lock(VESTING_ID, who, locked, reasons);
+		}
+	}
+
 	/// Write an accounts updated vesting schedules to storage.
 	fn write_vesting(
 		who: &T::AccountId,
@@ -695,6 +879,40 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Transfer up to `amount` of `who` to the treasury, as far as the other locks of `who`
+	/// allow it. The rest is added to the revoked shortfall of `who`, and the lock is written
+	/// afterwards with the revoked shortfall on top of `locked_now`. Returns the transferred
+	/// amount and the rest.
+	fn transfer_revoked(
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+		locked_now: BalanceOf<T>,
+	) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+		// release the revoked amount from the lock before transferring it
+		Self::set_vesting_lock(who, locked_now.saturating_add(RevokedShortfall::<T>::get(who)));
+		let reducible: u128 = <T::Currency as fungible::Inspect<T::AccountId>>::reducible_balance(
+			who,
+			Preservation::Expendable,
+			Fortitude::Polite,
+		)
+		.unique_saturated_into();
+		let transferred = amount.min(reducible.unique_saturated_into());
+		T::Currency::transfer(
+			who,
+			&T::TreasuryAccount::get(),
+			transferred,
+			ExistenceRequirement::AllowDeath,
+		)?;
+
+		let shortfall = amount.saturating_sub(transferred);
+		if !shortfall.is_zero() {
+			RevokedShortfall::<T>::mutate(who, |total| *total = total.saturating_add(shortfall));
+		}
+		Self::write_lock(who, locked_now);
+
+		Ok((transferred, shortfall))
+	}
+
 	/// Unlock any vested funds of `who`.
 	fn do_vest(who: T::AccountId) -> DispatchResult {
 		let schedules = Vesting::<T>::get(&who).ok_or(Error::<T>::NotVesting)?;
@@ -720,6 +938,10 @@ impl<T: Config> Pallet<T> {
 				// any schedules that may be ending at this block.
 				let schedule1 = *schedules.get(idx1).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
 				let schedule2 = *schedules.get(idx2).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+				ensure!(
+					schedule1.is_linear() && schedule2.is_linear(),
+					Error::<T>::ScheduleNotMergeable
+				);
 
 				// The length of `schedules` decreases by 2 here since we filter out 2 schedules.
 				// Thus we know below that we can push the new merged schedule without error
@@ -807,19 +1029,7 @@ where
 			return Err(Error::<T>::InvalidScheduleParams.into());
 		};
 
-		let mut schedules = Vesting::<T>::get(who).unwrap_or_default();
-
-		// NOTE: we must push the new schedule so that `exec_action`
-		// will give the correct new locked amount.
-		ensure!(schedules.try_push(vesting_schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
-
-		let (schedules, locked_now) =
-			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
-
-		Self::write_vesting(who, schedules)?;
-		Self::write_lock(who, locked_now);
-
-		Ok(())
+		Self::do_add_vesting_schedule(who, vesting_schedule)
 	}
 
 	// Ensure we can call `add_vesting_schedule` without error. This should always
@@ -100,3 +100,71 @@ pub(crate) mod v1 {
 		Ok(())
 	}
 }
+
+// Migration from linear schedules to schedules with cliff, step unlocks and revocation.
+pub mod v2 {
+	use super::*;
+	use frame_support::traits::OnRuntimeUpgrade;
+	#[cfg(feature = "try-runtime")]
+	use sp_runtime::TryRuntimeError;
+
+	const LOG_TARGET: &str = "runtime::vesting";
+
+	/// The linear schedule before V2.
+	#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct OldVestingInfo<Balance, BlockNumber> {
+		pub locked: Balance,
+		pub per_block: Balance,
+		pub starting_block: BlockNumber,
+	}
+
+	/// Migrate the schedules to linear schedules without cliff that can't be revoked.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::<T>::get() != Releases::V1 {
+				log::info!(target: LOG_TARGET, "migration: Vesting storage version v2 already applied");
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut reads_writes = 0;
+			Vesting::<T>::translate::<
+				BoundedVec<
+					OldVestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+					MaxVestingSchedulesGet<T>,
+				>,
+				_,
+			>(|_key, schedules| {
+				reads_writes += 1;
+				let schedules: Vec<_> = schedules
+					.into_iter()
+					.map(|s| VestingInfo::new(s.locked, s.per_block, s.starting_block))
+					.collect();
+				// The number of schedules doesn't change, so this can't fail.
+				schedules.try_into().ok()
+			});
+			StorageVersion::<T>::put(Releases::V2);
+
+			log::info!(target: LOG_TARGET, "migration: migrated {} vesting accounts to v2", reads_writes);
+
+			T::DbWeight::get().reads_writes(reads_writes + 1, reads_writes + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			ensure!(StorageVersion::<T>::get() == Releases::V1, "must upgrade linearly");
+			let count = Vesting::<T>::iter_keys().count() as u64;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(StorageVersion::<T>::get() == Releases::V2, "must upgrade to v2");
+			let old_count: u64 = Decode::decode(&mut state.as_slice())
+				.map_err(|_| "the state parameter should be generated by pre_upgrade")?;
+			let new_count = Vesting::<T>::iter().count() as u64;
+			ensure!(old_count == new_count, "vesting accounts count changed");
+			Ok(())
+		}
+	}
+}
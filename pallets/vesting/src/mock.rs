@@ -70,6 +70,7 @@ parameter_types! {
 	pub static ExistentialDeposit: u64 = 1;
 	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
 	WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+	pub const TreasuryAccount: u64 = 100;
 }
 impl Config for Test {
 	type BlockNumberToBalance = Identity;
@@ -79,6 +80,7 @@ impl Config for Test {
 	type MinVestedTransfer = MinVestedTransfer;
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type WeightInfo = ();
+	type TreasuryAccount = TreasuryAccount;
 }
 
 pub struct ExtBuilder {
@@ -983,9 +983,9 @@ fn multiple_schedules_from_genesis_config_errors() {
 }
 
 #[test]
-fn build_genesis_has_storage_version_v1() {
+fn build_genesis_has_storage_version_v2() {
 	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
-		assert_eq!(StorageVersion::<Test>::get(), Releases::V1);
+		assert_eq!(StorageVersion::<Test>::get(), Releases::V2);
 	});
 }
 
@@ -1098,3 +1098,209 @@ fn vested_transfer_less_than_existential_deposit_fails() {
 		);
 	});
 }
+
+#[test]
+fn stepped_schedule_with_cliff_locked_at_works() {
+	// 10 blocks of cliff, then 1_000 unlocks every 5 blocks.
+	let sched = VestingInfo::new_stepped(10 * ED, ED / 5, 0u64, 10u64, 5u64, false);
+	assert!(sched.is_valid());
+	assert!(!sched.is_linear());
+
+	// Nothing unlocks until the cliff has passed.
+	assert_eq!(sched.locked_at::<Identity>(10, Some(0)), 10 * ED);
+	// Only whole periods unlock.
+	assert_eq!(sched.locked_at::<Identity>(11, Some(0)), 8 * ED);
+	assert_eq!(sched.locked_at::<Identity>(14, Some(0)), 8 * ED);
+	assert_eq!(sched.locked_at::<Identity>(15, Some(0)), 7 * ED);
+	assert_eq!(sched.locked_at::<Identity>(50, Some(0)), 0);
+	// Nothing unlocks without a start.
+	assert_eq!(sched.locked_at::<Identity>(50, None), 10 * ED);
+
+	assert_eq!(sched.ending_block_as_balance::<Identity>(), 50);
+	// A zero period is invalid.
+	assert!(!VestingInfo::new_stepped(10 * ED, ED, 0u64, 0u64, 0u64, false).is_valid());
+}
+
+#[test]
+fn revoke_schedule_should_work() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let treasury = <Test as Config>::TreasuryAccount::get();
+		assert_ok!(Vesting::init_vesting_start_at(RawOrigin::Root.into(), 1));
+
+		let sched = VestingInfo::new_stepped(10 * ED, ED, 0u64, 2u64, 2u64, true);
+		assert_ok!(Vesting::force_vested_transfer(RawOrigin::Root.into(), 3, DAVE, sched));
+		assert_eq!(Balances::free_balance(&DAVE), 50 * ED);
+
+		// 5 blocks after the start, 2 whole periods have unlocked.
+		System::set_block_number(6);
+		let details = Vesting::get_vesting_schedules(&DAVE);
+		assert_eq!(details.len(), 1);
+		assert_eq!(details[0].cliff, 2);
+		assert_eq!(details[0].period, 2);
+		assert!(details[0].revocable);
+		assert_eq!(details[0].vested, 4 * ED);
+		assert_eq!(details[0].unvested, 6 * ED);
+
+		assert_noop!(
+			Vesting::revoke_schedule(Some(DAVE).into(), DAVE, 0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Vesting::revoke_schedule(RawOrigin::Root.into(), DAVE, 1),
+			Error::<Test>::ScheduleIndexOutOfBounds
+		);
+
+		assert_ok!(Vesting::revoke_schedule(RawOrigin::Root.into(), DAVE, 0));
+		System::assert_last_event(
+			Event::<Test>::VestingScheduleRevoked { account: DAVE, unvested: 6 * ED, shortfall: 0 }
+				.into(),
+		);
+
+		// The unvested funds went to the treasury and nothing is locked anymore.
+		assert_eq!(Balances::free_balance(&treasury), 6 * ED);
+		assert_eq!(Balances::free_balance(&DAVE), 44 * ED);
+		assert_eq!(VestingStorage::<Test>::get(&DAVE), None);
+		assert_eq!(Vesting::vesting_balance(&DAVE), None);
+		assert!(Vesting::get_vesting_schedules(&DAVE).is_empty());
+	});
+}
+
+#[test]
+fn revoke_schedule_under_other_locks_should_record_shortfall() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let treasury = <Test as Config>::TreasuryAccount::get();
+		assert_ok!(Vesting::init_vesting_start_at(RawOrigin::Root.into(), 1));
+
+		let sched = VestingInfo::new_stepped(10 * ED, ED, 0u64, 2u64, 2u64, true);
+		assert_ok!(Vesting::force_vested_transfer(RawOrigin::Root.into(), 3, DAVE, sched));
+		Balances::set_lock(*b"otherlck", &DAVE, 48 * ED, WithdrawReasons::all());
+
+		// Only 2 of the 6 unvested ED can leave while the other lock holds 48 ED.
+		System::set_block_number(6);
+		assert_ok!(Vesting::revoke_schedule(RawOrigin::Root.into(), DAVE, 0));
+		System::assert_last_event(
+			Event::<Test>::VestingScheduleRevoked {
+				account: DAVE,
+				unvested: 2 * ED,
+				shortfall: 4 * ED,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(&treasury), 2 * ED);
+		assert_eq!(Balances::free_balance(&DAVE), 48 * ED);
+		assert_eq!(RevokedShortfall::<Test>::get(&DAVE), 4 * ED);
+		assert!(Vesting::get_vesting_schedules(&DAVE).is_empty());
+
+		assert_noop!(
+			Vesting::collect_revoked_shortfall(Some(ALICE).into(), ALICE),
+			Error::<Test>::NoRevokedShortfall
+		);
+		Balances::set_lock(*b"otherlck", &DAVE, 45 * ED, WithdrawReasons::all());
+		System::reset_events();
+		assert_ok!(Vesting::collect_revoked_shortfall(Some(ALICE).into(), DAVE));
+		// The lock is not removed while a shortfall remains.
+		assert!(!System::events()
+			.iter()
+			.any(|record| record.event == Event::<Test>::VestingCompleted { account: DAVE }.into()));
+		System::assert_has_event(
+			Event::<Test>::VestingUpdated { account: DAVE, unvested: ED }.into(),
+		);
+		System::assert_last_event(
+			Event::<Test>::RevokedShortfallCollected {
+				account: DAVE,
+				collected: 3 * ED,
+				shortfall: ED,
+			}
+			.into(),
+		);
+		assert_eq!(RevokedShortfall::<Test>::get(&DAVE), ED);
+
+		// The rest of the shortfall stays locked once the other lock is gone.
+		Balances::remove_lock(*b"otherlck", &DAVE);
+		assert_eq!(Balances::usable_balance(&DAVE), 44 * ED);
+		System::reset_events();
+		assert_ok!(Vesting::collect_revoked_shortfall(Some(ALICE).into(), DAVE));
+		System::assert_has_event(Event::<Test>::VestingCompleted { account: DAVE }.into());
+		assert_eq!(Balances::free_balance(&treasury), 6 * ED);
+		assert_eq!(Balances::free_balance(&DAVE), 44 * ED);
+		assert_eq!(RevokedShortfall::<Test>::get(&DAVE), 0);
+		assert_eq!(Balances::locks(&DAVE).len(), 0);
+	});
+}
+
+#[test]
+fn revoke_non_revocable_schedule_should_fail() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		assert_noop!(
+			Vesting::revoke_schedule(RawOrigin::Root.into(), ALICE, 0),
+			Error::<Test>::ScheduleNotRevocable
+		);
+		assert_noop!(
+			Vesting::revoke_schedule(RawOrigin::Root.into(), DAVE, 0),
+			Error::<Test>::NotVesting
+		);
+	});
+}
+
+#[test]
+fn vested_transfer_revocable_schedule_should_fail() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let sched = VestingInfo::new_stepped(10 * ED, ED, 0u64, 2u64, 2u64, true);
+		assert_noop!(
+			Vesting::vested_transfer(Some(3).into(), DAVE, sched),
+			Error::<Test>::RevocableScheduleNotAllowed
+		);
+
+		// Root can still create it.
+		assert_ok!(Vesting::force_vested_transfer(RawOrigin::Root.into(), 3, DAVE, sched));
+		assert_eq!(VestingStorage::<Test>::get(&DAVE).unwrap(), vec![sched]);
+	});
+}
+
+#[test]
+fn merge_non_linear_schedules_should_fail() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let sched0 = VestingInfo::new(ED * 20, ED, 10u64);
+		let sched1 = VestingInfo::new_stepped(ED * 20, ED, 10u64, 5u64, 1u64, false);
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), BOB, sched1));
+		assert_eq!(VestingStorage::<Test>::get(&BOB).unwrap(), vec![sched0, sched1]);
+
+		assert_noop!(
+			Vesting::merge_schedules(Some(BOB).into(), 0, 1),
+			Error::<Test>::ScheduleNotMergeable
+		);
+	});
+}
+
+#[test]
+fn migrate_to_v2_works() {
+	use crate::migrations::v2::{MigrateToV2, OldVestingInfo};
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	ExtBuilder::default()
+		.existential_deposit(ED)
+		.vesting_genesis_config(vec![])
+		.build()
+		.execute_with(|| {
+			let old = vec![
+				OldVestingInfo { locked: 10 * ED, per_block: ED, starting_block: 5u64 },
+				OldVestingInfo { locked: 20 * ED, per_block: 2 * ED, starting_block: 0u64 },
+			];
+			frame_support::storage::unhashed::put_raw(
+				&VestingStorage::<Test>::hashed_key_for(DAVE),
+				&old.encode(),
+			);
+			StorageVersion::<Test>::put(Releases::V1);
+
+			MigrateToV2::<Test>::on_runtime_upgrade();
+
+			assert_eq!(StorageVersion::<Test>::get(), Releases::V2);
+			assert_eq!(
+				VestingStorage::<Test>::get(&DAVE).unwrap(),
+				vec![VestingInfo::new(10 * ED, ED, 5u64), VestingInfo::new(20 * ED, 2 * ED, 0u64)]
+			);
+
+			// Running it again is a noop.
+			assert_storage_noop!(MigrateToV2::<Test>::on_runtime_upgrade());
+		});
+}
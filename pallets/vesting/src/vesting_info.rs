@@ -27,6 +27,14 @@ pub struct VestingInfo<Balance, BlockNumber> {
 	pub per_block: Balance,
 	/// Starting block for unlocking(vesting).
 	pub starting_block: BlockNumber,
+	/// Number of blocks after `starting_block` during which nothing unlocks. The amount vested
+	/// during the cliff unlocks at once when it ends.
+	pub cliff: BlockNumber,
+	/// Number of blocks between two unlocks, e.g. a month for monthly step unlocks. A period of
+	/// one block unlocks linearly.
+	pub period: BlockNumber,
+	/// Whether the schedule can be revoked, sending the unvested amount to the treasury.
+	pub revocable: bool,
 }
 
 impl<Balance, BlockNumber> VestingInfo<Balance, BlockNumber>
@@ -40,13 +48,38 @@ where
 		per_block: Balance,
 		starting_block: BlockNumber,
 	) -> VestingInfo<Balance, BlockNumber> {
-		VestingInfo { locked, per_block, starting_block }
+		VestingInfo {
+			locked,
+			per_block,
+			starting_block,
+			cliff: Zero::zero(),
+			period: One::one(),
+			revocable: false,
+		}
+	}
+
+	/// Instantiate a new `VestingInfo` with a cliff, unlocking in steps of `period` blocks.
+	pub fn new_stepped(
+		locked: Balance,
+		per_block: Balance,
+		starting_block: BlockNumber,
+		cliff: BlockNumber,
+		period: BlockNumber,
+		revocable: bool,
+	) -> VestingInfo<Balance, BlockNumber> {
+		VestingInfo { locked, per_block, starting_block, cliff, period, revocable }
 	}
 
 	/// Validate parameters for `VestingInfo`. Note that this does not check
 	/// against `MinVestedTransfer`.
 	pub fn is_valid(&self) -> bool {
-		!self.locked.is_zero() && !self.raw_per_block().is_zero()
+		!self.locked.is_zero() && !self.raw_per_block().is_zero() && !self.period.is_zero()
+	}
+
+	/// Whether the schedule unlocks linearly from its start, without cliff or steps, and can't
+	/// be revoked.
+	pub fn is_linear(&self) -> bool {
+		self.cliff.is_zero() && self.period() == One::one() && !self.revocable
 	}
 
 	/// Locked amount at schedule creation.
@@ -72,17 +105,25 @@ where
 		self.starting_block
 	}
 
+	/// Number of blocks between two unlocks. Corrects for a `period` of 0.
+	pub fn period(&self) -> BlockNumber {
+		self.period.max(One::one())
+	}
+
 	/// Amount locked at block `n`.
 	pub fn locked_at<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
 		&self,
 		n: BlockNumber,
 		start_at: Option<BlockNumber>,
 	) -> Balance {
-		// Number of blocks that count toward vesting
+		// Number of blocks that count toward vesting, nothing vests until the cliff has passed
 		let vested_block_count = match start_at {
-			Some(st) if st < n => n.saturating_sub(st),
+			Some(st) if st.saturating_add(self.cliff) < n => n.saturating_sub(st),
 			_ => return self.locked,
 		};
+		// Only whole periods count toward vesting
+		let vested_block_count =
+			vested_block_count.saturating_sub(vested_block_count % self.period());
 		let vested_block_count = BlockNumberToBalance::convert(vested_block_count);
 		// Return amount that is still locked in vesting.
 		vested_block_count
@@ -96,6 +137,8 @@ where
 		&self,
 	) -> Balance {
 		let starting_block = BlockNumberToBalance::convert(self.starting_block);
+		let period = BlockNumberToBalance::convert(self.period());
+		let cliff = BlockNumberToBalance::convert(self.cliff);
 		let duration = if self.per_block() >= self.locked {
 			// If `per_block` is bigger than `locked`, the schedule will end
 			// the block after starting.
@@ -110,6 +153,13 @@ where
 					One::one()
 				}
 		};
+		// The last unlock happens at the end of a period, and not before the cliff has passed.
+		let duration = if (duration % period).is_zero() {
+			duration
+		} else {
+			duration.saturating_add(period).saturating_sub(duration % period)
+		};
+		let duration = duration.max(cliff.saturating_add(One::one()));
 
 		starting_block.saturating_add(duration)
 	}
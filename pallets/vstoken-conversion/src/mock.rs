@@ -26,7 +26,10 @@ use bifrost_primitives::{
 	currency::{ASG, KSM},
 	BifrostVsbondAccount,
 };
-use frame_support::{derive_impl, ord_parameter_types, parameter_types, traits::Nothing};
+use frame_support::{
+	derive_impl, ord_parameter_types, parameter_types,
+	traits::{Everything, Nothing},
+};
 use frame_system::EnsureSignedBy;
 use sp_core::ConstU32;
 use sp_runtime::{traits::IdentityLookup, AccountId32, BuildStorage};
@@ -78,6 +81,7 @@ pub type AdaptedBasicCurrency =
 
 impl bifrost_currencies::Config for Runtime {
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type WeightInfo = ();
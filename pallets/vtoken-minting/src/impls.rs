@@ -22,9 +22,9 @@
 use crate::{
 	AccountIdOf, BalanceOf, Config, CurrencyIdOf, Error, Event, Fees, HookIterationLimit,
 	MinTimeUnit, MinimumMint, MinimumRedeem, MintWithLockBlocks, OnRedeemSuccess, OngoingTimeUnit,
-	Pallet, RedeemTo, TimeUnitUnlockLedger, TokenPool, TokenUnlockLedger, TokenUnlockNextId,
-	UnlockDuration, UnlockId, UnlockingTotal, UserUnlockLedger, VtokenIncentiveCoef,
-	VtokenLockLedger, WeightInfo,
+	Pallet, PrepaidRedeemFees, RedeemTo, TimeUnitUnlockLedger, TokenPool, TokenUnlockLedger,
+	TokenUnlockNextId, UnlockDuration, UnlockId, UnlockingTotal, UserUnlockLedger,
+	VtokenIncentiveCoef, VtokenLockLedger, WeightInfo,
 };
 use bb_bnc::traits::BbBNCInterface;
 use bifrost_primitives::{
@@ -311,9 +311,35 @@ impl<T: Config> Pallet<T> {
 			is_remove_record,
 		)?;
 		Self::update_unlocking_total(&currency_id, &currency_amount, operation)?;
+		if is_remove_record {
+			Self::refund_prepaid_redeem_fee(account, currency_id, unlock_id)?;
+		}
 		Ok(is_remove_record)
 	}
 
+	/// Refund the unspent xcm fee prepaid for an unlock to its redeemer.
+	/// Parameters:
+	/// - `redeemer`: The redeemer account id.
+	/// - `currency_id`: The currency id.
+	/// - `unlock_id`: The unlock id.
+	fn refund_prepaid_redeem_fee(
+		redeemer: &AccountIdOf<T>,
+		currency_id: &CurrencyId,
+		unlock_id: &UnlockId,
+	) -> DispatchResult {
+		if let Some((fee_currency, fee_amount)) =
+			PrepaidRedeemFees::<T>::take(currency_id, unlock_id)
+		{
+			T::MultiCurrency::transfer(
+				fee_currency,
+				&T::EntranceAccount::get().into_account_truncating(),
+				redeemer,
+				fee_amount,
+			)?;
+		}
+		Ok(())
+	}
+
 	/// Mint without transfer.
 	/// Parameters:
 	/// - `minter`: The minter account id.
@@ -371,6 +397,7 @@ impl<T: Config> Pallet<T> {
 		let (redeem_currency_amount, redeem_to) = Self::transfer_to_by_redeem_type(
 			redeemer.clone(),
 			redeem_currency_id,
+			unlock_id,
 			redeem_currency_amount,
 			entrance_account_balance,
 			redeem_type,
@@ -406,6 +433,7 @@ impl<T: Config> Pallet<T> {
 	/// Parameters:
 	/// - `redeemer`: The redeemer account id.
 	/// - `redeem_currency_id`: The redeem currency id.
+	/// - `unlock_id`: The unlock id.
 	/// - `redeem_currency_amount`: The redeem currency amount.
 	/// - `entrance_account_balance`: The entrance account balance.
 	/// - `redeem_type`: The redeem type.
@@ -414,6 +442,7 @@ impl<T: Config> Pallet<T> {
 	pub fn transfer_to_by_redeem_type(
 		redeemer: T::AccountId,
 		redeem_currency_id: CurrencyId,
+		unlock_id: &UnlockId,
 		mut redeem_currency_amount: BalanceOf<T>,
 		entrance_account_balance: BalanceOf<T>,
 		redeem_type: RedeemType<T::AccountId>,
@@ -495,6 +524,35 @@ impl<T: Config> Pallet<T> {
 					),
 					RedeemTo::Moonbeam(receiver),
 				),
+				RedeemType::Destination { location, weight_limit, .. } => {
+					let dest_weight_limit = weight_limit.map_or(Unlimited, Limited);
+					// the prepaid fee is only spent once, as the unlock may arrive in parts
+					match PrepaidRedeemFees::<T>::take(redeem_currency_id, unlock_id) {
+						Some((fee_currency, fee_amount)) => {
+							let assets = vec![
+								(redeem_currency_id, redeem_currency_amount),
+								(fee_currency, fee_amount),
+							];
+							T::XcmTransfer::transfer_multicurrencies(
+								entrance_account.clone(),
+								assets,
+								1,
+								location.clone(),
+								dest_weight_limit,
+							)?;
+						},
+						None => {
+							T::XcmTransfer::transfer(
+								entrance_account.clone(),
+								redeem_currency_id,
+								redeem_currency_amount,
+								location.clone(),
+								dest_weight_limit,
+							)?;
+						},
+					}
+					return Ok((redeem_currency_amount, RedeemTo::Destination(location)));
+				},
 				RedeemType::Native => {
 					unreachable!()
 				},
@@ -922,6 +980,25 @@ impl<T: Config> VtokenMintingInterface<AccountIdOf<T>, CurrencyIdOf<T>, BalanceO
 		v_currency_amount: BalanceOf<T>,
 		redeem_type: RedeemType<AccountIdOf<T>>,
 	) -> DispatchResultWithPostInfo {
+		// The xcm fee of a registered destination is prepaid by the exchanger to the entrance
+		// account, which transfers it along with the redeemed currency
+		if let RedeemType::Destination { fee: Some((fee_currency, fee_amount)), .. } = &redeem_type
+		{
+			let currency_id =
+				v_currency_id.to_token().map_err(|_| Error::<T>::NotSupportTokenType)?;
+			let fee_amount: BalanceOf<T> = (*fee_amount).saturated_into();
+			T::MultiCurrency::transfer(
+				*fee_currency,
+				&exchanger,
+				&T::EntranceAccount::get().into_account_truncating(),
+				fee_amount,
+			)?;
+			PrepaidRedeemFees::<T>::insert(
+				currency_id,
+				TokenUnlockNextId::<T>::get(currency_id),
+				(*fee_currency, fee_amount),
+			);
+		}
 		Self::do_redeem(exchanger, v_currency_id, v_currency_amount, redeem_type)
 	}
 
@@ -397,6 +397,20 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The xcm fee prepaid to the entrance account for an unlock redeemed to a registered
+	/// destination, by currency_id and unlock_id. It's transferred along with the unlock to the
+	/// destination once, or refunded to the redeemer if the unlock ends without it.
+	#[pallet::storage]
+	pub type PrepaidRedeemFees<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyIdOf<T>,
+		Blake2_128Concat,
+		UnlockId,
+		(CurrencyIdOf<T>, BalanceOf<T>),
+		OptionQuery,
+	>;
+
 	/// According to the user's account, the locked amount and unlock id list are stored.
 	#[pallet::storage]
 	pub type UserUnlockLedger<T: Config> = StorageDoubleMap<
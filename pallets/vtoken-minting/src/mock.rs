@@ -29,7 +29,10 @@ use bifrost_primitives::{
 	IncentivePoolAccount, MockXcmTransfer, MoonbeamChainId, SlpxOperator, KUSD,
 };
 use bifrost_runtime_common::{micro, milli};
-use frame_support::{derive_impl, ord_parameter_types, parameter_types, traits::Nothing};
+use frame_support::{
+	derive_impl, ord_parameter_types, parameter_types,
+	traits::{Everything, Nothing},
+};
 use frame_system::EnsureSignedBy;
 use orml_traits::parameter_type_with_key;
 use sp_runtime::{
@@ -84,6 +87,7 @@ pub type AdaptedBasicCurrency =
 
 impl bifrost_currencies::Config for Runtime {
 	type GetNativeCurrencyId = NativeCurrencyId;
+	type TransferFilter = Everything;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type WeightInfo = ();
@@ -23,10 +23,11 @@
 use crate::{mock::*, DispatchError::Module, *};
 use bifrost_primitives::{
 	currency::{BNC, FIL, KSM, MOVR, VBNC, VFIL, VKSM, VMOVR},
-	VtokenMintingOperator,
+	VtokenMintingInterface, VtokenMintingOperator,
 };
 use frame_support::{assert_noop, assert_ok, sp_runtime::Permill, BoundedVec};
 use sp_runtime::ModuleError;
+use xcm::v4::Location;
 
 #[test]
 fn mint_bnc() {
@@ -418,6 +419,74 @@ fn rebond_by_unlock_id() {
 	});
 }
 
+const XCM_FEE: Balance = 100_000_000_000;
+
+fn slpx_redeem_to_destination() {
+	assert_ok!(VtokenMinting::set_unlock_duration(
+		RuntimeOrigin::signed(ALICE),
+		KSM,
+		TimeUnit::Era(0)
+	));
+	assert_ok!(VtokenMinting::set_hook_iteration_limit(RuntimeOrigin::signed(ALICE), 1));
+	assert_ok!(VtokenMinting::increase_token_pool(KSM, 1000));
+	assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+	assert_ok!(VtokenMinting::mint(Some(BOB).into(), KSM, 100, BoundedVec::default(), None));
+	assert_ok!(VtokenMinting::slpx_redeem(
+		BOB,
+		VKSM,
+		100,
+		RedeemType::Destination {
+			location: Location::parent(),
+			weight_limit: None,
+			fee: Some((BNC, XCM_FEE))
+		}
+	));
+	assert_eq!(PrepaidRedeemFees::<Runtime>::get(KSM, 0), Some((BNC, XCM_FEE)));
+}
+
+#[test]
+fn partial_redeem_to_destination_should_spend_prepaid_fee_once() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		let bob_bnc = Balances::free_balance(&BOB);
+		slpx_redeem_to_destination();
+		assert_eq!(Balances::free_balance(&BOB), bob_bnc - XCM_FEE);
+
+		// only part of the unlock is available, it's redeemed natively
+		let (entrance_account, _exit_account) = VtokenMinting::get_entrance_and_exit_accounts();
+		assert_ok!(Tokens::withdraw(KSM, &entrance_account, 40));
+		MinTimeUnit::<Runtime>::insert(KSM, TimeUnit::Era(1));
+		assert_ok!(VtokenMinting::handle_ledger_by_currency(KSM));
+		assert_eq!(Tokens::free_balance(KSM, &entrance_account), 0);
+		assert_eq!(TokenUnlockLedger::<Runtime>::get(KSM, 0).map(|ledger| ledger.1), Some(40));
+		assert_eq!(PrepaidRedeemFees::<Runtime>::get(KSM, 0), Some((BNC, XCM_FEE)));
+
+		// the rest is transferred to the destination along with the prepaid fee
+		assert_ok!(Tokens::deposit(KSM, &entrance_account, 40));
+		assert_ok!(VtokenMinting::handle_ledger_by_currency(KSM));
+		assert_eq!(TokenUnlockLedger::<Runtime>::get(KSM, 0), None);
+		assert_eq!(PrepaidRedeemFees::<Runtime>::get(KSM, 0), None);
+		assert_eq!(Balances::free_balance(&BOB), bob_bnc - XCM_FEE);
+	});
+}
+
+#[test]
+fn rebond_should_refund_prepaid_redeem_fee() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		let bob_bnc = Balances::free_balance(&BOB);
+		slpx_redeem_to_destination();
+		assert_ok!(VtokenMinting::add_support_rebond_token(RuntimeOrigin::signed(ALICE), KSM));
+
+		// a partial rebond keeps the fee for the rest of the unlock
+		assert_ok!(VtokenMinting::rebond(Some(BOB).into(), KSM, 60));
+		assert_eq!(PrepaidRedeemFees::<Runtime>::get(KSM, 0), Some((BNC, XCM_FEE)));
+		assert_eq!(Balances::free_balance(&BOB), bob_bnc - XCM_FEE);
+
+		assert_ok!(VtokenMinting::rebond_by_unlock_id(Some(BOB).into(), KSM, 0));
+		assert_eq!(PrepaidRedeemFees::<Runtime>::get(KSM, 0), None);
+		assert_eq!(Balances::free_balance(&BOB), bob_bnc);
+	});
+}
+
 #[test]
 fn fast_redeem_for_fil() {
 	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
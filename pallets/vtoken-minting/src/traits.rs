@@ -22,6 +22,7 @@ use frame_support::pallet_prelude::Weight;
 use parity_scale_codec::{Decode, Encode};
 use sp_core::H160;
 use sp_runtime::RuntimeDebug;
+use xcm::v4::Location;
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
 pub enum RedeemTo<AccountId> {
@@ -37,6 +38,8 @@ pub enum RedeemTo<AccountId> {
 	Interlay(AccountId),
 	/// Manta chain.
 	Manta(AccountId),
+	/// Destination chain registered in slpx.
+	Destination(Location),
 }
 
 pub trait OnRedeemSuccess<AccountId, CurrencyId, Balance> {
@@ -280,6 +280,8 @@ impl WeightInfo for () {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
 	/// Storage: VtokenMinting Fees (r:1 w:0)
 	/// Proof: VtokenMinting Fees (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting PrepaidRedeemFees (r:1 w:1)
+	/// Proof: VtokenMinting PrepaidRedeemFees (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	/// Storage: Tokens Accounts (r:3 w:3)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
 	/// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
@@ -290,8 +292,8 @@ impl WeightInfo for () {
 		//  Estimated: `8769`
 		// Minimum execution time: 234_399_000 picoseconds.
 		Weight::from_parts(237_749_000, 8769)
-			.saturating_add(RocksDbWeight::get().reads(12_u64))
-			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
 	}
 	/// Storage: VtokenMinting TokenToRebond (r:1 w:1)
 	/// Proof: VtokenMinting TokenToRebond (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
@@ -309,6 +311,8 @@ impl WeightInfo for () {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
 	/// Storage: VtokenMinting Fees (r:1 w:0)
 	/// Proof: VtokenMinting Fees (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting PrepaidRedeemFees (r:1 w:1)
+	/// Proof: VtokenMinting PrepaidRedeemFees (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	/// Storage: Tokens Accounts (r:3 w:3)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
 	/// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
@@ -319,8 +323,8 @@ impl WeightInfo for () {
 		//  Estimated: `8769`
 		// Minimum execution time: 224_388_000 picoseconds.
 		Weight::from_parts(227_544_000, 8769)
-			.saturating_add(RocksDbWeight::get().reads(12_u64))
-			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
 	}
 	/// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
 	/// Proof: VtokenMinting OngoingTimeUnit (max_values: None, max_size: Some(27), added: 2502, mode: MaxEncodedLen)
@@ -0,0 +1,74 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AssetStatus, CurrencyId};
+use frame_support::weights::Weight;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+use xcm::VersionedLocation;
+
+/// The category of an asset.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
+pub enum AssetCategory {
+	Native,
+	VToken,
+	VSToken,
+	Foreign,
+	LpToken,
+	LendToken,
+}
+
+/// Optional metadata of an asset, in addition to its name, symbol, decimals and minimal balance.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub struct ExtendedAssetMetadata<AccountId> {
+	/// The hash of the logo URI.
+	pub logo_uri_hash: Option<H256>,
+	pub issuer: Option<AccountId>,
+	pub category: AssetCategory,
+	/// Whether holding the asset is sufficient for an account to exist.
+	pub is_sufficient: bool,
+}
+
+/// The role a location is trusted with for an asset received over XCM.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
+pub enum XcmTransferRule {
+	/// The location is a reserve of the asset.
+	Reserve,
+	/// The location can teleport the asset.
+	Teleport,
+}
+
+/// An asset of the asset registry with all its metadata.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub struct AssetInfo<AccountId, Balance> {
+	pub currency_id: CurrencyId,
+	pub name: Vec<u8>,
+	pub symbol: Vec<u8>,
+	pub decimals: u8,
+	pub minimal_balance: Balance,
+	pub status: AssetStatus,
+	pub location: Option<VersionedLocation>,
+	/// The XCM weight used to price the asset as a fee asset.
+	pub xcm_weight: Option<Weight>,
+	pub extended: Option<ExtendedAssetMetadata<AccountId>>,
+	/// The locations trusted for the asset over XCM.
+	pub xcm_rules: Vec<(VersionedLocation, XcmTransferRule)>,
+}
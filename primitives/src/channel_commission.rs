@@ -0,0 +1,57 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::CurrencyId;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{Percent, Permill, RuntimeDebug};
+
+/// The settlement result of a channel for one vtoken in a clearing period.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ChannelSettlement<Balance> {
+	/// The clearing period the settlement belongs to.
+	pub period: u32,
+	pub vtoken: CurrencyId,
+	pub commission_token: CurrencyId,
+	/// The vtoken amount minted through the channel in the period.
+	pub channel_mint: Balance,
+	/// The vtoken amount minted on the chain in the period.
+	pub total_mint: Balance,
+	/// The vtoken amount redeemed on the chain in the period.
+	pub total_redeem: Balance,
+	/// The channel share of the vtoken used for the settlement.
+	pub share: Permill,
+	/// The channel commission rate used for the settlement.
+	pub rate: Percent,
+	/// The commission amount credited to the channel.
+	pub commission: Balance,
+}
+
+/// The ranking of a channel for one vtoken in the ongoing period.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ChannelRanking<Balance> {
+	pub channel_id: u32,
+	/// The vtoken amount minted through the channel in the ongoing period.
+	pub mint: Balance,
+	/// The current channel share of the vtoken.
+	pub share: Permill,
+}
@@ -0,0 +1,57 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::CurrencyId;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_core::H256;
+use sp_runtime::RuntimeDebug;
+
+/// The status of a cross-out request.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum CrossOutStatus {
+	/// Waiting for a bridge operator, the crosser can ask to cancel it after the timeout.
+	Pending,
+	/// Picked up by a bridge operator.
+	Processing,
+	/// Transferred on the foreign chain.
+	Completed,
+	/// Cancelled by the crosser and refunded by a bridge operator.
+	Refunded,
+	/// Cancelled by the crosser, waiting for a bridge operator to confirm it was not transferred
+	/// and refund it.
+	Cancelling,
+}
+
+/// A cross-out request of an account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CrossOutRequest<AccountId, Balance, BlockNumber> {
+	pub crosser: AccountId,
+	pub currency_id: CurrencyId,
+	pub amount: Balance,
+	pub status: CrossOutStatus,
+	/// The block the request was created at.
+	pub created_at: BlockNumber,
+	/// The block the status was last updated at.
+	pub updated_at: BlockNumber,
+	/// The hash of the transfer on the foreign chain, reported on completion.
+	pub foreign_tx_hash: Option<H256>,
+}
@@ -31,6 +31,12 @@ use sp_runtime::{
 	FixedU128, MultiSignature, OpaqueExtrinsic, Permill,
 };
 
+pub mod asset_registry;
+pub use asset_registry::*;
+pub mod channel_commission;
+pub use channel_commission::*;
+pub mod cross_in_out;
+pub use cross_in_out::*;
 pub mod currency;
 pub use currency::*;
 pub mod xcm;
@@ -44,8 +50,14 @@ pub mod salp;
 pub use salp::*;
 pub mod traits;
 pub use crate::traits::*;
+pub mod stable_pool;
+pub use crate::stable_pool::*;
 pub mod time_unit;
 pub use crate::time_unit::*;
+pub mod token_issuer;
+pub use crate::token_issuer::*;
+pub mod vesting;
+pub use crate::vesting::*;
 
 #[cfg(test)]
 mod tests;
@@ -220,6 +232,15 @@ pub enum RedeemType<AccountId> {
 	Interlay(AccountId),
 	/// Manta chain.
 	Manta(AccountId),
+	/// Destination chain registered in slpx.
+	Destination {
+		/// The receiver on the destination chain.
+		location: ::xcm::v4::Location,
+		/// The xcm weight limit on the destination chain, unlimited if `None`.
+		weight_limit: Option<frame_support::weights::Weight>,
+		/// The xcm fee transferred along in another currency, prepaid by the redeemer.
+		fee: Option<(CurrencyId, Balance)>,
+	},
 }
 
 impl<AccountId> Default for RedeemType<AccountId> {
@@ -228,6 +249,35 @@ impl<AccountId> Default for RedeemType<AccountId> {
 	}
 }
 
+/// The lifecycle status of an asset in the asset registry.
+#[derive(
+	PartialEq, Eq, Copy, Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo, Default,
+)]
+pub enum AssetStatus {
+	/// The asset can be used without restriction.
+	#[default]
+	Active,
+	/// The asset can't be transferred.
+	Frozen,
+	/// The asset can only be transferred, redeemed or withdrawn. No new positions can be
+	/// opened with it.
+	Deprecated,
+	/// The asset can't be used anymore. Its metadata is kept.
+	Destroyed,
+}
+
+impl AssetStatus {
+	/// Whether new positions can be opened with the asset.
+	pub fn is_active(&self) -> bool {
+		matches!(self, Self::Active)
+	}
+
+	/// Whether the asset can be transferred, including redeeming or withdrawing it.
+	pub fn is_transferable(&self) -> bool {
+		matches!(self, Self::Active | Self::Deprecated)
+	}
+}
+
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
 pub enum XcmOperationType {
 	// SALP operations
@@ -86,13 +86,19 @@ impl XcmTransfer<AccountId, Balance, CurrencyId> for MockXcmTransfer {
 	}
 
 	fn transfer_multicurrencies(
-		_who: AccountId,
-		_currencies: Vec<(CurrencyId, Balance)>,
-		_fee_item: u32,
-		_dest: Location,
+		who: AccountId,
+		currencies: Vec<(CurrencyId, Balance)>,
+		fee_item: u32,
+		dest: Location,
 		_dest_weight_limit: WeightLimit,
 	) -> Result<Transferred<AccountId>, DispatchError> {
-		unimplemented!()
+		let fee = currencies.get(fee_item as usize).map_or(0, |(_, amount)| *amount);
+		Ok(Transferred {
+			sender: who,
+			assets: Default::default(),
+			fee: Asset { id: AssetId(Location::here()), fun: Fungible(fee) },
+			dest,
+		})
 	}
 
 	fn transfer_multiassets(
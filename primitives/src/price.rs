@@ -16,7 +16,24 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Balance, CurrencyId, Price, PriceDetail};
+use crate::{Balance, CurrencyId, Price, PriceDetail, Timestamp};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::RuntimeDebug;
+
+/// The price of an asset taken at a block, in the format of `OraclePriceProvider::get_price`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PriceSnapshot<BlockNumber> {
+	/// The block the snapshot was taken at.
+	pub block_number: BlockNumber,
+	/// The price of the asset.
+	pub price: Price,
+	/// The timestamp of the price, zero for an emergency price.
+	pub timestamp: Timestamp,
+}
 
 pub trait OraclePriceProvider {
 	fn get_price(asset_id: &CurrencyId) -> Option<PriceDetail>;
@@ -33,3 +50,27 @@ pub trait OraclePriceProvider {
 		currency_out: &CurrencyId,
 	) -> Option<(Balance, Price, Price)>;
 }
+
+impl OraclePriceProvider for () {
+	fn get_price(_asset_id: &CurrencyId) -> Option<PriceDetail> {
+		None
+	}
+
+	fn get_amount_by_prices(
+		_currency_in: &CurrencyId,
+		_amount_in: Balance,
+		_currency_in_price: Price,
+		_currency_out: &CurrencyId,
+		_currency_out_price: Price,
+	) -> Option<Balance> {
+		None
+	}
+
+	fn get_oracle_amount_by_currency_and_amount_in(
+		_currency_in: &CurrencyId,
+		_amount_in: Balance,
+		_currency_out: &CurrencyId,
+	) -> Option<(Balance, Price, Price)> {
+		None
+	}
+}
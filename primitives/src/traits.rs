@@ -21,8 +21,8 @@
 #![allow(clippy::unnecessary_cast)]
 
 use crate::{
-	AssetIds, CurrencyId, DerivativeIndex, LeasePeriod, ParaId, PoolId, RedeemType, TokenId,
-	TokenSymbol, XcmOperationType,
+	AssetIds, AssetStatus, CurrencyId, DerivativeIndex, LeasePeriod, ParaId, PoolId, RedeemType,
+	TokenId, TokenSymbol, XcmOperationType,
 };
 use frame_support::pallet_prelude::{DispatchResultWithPostInfo, Weight};
 use parity_scale_codec::{Decode, Encode, FullCodec};
@@ -154,6 +154,8 @@ pub trait CurrencyIdMapping<CurrencyId, MultiLocation, AssetMetadata> {
 	fn get_currency_id(multi_location: xcm::v4::Location) -> Option<CurrencyId>;
 	/// Returns all currencies in currencyMetadata.
 	fn get_all_currency() -> Vec<CurrencyId>;
+	/// Returns the lifecycle status of a given CurrencyId.
+	fn get_asset_status(currency_id: CurrencyId) -> AssetStatus;
 }
 
 pub trait CurrencyIdConversion<CurrencyId> {
@@ -234,6 +236,32 @@ pub trait FarmingInfo<Balance, CurrencyId> {
 	fn get_token_shares(pool_id: PoolId, currency_id: CurrencyId) -> Balance;
 }
 
+/// The interface to stake into and unstake from farming pools on behalf of an account.
+pub trait FarmingOperator<AccountId, Balance, CurrencyId> {
+	/// Get the token staked by the farming pool, if the pool stakes a single token one to one.
+	fn get_staking_token(pool_id: PoolId) -> Option<CurrencyId>;
+
+	/// Deposit `amount` shares into the farming pool for `who`.
+	fn deposit(who: &AccountId, pool_id: PoolId, amount: Balance) -> DispatchResult;
+
+	/// Withdraw `amount` shares from the farming pool and claim the withdrawable tokens of `who`.
+	fn withdraw(who: &AccountId, pool_id: PoolId, amount: Balance) -> DispatchResult;
+}
+
+impl<AccountId, Balance, CurrencyId> FarmingOperator<AccountId, Balance, CurrencyId> for () {
+	fn get_staking_token(_pool_id: PoolId) -> Option<CurrencyId> {
+		None
+	}
+
+	fn deposit(_who: &AccountId, _pool_id: PoolId, _amount: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn withdraw(_who: &AccountId, _pool_id: PoolId, _amount: Balance) -> DispatchResult {
+		Ok(())
+	}
+}
+
 pub trait VtokenMintingInterface<AccountId, CurrencyId, Balance> {
 	fn mint(
 		exchanger: AccountId,
@@ -233,7 +233,7 @@ pub mod v1 {
 
 pub mod slpx_migrates_whitelist {
 	use super::*;
-	use bifrost_slpx::types::SupportChain;
+	use bifrost_slpx::types::{ASTAR_DESTINATION, MOONBEAM_DESTINATION};
 	use sp_core::crypto::Ss58Codec;
 
 	pub struct UpdateWhitelist;
@@ -245,7 +245,7 @@ pub mod slpx_migrates_whitelist {
 				.try_into()
 				.unwrap();
 			bifrost_slpx::WhitelistAccountId::<Runtime>::insert(
-				SupportChain::Moonbeam,
+				MOONBEAM_DESTINATION,
 				new_whitelist,
 			);
 
@@ -254,8 +254,7 @@ pub mod slpx_migrates_whitelist {
 
 		#[cfg(feature = "try-runtime")]
 		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
-			let whitelist =
-				bifrost_slpx::WhitelistAccountId::<Runtime>::get(SupportChain::Moonbeam);
+			let whitelist = bifrost_slpx::WhitelistAccountId::<Runtime>::get(MOONBEAM_DESTINATION);
 			let new_whitelist: BoundedVec<AccountId, ConstU32<10>> =
 				vec![AccountId::from_ss58check("gtXJWw9ME9w7cXfmR6n9MFkKCSu2MrtA3dcFV2BhHpEZFjZ")
 					.unwrap()]
@@ -123,4 +123,43 @@ impl<T: frame_system::Config> bifrost_asset_registry::WeightInfo for BifrostWeig
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: `AssetRegistry::CurrencyMetadatas` (r:1 w:0)
+	// Proof: `AssetRegistry::CurrencyMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::AssetStatuses` (r:1 w:1)
+	// Proof: `AssetRegistry::AssetStatuses` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_asset_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `409`
+		//  Estimated: `3874`
+		// Minimum execution time: 13_580 nanoseconds.
+		Weight::from_parts(14_000_000, 3874)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `AssetRegistry::CurrencyMetadatas` (r:1 w:0)
+	// Proof: `AssetRegistry::CurrencyMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::ExtendedMetadatas` (r:0 w:1)
+	// Proof: `AssetRegistry::ExtendedMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_extended_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `409`
+		//  Estimated: `3874`
+		// Minimum execution time: 14_550 nanoseconds.
+		Weight::from_parts(15_000_000, 3874)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `AssetRegistry::CurrencyIdToLocations` (r:1 w:0)
+	// Proof: `AssetRegistry::CurrencyIdToLocations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::XcmTransferRules` (r:0 w:1)
+	// Proof: `AssetRegistry::XcmTransferRules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_xcm_transfer_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `515`
+		//  Estimated: `3980`
+		// Minimum execution time: 15_520 nanoseconds.
+		Weight::from_parts(16_000_000, 3980)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
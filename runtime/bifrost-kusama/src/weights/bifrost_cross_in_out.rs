@@ -126,14 +126,20 @@ impl<T: frame_system::Config> bifrost_cross_in_out::WeightInfo for BifrostWeight
 	// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
 	// Storage: Tokens TotalIssuance (r:1 w:1)
 	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	// Storage: CrossInOut NextCrossOutRequestId (r:1 w:1)
+	// Proof Skipped: CrossInOut NextCrossOutRequestId (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CrossInOut AccountCrossOutRequests (r:0 w:1)
+	// Proof Skipped: CrossInOut AccountCrossOutRequests (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut CrossOutRequests (r:0 w:1)
+	// Proof Skipped: CrossInOut CrossOutRequests (max_values: None, max_size: None, mode: Measured)
 	fn cross_out() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1781`
 		//  Estimated: `5246`
 		// Minimum execution time: 121_799 nanoseconds.
 		Weight::from_parts(124_055_000, 5246)
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	// Storage: CrossInOut CrossCurrencyRegistry (r:1 w:0)
 	// Proof Skipped: CrossInOut CrossCurrencyRegistry (max_values: None, max_size: None, mode: Measured)
@@ -150,4 +156,192 @@ impl<T: frame_system::Config> bifrost_cross_in_out::WeightInfo for BifrostWeight
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	// Storage: CrossInOut CrossCurrencyRegistry (r:1 w:0)
+	// Proof Skipped: CrossInOut CrossCurrencyRegistry (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut PausedCrossIns (r:1 w:0)
+	// Proof Skipped: CrossInOut PausedCrossIns (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut CrossedInTransactions (r:1 w:1)
+	// Proof Skipped: CrossInOut CrossedInTransactions (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut CrossInAttestors (r:1 w:0)
+	// Proof Skipped: CrossInOut CrossInAttestors (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut CrossInThreshold (r:1 w:0)
+	// Proof Skipped: CrossInOut CrossInThreshold (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut CrossingMinimumAmount (r:1 w:0)
+	// Proof Skipped: CrossInOut CrossingMinimumAmount (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut PendingCrossIns (r:1 w:1)
+	// Proof Skipped: CrossInOut PendingCrossIns (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut CrossInAttestations (r:500 w:500)
+	// Proof Skipped: CrossInOut CrossInAttestations (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut CrossInRateLimits (r:1 w:1)
+	// Proof Skipped: CrossInOut CrossInRateLimits (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	// The range of component `n` is `[1, 500]`.
+	fn cross_in(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1077 + n * (97 ±0)`
+		//  Estimated: `6142 + n * (2572 ±0)`
+		// Minimum execution time: 114_849_000 picoseconds.
+		Weight::from_parts(118_402_000, 6142)
+			// Standard Error: 21_318
+			.saturating_add(Weight::from_parts(8_124_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2572).saturating_mul(n.into()))
+	}
+	// Storage: CrossInOut CrossInAttestors (r:1 w:1)
+	// Proof Skipped: CrossInOut CrossInAttestors (max_values: None, max_size: None, mode: Measured)
+	fn add_cross_in_attestor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `119`
+		//  Estimated: `3584`
+		// Minimum execution time: 35_182_000 picoseconds.
+		Weight::from_parts(36_271_000, 3584)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CrossInOut CrossInAttestors (r:1 w:1)
+	// Proof Skipped: CrossInOut CrossInAttestors (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut CrossInThreshold (r:1 w:0)
+	// Proof Skipped: CrossInOut CrossInThreshold (max_values: None, max_size: None, mode: Measured)
+	fn remove_cross_in_attestor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `179`
+		//  Estimated: `3644`
+		// Minimum execution time: 37_747_000 picoseconds.
+		Weight::from_parts(38_915_000, 3644)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CrossInOut CrossInAttestors (r:1 w:0)
+	// Proof Skipped: CrossInOut CrossInAttestors (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut CrossInThreshold (r:0 w:1)
+	// Proof Skipped: CrossInOut CrossInThreshold (max_values: None, max_size: None, mode: Measured)
+	fn set_cross_in_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `179`
+		//  Estimated: `3644`
+		// Minimum execution time: 27_408_000 picoseconds.
+		Weight::from_parts(28_261_000, 3644)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CrossInOut CrossInRateLimits (r:1 w:1)
+	// Proof Skipped: CrossInOut CrossInRateLimits (max_values: None, max_size: None, mode: Measured)
+	fn set_cross_in_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `119`
+		//  Estimated: `3584`
+		// Minimum execution time: 28_873_000 picoseconds.
+		Weight::from_parts(29_766_000, 3584)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CrossInOut PausedCrossIns (r:0 w:1)
+	// Proof Skipped: CrossInOut PausedCrossIns (max_values: None, max_size: None, mode: Measured)
+	fn set_cross_in_paused() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 20_188_000 picoseconds.
+		Weight::from_parts(20_813_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CrossInOut CrossOutOperators (r:1 w:1)
+	// Proof Skipped: CrossInOut CrossOutOperators (max_values: None, max_size: None, mode: Measured)
+	fn add_cross_out_operator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `119`
+		//  Estimated: `3584`
+		// Minimum execution time: 34_865_000 picoseconds.
+		Weight::from_parts(35_944_000, 3584)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CrossInOut CrossOutOperators (r:1 w:1)
+	// Proof Skipped: CrossInOut CrossOutOperators (max_values: None, max_size: None, mode: Measured)
+	fn remove_cross_out_operator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `179`
+		//  Estimated: `3644`
+		// Minimum execution time: 37_346_000 picoseconds.
+		Weight::from_parts(38_502_000, 3644)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CrossInOut CrossOutRequests (r:1 w:1)
+	// Proof Skipped: CrossInOut CrossOutRequests (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut CrossOutOperators (r:1 w:0)
+	// Proof Skipped: CrossInOut CrossOutOperators (max_values: None, max_size: None, mode: Measured)
+	fn process_cross_out() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `322`
+		//  Estimated: `3787`
+		// Minimum execution time: 39_854_000 picoseconds.
+		Weight::from_parts(41_087_000, 3787)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CrossInOut CrossOutRequests (r:1 w:1)
+	// Proof Skipped: CrossInOut CrossOutRequests (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut CrossOutOperators (r:1 w:0)
+	// Proof Skipped: CrossInOut CrossOutOperators (max_values: None, max_size: None, mode: Measured)
+	fn complete_cross_out() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `322`
+		//  Estimated: `3787`
+		// Minimum execution time: 41_045_000 picoseconds.
+		Weight::from_parts(42_315_000, 3787)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CrossInOut CrossOutRequests (r:1 w:1)
+	// Proof Skipped: CrossInOut CrossOutRequests (max_values: None, max_size: None, mode: Measured)
+	fn cancel_cross_out() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `322`
+		//  Estimated: `3787`
+		// Minimum execution time: 36_418_000 picoseconds.
+		Weight::from_parts(37_592_000, 3787)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CrossInOut PendingCrossIns (r:1 w:499)
+	// Proof Skipped: CrossInOut PendingCrossIns (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut CrossInAttestations (r:0 w:499)
+	// Proof Skipped: CrossInOut CrossInAttestations (max_values: None, max_size: None, mode: Measured)
+	// The range of component `n` is `[1, 499]`.
+	fn remove_pending_cross_in(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `253 + n * (142 ±0)`
+		//  Estimated: `6127 + n * (2617 ±0)`
+		// Minimum execution time: 37_067_000 picoseconds.
+		Weight::from_parts(38_214_000, 6127)
+			// Standard Error: 18_904
+			.saturating_add(Weight::from_parts(11_503_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2617).saturating_mul(n.into()))
+	}
+	// Storage: CrossInOut CrossOutRequests (r:1 w:1)
+	// Proof Skipped: CrossInOut CrossOutRequests (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut CrossOutOperators (r:1 w:0)
+	// Proof Skipped: CrossInOut CrossOutOperators (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	fn refund_cross_out() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1259`
+		//  Estimated: `3787`
+		// Minimum execution time: 89_361_000 picoseconds.
+		Weight::from_parts(91_905_000, 3787)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
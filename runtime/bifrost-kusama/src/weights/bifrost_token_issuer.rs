@@ -106,14 +106,26 @@ impl<T: frame_system::Config> bifrost_token_issuer::WeightInfo for BifrostWeight
 	// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
 	// Storage: Tokens TotalIssuance (r:1 w:1)
 	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	// Storage: TokenIssuer MaxSupply (r:1 w:0)
+	// Proof Skipped: TokenIssuer MaxSupply (max_values: None, max_size: None, mode: Measured)
+	// Storage: TokenIssuer NextIssuanceId (r:1 w:1)
+	// Proof Skipped: TokenIssuer NextIssuanceId (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: TokenIssuer ApprovalThresholds (r:1 w:0)
+	// Proof Skipped: TokenIssuer ApprovalThresholds (max_values: None, max_size: None, mode: Measured)
+	// Storage: TokenIssuer IssuerQuotas (r:1 w:1)
+	// Proof Skipped: TokenIssuer IssuerQuotas (max_values: None, max_size: None, mode: Measured)
+	// Storage: TokenIssuer IssuanceRecords (r:0 w:2)
+	// Proof Skipped: TokenIssuer IssuanceRecords (max_values: None, max_size: None, mode: Measured)
+	// Storage: TokenIssuer CurrencyIssuances (r:1 w:1)
+	// Proof Skipped: TokenIssuer CurrencyIssuances (max_values: None, max_size: None, mode: Measured)
 	fn issue() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1870`
-		//  Estimated: `5335`
-		// Minimum execution time: 104_659 nanoseconds.
-		Weight::from_parts(106_259_000, 5335)
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(2))
+		//  Measured:  `2105`
+		//  Estimated: `6204`
+		// Minimum execution time: 141_380 nanoseconds.
+		Weight::from_parts(144_917_000, 6204)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	// Storage: TokenIssuer TransferWhiteList (r:1 w:0)
 	// Proof Skipped: TokenIssuer TransferWhiteList (max_values: None, max_size: None, mode: Measured)
@@ -130,4 +142,86 @@ impl<T: frame_system::Config> bifrost_token_issuer::WeightInfo for BifrostWeight
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	// Storage: TokenIssuer MaxSupply (r:0 w:1)
+	// Proof Skipped: TokenIssuer MaxSupply (max_values: None, max_size: None, mode: Measured)
+	fn set_max_supply() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 20_814 nanoseconds.
+		Weight::from_parts(21_458_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: TokenIssuer IssuerQuotas (r:1 w:1)
+	// Proof Skipped: TokenIssuer IssuerQuotas (max_values: None, max_size: None, mode: Measured)
+	fn set_issuer_quota() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3607`
+		// Minimum execution time: 32_768 nanoseconds.
+		Weight::from_parts(33_782_000, 3607)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: TokenIssuer ApprovalThresholds (r:0 w:1)
+	// Proof Skipped: TokenIssuer ApprovalThresholds (max_values: None, max_size: None, mode: Measured)
+	fn set_approval_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 20_493 nanoseconds.
+		Weight::from_parts(21_127_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: TokenIssuer IssueProposals (r:1 w:1)
+	// Proof Skipped: TokenIssuer IssueProposals (max_values: None, max_size: None, mode: Measured)
+	// Storage: TokenIssuer PendingProposals (r:1 w:1)
+	// Proof Skipped: TokenIssuer PendingProposals (max_values: None, max_size: None, mode: Measured)
+	// Storage: TokenIssuer MaxSupply (r:1 w:0)
+	// Proof Skipped: TokenIssuer MaxSupply (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
+	// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	// Storage: TokenIssuer IssuanceRecords (r:0 w:2)
+	// Proof Skipped: TokenIssuer IssuanceRecords (max_values: None, max_size: None, mode: Measured)
+	// Storage: TokenIssuer CurrencyIssuances (r:1 w:1)
+	// Proof Skipped: TokenIssuer CurrencyIssuances (max_values: None, max_size: None, mode: Measured)
+	fn approve_issue() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2147`
+		//  Estimated: `6012`
+		// Minimum execution time: 114_813 nanoseconds.
+		Weight::from_parts(118_364_000, 6012)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	// Storage: TokenIssuer IssueProposals (r:1 w:1)
+	// Proof Skipped: TokenIssuer IssueProposals (max_values: None, max_size: None, mode: Measured)
+	// Storage: TokenIssuer PendingProposals (r:1 w:1)
+	// Proof Skipped: TokenIssuer PendingProposals (max_values: None, max_size: None, mode: Measured)
+	fn reject_issue() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `330`
+		//  Estimated: `3795`
+		// Minimum execution time: 33_188 nanoseconds.
+		Weight::from_parts(34_215_000, 3795)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: TokenIssuer IssueProposals (r:1 w:1)
+	// Proof Skipped: TokenIssuer IssueProposals (max_values: None, max_size: None, mode: Measured)
+	// Storage: TokenIssuer PendingProposals (r:1 w:1)
+	// Proof Skipped: TokenIssuer PendingProposals (max_values: None, max_size: None, mode: Measured)
+	fn remove_expired_issue() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `395`
+		//  Estimated: `3860`
+		// Minimum execution time: 35_902 nanoseconds.
+		Weight::from_parts(36_947_000, 3860)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -27,7 +27,7 @@ pub use crate::evm::accounts_conversion::{ExtendedAddressMapping, FindAuthorTrun
 use crate::{
 	evm::runner::{FeeAssetBalanceInCurrency, WrapRunner},
 	governance::TechAdminOrCouncil,
-	Aura, ConstU32, DynamicFee, EVMChainId, Runtime, RuntimeEvent, Timestamp, Weight, EVM,
+	Aura, ConstU32, DynamicFee, EVMChainId, Runtime, RuntimeEvent, Timestamp, Weight, DAYS, EVM,
 	MAXIMUM_BLOCK_WEIGHT, NORMAL_DISPATCH_RATIO, WEIGHT_REF_TIME_PER_SECOND,
 };
 use bifrost_primitives::{currency::WETH, CurrencyId};
@@ -148,6 +148,8 @@ impl pallet_evm_accounts::Config for Runtime {
 	type FeeMultiplier = ConstU32<50>;
 	type EvmNonceProvider = EvmNonceProvider;
 	type ControllerOrigin = TechAdminOrCouncil;
+	type ChainId = EVMChainId;
+	type UnbindCooldown = ConstU32<DAYS>;
 	type WeightInfo = ();
 }
 
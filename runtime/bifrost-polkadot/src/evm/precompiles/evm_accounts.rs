@@ -0,0 +1,101 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	evm::precompiles::{
+		handle::{EvmDataWriter, FunctionModifier, PrecompileHandleExt},
+		substrate::RuntimeHelper,
+		succeed, Address,
+	},
+	AccountId, EVMAccounts, Runtime,
+};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use pallet_evm::{Precompile, PrecompileHandle, PrecompileResult};
+use pallet_traits::evm::InspectEvmAccounts;
+use primitive_types::{H160, H256};
+use sp_runtime::RuntimeDebug;
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	EvmAddress = "evmAddress(bytes32)",
+	BoundEvmAddress = "boundEvmAddress(bytes32)",
+	AccountId = "accountId(address)",
+}
+
+/// Exposes the address conversions of `pallet_evm_accounts`, so that contracts can find the EVM
+/// address bound or claimed by a Substrate account.
+pub struct EvmAccountsPrecompile;
+
+impl Precompile for EvmAccountsPrecompile {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let selector = handle.read_selector()?;
+
+		handle.check_function_modifier(FunctionModifier::View)?;
+
+		match selector {
+			Action::EvmAddress => Self::evm_address(handle),
+			Action::BoundEvmAddress => Self::bound_evm_address(handle),
+			Action::AccountId => Self::account_id(handle),
+		}
+	}
+}
+
+impl EvmAccountsPrecompile {
+	fn evm_address(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		// EVMAccounts::ClaimedAddress
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+		let who = AccountId::from(input.read::<H256>()?.0);
+
+		let address = EVMAccounts::evm_address(&who);
+
+		Ok(succeed(EvmDataWriter::new().write(Address(address)).build()))
+	}
+
+	fn bound_evm_address(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		// EVMAccounts::ClaimedAddress, EVMAccounts::ClaimedAccount and
+		// EVMAccounts::AccountExtension
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(3))?;
+
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+		let who = AccountId::from(input.read::<H256>()?.0);
+
+		// the zero address if the account is not bound
+		let address = EVMAccounts::bound_evm_address(&who).unwrap_or_else(H160::zero);
+
+		Ok(succeed(EvmDataWriter::new().write(Address(address)).build()))
+	}
+
+	fn account_id(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		// EVMAccounts::ClaimedAccount and EVMAccounts::AccountExtension
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))?;
+
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+		let address: H160 = input.read::<Address>()?.into();
+
+		let who = EVMAccounts::account_id(address);
+
+		Ok(succeed(EvmDataWriter::new().write(H256::from(<[u8; 32]>::from(who))).build()))
+	}
+}
@@ -19,7 +19,8 @@
 use core::marker::PhantomData;
 
 use crate::evm::precompiles::{
-	erc20_mapping::is_asset_address, multicurrency::MultiCurrencyPrecompile,
+	erc20_mapping::is_asset_address, evm_accounts::EvmAccountsPrecompile,
+	multicurrency::MultiCurrencyPrecompile,
 };
 use ethabi::Token;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
@@ -39,6 +40,7 @@ use sp_std::{borrow::ToOwned, vec::Vec};
 
 pub mod costs;
 pub mod erc20_mapping;
+pub mod evm_accounts;
 pub mod handle;
 pub mod multicurrency;
 pub mod substrate;
@@ -81,6 +83,7 @@ impl<R> BifrostPrecompiles<R> {
 // See also
 // https://docs.moonbeam.network/builders/pallets-precompiles/precompiles/overview/#precompiled-contract-addresses
 const DISPATCH_ADDR: H160 = addr(1025);
+const EVM_ACCOUNTS_ADDR: H160 = addr(1026);
 
 pub const ECRECOVER: H160 = H160(hex!("0000000000000000000000000000000000000001"));
 pub const SHA256: H160 = H160(hex!("0000000000000000000000000000000000000002"));
@@ -137,6 +140,8 @@ where
 			Some(Blake2F::execute(handle))
 		} else if address == DISPATCH_ADDR {
 			Some(pallet_evm_precompile_dispatch::Dispatch::<R>::execute(handle))
+		} else if address == EVM_ACCOUNTS_ADDR {
+			Some(EvmAccountsPrecompile::execute(handle))
 		} else if is_asset_address(address) {
 			Some(MultiCurrencyPrecompile::<R>::execute(handle))
 		} else {
@@ -146,6 +151,7 @@ where
 
 	fn is_precompile(&self, address: H160, _remaining_gas: u64) -> IsPrecompileResult {
 		let is_precompile = address == DISPATCH_ADDR ||
+			address == EVM_ACCOUNTS_ADDR ||
 			is_asset_address(address) ||
 			is_standard_precompile(address);
 		IsPrecompileResult::Answer { is_precompile, extra_cost: 0 }
@@ -176,10 +176,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("bifrost_polkadot"),
 	impl_name: create_runtime_str!("bifrost_polkadot"),
 	authoring_version: 0,
-	spec_version: 14000,
+	spec_version: 14001,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 0,
 };
 
@@ -886,6 +886,7 @@ impl bifrost_vesting::Config for Runtime {
 	type MinVestedTransfer = ExistentialDeposit;
 	type WeightInfo = weights::bifrost_vesting::BifrostWeight<Runtime>;
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	type TreasuryAccount = BifrostTreasuryAccount;
 	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
 
@@ -893,6 +894,8 @@ impl bifrost_vesting::Config for Runtime {
 
 parameter_types! {
 	pub MaxFeeCurrencyOrderListLen: u32 = 50;
+	pub const MaxSponsoredCalls: u32 = 20;
+	pub const MaxSponsorWhitelist: u32 = 100;
 }
 
 impl bifrost_flexible_fee::Config for Runtime {
@@ -911,6 +914,10 @@ impl bifrost_flexible_fee::Config for Runtime {
 	type XcmRouter = XcmRouter;
 	type PalletId = FlexibleFeePalletId;
 	type OraclePriceProvider = Prices;
+	type StablePoolHandler = StablePool;
+	type MaxSponsoredCalls = MaxSponsoredCalls;
+	type MaxSponsorWhitelist = MaxSponsorWhitelist;
+	type AssetIdMaps = AssetIdMaps<Runtime>;
 }
 
 parameter_types! {
@@ -1143,6 +1150,7 @@ impl bifrost_farming::Config for Runtime {
 	type BlockNumberToBalance = ConvertInto;
 	type WhitelistMaximumLimit = WhitelistMaximumLimit;
 	type GaugeRewardIssuer = FarmingGaugeRewardIssuerPalletId;
+	type AssetIdMaps = AssetIdMaps<Runtime>;
 }
 
 parameter_types! {
@@ -1174,6 +1182,10 @@ impl bifrost_fee_share::Config for Runtime {
 	type OraclePriceProvider = Prices;
 }
 
+parameter_types! {
+	pub const CrossOutTimeout: BlockNumber = 2 * DAYS;
+}
+
 impl bifrost_cross_in_out::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MultiCurrency = Currencies;
@@ -1181,6 +1193,7 @@ impl bifrost_cross_in_out::Config for Runtime {
 	type EntrancePalletId = SlpEntrancePalletId;
 	type WeightInfo = weights::bifrost_cross_in_out::BifrostWeight<Runtime>;
 	type MaxLengthLimit = MaxLengthLimit;
+	type CrossOutTimeout = CrossOutTimeout;
 }
 
 impl bifrost_slpx::Config for Runtime {
@@ -1196,6 +1209,9 @@ impl bifrost_slpx::Config for Runtime {
 	type ParachainId = ParachainInfo;
 	type WeightInfo = weights::bifrost_slpx::BifrostWeight<Runtime>;
 	type MaxOrderSize = ConstU32<500>;
+	type MaxOrdersPerBlock = ConstU32<50>;
+	type MaxOrderRetries = ConstU32<5>;
+	type OrderRetryInterval = ConstU32<50>;
 }
 
 pub struct EnsurePoolAssetId;
@@ -1231,6 +1247,14 @@ impl bifrost_stable_pool::Config for Runtime {
 	type VtokenMinting = VtokenMinting;
 	type CurrencyIdConversion = AssetIdMaps<Runtime>;
 	type CurrencyIdRegister = AssetIdMaps<Runtime>;
+	type DexOperator = ZenlinkProtocol;
+	type ParachainId = ParachainInfo;
+	type MaxSwapHops = ConstU32<3>;
+	type MaxRouterPools = ConstU32<10>;
+	type OraclePriceProvider = Prices;
+	type LendMarket = LendMarket;
+	type Farming = Farming;
+	type AssetIdMaps = AssetIdMaps<Runtime>;
 }
 
 parameter_types! {
@@ -1429,6 +1453,15 @@ impl DataFeeder<CurrencyId, TimeStampedPrice, AccountId> for AggregatedDataProvi
 	}
 }
 
+parameter_types! {
+	pub const MaxVtokenPriceDeviation: Permill = Permill::from_percent(10);
+	pub const PriceTwapPeriod: Moment = 30 * 60 * 1_000;
+	pub const MaxPriceConfigs: u32 = 50;
+	pub const MaxSnapshotAssets: u32 = 50;
+	pub const MaxPriceSnapshots: u32 = 168;
+	pub const PriceSnapshotMaxAge: BlockNumber = 7 * DAYS;
+}
+
 impl pallet_prices::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Source = AggregatedDataProvider;
@@ -1437,6 +1470,16 @@ impl pallet_prices::Config for Runtime {
 	type RelayCurrency = RelayCurrencyId;
 	type CurrencyIdConvert = AssetIdMaps<Runtime>;
 	type Assets = Currencies;
+	type VtokenMinting = VtokenMinting;
+	type MaxVtokenPriceDeviation = MaxVtokenPriceDeviation;
+	type Time = Timestamp;
+	type StablePoolHandler = StablePool;
+	type XcmPriceOrigin = pallet_xcm::EnsureXcm<Everything>;
+	type TwapPeriod = PriceTwapPeriod;
+	type MaxPriceConfigs = MaxPriceConfigs;
+	type MaxSnapshotAssets = MaxSnapshotAssets;
+	type MaxPriceSnapshots = MaxPriceSnapshots;
+	type PriceSnapshotMaxAge = PriceSnapshotMaxAge;
 	type WeightInfo = pallet_prices::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1444,6 +1487,7 @@ impl lend_market::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PalletId = LendMarketPalletId;
 	type OraclePriceProvider = Prices;
+	type PriceCircuitBreaker = Prices;
 	type ReserveOrigin = TechAdminOrCouncil;
 	type UpdateOrigin = TechAdminOrCouncil;
 	type WeightInfo = lend_market::weights::BifrostWeight<Runtime>;
@@ -1452,6 +1496,7 @@ impl lend_market::Config for Runtime {
 	type RewardAssetId = NativeCurrencyId;
 	type LiquidationFreeAssetId = RelayCurrencyId;
 	type MaxLengthLimit = MaxLengthLimit;
+	type AssetIdMaps = AssetIdMaps<Runtime>;
 }
 
 parameter_types! {
@@ -1484,6 +1529,8 @@ impl leverage_staking::Config for Runtime {
 parameter_types! {
 	pub const ClearingDuration: u32 = prod_or_fast!(1 * DAYS, 10 * MINUTES);
 	pub const NameLengthLimit: u32 = 20;
+	pub const MaxHistoryPeriods: u32 = 30;
+	pub const MaxCommissionTiers: u32 = 10;
 	pub BifrostCommissionReceiver: AccountId = TreasuryPalletId::get().into_account_truncating();
 }
 
@@ -1493,9 +1540,12 @@ impl bifrost_channel_commission::Config for Runtime {
 	type ControlOrigin = EitherOfDiverse<CoreAdminOrCouncil, LiquidStaking>;
 	type CommissionPalletId = CommissionPalletId;
 	type BifrostCommissionReceiver = BifrostCommissionReceiver;
+	type VtokenMintingInterface = VtokenMinting;
 	type WeightInfo = weights::bifrost_channel_commission::BifrostWeight<Runtime>;
 	type ClearingDuration = ClearingDuration;
 	type NameLengthLimit = NameLengthLimit;
+	type MaxHistoryPeriods = MaxHistoryPeriods;
+	type MaxCommissionTiers = MaxCommissionTiers;
 }
 
 impl bifrost_clouds_convert::Config for Runtime {
@@ -1784,6 +1834,12 @@ pub type SignedBlock = generic::SignedBlock<Block>;
 /// BlockId type as expected by this runtime.
 pub type BlockId = generic::BlockId<Block>;
 /// The SignedExtension to the basic transaction logic.
+///
+/// Since `transaction_version` 2 (`spec_version` 14001), `ChargeFlexibleFee` takes the place of
+/// `ChargeTransactionPayment`. Integrators must encode it as `(Compact<Balance> tip,
+/// FeePreference { currency_id: Option<CurrencyId>, max_fee: Option<Balance> },
+/// Option<AccountId> sponsor)` instead of the tip alone, with no additional signed data. The
+/// defaults `(tip, (None, None), None)` charge the signer as before.
 pub type SignedExtra = (
 	frame_system::CheckNonZeroSender<Runtime>,
 	frame_system::CheckSpecVersion<Runtime>,
@@ -1792,7 +1848,7 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	bifrost_flexible_fee::ChargeFlexibleFee<Runtime>,
 	frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
@@ -1830,7 +1886,10 @@ pub mod migrations {
 		pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 		bifrost_cross_in_out::migrations::v3::MigrateToV2<Runtime>,
 		bifrost_asset_registry::migrations::v1::MigrateToV1<Runtime>,
+		bifrost_vesting::migrations::v2::MigrateToV2<Runtime>,
 		bifrost_slpx::migration::v2::MigrateToV2<Runtime>,
+		bifrost_slpx::migration::v3::MigrateToV3<Runtime>,
+		bifrost_channel_commission::migration::v1::MigrateToV1<Runtime>,
 		frame_support::migrations::RemovePallet<SystemMakerName, RocksDbWeight>,
 		bifrost_system_staking::migrations::v1::MigrateToV1<Runtime>,
 		lend_market::migrations::v1::MigrateToV1<Runtime>,
@@ -2050,8 +2109,8 @@ impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
 							_ => (None, None),
 						};
 
-			// don't allow calling EVM RPC or Runtime API from a bound address
-			if EVMAccounts::bound_account_id(from).is_some() {
+			// don't allow calling EVM RPC or Runtime API from a bound address, unless it was claimed
+			if EVMAccounts::bound_account_id(from).is_some() && EVMAccounts::claimed_account_id(from).is_none() {
 				return Err(pallet_evm_accounts::Error::<Runtime>::BoundAddressCannotBeUsed.into())
 			};
 
@@ -2131,8 +2190,8 @@ impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
 					_ => (None, None),
 				};
 
-			// don't allow calling EVM RPC or Runtime API from a bound address
-			if EVMAccounts::bound_account_id(from).is_some() {
+			// don't allow calling EVM RPC or Runtime API from a bound address, unless it was claimed
+			if EVMAccounts::bound_account_id(from).is_some() && EVMAccounts::claimed_account_id(from).is_none() {
 				return Err(pallet_evm_accounts::Error::<Runtime>::BoundAddressCannotBeUsed.into())
 				};
 
@@ -2225,6 +2284,7 @@ impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_evm_accounts_rpc_runtime_api::EvmAccountsApi<Block, AccountId, H160> for Runtime {
 		fn evm_address(account_id: AccountId) -> H160 {
 			EVMAccounts::evm_address(&account_id)
@@ -2235,6 +2295,9 @@ impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
 		fn account_id(evm_address: H160) -> AccountId {
 			EVMAccounts::account_id(evm_address)
 		}
+		fn bound_evm_address(account_id: AccountId) -> Option<H160> {
+			EVMAccounts::bound_evm_address(&account_id)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
@@ -2309,9 +2372,17 @@ impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
 
 	impl bifrost_flexible_fee_rpc_runtime_api::FlexibleFeeRuntimeApi<Block, AccountId> for Runtime {
 		fn get_fee_token_and_amount(who: AccountId, fee: Balance,utx: <Block as BlockT>::Extrinsic) -> (CurrencyId, Balance) {
+			let preference = utx
+				.0
+				.signature
+				.as_ref()
+				.and_then(|(_, _, extra)| {
+					bifrost_flexible_fee::FeePreferenceOf::<Runtime>::fee_preference(extra)
+				})
+				.unwrap_or_default();
 			let call = utx.0.function;
 
-			let rs = FlexibleFee::cal_fee_token_and_amount(&who, fee, &call);
+			let rs = FlexibleFee::cal_fee_token_and_amount(&who, fee, &call, preference);
 
 			match rs {
 				Ok(val) => val,
@@ -2437,6 +2508,17 @@ impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
 		}
 	}
 
+	impl bifrost_channel_commission_rpc_runtime_api::ChannelCommissionRuntimeApi<Block> for Runtime {
+		fn get_channel_settlement_history(channel_id: u32) -> Vec<bifrost_primitives::ChannelSettlement<Balance>> {
+			ChannelCommission::get_channel_settlement_history(channel_id)
+		}
+
+		fn get_period_leaderboard(vtoken: CurrencyId, limit: u32) -> Vec<bifrost_primitives::ChannelRanking<Balance>> {
+			ChannelCommission::get_period_leaderboard(vtoken, limit)
+		}
+	}
+
+	#[api_version(2)]
 	impl bifrost_stable_pool_rpc_runtime_api::StablePoolRuntimeApi<Block> for Runtime {
 		fn get_swap_output(
 			pool_id: u32,
@@ -2453,6 +2535,73 @@ impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
 		) -> Balance {
 			StablePool::add_liquidity_amount(pool_id, amounts).unwrap_or(Zero::zero())
 		}
+
+		fn get_route_swap_output(
+			currency_id_in: CurrencyId,
+			currency_id_out: CurrencyId,
+			amount: Balance,
+		) -> Balance {
+			StablePool::get_route_swap_output(currency_id_in, currency_id_out, amount).unwrap_or(Zero::zero())
+		}
+
+		fn get_route_swap_input(
+			currency_id_in: CurrencyId,
+			currency_id_out: CurrencyId,
+			amount: Balance,
+		) -> Balance {
+			StablePool::get_route_swap_input(currency_id_in, currency_id_out, amount).unwrap_or(Zero::zero())
+		}
+
+		fn get_best_route(
+			currency_id_in: CurrencyId,
+			currency_id_out: CurrencyId,
+			amount: Balance,
+			exact_in: bool,
+		) -> Vec<bifrost_primitives::SwapRouteStep<CurrencyId>> {
+			StablePool::find_best_route(currency_id_in, currency_id_out, amount, exact_in)
+				.map(|(route, _)| route)
+				.unwrap_or_default()
+		}
+	}
+
+	impl bifrost_asset_registry_rpc_runtime_api::AssetRegistryRuntimeApi<Block, AccountId> for Runtime {
+		fn get_assets() -> Vec<bifrost_primitives::AssetInfo<AccountId, Balance>> {
+			AssetRegistry::get_assets()
+		}
+	}
+
+	impl bifrost_cross_in_out_rpc_runtime_api::CrossInOutRuntimeApi<Block, AccountId> for Runtime {
+		fn get_cross_out_requests(who: AccountId) -> Vec<(u64, bifrost_primitives::CrossOutRequest<AccountId, Balance, BlockNumber>)> {
+			CrossInOut::get_cross_out_requests(&who)
+		}
+	}
+
+	impl bifrost_vesting_rpc_runtime_api::VestingRuntimeApi<Block, AccountId> for Runtime {
+		fn get_vesting_schedules(who: AccountId) -> Vec<bifrost_primitives::VestingScheduleDetail<Balance, BlockNumber>> {
+			Vesting::get_vesting_schedules(&who)
+		}
+	}
+
+	impl pallet_prices_rpc_runtime_api::PricesApi<Block, BlockNumber> for Runtime {
+		fn get_price(asset_id: CurrencyId) -> Option<bifrost_primitives::PriceDetail> {
+			<Prices as bifrost_primitives::OraclePriceProvider>::get_price(&asset_id)
+		}
+
+		fn get_emergency_price(asset_id: CurrencyId) -> Option<bifrost_primitives::PriceDetail> {
+			Prices::get_emergency_price(&asset_id)
+		}
+
+		fn get_derived_price(asset_id: CurrencyId) -> Option<bifrost_primitives::PriceDetail> {
+			Prices::get_derived_price(&asset_id)
+		}
+
+		fn get_price_history(asset_id: CurrencyId, from: BlockNumber, to: BlockNumber) -> Vec<bifrost_primitives::PriceSnapshot<BlockNumber>> {
+			Prices::get_price_history(&asset_id, from, to)
+		}
+
+		fn get_price_at(asset_id: CurrencyId, block_number: BlockNumber) -> Option<bifrost_primitives::PriceSnapshot<BlockNumber>> {
+			Prices::get_price_at(&asset_id, block_number)
+		}
 	}
 
 	impl bifrost_vtoken_minting_rpc_runtime_api::VtokenMintingRuntimeApi<Block, CurrencyId, Balance> for Runtime {
@@ -233,7 +233,7 @@ pub mod v1 {
 
 pub mod slpx_migrates_whitelist {
 	use super::*;
-	use bifrost_slpx::types::SupportChain;
+	use bifrost_slpx::types::{ASTAR_DESTINATION, MOONBEAM_DESTINATION};
 	use sp_core::crypto::Ss58Codec;
 
 	pub struct UpdateWhitelist;
@@ -249,7 +249,7 @@ pub mod slpx_migrates_whitelist {
 			.try_into()
 			.unwrap();
 			bifrost_slpx::WhitelistAccountId::<Runtime>::insert(
-				SupportChain::Moonbeam,
+				MOONBEAM_DESTINATION,
 				new_whitelist,
 			);
 
@@ -261,15 +261,14 @@ pub mod slpx_migrates_whitelist {
 			]
 			.try_into()
 			.unwrap();
-			bifrost_slpx::WhitelistAccountId::<Runtime>::insert(SupportChain::Astar, new_whitelist);
+			bifrost_slpx::WhitelistAccountId::<Runtime>::insert(ASTAR_DESTINATION, new_whitelist);
 
 			Weight::from(<Runtime as frame_system::Config>::DbWeight::get().writes(2u64))
 		}
 
 		#[cfg(feature = "try-runtime")]
 		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
-			let whitelist =
-				bifrost_slpx::WhitelistAccountId::<Runtime>::get(SupportChain::Moonbeam);
+			let whitelist = bifrost_slpx::WhitelistAccountId::<Runtime>::get(MOONBEAM_DESTINATION);
 			let new_whitelist: BoundedVec<AccountId, ConstU32<10>> = vec![
 				AccountId::from_ss58check("gWEvf2EDMzxR7JHyrEHXf3nqxKLGvHaFbk7HUkJnNPUxDts")
 					.unwrap(),
@@ -280,7 +279,7 @@ pub mod slpx_migrates_whitelist {
 			.unwrap();
 			assert_eq!(whitelist, new_whitelist);
 
-			let whitelist = bifrost_slpx::WhitelistAccountId::<Runtime>::get(SupportChain::Astar);
+			let whitelist = bifrost_slpx::WhitelistAccountId::<Runtime>::get(ASTAR_DESTINATION);
 			let new_whitelist: BoundedVec<AccountId, ConstU32<10>> = vec![
 				AccountId::from_ss58check("g96o4GVpsAop1MJiArnmUYtXUjEisfkbfcpsuqmXrS28MEr")
 					.unwrap(),
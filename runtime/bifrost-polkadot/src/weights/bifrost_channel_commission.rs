@@ -148,19 +148,22 @@ impl<T: frame_system::Config> bifrost_channel_commission::WeightInfo for Bifrost
 			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
-	/// Storage: `ChannelCommission::ChannelNextId` (r:1 w:0)
-	/// Proof: `ChannelCommission::ChannelNextId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// The range of component `x` is `[1, 30]`.
-	fn on_initialize(x: u32, ) -> Weight {
+	// Storage: `ChannelCommission::ClearingCursor` (r:1 w:1)
+	// Proof: `ChannelCommission::ClearingCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::ClearingDelayed` (r:1 w:1)
+	// Proof: `ChannelCommission::ClearingDelayed` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::ClearingPeriod` (r:1 w:0)
+	// Proof: `ChannelCommission::ClearingPeriod` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::ClearingProgressAt` (r:0 w:1)
+	// Proof: `ChannelCommission::ClearingProgressAt` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn on_initialize() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `347`
-		//  Estimated: `1832`
-		// Minimum execution time: 0_000 picoseconds.
-		Weight::from_parts(133_647_144, 0)
-			.saturating_add(Weight::from_parts(0, 1832))
-			// Standard Error: 66_075
-			.saturating_add(Weight::from_parts(220_865, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+		//  Measured:  `142`
+		//  Estimated: `1627`
+		// Minimum execution time: 6_412_000 picoseconds.
+		Weight::from_parts(6_688_000, 1627)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 
 	fn set_channel_vtoken_shares(x: u32, ) -> Weight {
@@ -172,4 +175,133 @@ impl<T: frame_system::Config> bifrost_channel_commission::WeightInfo for Bifrost
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 2499).saturating_mul(x.into()))
 	}
+	// Storage: `ChannelCommission::Channels` (r:1 w:0)
+	// Proof: `ChannelCommission::Channels` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::CommissionTokens` (r:1 w:0)
+	// Proof: `ChannelCommission::CommissionTokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::ChannelCommissionTiers` (r:0 w:1)
+	// Proof: `ChannelCommission::ChannelCommissionTiers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_channel_commission_tiers() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `284`
+		//  Estimated: `3749`
+		// Minimum execution time: 37_456_000 picoseconds.
+		Weight::from_parts(38_615_000, 3749)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `ChannelCommission::Channels` (r:1 w:0)
+	// Proof: `ChannelCommission::Channels` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::ChannelAutoCompound` (r:0 w:1)
+	// Proof: `ChannelCommission::ChannelAutoCompound` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_channel_auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `219`
+		//  Estimated: `3684`
+		// Minimum execution time: 30_267_000 picoseconds.
+		Weight::from_parts(31_204_000, 3684)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `ChannelCommission::ClearingPeriod` (r:1 w:1)
+	// Proof: `ChannelCommission::ClearingPeriod` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::VtokenIssuanceSnapshots` (r:31 w:30)
+	// Proof: `ChannelCommission::VtokenIssuanceSnapshots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Tokens::TotalIssuance` (r:30 w:0)
+	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::PeriodVtokenTotalMint` (r:31 w:30)
+	// Proof: `ChannelCommission::PeriodVtokenTotalMint` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::PeriodVtokenTotalRedeem` (r:31 w:30)
+	// Proof: `ChannelCommission::PeriodVtokenTotalRedeem` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::PeriodChannelVtokenMint` (r:1801 w:1800)
+	// Proof: `ChannelCommission::PeriodChannelVtokenMint` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::PeriodTotalCommissions` (r:31 w:30)
+	// Proof: `ChannelCommission::PeriodTotalCommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// The range of component `x` is `[1, 60]`.
+	// The range of component `y` is `[1, 30]`.
+	fn set_clearing_environment(x: u32, y: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `217`
+		//  Estimated: `1627`
+		// Minimum execution time: 21_111_000 picoseconds.
+		Weight::from_parts(21_764_000, 1627)
+			// Standard Error: 187_600
+			.saturating_add(Weight::from_parts(9_380_000, 0).saturating_mul(x.into()))
+			// Standard Error: 353_640
+			.saturating_add(Weight::from_parts(17_682_000, 0).saturating_mul(y.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((30).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().reads((5).saturating_mul(y.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((30).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((4).saturating_mul(y.into())))
+	}
+	// Storage: `ChannelCommission::Channels` (r:1 w:0)
+	// Proof: `ChannelCommission::Channels` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::ChannelAutoCompound` (r:1 w:0)
+	// Proof: `ChannelCommission::ChannelAutoCompound` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::ClearingPeriod` (r:1 w:0)
+	// Proof: `ChannelCommission::ClearingPeriod` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::ChannelVtokenShares` (r:31 w:30)
+	// Proof: `ChannelCommission::ChannelVtokenShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::CommissionTokens` (r:30 w:0)
+	// Proof: `ChannelCommission::CommissionTokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::VtokenIssuanceSnapshots` (r:30 w:0)
+	// Proof: `ChannelCommission::VtokenIssuanceSnapshots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::PeriodTotalCommissions` (r:30 w:0)
+	// Proof: `ChannelCommission::PeriodTotalCommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::ChannelCommissionTiers` (r:30 w:0)
+	// Proof: `ChannelCommission::ChannelCommissionTiers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::ChannelCommissionTokenRates` (r:30 w:0)
+	// Proof: `ChannelCommission::ChannelCommissionTokenRates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::ChannelClaimableCommissions` (r:30 w:30)
+	// Proof: `ChannelCommission::ChannelClaimableCommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::PeriodClearedCommissions` (r:30 w:30)
+	// Proof: `ChannelCommission::PeriodClearedCommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::PeriodChannelVtokenMint` (r:30 w:0)
+	// Proof: `ChannelCommission::PeriodChannelVtokenMint` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::PeriodVtokenTotalMint` (r:30 w:0)
+	// Proof: `ChannelCommission::PeriodVtokenTotalMint` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::PeriodVtokenTotalRedeem` (r:30 w:0)
+	// Proof: `ChannelCommission::PeriodVtokenTotalRedeem` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::ChannelSettlementHistory` (r:0 w:2)
+	// Proof: `ChannelCommission::ChannelSettlementHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// The range of component `x` is `[1, 30]`.
+	fn clear_channel_commissions(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `455`
+		//  Estimated: `3684`
+		// Minimum execution time: 29_731_000 picoseconds.
+		Weight::from_parts(30_651_000, 3684)
+			// Standard Error: 967_880
+			.saturating_add(Weight::from_parts(48_394_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((11).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((4).saturating_mul(x.into())))
+	}
+	// Storage: `ChannelCommission::CommissionTokens` (r:31 w:0)
+	// Proof: `ChannelCommission::CommissionTokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::PeriodTotalCommissions` (r:30 w:0)
+	// Proof: `ChannelCommission::PeriodTotalCommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ChannelCommission::PeriodClearedCommissions` (r:30 w:30)
+	// Proof: `ChannelCommission::PeriodClearedCommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Tokens::Accounts` (r:60 w:60)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// The range of component `x` is `[1, 30]`.
+	fn clear_bifrost_commissions(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `266`
+		//  Estimated: `3593`
+		// Minimum execution time: 17_971_000 picoseconds.
+		Weight::from_parts(18_527_000, 3593)
+			// Standard Error: 824_140
+			.saturating_add(Weight::from_parts(41_207_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((4).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3).saturating_mul(x.into())))
+	}
 }
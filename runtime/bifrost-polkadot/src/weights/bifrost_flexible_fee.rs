@@ -74,4 +74,40 @@ impl<T: frame_system::Config> bifrost_flexible_fee::WeightInfo for BifrostWeight
 		Weight::from_parts(10_160_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: FlexibleFee SponsorPolicies (r:1 w:1)
+	// Proof Skipped: FlexibleFee SponsorPolicies (max_values: None, max_size: None, mode: Measured)
+	// The range of component `x` is `[0, 100]`.
+	fn register_sponsor_policy(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 23_776_000 picoseconds.
+		Weight::from_parts(24_512_000, 0)
+			.saturating_add(Weight::from_parts(112_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: FlexibleFee SponsorPolicies (r:1 w:1)
+	// Proof Skipped: FlexibleFee SponsorPolicies (max_values: None, max_size: None, mode: Measured)
+	// The range of component `x` is `[0, 100]`.
+	fn remove_sponsor_policy(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 19_277_000 picoseconds.
+		Weight::from_parts(19_874_000, 0)
+			.saturating_add(Weight::from_parts(24_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: FlexibleFee MinPoolFeeRates (r:0 w:1)
+	// Proof Skipped: FlexibleFee MinPoolFeeRates (max_values: None, max_size: None, mode: Measured)
+	fn set_min_pool_fee_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_915_000 picoseconds.
+		Weight::from_parts(11_403_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -247,6 +247,8 @@ impl<T: frame_system::Config> bifrost_vtoken_minting::WeightInfo for BifrostWeig
 	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
 	// Storage: VtokenMinting Fees (r:1 w:0)
 	// Proof: VtokenMinting Fees (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: VtokenMinting PrepaidRedeemFees (r:1 w:1)
+	// Proof: VtokenMinting PrepaidRedeemFees (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: Tokens Accounts (r:3 w:3)
 	// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
 	// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
@@ -257,8 +259,8 @@ impl<T: frame_system::Config> bifrost_vtoken_minting::WeightInfo for BifrostWeig
 		//  Estimated: `8769`
 		// Minimum execution time: 231_220 nanoseconds.
 		Weight::from_parts(235_084_000, 8769)
-			.saturating_add(T::DbWeight::get().reads(12))
-			.saturating_add(T::DbWeight::get().writes(10))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(11))
 	}
 	// Storage: VtokenMinting TokenToRebond (r:1 w:1)
 	// Proof: VtokenMinting TokenToRebond (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
@@ -276,6 +278,8 @@ impl<T: frame_system::Config> bifrost_vtoken_minting::WeightInfo for BifrostWeig
 	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
 	// Storage: VtokenMinting Fees (r:1 w:0)
 	// Proof: VtokenMinting Fees (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: VtokenMinting PrepaidRedeemFees (r:1 w:1)
+	// Proof: VtokenMinting PrepaidRedeemFees (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: Tokens Accounts (r:3 w:3)
 	// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
 	// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
@@ -286,8 +290,8 @@ impl<T: frame_system::Config> bifrost_vtoken_minting::WeightInfo for BifrostWeig
 		//  Estimated: `8769`
 		// Minimum execution time: 221_577 nanoseconds.
 		Weight::from_parts(223_798_000, 8769)
-			.saturating_add(T::DbWeight::get().reads(12))
-			.saturating_add(T::DbWeight::get().writes(10))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(11))
 	}
 	// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
 	// Proof: VtokenMinting OngoingTimeUnit (max_values: None, max_size: Some(27), added: 2502, mode: MaxEncodedLen)
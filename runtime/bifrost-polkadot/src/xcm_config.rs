@@ -17,7 +17,9 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use bifrost_asset_registry::AssetIdMaps;
+use bifrost_asset_registry::{
+	AssetIdMaps, ReceivableAssets, RegisteredReserves, RegisteredTeleporters, TransferableAssets,
+};
 use bifrost_currencies::BasicCurrencyAdapter;
 use bifrost_primitives::{
 	currency::WETH_TOKEN_ID, AccountId, AccountIdToLocation, AssetHubLocation, AssetPrefixFrom,
@@ -137,6 +139,7 @@ pub type BifrostAssetTransactor = MultiCurrencyAdapter<
 	CurrencyId,
 	CurrencyIdConvert<ParachainInfo, Runtime>,
 	DepositToAlternative<BifrostTreasuryAccount, Currencies, CurrencyId, AccountId, Balance>,
+	TransferableAssets<Runtime>,
 >;
 
 parameter_types! {
@@ -296,11 +299,17 @@ impl xcm_executor::Config for XcmConfig {
 	type Barrier = Barrier;
 	type RuntimeCall = RuntimeCall;
 	type IsReserve = (
-		NativeAssetFrom<AssetHubLocation>,
-		AssetPrefixFrom<EthereumLocation, AssetHubLocation>,
-		MultiNativeAsset<RelativeReserveProvider>,
+		ReceivableAssets<
+			Runtime,
+			(
+				NativeAssetFrom<AssetHubLocation>,
+				AssetPrefixFrom<EthereumLocation, AssetHubLocation>,
+				MultiNativeAsset<RelativeReserveProvider>,
+			),
+		>,
+		RegisteredReserves<Runtime>,
 	);
-	type IsTeleporter = ();
+	type IsTeleporter = RegisteredTeleporters<Runtime>;
 	type UniversalLocation = PolkadotUniversalLocation;
 	type OriginConverter = XcmOriginToTransactDispatchOrigin;
 	type ResponseHandler = PolkadotXcm;
@@ -417,6 +426,7 @@ impl bifrost_currencies::Config for Runtime {
 	type GetNativeCurrencyId = NativeCurrencyId;
 	type MultiCurrency = Tokens;
 	type NativeCurrency = BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
+	type TransferFilter = TransferableAssets<Runtime>;
 	type WeightInfo = weights::bifrost_currencies::WeightInfo<Runtime>;
 }
 
@@ -472,8 +482,8 @@ impl MutationHooks<AccountId, CurrencyId, Balance> for CurrencyHooks {
 	type OnSlash = ();
 	type PreDeposit = ();
 	type PostDeposit = ();
-	type PreTransfer = ();
-	type PostTransfer = ();
+	type PreTransfer = bifrost_currencies::TransferFilterHook<Runtime>;
+	type PostTransfer = Farming;
 	type OnNewTokenAccount = ();
 	type OnKilledTokenAccount = ();
 }
@@ -540,3 +550,62 @@ impl bifrost_xcm_interface::Config for Runtime {
 	type XcmRouter = XcmRouter;
 	type WeightInfo = weights::bifrost_xcm_interface::BifrostWeight<Runtime>;
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bifrost_asset_registry::AssetStatuses;
+	use bifrost_primitives::AssetStatus;
+	use frame_support::{assert_noop, assert_ok};
+	use sp_runtime::BuildStorage;
+
+	#[test]
+	fn frozen_asset_can_not_be_transferred_with_tokens() {
+		let mut ext: sp_io::TestExternalities =
+			frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap().into();
+		ext.execute_with(|| {
+			let alice = AccountId::new([1u8; 32]);
+			let bob = AccountId::new([2u8; 32]);
+			let currency_id = CurrencyId::Token2(DOT_TOKEN_ID);
+			assert_ok!(Tokens::deposit(currency_id, &alice, 1_000_000_000_000));
+			AssetStatuses::<Runtime>::insert(currency_id, AssetStatus::Frozen);
+
+			assert_noop!(
+				Tokens::transfer(
+					RuntimeOrigin::signed(alice.clone()),
+					bob.clone().into(),
+					currency_id,
+					100_000_000_000
+				),
+				bifrost_currencies::Error::<Runtime>::CurrencyNotTransferable
+			);
+			assert_noop!(
+				Tokens::transfer_keep_alive(
+					RuntimeOrigin::signed(alice.clone()),
+					bob.clone().into(),
+					currency_id,
+					100_000_000_000
+				),
+				bifrost_currencies::Error::<Runtime>::CurrencyNotTransferable
+			);
+			assert_noop!(
+				Tokens::transfer_all(
+					RuntimeOrigin::signed(alice.clone()),
+					bob.clone().into(),
+					currency_id,
+					false
+				),
+				bifrost_currencies::Error::<Runtime>::CurrencyNotTransferable
+			);
+
+			AssetStatuses::<Runtime>::remove(currency_id);
+			assert_ok!(Tokens::transfer(
+				RuntimeOrigin::signed(alice),
+				bob.clone().into(),
+				currency_id,
+				100_000_000_000
+			));
+			assert_eq!(Tokens::free_balance(currency_id, &bob), 100_000_000_000);
+		});
+	}
+}
@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use frame_support::traits::Get;
+use frame_support::traits::{Contains, Get};
 use orml_xcm_support::UnknownAsset as UnknownAssetT;
 use parity_scale_codec::FullCodec;
 use sp_runtime::{
@@ -48,6 +48,8 @@ enum Error {
 	AccountIdConversionFailed,
 	/// `CurrencyId` conversion failed.
 	CurrencyIdConversionFailed,
+	/// The currency can't be transferred.
+	CurrencyNotTransferable,
 }
 
 impl From<Error> for XcmError {
@@ -59,6 +61,8 @@ impl From<Error> for XcmError {
 				XcmError::FailedToTransactAsset("AccountIdConversionFailed"),
 			Error::CurrencyIdConversionFailed =>
 				XcmError::FailedToTransactAsset("CurrencyIdConversionFailed"),
+			Error::CurrencyNotTransferable =>
+				XcmError::FailedToTransactAsset("CurrencyNotTransferable"),
 		}
 	}
 }
@@ -124,7 +128,8 @@ impl<
 /// errors.
 ///
 /// If the asset is known, deposit/withdraw will be handled by `MultiCurrency`,
-/// else by `UnknownAsset` if unknown.
+/// else by `UnknownAsset` if unknown. Known currencies not in `TransferFilter` can't be
+/// deposited, withdrawn or transferred.
 #[allow(clippy::type_complexity)]
 pub struct MultiCurrencyAdapter<
 	MultiCurrency,
@@ -135,6 +140,7 @@ pub struct MultiCurrencyAdapter<
 	CurrencyId,
 	CurrencyIdConvert,
 	DepositFailureHandler,
+	TransferFilter,
 >(
 	PhantomData<(
 		MultiCurrency,
@@ -145,6 +151,7 @@ pub struct MultiCurrencyAdapter<
 		CurrencyId,
 		CurrencyIdConvert,
 		DepositFailureHandler,
+		TransferFilter,
 	)>,
 );
 
@@ -157,6 +164,7 @@ impl<
 		CurrencyId: FullCodec + Eq + PartialEq + Copy + MaybeSerializeDeserialize + Debug,
 		CurrencyIdConvert: Convert<Asset, Option<CurrencyId>>,
 		DepositFailureHandler: OnDepositFail<CurrencyId, AccountId, MultiCurrency::Balance>,
+		TransferFilter: Contains<CurrencyId>,
 	> TransactAsset
 	for MultiCurrencyAdapter<
 		MultiCurrency,
@@ -167,6 +175,7 @@ impl<
 		CurrencyId,
 		CurrencyIdConvert,
 		DepositFailureHandler,
+		TransferFilter,
 	>
 {
 	fn deposit_asset(
@@ -179,6 +188,9 @@ impl<
 			CurrencyIdConvert::convert(asset.clone()),
 			Match::matches_fungible(asset),
 		) {
+			// known asset that can't be transferred, it is trapped
+			(_, Some(currency_id), Some(_)) if !TransferFilter::contains(&currency_id) =>
+				Err(Error::CurrencyNotTransferable.into()),
 			// known asset
 			(Some(who), Some(currency_id), Some(amount)) =>
 				MultiCurrency::deposit(currency_id, &who, amount).or_else(|err| {
@@ -204,6 +216,9 @@ impl<
 				.ok_or(XcmError::from(Error::AccountIdConversionFailed))?;
 			let currency_id = CurrencyIdConvert::convert(asset.clone())
 				.ok_or_else(|| XcmError::from(Error::CurrencyIdConversionFailed))?;
+			if !TransferFilter::contains(&currency_id) {
+				return Err(Error::CurrencyNotTransferable.into());
+			}
 			let amount: MultiCurrency::Balance = Match::matches_fungible(asset)
 				.ok_or_else(|| XcmError::from(Error::FailedToMatchFungible))?
 				.saturated_into();
@@ -226,6 +241,9 @@ impl<
 			.ok_or(XcmError::from(Error::AccountIdConversionFailed))?;
 		let currency_id = CurrencyIdConvert::convert(asset.clone())
 			.ok_or_else(|| XcmError::from(Error::CurrencyIdConversionFailed))?;
+		if !TransferFilter::contains(&currency_id) {
+			return Err(Error::CurrencyNotTransferable.into());
+		}
 		let amount: MultiCurrency::Balance = Match::matches_fungible(asset)
 			.ok_or_else(|| XcmError::from(Error::FailedToMatchFungible))?
 			.saturated_into();